@@ -0,0 +1,12 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_clock_correction_requests_one_pending;
+DROP INDEX IF EXISTS idx_clock_correction_requests_pending;
+DROP INDEX IF EXISTS idx_clock_correction_requests_user;
+DROP INDEX IF EXISTS idx_clock_correction_requests_entry;
+DROP INDEX IF EXISTS idx_clock_correction_requests_org;
+
+-- Drop table
+DROP TABLE IF EXISTS clock_correction_requests;
+
+-- Drop enum
+DROP TYPE IF EXISTS clock_correction_status;
@@ -0,0 +1,35 @@
+-- Clock Correction Status Enum
+-- pending: Waiting for manager review
+-- approved: Correction applied to the clock entry
+-- rejected: Correction refused, clock entry unchanged
+CREATE TYPE clock_correction_status AS ENUM ('pending', 'approved', 'rejected');
+
+-- Clock Correction Requests Table
+-- Employees propose corrected clock_in/clock_out values for one of their entries.
+-- Original values are kept so the history of an entry can always be reconstructed.
+CREATE TABLE clock_correction_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    clock_entry_id UUID NOT NULL REFERENCES clock_entries(id) ON DELETE CASCADE,
+    original_clock_in TIMESTAMPTZ NOT NULL,   -- Entry values when the request was made
+    original_clock_out TIMESTAMPTZ,           -- NULL if the entry was still open
+    requested_clock_in TIMESTAMPTZ NOT NULL,
+    requested_clock_out TIMESTAMPTZ NOT NULL,
+    reason TEXT NOT NULL,
+    status clock_correction_status NOT NULL DEFAULT 'pending',
+    reviewed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    review_notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_clock_corrections_times CHECK (requested_clock_out > requested_clock_in)
+);
+
+-- Indexes for performance
+CREATE INDEX idx_clock_correction_requests_org ON clock_correction_requests(organization_id);
+CREATE INDEX idx_clock_correction_requests_entry ON clock_correction_requests(clock_entry_id);
+CREATE INDEX idx_clock_correction_requests_user ON clock_correction_requests(user_id);
+CREATE INDEX idx_clock_correction_requests_pending ON clock_correction_requests(organization_id, status) WHERE status = 'pending';
+
+-- Only one pending correction per clock entry
+CREATE UNIQUE INDEX idx_clock_correction_requests_one_pending ON clock_correction_requests(clock_entry_id) WHERE status = 'pending';
@@ -0,0 +1,6 @@
+-- Remove source tracking from clock_entries table
+ALTER TABLE clock_entries
+DROP COLUMN created_by,
+DROP COLUMN source;
+
+DROP TYPE IF EXISTS clock_entry_source;
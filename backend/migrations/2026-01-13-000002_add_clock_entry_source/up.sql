@@ -0,0 +1,13 @@
+-- Clock Entry Source Enum
+-- clock: Created by the user clocking in/out
+-- manual: Recorded retroactively by a manager or admin on behalf of the user
+CREATE TYPE clock_entry_source AS ENUM ('clock', 'manual');
+
+ALTER TABLE clock_entries
+ADD COLUMN source clock_entry_source NOT NULL DEFAULT 'clock',
+ADD COLUMN created_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN clock_entries.source IS
+'How the entry was created. Manual entries are recorded by a manager or admin.';
+COMMENT ON COLUMN clock_entries.created_by IS
+'User who recorded a manual entry. NULL for entries created by clocking in.';
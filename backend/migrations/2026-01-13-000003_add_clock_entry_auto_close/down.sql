@@ -0,0 +1,6 @@
+-- Note: PostgreSQL cannot drop a value from an enum type,
+-- 'clock_auto_closed' remains in notification_type.
+DROP INDEX IF EXISTS idx_clock_entries_open;
+
+ALTER TABLE clock_entries
+DROP COLUMN auto_closed;
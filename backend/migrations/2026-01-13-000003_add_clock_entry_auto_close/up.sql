@@ -0,0 +1,12 @@
+-- Track clock entries closed automatically by the background job
+-- Such entries stay pending until a manager reviews them
+ALTER TABLE clock_entries
+ADD COLUMN auto_closed BOOLEAN NOT NULL DEFAULT false;
+
+COMMENT ON COLUMN clock_entries.auto_closed IS
+'True when the entry was left open and closed by the auto-close job. Requires review.';
+
+CREATE INDEX idx_clock_entries_open ON clock_entries(clock_in) WHERE clock_out IS NULL;
+
+-- Notification sent to the employee and their managers when an entry is auto-closed
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'clock_auto_closed';
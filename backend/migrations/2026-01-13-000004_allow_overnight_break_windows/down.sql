@@ -0,0 +1,5 @@
+-- Note: fails if overnight break windows exist, delete them first
+ALTER TABLE break_windows DROP CONSTRAINT IF EXISTS break_windows_check;
+
+ALTER TABLE break_windows
+ADD CONSTRAINT break_windows_check CHECK (window_start < window_end);
@@ -0,0 +1,6 @@
+-- Allow break windows spanning midnight for overnight shifts
+-- A window_end before window_start means the window ends the next day
+ALTER TABLE break_windows DROP CONSTRAINT IF EXISTS break_windows_check;
+
+ALTER TABLE break_windows
+ADD CONSTRAINT break_windows_check CHECK (window_start <> window_end);
@@ -0,0 +1,22 @@
+-- Remove geofencing
+DROP INDEX IF EXISTS idx_clock_entries_outside_geofence;
+
+ALTER TABLE clock_entries
+DROP COLUMN IF EXISTS outside_geofence,
+DROP COLUMN IF EXISTS work_site_id,
+DROP COLUMN IF EXISTS clock_out_accuracy,
+DROP COLUMN IF EXISTS clock_out_longitude,
+DROP COLUMN IF EXISTS clock_out_latitude,
+DROP COLUMN IF EXISTS clock_in_accuracy,
+DROP COLUMN IF EXISTS clock_in_longitude,
+DROP COLUMN IF EXISTS clock_in_latitude;
+
+ALTER TABLE clock_restrictions
+DROP COLUMN IF EXISTS geofence_mode;
+
+DROP TRIGGER IF EXISTS set_work_sites_updated_at ON work_sites;
+DROP INDEX IF EXISTS idx_work_sites_active;
+DROP INDEX IF EXISTS idx_work_sites_org;
+DROP TABLE IF EXISTS work_sites;
+
+DROP TYPE IF EXISTS geofence_mode;
@@ -0,0 +1,53 @@
+-- Geofence Mode Enum
+-- off: Position is neither required nor checked
+-- record_only: Position is checked and stored, entries outside every site are flagged
+-- enforce: Clocking in/out is refused outside every active work site
+CREATE TYPE geofence_mode AS ENUM ('off', 'record_only', 'enforce');
+
+-- Work Sites Table
+-- Physical locations of an organization, described by a center and a radius
+CREATE TABLE work_sites (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    latitude DOUBLE PRECISION NOT NULL CHECK (latitude BETWEEN -90 AND 90),
+    longitude DOUBLE PRECISION NOT NULL CHECK (longitude BETWEEN -180 AND 180),
+    radius_meters INTEGER NOT NULL CHECK (radius_meters > 0),
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_work_sites_org ON work_sites(organization_id);
+CREATE INDEX idx_work_sites_active ON work_sites(organization_id, is_active) WHERE is_active = true;
+
+-- Trigger to update work_sites.updated_at
+CREATE TRIGGER set_work_sites_updated_at
+    BEFORE UPDATE ON work_sites
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Geofence mode on clock restrictions, evaluated independently of the time window mode
+ALTER TABLE clock_restrictions
+ADD COLUMN geofence_mode geofence_mode NOT NULL DEFAULT 'off';
+
+-- Position captured when clocking in/out
+ALTER TABLE clock_entries
+ADD COLUMN clock_in_latitude DOUBLE PRECISION,
+ADD COLUMN clock_in_longitude DOUBLE PRECISION,
+ADD COLUMN clock_in_accuracy DOUBLE PRECISION,
+ADD COLUMN clock_out_latitude DOUBLE PRECISION,
+ADD COLUMN clock_out_longitude DOUBLE PRECISION,
+ADD COLUMN clock_out_accuracy DOUBLE PRECISION,
+ADD COLUMN work_site_id UUID REFERENCES work_sites(id) ON DELETE SET NULL,
+ADD COLUMN outside_geofence BOOLEAN NOT NULL DEFAULT false;
+
+COMMENT ON COLUMN clock_entries.clock_in_accuracy IS
+'Accuracy radius in meters reported by the client for the clock-in position.';
+COMMENT ON COLUMN clock_entries.work_site_id IS
+'Work site matched by the clock-in position, if any.';
+COMMENT ON COLUMN clock_entries.outside_geofence IS
+'True when a geofenced clock in/out happened outside every work site or without a position.';
+
+CREATE INDEX idx_clock_entries_outside_geofence ON clock_entries(organization_id)
+WHERE outside_geofence = true;
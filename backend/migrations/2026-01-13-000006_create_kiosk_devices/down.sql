@@ -0,0 +1,15 @@
+-- Remove kiosk devices and PINs
+ALTER TABLE clock_entries
+DROP COLUMN IF EXISTS kiosk_device_id;
+
+-- Note: PostgreSQL cannot drop a value from an enum type,
+-- 'kiosk' remains in clock_entry_source. Kiosk entries become regular clock entries.
+UPDATE clock_entries SET source = 'clock' WHERE source::text = 'kiosk';
+
+DROP TRIGGER IF EXISTS set_kiosk_pins_updated_at ON kiosk_pins;
+DROP INDEX IF EXISTS idx_kiosk_pins_org;
+DROP TABLE IF EXISTS kiosk_pins;
+
+DROP TRIGGER IF EXISTS set_kiosk_devices_updated_at ON kiosk_devices;
+DROP INDEX IF EXISTS idx_kiosk_devices_org;
+DROP TABLE IF EXISTS kiosk_devices;
@@ -0,0 +1,48 @@
+-- Kiosk Devices Table
+-- Shared terminals where employees clock in/out with a PIN.
+-- The device authenticates with a long-lived credential, only its SHA-256 hash is stored.
+CREATE TABLE kiosk_devices (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    work_site_id UUID REFERENCES work_sites(id) ON DELETE SET NULL, -- Where the device is installed
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    last_used_at TIMESTAMPTZ,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_kiosk_devices_org ON kiosk_devices(organization_id);
+
+CREATE TRIGGER set_kiosk_devices_updated_at
+    BEFORE UPDATE ON kiosk_devices
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Kiosk PINs Table
+-- One argon2-hashed PIN per employee, used on kiosk devices
+CREATE TABLE kiosk_pins (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    pin_hash VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_kiosk_pins_org ON kiosk_pins(organization_id);
+
+CREATE TRIGGER set_kiosk_pins_updated_at
+    BEFORE UPDATE ON kiosk_pins
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Entries created from a kiosk device
+ALTER TYPE clock_entry_source ADD VALUE IF NOT EXISTS 'kiosk';
+
+ALTER TABLE clock_entries
+ADD COLUMN kiosk_device_id UUID REFERENCES kiosk_devices(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN clock_entries.kiosk_device_id IS
+'Kiosk device used to clock in. NULL for entries created from a personal session.';
@@ -0,0 +1,13 @@
+-- Remove offline clock sync
+ALTER TABLE clock_entries
+DROP COLUMN IF EXISTS clock_drift_seconds;
+
+-- Note: PostgreSQL cannot drop a value from an enum type,
+-- 'sync' remains in clock_entry_source. Synced entries become regular clock entries.
+UPDATE clock_entries SET source = 'clock' WHERE source::text = 'sync';
+
+DROP INDEX IF EXISTS idx_clock_sync_events_entry;
+DROP INDEX IF EXISTS idx_clock_sync_events_org;
+DROP TABLE IF EXISTS clock_sync_events;
+
+DROP TYPE IF EXISTS clock_sync_status;
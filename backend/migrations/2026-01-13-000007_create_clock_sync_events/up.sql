@@ -0,0 +1,32 @@
+-- Offline clock sync
+-- Mobile devices record clock events while offline and upload them in batches.
+-- Every processed event is kept so that a retried upload is answered with the same result.
+CREATE TYPE clock_sync_status AS ENUM ('applied', 'flagged', 'rejected');
+
+CREATE TABLE clock_sync_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    device_id VARCHAR(100) NOT NULL,
+    idempotency_key VARCHAR(100) NOT NULL,
+    action VARCHAR(10) NOT NULL CHECK (action IN ('clock_in', 'clock_out')),
+    client_timestamp TIMESTAMPTZ NOT NULL, -- When the event happened, by the device clock
+    clock_drift_seconds INTEGER NOT NULL DEFAULT 0, -- Device clock minus server clock at upload
+    status clock_sync_status NOT NULL,
+    clock_entry_id UUID REFERENCES clock_entries(id) ON DELETE SET NULL,
+    message TEXT, -- Reason for rejection or flag
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, idempotency_key)
+);
+
+CREATE INDEX idx_clock_sync_events_org ON clock_sync_events(organization_id);
+CREATE INDEX idx_clock_sync_events_entry ON clock_sync_events(clock_entry_id);
+
+-- Entries replayed from an offline batch
+ALTER TYPE clock_entry_source ADD VALUE IF NOT EXISTS 'sync';
+
+ALTER TABLE clock_entries
+ADD COLUMN clock_drift_seconds INTEGER;
+
+COMMENT ON COLUMN clock_entries.clock_drift_seconds IS
+'Device clock drift of a synced entry when it exceeded the tolerance. Such entries need manager approval.';
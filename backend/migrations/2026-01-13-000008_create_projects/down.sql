@@ -0,0 +1,13 @@
+-- Remove projects, tasks and time allocations
+DROP INDEX IF EXISTS idx_time_allocations_user;
+DROP INDEX IF EXISTS idx_time_allocations_project;
+DROP INDEX IF EXISTS idx_time_allocations_entry;
+DROP TABLE IF EXISTS time_allocations;
+
+DROP TRIGGER IF EXISTS set_project_tasks_updated_at ON project_tasks;
+DROP INDEX IF EXISTS idx_project_tasks_project;
+DROP TABLE IF EXISTS project_tasks;
+
+DROP TRIGGER IF EXISTS set_projects_updated_at ON projects;
+DROP INDEX IF EXISTS idx_projects_org;
+DROP TABLE IF EXISTS projects;
@@ -0,0 +1,63 @@
+-- Projects Table
+-- Billable projects of an organization, clock time is allocated to them
+CREATE TABLE projects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    code VARCHAR(20), -- Short reference used on invoices
+    client_name VARCHAR(100),
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, name)
+);
+
+CREATE INDEX idx_projects_org ON projects(organization_id);
+
+CREATE TRIGGER set_projects_updated_at
+    BEFORE UPDATE ON projects
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Project Tasks Table
+CREATE TABLE project_tasks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (project_id, name)
+);
+
+CREATE INDEX idx_project_tasks_project ON project_tasks(project_id);
+
+CREATE TRIGGER set_project_tasks_updated_at
+    BEFORE UPDATE ON project_tasks
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Time Allocations Table
+-- Splits the worked time of a clock entry between projects.
+-- An allocation covers either a duration or a sub-segment (start/end) of the entry.
+-- Projects and tasks with allocations cannot be deleted, deactivate them instead.
+CREATE TABLE time_allocations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    clock_entry_id UUID NOT NULL REFERENCES clock_entries(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE RESTRICT,
+    task_id UUID REFERENCES project_tasks(id) ON DELETE RESTRICT,
+    segment_start TIMESTAMPTZ,
+    segment_end TIMESTAMPTZ,
+    minutes INTEGER NOT NULL CHECK (minutes > 0),
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((segment_start IS NULL) = (segment_end IS NULL)),
+    CHECK (segment_end > segment_start)
+);
+
+CREATE INDEX idx_time_allocations_entry ON time_allocations(clock_entry_id);
+CREATE INDEX idx_time_allocations_project ON time_allocations(project_id);
+CREATE INDEX idx_time_allocations_user ON time_allocations(user_id);
@@ -0,0 +1,8 @@
+-- Remove clock approval rules
+ALTER TABLE clock_entries
+DROP COLUMN IF EXISTS auto_approval_rule_id;
+
+DROP TRIGGER IF EXISTS set_clock_approval_rules_updated_at ON clock_approval_rules;
+DROP INDEX IF EXISTS idx_clock_approval_rules_team;
+DROP INDEX IF EXISTS idx_clock_approval_rules_org;
+DROP TABLE IF EXISTS clock_approval_rules;
@@ -0,0 +1,37 @@
+-- Clock Approval Rules Table
+-- Closed entries matching an active rule are approved automatically on clock out.
+-- Rules apply to a whole organization (team_id NULL) or to the members of a team.
+-- A NULL condition is not checked; a rule needs at least one condition.
+CREATE TABLE clock_approval_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    team_id UUID REFERENCES teams(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    -- Clock in and out within this many minutes of the scheduled shift
+    max_schedule_deviation_minutes INTEGER CHECK (max_schedule_deviation_minutes >= 0),
+    -- Entry duration at most this many minutes
+    max_duration_minutes INTEGER CHECK (max_duration_minutes > 0),
+    -- Whether entries made through a restriction override can match
+    allow_override BOOLEAN NOT NULL DEFAULT false,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, name),
+    CHECK (max_schedule_deviation_minutes IS NOT NULL OR max_duration_minutes IS NOT NULL)
+);
+
+CREATE INDEX idx_clock_approval_rules_org ON clock_approval_rules(organization_id);
+CREATE INDEX idx_clock_approval_rules_team ON clock_approval_rules(team_id);
+
+-- Trigger to update clock_approval_rules.updated_at
+CREATE TRIGGER set_clock_approval_rules_updated_at
+    BEFORE UPDATE ON clock_approval_rules
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Rule that approved an entry automatically
+ALTER TABLE clock_entries
+ADD COLUMN auto_approval_rule_id UUID REFERENCES clock_approval_rules(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN clock_entries.auto_approval_rule_id IS
+'Approval rule that approved the entry on clock out (NULL for manual approvals).';
@@ -0,0 +1,9 @@
+-- Remove pay periods
+DROP TRIGGER IF EXISTS set_pay_periods_updated_at ON pay_periods;
+DROP INDEX IF EXISTS idx_pay_periods_closed;
+DROP TABLE IF EXISTS pay_periods;
+
+DROP TRIGGER IF EXISTS set_pay_period_settings_updated_at ON pay_period_settings;
+DROP TABLE IF EXISTS pay_period_settings;
+
+DROP TYPE IF EXISTS pay_period_frequency;
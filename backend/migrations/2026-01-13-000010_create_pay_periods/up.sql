@@ -0,0 +1,50 @@
+-- Pay Period Frequency Enum
+-- weekly / bi_weekly: periods of 7 or 14 days counted from the anchor date
+-- semi_monthly: 1st to 15th and 16th to the end of the month
+-- monthly: calendar months
+CREATE TYPE pay_period_frequency AS ENUM ('weekly', 'bi_weekly', 'semi_monthly', 'monthly');
+
+-- Pay Period Settings Table
+-- One row per organization; organizations without a row use monthly periods
+CREATE TABLE pay_period_settings (
+    organization_id UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    frequency pay_period_frequency NOT NULL DEFAULT 'monthly',
+    anchor_date DATE NOT NULL DEFAULT '2024-01-01', -- First day of a reference weekly/bi-weekly period
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Trigger to update pay_period_settings.updated_at
+CREATE TRIGGER set_pay_period_settings_updated_at
+    BEFORE UPDATE ON pay_period_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Pay Periods Table
+-- Periods closed for payroll. Clock entries, breaks and absences dated inside a
+-- closed period cannot be modified. Reopened periods are kept for history.
+-- Dates are local to the organization's timezone.
+CREATE TABLE pay_periods (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    is_closed BOOLEAN NOT NULL DEFAULT true,
+    closed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    closed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reopened_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    reopened_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, start_date),
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_pay_periods_closed ON pay_periods(organization_id, start_date, end_date)
+WHERE is_closed = true;
+
+-- Trigger to update pay_periods.updated_at
+CREATE TRIGGER set_pay_periods_updated_at
+    BEFORE UPDATE ON pay_periods
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,15 @@
+-- Remove clock anomalies
+DROP TRIGGER IF EXISTS set_clock_anomalies_updated_at ON clock_anomalies;
+DROP INDEX IF EXISTS idx_clock_anomalies_user;
+DROP INDEX IF EXISTS idx_clock_anomalies_queue;
+DROP TABLE IF EXISTS clock_anomalies;
+
+DROP TRIGGER IF EXISTS set_anomaly_settings_updated_at ON anomaly_settings;
+DROP TABLE IF EXISTS anomaly_settings;
+
+ALTER TABLE clock_entries
+DROP COLUMN IF EXISTS clock_in_ip;
+
+DROP TYPE IF EXISTS anomaly_status;
+DROP TYPE IF EXISTS anomaly_severity;
+DROP TYPE IF EXISTS anomaly_kind;
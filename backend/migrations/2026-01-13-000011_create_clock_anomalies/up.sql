@@ -0,0 +1,64 @@
+-- Clock Anomaly Enums
+CREATE TYPE anomaly_kind AS ENUM (
+    'long_shift',         -- Shift longer than the configured maximum
+    'absence_overlap',    -- Entry on a day covered by an approved absence
+    'closed_day_overlap', -- Entry on an organization closed day
+    'rapid_clocking',     -- Many clock-ins within a short window
+    'new_ip',             -- Clock-in from an address never used by the user
+    'long_break'          -- Break longer than the policy's maximum duration
+);
+CREATE TYPE anomaly_severity AS ENUM ('low', 'medium', 'high');
+CREATE TYPE anomaly_status AS ENUM ('open', 'dismissed', 'resolved');
+
+-- Address the clock-in was made from (IPv4 or IPv6)
+ALTER TABLE clock_entries
+ADD COLUMN clock_in_ip VARCHAR(45);
+
+-- Anomaly Settings Table
+-- One row per organization; organizations without a row use the defaults
+CREATE TABLE anomaly_settings (
+    organization_id UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    max_shift_hours INTEGER NOT NULL DEFAULT 12 CHECK (max_shift_hours > 0),
+    rapid_clock_max_entries INTEGER NOT NULL DEFAULT 3 CHECK (rapid_clock_max_entries > 0),
+    rapid_clock_window_minutes INTEGER NOT NULL DEFAULT 60 CHECK (rapid_clock_window_minutes > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Trigger to update anomaly_settings.updated_at
+CREATE TRIGGER set_anomaly_settings_updated_at
+    BEFORE UPDATE ON anomaly_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Clock Anomalies Table
+-- Findings of the anomaly engine, reviewed by managers.
+-- One finding per entry and kind so that re-evaluation never duplicates or
+-- resurrects a dismissed finding.
+CREATE TABLE clock_anomalies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    clock_entry_id UUID NOT NULL REFERENCES clock_entries(id) ON DELETE CASCADE,
+    break_entry_id UUID REFERENCES break_entries(id) ON DELETE SET NULL,
+    kind anomaly_kind NOT NULL,
+    severity anomaly_severity NOT NULL,
+    message TEXT NOT NULL,
+    status anomaly_status NOT NULL DEFAULT 'open',
+    reviewed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    review_note TEXT,
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (clock_entry_id, kind)
+);
+
+CREATE INDEX idx_clock_anomalies_queue ON clock_anomalies(organization_id, status, detected_at);
+CREATE INDEX idx_clock_anomalies_user ON clock_anomalies(user_id);
+
+-- Trigger to update clock_anomalies.updated_at
+CREATE TRIGGER set_clock_anomalies_updated_at
+    BEFORE UPDATE ON clock_anomalies
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
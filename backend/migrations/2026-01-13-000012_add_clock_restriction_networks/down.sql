@@ -0,0 +1,3 @@
+-- Remove allowed_networks column from clock_restrictions table
+ALTER TABLE clock_restrictions
+DROP COLUMN allowed_networks;
@@ -0,0 +1,8 @@
+-- Add allowed_networks column to clock_restrictions table
+-- Empty = any network, otherwise clock actions must come from one of these CIDR ranges
+ALTER TABLE clock_restrictions
+ADD COLUMN allowed_networks TEXT[] NOT NULL DEFAULT '{}'
+CHECK (array_position(allowed_networks, NULL) IS NULL);
+
+COMMENT ON COLUMN clock_restrictions.allowed_networks IS
+'CIDR ranges clock actions must come from (e.g. 203.0.113.0/24). Empty means any network.';
@@ -0,0 +1,19 @@
+-- Note: fails if rotation-specific days or break windows exist, delete them first
+DROP INDEX IF EXISTS idx_break_windows_policy_week_day;
+
+ALTER TABLE break_windows DROP COLUMN IF EXISTS week_index;
+
+ALTER TABLE break_windows
+ADD CONSTRAINT break_windows_break_policy_id_day_of_week_key UNIQUE (break_policy_id, day_of_week);
+
+ALTER TABLE work_schedule_days
+DROP CONSTRAINT IF EXISTS work_schedule_days_schedule_week_day_key;
+
+ALTER TABLE work_schedule_days DROP COLUMN IF EXISTS week_index;
+
+ALTER TABLE work_schedule_days
+ADD CONSTRAINT work_schedule_days_work_schedule_id_day_of_week_key UNIQUE (work_schedule_id, day_of_week);
+
+ALTER TABLE work_schedules
+DROP COLUMN IF EXISTS cycle_anchor_date,
+DROP COLUMN IF EXISTS cycle_weeks;
@@ -0,0 +1,31 @@
+-- Rotating schedules: a schedule repeats every cycle_weeks weeks,
+-- the week containing cycle_anchor_date being rotation week 0
+ALTER TABLE work_schedules
+ADD COLUMN cycle_weeks SMALLINT NOT NULL DEFAULT 1 CHECK (cycle_weeks BETWEEN 1 AND 12),
+ADD COLUMN cycle_anchor_date DATE NOT NULL DEFAULT CURRENT_DATE;
+
+UPDATE work_schedules SET cycle_anchor_date = created_at::date;
+
+-- Schedule days are keyed by (rotation week, weekday)
+ALTER TABLE work_schedule_days
+ADD COLUMN week_index SMALLINT NOT NULL DEFAULT 0 CHECK (week_index >= 0);
+
+ALTER TABLE work_schedule_days
+DROP CONSTRAINT IF EXISTS work_schedule_days_work_schedule_id_day_of_week_key;
+
+ALTER TABLE work_schedule_days
+ADD CONSTRAINT work_schedule_days_schedule_week_day_key UNIQUE (work_schedule_id, week_index, day_of_week);
+
+-- Break windows may target one rotation week (NULL applies to every week)
+ALTER TABLE break_windows
+ADD COLUMN week_index SMALLINT CHECK (week_index >= 0);
+
+ALTER TABLE break_windows
+DROP CONSTRAINT IF EXISTS break_windows_break_policy_id_day_of_week_key;
+
+CREATE UNIQUE INDEX idx_break_windows_policy_week_day
+ON break_windows(break_policy_id, COALESCE(week_index, -1), day_of_week);
+
+COMMENT ON COLUMN work_schedules.cycle_weeks IS 'Number of weeks in the rotation (1 for a weekly schedule)';
+COMMENT ON COLUMN work_schedules.cycle_anchor_date IS 'A date in rotation week 0';
+COMMENT ON COLUMN break_windows.week_index IS 'Rotation week of the user schedule the window applies to (NULL for every week)';
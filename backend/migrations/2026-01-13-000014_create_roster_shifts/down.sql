@@ -0,0 +1,7 @@
+-- Remove roster shifts
+DROP TRIGGER IF EXISTS set_roster_shifts_updated_at ON roster_shifts;
+DROP INDEX IF EXISTS idx_roster_shifts_user_date;
+DROP INDEX IF EXISTS idx_roster_shifts_org_date;
+DROP TABLE IF EXISTS roster_shifts;
+
+DROP TYPE IF EXISTS roster_shift_status;
@@ -0,0 +1,34 @@
+-- Roster shift status
+-- Drafts are only visible to managers until their week is published
+CREATE TYPE roster_shift_status AS ENUM ('draft', 'published');
+
+-- Roster Shifts Table
+-- Concrete shifts planned for a date. A published shift takes precedence over
+-- the user's weekly work schedule on that date.
+CREATE TABLE roster_shifts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    team_id UUID REFERENCES teams(id) ON DELETE SET NULL,
+    shift_date DATE NOT NULL,
+    -- An end_time before start_time means the shift ends the next day
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL CHECK (end_time <> start_time),
+    break_minutes INTEGER NOT NULL DEFAULT 0 CHECK (break_minutes >= 0),
+    position VARCHAR(100),
+    notes TEXT,
+    status roster_shift_status NOT NULL DEFAULT 'draft',
+    published_at TIMESTAMPTZ,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_roster_shifts_org_date ON roster_shifts(organization_id, shift_date);
+CREATE INDEX idx_roster_shifts_user_date ON roster_shifts(user_id, shift_date);
+
+-- Trigger to update roster_shifts.updated_at
+CREATE TRIGGER set_roster_shifts_updated_at
+    BEFORE UPDATE ON roster_shifts
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,12 @@
+-- Remove shift swaps and roster settings
+-- Note: PostgreSQL cannot drop a value from an enum type,
+-- the shift_swap_* values remain in notification_type.
+DROP TRIGGER IF EXISTS set_shift_swap_requests_updated_at ON shift_swap_requests;
+DROP INDEX IF EXISTS idx_shift_swap_requests_org_status;
+DROP INDEX IF EXISTS idx_shift_swap_requests_active_shift;
+DROP TABLE IF EXISTS shift_swap_requests;
+
+DROP TRIGGER IF EXISTS set_roster_settings_updated_at ON roster_settings;
+DROP TABLE IF EXISTS roster_settings;
+
+DROP TYPE IF EXISTS shift_swap_status;
@@ -0,0 +1,62 @@
+-- Shift swap status
+CREATE TYPE shift_swap_status AS ENUM (
+    'open',             -- Offered to a colleague or released to the open pool
+    'pending_approval', -- Claimed, awaiting manager approval
+    'approved',         -- Applied: the shift now belongs to the claimant
+    'rejected',
+    'cancelled'
+);
+
+-- Roster Settings Table
+-- One row per organization; organizations without a row use the defaults
+CREATE TABLE roster_settings (
+    organization_id UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    swap_requires_approval BOOLEAN NOT NULL DEFAULT true,
+    -- Minimum rest between two shifts of the same employee
+    min_rest_hours INTEGER NOT NULL DEFAULT 11 CHECK (min_rest_hours BETWEEN 0 AND 24),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Trigger to update roster_settings.updated_at
+CREATE TRIGGER set_roster_settings_updated_at
+    BEFORE UPDATE ON roster_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Shift Swap Requests Table
+-- A published shift offered by its employee to a colleague (target_user_id)
+-- or to anyone (open pool), then claimed by a colleague
+CREATE TABLE shift_swap_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    shift_id UUID NOT NULL REFERENCES roster_shifts(id) ON DELETE CASCADE,
+    requester_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    target_user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    claimed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    claimed_at TIMESTAMPTZ,
+    status shift_swap_status NOT NULL DEFAULT 'open',
+    note TEXT,
+    reviewed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    review_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A shift can only be on offer once at a time
+CREATE UNIQUE INDEX idx_shift_swap_requests_active_shift ON shift_swap_requests(shift_id)
+    WHERE status IN ('open', 'pending_approval');
+CREATE INDEX idx_shift_swap_requests_org_status ON shift_swap_requests(organization_id, status);
+
+-- Trigger to update shift_swap_requests.updated_at
+CREATE TRIGGER set_shift_swap_requests_updated_at
+    BEFORE UPDATE ON shift_swap_requests
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Notifications sent to the participants of a swap
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'shift_swap_offered';
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'shift_swap_claimed';
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'shift_swap_approved';
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'shift_swap_rejected';
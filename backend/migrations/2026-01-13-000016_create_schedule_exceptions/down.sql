@@ -0,0 +1,4 @@
+-- Remove schedule exceptions
+DROP TRIGGER IF EXISTS set_schedule_exceptions_updated_at ON schedule_exceptions;
+DROP INDEX IF EXISTS idx_schedule_exceptions_org_date;
+DROP TABLE IF EXISTS schedule_exceptions;
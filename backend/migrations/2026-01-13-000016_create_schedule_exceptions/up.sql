@@ -0,0 +1,32 @@
+-- Schedule Exceptions Table
+-- One-off changes to a user's schedule on a single date: different hours
+-- (training, late start) or a day off. An exception replaces the schedule's
+-- day for that date.
+CREATE TABLE schedule_exceptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    exception_date DATE NOT NULL,
+    is_day_off BOOLEAN NOT NULL DEFAULT false,
+    -- An end_time before start_time means the shift ends the next day
+    start_time TIME,
+    end_time TIME,
+    break_minutes INTEGER NOT NULL DEFAULT 0 CHECK (break_minutes >= 0),
+    reason VARCHAR(255),
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, exception_date),
+    CHECK (
+        is_day_off
+        OR (start_time IS NOT NULL AND end_time IS NOT NULL AND start_time <> end_time)
+    )
+);
+
+CREATE INDEX idx_schedule_exceptions_org_date ON schedule_exceptions(organization_id, exception_date);
+
+-- Trigger to update schedule_exceptions.updated_at
+CREATE TRIGGER set_schedule_exceptions_updated_at
+    BEFORE UPDATE ON schedule_exceptions
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
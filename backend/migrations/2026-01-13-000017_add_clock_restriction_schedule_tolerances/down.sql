@@ -0,0 +1,4 @@
+-- Remove schedule tolerances from clock_restrictions table
+ALTER TABLE clock_restrictions
+DROP COLUMN schedule_late_minutes,
+DROP COLUMN schedule_early_minutes;
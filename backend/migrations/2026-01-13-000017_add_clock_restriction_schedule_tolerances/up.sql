@@ -0,0 +1,8 @@
+-- Add schedule tolerances to clock_restrictions table
+-- With enforce_schedule, clock in is allowed from start - early to start + late
+-- of a planned shift, and clock out from end - early to end + late
+ALTER TABLE clock_restrictions
+ADD COLUMN schedule_early_minutes INTEGER NOT NULL DEFAULT 15
+    CHECK (schedule_early_minutes BETWEEN 0 AND 720),
+ADD COLUMN schedule_late_minutes INTEGER NOT NULL DEFAULT 15
+    CHECK (schedule_late_minutes BETWEEN 0 AND 720);
@@ -0,0 +1,5 @@
+ALTER TABLE absences
+    DROP COLUMN end_half,
+    DROP COLUMN start_half;
+
+DROP TYPE IF EXISTS absence_half_day;
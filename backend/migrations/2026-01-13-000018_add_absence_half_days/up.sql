@@ -0,0 +1,14 @@
+-- Half-day absences
+-- Part of a date taken off: the whole day, or only its morning or afternoon
+CREATE TYPE absence_half_day AS ENUM (
+    'full',
+    'morning',
+    'afternoon'
+);
+
+-- start_half: part of start_date taken (full or afternoon when the absence spans several days)
+-- end_half: part of end_date taken (full or morning when the absence spans several days)
+-- Single-day absences use the same value for both
+ALTER TABLE absences
+    ADD COLUMN start_half absence_half_day NOT NULL DEFAULT 'full',
+    ADD COLUMN end_half absence_half_day NOT NULL DEFAULT 'full';
@@ -0,0 +1,16 @@
+ALTER TABLE leave_balances
+    ALTER COLUMN adjustment TYPE DECIMAL(4,1),
+    ALTER COLUMN used TYPE DECIMAL(4,1),
+    ALTER COLUMN initial_balance TYPE DECIMAL(4,1);
+
+ALTER TABLE absences
+    DROP CONSTRAINT chk_absences_hours,
+    ALTER COLUMN days_count TYPE DECIMAL(4,1),
+    DROP COLUMN hours,
+    DROP COLUMN end_time,
+    DROP COLUMN start_time;
+
+ALTER TABLE absence_types
+    DROP COLUMN unit;
+
+DROP TYPE IF EXISTS absence_unit;
@@ -0,0 +1,27 @@
+-- Hour-based absences
+-- Unit in which an absence type is requested and its balances are held
+CREATE TYPE absence_unit AS ENUM (
+    'days',
+    'hours'
+);
+
+ALTER TABLE absence_types
+    ADD COLUMN unit absence_unit NOT NULL DEFAULT 'days';
+
+-- Hourly absences cover start_time to end_time on a single date
+-- hours: requested duration, days_count: its share of the day's scheduled hours
+ALTER TABLE absences
+    ADD COLUMN start_time TIME,
+    ADD COLUMN end_time TIME,
+    ADD COLUMN hours DECIMAL(5,2),
+    ALTER COLUMN days_count TYPE DECIMAL(5,2),
+    ADD CONSTRAINT chk_absences_hours CHECK (
+        (start_time IS NULL AND end_time IS NULL AND hours IS NULL)
+        OR (start_date = end_date AND start_time < end_time AND hours > 0)
+    );
+
+-- Balances of hourly types are held in hours
+ALTER TABLE leave_balances
+    ALTER COLUMN initial_balance TYPE DECIMAL(6,2),
+    ALTER COLUMN used TYPE DECIMAL(6,2),
+    ALTER COLUMN adjustment TYPE DECIMAL(6,2);
@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS set_absence_settings_updated_at ON absence_settings;
+DROP TABLE IF EXISTS absence_settings;
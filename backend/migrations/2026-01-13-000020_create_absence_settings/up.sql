@@ -0,0 +1,17 @@
+-- Absence Settings Table
+-- One row per organization; organizations without a row use the defaults
+-- working_days: fallback working week for users without a work schedule
+-- (0 = Monday, 6 = Sunday, as in work_schedule_days.day_of_week)
+CREATE TABLE absence_settings (
+    organization_id UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    working_days SMALLINT[] NOT NULL DEFAULT '{0,1,2,3,4}'
+        CHECK (working_days <@ ARRAY[0,1,2,3,4,5,6]::SMALLINT[]),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Trigger to update absence_settings.updated_at
+CREATE TRIGGER set_absence_settings_updated_at
+    BEFORE UPDATE ON absence_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
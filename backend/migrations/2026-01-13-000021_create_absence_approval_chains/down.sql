@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_absence_approvals_absence;
+DROP TABLE IF EXISTS absence_approvals;
+DROP TABLE IF EXISTS absence_approval_steps;
@@ -0,0 +1,29 @@
+-- Absence Approval Steps Table
+-- Approval chain of an absence type, walked in step_order
+-- min_days: the step only applies to absences of more than this many days
+-- Types without steps need a single approval from a manager or admin
+CREATE TABLE absence_approval_steps (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    absence_type_id UUID NOT NULL REFERENCES absence_types(id) ON DELETE CASCADE,
+    step_order SMALLINT NOT NULL CHECK (step_order >= 1),
+    approver_role user_role NOT NULL CHECK (approver_role IN ('manager', 'admin')),
+    min_days DECIMAL(5,2) CHECK (min_days >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(absence_type_id, step_order)
+);
+
+-- Absence Approvals Table
+-- One row per approved step of an absence
+CREATE TABLE absence_approvals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    absence_id UUID NOT NULL REFERENCES absences(id) ON DELETE CASCADE,
+    step_order SMALLINT NOT NULL CHECK (step_order >= 1),
+    approver_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    comment TEXT,
+    approved_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(absence_id, step_order)
+);
+
+CREATE INDEX idx_absence_approvals_absence ON absence_approvals(absence_id);
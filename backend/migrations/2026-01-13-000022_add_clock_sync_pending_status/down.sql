@@ -0,0 +1,3 @@
+-- Note: PostgreSQL cannot drop a value from an enum type,
+-- 'pending' remains in clock_sync_status. Unfinished reservations are discarded.
+DELETE FROM clock_sync_events WHERE status::text = 'pending';
@@ -0,0 +1,4 @@
+-- Offline clock events are reserved before they are replayed
+-- The idempotency row is inserted as 'pending' first, so a concurrent retry of the same
+-- event hits the unique key instead of replaying it a second time.
+ALTER TYPE clock_sync_status ADD VALUE IF NOT EXISTS 'pending';
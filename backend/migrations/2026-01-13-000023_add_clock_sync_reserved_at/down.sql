@@ -0,0 +1 @@
+ALTER TABLE clock_sync_events DROP COLUMN IF EXISTS reserved_at;
@@ -0,0 +1,5 @@
+-- When an offline clock event was last reserved for replay
+-- A pending reservation older than the replay timeout was abandoned (crash, lost connection)
+-- and is taken over by the next upload of the event.
+ALTER TABLE clock_sync_events
+ADD COLUMN reserved_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
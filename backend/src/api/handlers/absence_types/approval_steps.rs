@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{AbsenceTypeService, UpdateApprovalStepsRequest};
+
+/// GET /api/v1/absence-types/:id/approval-steps
+///
+/// Get the approval chain of an absence type (Manager+ only)
+pub async fn get_approval_steps(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(type_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view approval chains".to_string(),
+        ));
+    }
+
+    let service = AbsenceTypeService::new(state.db_pool.clone());
+    let steps = service.get_approval_steps(claims.org_id, type_id).await?;
+
+    Ok((StatusCode::OK, Json(steps)))
+}
+
+/// PUT /api/v1/absence-types/:id/approval-steps
+///
+/// Replace the approval chain of an absence type (Admin+ only)
+pub async fn update_approval_steps(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(type_id): Path<Uuid>,
+    Json(body): Json<UpdateApprovalStepsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update approval chains".to_string(),
+        ));
+    }
+
+    let service = AbsenceTypeService::new(state.db_pool.clone());
+    let steps = service
+        .update_approval_steps(claims.org_id, type_id, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(steps)))
+}
@@ -1,9 +1,11 @@
+mod approval_steps;
 mod create;
 mod delete;
 mod get;
 mod list;
 mod update;
 
+pub use approval_steps::{get_approval_steps, update_approval_steps};
 pub use create::create_absence_type;
 pub use delete::delete_absence_type;
 pub use get::get_absence_type;
@@ -4,6 +4,7 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::config::AppState;
@@ -12,13 +13,19 @@ use crate::error::AppError;
 use crate::extractors::AuthenticatedUser;
 use crate::services::AbsenceService;
 
+#[derive(Debug, Deserialize)]
+pub struct ApproveAbsenceBody {
+    pub comment: Option<String>,
+}
+
 /// POST /api/v1/absences/:id/approve
 ///
-/// Approve an absence request (Manager+ only)
+/// Approve the next step of an absence request (Manager+ only)
 pub async fn approve_absence(
     State(state): State<AppState>,
     AuthenticatedUser(claims): AuthenticatedUser,
     Path(absence_id): Path<Uuid>,
+    body: Option<Json<ApproveAbsenceBody>>,
 ) -> Result<impl IntoResponse, AppError> {
     // Check authorization - Manager+ only
     if claims.role < UserRole::Manager {
@@ -27,9 +34,11 @@ pub async fn approve_absence(
         ));
     }
 
+    let comment = body.and_then(|Json(body)| body.comment);
+
     let service = AbsenceService::new(state.db_pool.clone());
     let absence = service
-        .approve(claims.org_id, absence_id, claims.sub, claims.role)
+        .approve(claims.org_id, absence_id, claims.sub, claims.role, comment)
         .await?;
 
     Ok((StatusCode::OK, Json(absence)))
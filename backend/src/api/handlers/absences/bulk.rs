@@ -0,0 +1,56 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::BulkReviewRequest;
+use crate::services::BulkReviewService;
+
+/// POST /api/v1/absences/bulk-approve
+///
+/// Approve several pending absences, selected by ids or filter (Manager+ only)
+/// Returns one result per absence; failures do not stop the batch
+pub async fn bulk_approve_absences(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<BulkReviewRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can approve absences".to_string(),
+        ));
+    }
+
+    let service = BulkReviewService::new(state.db_pool.clone());
+    let response = service
+        .approve_absences(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// POST /api/v1/absences/bulk-reject
+///
+/// Reject several pending absences, selected by ids or filter (Manager+ only)
+/// Returns one result per absence; failures do not stop the batch
+pub async fn bulk_reject_absences(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<BulkReviewRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can reject absences".to_string(),
+        ));
+    }
+
+    let service = BulkReviewService::new(state.db_pool.clone());
+    let response = service
+        .reject_absences(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
@@ -1,15 +1,21 @@
 mod approve;
+mod bulk;
 mod cancel;
 mod create;
 mod get;
 mod list;
 mod pending;
+mod preview;
 mod reject;
+mod settings;
 
 pub use approve::approve_absence;
+pub use bulk::{bulk_approve_absences, bulk_reject_absences};
 pub use cancel::cancel_absence;
 pub use create::create_absence;
 pub use get::get_absence;
 pub use list::list_absences;
 pub use pending::list_pending_absences;
+pub use preview::preview_absence;
 pub use reject::reject_absence;
+pub use settings::{get_absence_settings, update_absence_settings};
@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{AbsenceService, CreateAbsenceRequest};
+
+/// GET /api/v1/absences/preview
+///
+/// Preview the days or hours an absence request would take, and the balance left
+/// afterwards, before submitting it (all authenticated users)
+#[tracing::instrument(
+    name = "absences.preview",
+    skip(state, query),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn preview_absence(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<CreateAbsenceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = AbsenceService::new(state.db_pool.clone());
+    let preview = service
+        .preview_request(claims.org_id, claims.sub, query)
+        .await?;
+
+    Ok((StatusCode::OK, Json(preview)))
+}
@@ -7,6 +7,7 @@ use validator::Validate;
 use crate::config::AppState;
 use crate::domain::enums::UserRole;
 use crate::error::AppError;
+use crate::models::organization::validate_timezone;
 use crate::models::{NewOrganization, NewUser, OrganizationResponse, UserResponse};
 use crate::repositories::{OrganizationRepository, UserRepository};
 use crate::schema::users;
@@ -42,6 +43,7 @@ pub struct BootstrapRequest {
     pub password: String,
 
     #[validate(length(min = 2, max = 50, message = "Timezone must be 2-50 characters"))]
+    #[validate(custom(function = "validate_timezone"))]
     pub timezone: Option<String>,
 }
 
@@ -212,5 +214,17 @@ mod tests {
             timezone: None,
         };
         assert!(invalid_slug.validate().is_err());
+
+        // Invalid timezone
+        let invalid_timezone = BootstrapRequest {
+            organization_name: "My Company".to_string(),
+            organization_slug: "my-company".to_string(),
+            email: "admin@company.com".to_string(),
+            first_name: "Admin".to_string(),
+            last_name: "User".to_string(),
+            password: "SecurePass123@".to_string(),
+            timezone: Some("Paris".to_string()),
+        };
+        assert!(invalid_timezone.validate().is_err());
     }
 }
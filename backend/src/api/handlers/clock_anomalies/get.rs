@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ClockAnomalyService;
+
+/// GET /api/v1/clock-anomalies/:id
+///
+/// Get a clock anomaly (Manager+ only)
+#[tracing::instrument(
+    name = "clock_anomalies.get",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, anomaly_id = %anomaly_id)
+)]
+pub async fn get_clock_anomaly(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(anomaly_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockAnomalyService::new(state.db_pool.clone());
+
+    let anomaly = service
+        .get(claims.org_id, anomaly_id, claims.sub, claims.role)
+        .await?;
+
+    Ok((StatusCode::OK, Json(anomaly)))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::{AnomalyKind, AnomalySeverity, AnomalyStatus};
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::{ClockAnomalyFilter, Pagination};
+use crate::services::ClockAnomalyService;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ListClockAnomaliesQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    pub status: Option<AnomalyStatus>,
+    pub kind: Option<AnomalyKind>,
+    pub severity: Option<AnomalySeverity>,
+    pub user_id: Option<Uuid>,
+    pub team_id: Option<Uuid>,
+}
+
+/// GET /api/v1/clock-anomalies
+///
+/// Review queue of clock anomalies, open ones by default (Manager+ only)
+#[tracing::instrument(
+    name = "clock_anomalies.list",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, page = ?query.page)
+)]
+pub async fn list_clock_anomalies(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<ListClockAnomaliesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockAnomalyService::new(state.db_pool.clone());
+
+    let pagination = Pagination {
+        page: query.page.unwrap_or(1).max(1),
+        per_page: query.per_page.unwrap_or(20).clamp(1, 100),
+    };
+    let filter = ClockAnomalyFilter {
+        status: query.status,
+        kind: query.kind,
+        severity: query.severity,
+        user_id: query.user_id,
+        team_id: query.team_id,
+    };
+
+    let anomalies = service
+        .list(claims.org_id, claims.sub, claims.role, filter, pagination)
+        .await?;
+
+    Ok((StatusCode::OK, Json(anomalies)))
+}
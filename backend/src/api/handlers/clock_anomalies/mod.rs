@@ -0,0 +1,9 @@
+mod get;
+mod list;
+mod review;
+mod settings;
+
+pub use get::get_clock_anomaly;
+pub use list::list_clock_anomalies;
+pub use review::{dismiss_clock_anomaly, resolve_clock_anomaly};
+pub use settings::{get_anomaly_settings, update_anomaly_settings};
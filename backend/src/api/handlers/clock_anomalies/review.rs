@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{
+    ClockAnomalyService, DismissClockAnomalyRequest, ResolveClockAnomalyRequest,
+};
+
+/// POST /api/v1/clock-anomalies/:id/dismiss
+///
+/// Dismiss a clock anomaly as legitimate (Manager+ only)
+#[tracing::instrument(
+    name = "clock_anomalies.dismiss",
+    skip(state, body),
+    fields(reviewer_id = %claims.sub, org_id = %claims.org_id, anomaly_id = %anomaly_id)
+)]
+pub async fn dismiss_clock_anomaly(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(anomaly_id): Path<Uuid>,
+    Json(body): Json<DismissClockAnomalyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockAnomalyService::new(state.db_pool.clone());
+
+    let anomaly = service
+        .dismiss(claims.org_id, anomaly_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(anomaly)))
+}
+
+/// POST /api/v1/clock-anomalies/:id/resolve
+///
+/// Resolve a clock anomaly, optionally rejecting its clock entry (Manager+ only)
+#[tracing::instrument(
+    name = "clock_anomalies.resolve",
+    skip(state, body),
+    fields(reviewer_id = %claims.sub, org_id = %claims.org_id, anomaly_id = %anomaly_id)
+)]
+pub async fn resolve_clock_anomaly(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(anomaly_id): Path<Uuid>,
+    Json(body): Json<ResolveClockAnomalyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockAnomalyService::new(state.db_pool.clone());
+
+    let anomaly = service
+        .resolve(claims.org_id, anomaly_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(anomaly)))
+}
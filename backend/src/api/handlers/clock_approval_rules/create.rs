@@ -0,0 +1,28 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{ClockApprovalRuleService, CreateClockApprovalRuleRequest};
+
+/// POST /api/v1/clock-approval-rules
+///
+/// Create a new auto-approval rule (Admin+ only)
+pub async fn create_approval_rule(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<CreateClockApprovalRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can create approval rules".to_string(),
+        ));
+    }
+
+    let service = ClockApprovalRuleService::new(state.db_pool.clone());
+    let rule = service.create(claims.org_id, body).await?;
+
+    Ok((StatusCode::CREATED, Json(rule)))
+}
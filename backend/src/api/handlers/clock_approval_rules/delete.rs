@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ClockApprovalRuleService;
+
+/// DELETE /api/v1/clock-approval-rules/:id
+///
+/// Delete an auto-approval rule (Admin+ only)
+/// Entries it already approved stay approved
+pub async fn delete_approval_rule(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can delete approval rules".to_string(),
+        ));
+    }
+
+    let service = ClockApprovalRuleService::new(state.db_pool.clone());
+    service.delete(claims.org_id, rule_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ClockApprovalRuleService;
+
+/// GET /api/v1/clock-approval-rules/:id
+///
+/// Get an auto-approval rule by ID (Manager+ only)
+pub async fn get_approval_rule(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view approval rules".to_string(),
+        ));
+    }
+
+    let service = ClockApprovalRuleService::new(state.db_pool.clone());
+    let rule = service.get(claims.org_id, rule_id).await?;
+
+    Ok((StatusCode::OK, Json(rule)))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::ClockApprovalRuleFilter;
+use crate::services::ClockApprovalRuleService;
+
+/// GET /api/v1/clock-approval-rules
+///
+/// List auto-approval rules of the organization (Manager+ only)
+pub async fn list_approval_rules(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<ClockApprovalRuleFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view approval rules".to_string(),
+        ));
+    }
+
+    let service = ClockApprovalRuleService::new(state.db_pool.clone());
+    let rules = service.list(claims.org_id, filter).await?;
+
+    Ok((StatusCode::OK, Json(rules)))
+}
@@ -0,0 +1,11 @@
+mod create;
+mod delete;
+mod get;
+mod list;
+mod update;
+
+pub use create::create_approval_rule;
+pub use delete::delete_approval_rule;
+pub use get::get_approval_rule;
+pub use list::list_approval_rules;
+pub use update::update_approval_rule;
@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{ClockApprovalRuleService, UpdateClockApprovalRuleRequest};
+
+/// PUT /api/v1/clock-approval-rules/:id
+///
+/// Update an auto-approval rule (Admin+ only)
+pub async fn update_approval_rule(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(rule_id): Path<Uuid>,
+    Json(body): Json<UpdateClockApprovalRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update approval rules".to_string(),
+        ));
+    }
+
+    let service = ClockApprovalRuleService::new(state.db_pool.clone());
+    let rule = service.update(claims.org_id, rule_id, body).await?;
+
+    Ok((StatusCode::OK, Json(rule)))
+}
@@ -8,13 +8,18 @@ use serde::Deserialize;
 
 use crate::config::AppState;
 use crate::error::AppError;
-use crate::extractors::AuthenticatedUser;
+use crate::extractors::{AuthenticatedUser, ClientIp};
+use crate::services::work_site_service::parse_position;
 use crate::services::ClockService;
 
 #[derive(Debug, Deserialize)]
 pub struct ValidateQuery {
     /// The action to validate: "clock_in" or "clock_out"
     pub action: String,
+    /// Optional device position for geofence checks
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub accuracy: Option<f64>,
 }
 
 /// GET /api/v1/clock-restrictions/validate
@@ -22,18 +27,26 @@ pub struct ValidateQuery {
 /// Check if a clock action is currently allowed
 #[tracing::instrument(
     name = "clock_restrictions.validate",
-    skip(state),
+    skip(state, client_ip),
     fields(user_id = %claims.sub, org_id = %claims.org_id, action = %query.action)
 )]
 pub async fn validate_clock_action(
     State(state): State<AppState>,
     AuthenticatedUser(claims): AuthenticatedUser,
+    ClientIp(client_ip): ClientIp,
     Query(query): Query<ValidateQuery>,
 ) -> Result<impl IntoResponse, AppError> {
+    let position = parse_position(query.latitude, query.longitude, query.accuracy)?;
     let service = ClockService::new(state.db_pool.clone());
 
     let validation = service
-        .validate_clock_action(claims.org_id, claims.sub, &query.action)
+        .validate_clock_action(
+            claims.org_id,
+            claims.sub,
+            &query.action,
+            position.as_ref(),
+            client_ip,
+        )
         .await?;
 
     Ok((StatusCode::OK, Json(validation)))
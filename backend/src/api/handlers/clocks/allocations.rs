@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::SetTimeAllocationsRequest;
+use crate::services::TimeAllocationService;
+
+/// GET /api/v1/clocks/:id/allocations
+///
+/// Get the project allocations of a clock entry
+pub async fn get_allocations(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(entry_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = TimeAllocationService::new(state.db_pool.clone());
+    let allocations = service
+        .get_for_entry(claims.org_id, claims.sub, claims.role, entry_id)
+        .await?;
+
+    Ok(Json(allocations))
+}
+
+/// PUT /api/v1/clocks/:id/allocations
+///
+/// Replace the project allocations of a clock entry
+/// Allocations cannot exceed the worked time of the entry
+pub async fn set_allocations(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(entry_id): Path<Uuid>,
+    Json(body): Json<SetTimeAllocationsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = TimeAllocationService::new(state.db_pool.clone());
+    let allocations = service
+        .set_for_entry(claims.org_id, claims.sub, claims.role, entry_id, body)
+        .await?;
+
+    Ok(Json(allocations))
+}
@@ -0,0 +1,51 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::BulkReviewRequest;
+use crate::services::BulkReviewService;
+
+/// POST /api/v1/clocks/bulk-approve
+///
+/// Approve several pending clock entries, selected by ids or filter (Manager+ only)
+/// Returns one result per entry; failures do not stop the batch
+#[tracing::instrument(
+    name = "clocks.bulk_approve",
+    skip(state, body),
+    fields(approver_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn bulk_approve_entries(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<BulkReviewRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = BulkReviewService::new(state.db_pool.clone());
+    let response = service
+        .approve_entries(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// POST /api/v1/clocks/bulk-reject
+///
+/// Reject several pending clock entries, selected by ids or filter (Manager+ only)
+/// Returns one result per entry; failures do not stop the batch
+#[tracing::instrument(
+    name = "clocks.bulk_reject",
+    skip(state, body),
+    fields(rejecter_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn bulk_reject_entries(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<BulkReviewRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = BulkReviewService::new(state.db_pool.clone());
+    let response = service
+        .reject_entries(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
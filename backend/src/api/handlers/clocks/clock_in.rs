@@ -3,12 +3,19 @@ use serde::Deserialize;
 
 use crate::config::AppState;
 use crate::error::AppError;
-use crate::extractors::AuthenticatedUser;
+use crate::extractors::{AuthenticatedUser, ClientIp};
+use crate::models::ClockContext;
+use crate::services::work_site_service::parse_position;
 use crate::services::ClockService;
 
 #[derive(Debug, Deserialize)]
 pub struct ClockInRequest {
     pub notes: Option<String>,
+    /// Device position, required when the geofence is enforced
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Accuracy radius in meters
+    pub accuracy: Option<f64>,
 }
 
 /// POST /api/v1/clocks/in
@@ -16,18 +23,29 @@ pub struct ClockInRequest {
 /// Clock in - start tracking time
 #[tracing::instrument(
     name = "clocks.clock_in",
-    skip(state, body),
+    skip(state, client_ip, body),
     fields(user_id = %claims.sub, org_id = %claims.org_id)
 )]
 pub async fn clock_in(
     State(state): State<AppState>,
     AuthenticatedUser(claims): AuthenticatedUser,
+    ClientIp(client_ip): ClientIp,
     Json(body): Json<ClockInRequest>,
 ) -> Result<impl IntoResponse, AppError> {
+    let position = parse_position(body.latitude, body.longitude, body.accuracy)?;
     let clock_service = ClockService::new(state.db_pool.clone());
 
     let entry = clock_service
-        .clock_in(claims.org_id, claims.sub, body.notes)
+        .clock_in(
+            claims.org_id,
+            claims.sub,
+            body.notes,
+            ClockContext {
+                position,
+                client_ip,
+                ..Default::default()
+            },
+        )
         .await?;
 
     Ok((StatusCode::CREATED, Json(entry)))
@@ -3,12 +3,19 @@ use serde::Deserialize;
 
 use crate::config::AppState;
 use crate::error::AppError;
-use crate::extractors::AuthenticatedUser;
+use crate::extractors::{AuthenticatedUser, ClientIp};
+use crate::models::ClockContext;
+use crate::services::work_site_service::parse_position;
 use crate::services::ClockService;
 
 #[derive(Debug, Deserialize, Default)]
 pub struct ClockOutRequest {
     pub notes: Option<String>,
+    /// Device position, required when the geofence is enforced
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Accuracy radius in meters
+    pub accuracy: Option<f64>,
 }
 
 /// POST /api/v1/clocks/out
@@ -16,19 +23,30 @@ pub struct ClockOutRequest {
 /// Clock out - stop tracking time with optional notes
 #[tracing::instrument(
     name = "clocks.clock_out",
-    skip(state, body),
+    skip(state, client_ip, body),
     fields(user_id = %claims.sub, org_id = %claims.org_id)
 )]
 pub async fn clock_out(
     State(state): State<AppState>,
     AuthenticatedUser(claims): AuthenticatedUser,
+    ClientIp(client_ip): ClientIp,
     body: Option<Json<ClockOutRequest>>,
 ) -> Result<impl IntoResponse, AppError> {
+    let body = body.map(|Json(b)| b).unwrap_or_default();
+    let position = parse_position(body.latitude, body.longitude, body.accuracy)?;
     let clock_service = ClockService::new(state.db_pool.clone());
 
-    let notes = body.and_then(|b| b.notes.clone());
     let entry = clock_service
-        .clock_out(claims.org_id, claims.sub, notes)
+        .clock_out(
+            claims.org_id,
+            claims.sub,
+            body.notes,
+            ClockContext {
+                position,
+                client_ip,
+                ..Default::default()
+            },
+        )
         .await?;
 
     Ok((StatusCode::OK, Json(entry)))
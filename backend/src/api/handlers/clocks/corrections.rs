@@ -0,0 +1,148 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::{CreateClockCorrectionRequest, Pagination};
+use crate::services::ClockCorrectionService;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PendingCorrectionsQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RejectCorrectionRequest {
+    pub reason: Option<String>,
+}
+
+/// POST /api/v1/clocks/:id/corrections
+///
+/// Request a correction of one of the current user's clock entries
+#[tracing::instrument(
+    name = "clocks.create_correction",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, entry_id = %entry_id)
+)]
+pub async fn create_correction(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(entry_id): Path<Uuid>,
+    Json(body): Json<CreateClockCorrectionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockCorrectionService::new(state.db_pool.clone());
+
+    let correction = service
+        .request_correction(claims.org_id, claims.sub, entry_id, body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(correction)))
+}
+
+/// GET /api/v1/clocks/:id/corrections
+///
+/// List the correction history of a clock entry (owner or Manager+)
+#[tracing::instrument(
+    name = "clocks.list_corrections",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, entry_id = %entry_id)
+)]
+pub async fn list_corrections(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(entry_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockCorrectionService::new(state.db_pool.clone());
+
+    let corrections = service
+        .list_for_entry(claims.org_id, entry_id, claims.sub, claims.role)
+        .await?;
+
+    Ok((StatusCode::OK, Json(corrections)))
+}
+
+/// GET /api/v1/clocks/corrections/pending
+///
+/// List pending correction requests for approval (Manager+ only)
+#[tracing::instrument(
+    name = "clocks.list_pending_corrections",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, page = ?query.page)
+)]
+pub async fn list_pending_corrections(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<PendingCorrectionsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockCorrectionService::new(state.db_pool.clone());
+
+    let pagination = Pagination {
+        page: query.page.unwrap_or(1).max(1),
+        per_page: query.per_page.unwrap_or(20).clamp(1, 100),
+    };
+
+    let pending = service
+        .list_pending(claims.org_id, claims.sub, claims.role, pagination)
+        .await?;
+
+    Ok((StatusCode::OK, Json(pending)))
+}
+
+/// POST /api/v1/clocks/corrections/:id/approve
+///
+/// Approve a correction request and apply it to the clock entry (Manager+ only)
+#[tracing::instrument(
+    name = "clocks.approve_correction",
+    skip(state),
+    fields(approver_id = %claims.sub, org_id = %claims.org_id, request_id = %request_id)
+)]
+pub async fn approve_correction(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(request_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockCorrectionService::new(state.db_pool.clone());
+
+    let correction = service
+        .approve_correction(claims.org_id, request_id, claims.sub, claims.role)
+        .await?;
+
+    Ok((StatusCode::OK, Json(correction)))
+}
+
+/// POST /api/v1/clocks/corrections/:id/reject
+///
+/// Reject a correction request (Manager+ only)
+#[tracing::instrument(
+    name = "clocks.reject_correction",
+    skip(state, body),
+    fields(rejecter_id = %claims.sub, org_id = %claims.org_id, request_id = %request_id)
+)]
+pub async fn reject_correction(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(request_id): Path<Uuid>,
+    Json(body): Json<RejectCorrectionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockCorrectionService::new(state.db_pool.clone());
+
+    let correction = service
+        .reject_correction(
+            claims.org_id,
+            request_id,
+            claims.sub,
+            claims.role,
+            body.reason,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(correction)))
+}
@@ -0,0 +1,72 @@
+use axum::{
+    extract::State,
+    http::{header::USER_AGENT, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::{AuditContext, CreateManualClockEntryRequest};
+use crate::services::{AuditService, ClockService};
+
+/// Extract client IP from request headers
+fn extract_client_ip(headers: &HeaderMap) -> Option<String> {
+    if let Some(forwarded) = headers.get("x-forwarded-for") {
+        if let Ok(value) = forwarded.to_str() {
+            if let Some(ip) = value.split(',').next() {
+                let ip = ip.trim();
+                if !ip.is_empty() {
+                    return Some(ip.to_string());
+                }
+            }
+        }
+    }
+    if let Some(real_ip) = headers.get("x-real-ip") {
+        if let Ok(ip) = real_ip.to_str() {
+            return Some(ip.to_string());
+        }
+    }
+    None
+}
+
+/// POST /api/v1/clocks/manual
+///
+/// Record a closed clock entry for a past period on behalf of a team member (Manager+ only)
+#[tracing::instrument(
+    name = "clocks.create_manual",
+    skip(state, headers, body),
+    fields(creator_id = %claims.sub, org_id = %claims.org_id, target_user_id = %body.user_id)
+)]
+pub async fn create_manual_entry(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    headers: HeaderMap,
+    Json(body): Json<CreateManualClockEntryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Extract audit context
+    let audit_ctx = AuditContext::new(
+        Some(claims.sub),
+        Some(claims.org_id),
+        extract_client_ip(&headers),
+        headers
+            .get(USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from),
+    );
+
+    let clock_service = ClockService::new(state.db_pool.clone());
+
+    let entry = clock_service
+        .create_manual_entry(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    // Log audit event
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let _ = audit_service
+        .log_create(&audit_ctx, "clock_entries", entry.id, &entry)
+        .await;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
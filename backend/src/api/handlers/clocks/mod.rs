@@ -1,17 +1,30 @@
 // Clock handlers for time tracking operations
 
+mod allocations;
 mod approve;
+mod bulk;
 mod clock_in;
 mod clock_out;
+mod corrections;
 mod history;
+mod manual;
 mod pending;
 mod reject;
 mod status;
+mod sync;
 
+pub use allocations::{get_allocations, set_allocations};
 pub use approve::approve_entry;
+pub use bulk::{bulk_approve_entries, bulk_reject_entries};
 pub use clock_in::clock_in;
 pub use clock_out::clock_out;
+pub use corrections::{
+    approve_correction, create_correction, list_corrections, list_pending_corrections,
+    reject_correction,
+};
 pub use history::get_history;
+pub use manual::create_manual_entry;
 pub use pending::list_pending;
 pub use reject::reject_entry;
 pub use status::get_status;
+pub use sync::sync_clock_events;
@@ -0,0 +1,27 @@
+use axum::{extract::State, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::ClockSyncBatchRequest;
+use crate::services::ClockSyncService;
+
+/// POST /api/v1/clocks/sync
+///
+/// Upload clock events recorded while offline
+/// Events are replayed in chronological order; the response holds one result per event
+#[tracing::instrument(
+    name = "clocks.sync",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, events = body.events.len())
+)]
+pub async fn sync_clock_events(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<ClockSyncBatchRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ClockSyncService::new(state.db_pool.clone());
+    let response = service.sync_batch(claims.org_id, claims.sub, body).await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,122 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::{AuthenticatedKiosk, ClientIp};
+use crate::models::{ClockContext, KioskDevice};
+use crate::services::{ClockService, KioskService};
+
+/// Rate limiter endpoint counting PIN attempts
+const PIN_RATE_LIMIT: &str = "kiosk_pin";
+
+#[derive(Debug, Deserialize)]
+pub struct KioskClockRequest {
+    pub user_id: Uuid,
+    pub pin: String,
+    pub notes: Option<String>,
+}
+
+/// Verify the employee PIN entered on a device
+///
+/// Attempts are limited per device and employee. Each attempt is counted before
+/// the PIN is checked, so concurrent guesses cannot exceed the limit; a correct
+/// PIN resets the counter.
+async fn verify_employee(
+    state: &AppState,
+    kiosk_service: &KioskService,
+    device: &KioskDevice,
+    body: &KioskClockRequest,
+) -> Result<(), AppError> {
+    let key = format!("{}:{}", device.id, body.user_id);
+    state.rate_limiter.check_rate_limit(PIN_RATE_LIMIT, &key)?;
+
+    let valid = kiosk_service
+        .verify_pin(device.organization_id, body.user_id, &body.pin)
+        .await?;
+
+    if !valid {
+        return Err(AppError::Unauthorized("Invalid PIN".to_string()));
+    }
+
+    state.rate_limiter.reset(PIN_RATE_LIMIT, &key)?;
+    Ok(())
+}
+
+/// GET /api/v1/kiosk/employees
+///
+/// List employees who can clock in on this device
+pub async fn list_kiosk_employees(
+    State(state): State<AppState>,
+    AuthenticatedKiosk(device): AuthenticatedKiosk,
+) -> Result<impl IntoResponse, AppError> {
+    let service = KioskService::new(state.db_pool.clone());
+    let employees = service.list_employees(device.organization_id).await?;
+
+    Ok(Json(employees))
+}
+
+/// POST /api/v1/kiosk/clock-in
+///
+/// Clock an employee in from a kiosk device using their PIN
+#[tracing::instrument(
+    name = "kiosk.clock_in",
+    skip(state, device, client_ip, body),
+    fields(device_id = %device.id, user_id = %body.user_id)
+)]
+pub async fn kiosk_clock_in(
+    State(state): State<AppState>,
+    AuthenticatedKiosk(device): AuthenticatedKiosk,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<KioskClockRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let kiosk_service = KioskService::new(state.db_pool.clone());
+    verify_employee(&state, &kiosk_service, &device, &body).await?;
+
+    let context = ClockContext {
+        position: kiosk_service.device_position(&device).await?,
+        kiosk_device_id: Some(device.id),
+        client_ip,
+        ..Default::default()
+    };
+
+    let clock_service = ClockService::new(state.db_pool.clone());
+    let entry = clock_service
+        .clock_in(device.organization_id, body.user_id, body.notes, context)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// POST /api/v1/kiosk/clock-out
+///
+/// Clock an employee out from a kiosk device using their PIN
+#[tracing::instrument(
+    name = "kiosk.clock_out",
+    skip(state, device, client_ip, body),
+    fields(device_id = %device.id, user_id = %body.user_id)
+)]
+pub async fn kiosk_clock_out(
+    State(state): State<AppState>,
+    AuthenticatedKiosk(device): AuthenticatedKiosk,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<KioskClockRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let kiosk_service = KioskService::new(state.db_pool.clone());
+    verify_employee(&state, &kiosk_service, &device, &body).await?;
+
+    let context = ClockContext {
+        position: kiosk_service.device_position(&device).await?,
+        kiosk_device_id: Some(device.id),
+        client_ip,
+        ..Default::default()
+    };
+
+    let clock_service = ClockService::new(state.db_pool.clone());
+    let entry = clock_service
+        .clock_out(device.organization_id, body.user_id, body.notes, context)
+        .await?;
+
+    Ok(Json(entry))
+}
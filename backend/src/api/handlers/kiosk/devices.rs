@@ -0,0 +1,178 @@
+use axum::{
+    extract::{Path, State},
+    http::{header::USER_AGENT, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::{AuditContext, Claims};
+use crate::services::{
+    AuditService, CreateKioskDeviceRequest, KioskService, UpdateKioskDeviceRequest,
+};
+
+/// Extract client IP from request headers
+fn extract_client_ip(headers: &HeaderMap) -> Option<String> {
+    if let Some(forwarded) = headers.get("x-forwarded-for") {
+        if let Ok(value) = forwarded.to_str() {
+            if let Some(ip) = value.split(',').next() {
+                let ip = ip.trim();
+                if !ip.is_empty() {
+                    return Some(ip.to_string());
+                }
+            }
+        }
+    }
+    if let Some(real_ip) = headers.get("x-real-ip") {
+        if let Ok(ip) = real_ip.to_str() {
+            return Some(ip.to_string());
+        }
+    }
+    None
+}
+
+fn audit_context(claims: &Claims, headers: &HeaderMap) -> AuditContext {
+    AuditContext::new(
+        Some(claims.sub),
+        Some(claims.org_id),
+        extract_client_ip(headers),
+        headers
+            .get(USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from),
+    )
+}
+
+/// POST /api/v1/kiosk-devices
+///
+/// Register a kiosk device (Admin+ only)
+/// The returned token is only shown once
+pub async fn create_kiosk_device(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    headers: HeaderMap,
+    Json(body): Json<CreateKioskDeviceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can register kiosk devices".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    let created = service
+        .create_device(claims.org_id, claims.sub, body)
+        .await?;
+
+    // Log audit event (without the credential)
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let _ = audit_service
+        .log_create(
+            &audit_context(&claims, &headers),
+            "kiosk_devices",
+            created.device.id,
+            &created.device,
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// GET /api/v1/kiosk-devices
+///
+/// List kiosk devices (Admin+ only)
+pub async fn list_kiosk_devices(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can view kiosk devices".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    let devices = service.list_devices(claims.org_id).await?;
+
+    Ok(Json(devices))
+}
+
+/// GET /api/v1/kiosk-devices/:id
+///
+/// Get a kiosk device (Admin+ only)
+pub async fn get_kiosk_device(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(device_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can view kiosk devices".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    let device = service.get_device(claims.org_id, device_id).await?;
+
+    Ok(Json(device))
+}
+
+/// PUT /api/v1/kiosk-devices/:id
+///
+/// Update a kiosk device (Admin+ only)
+pub async fn update_kiosk_device(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(device_id): Path<Uuid>,
+    Json(body): Json<UpdateKioskDeviceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update kiosk devices".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    let device = service
+        .update_device(claims.org_id, device_id, body)
+        .await?;
+
+    Ok(Json(device))
+}
+
+/// DELETE /api/v1/kiosk-devices/:id
+///
+/// Delete a kiosk device and revoke its credential (Admin+ only)
+pub async fn delete_kiosk_device(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    headers: HeaderMap,
+    Path(device_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can delete kiosk devices".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    let device = service.get_device(claims.org_id, device_id).await?;
+    service.delete_device(claims.org_id, device_id).await?;
+
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let _ = audit_service
+        .log_delete(
+            &audit_context(&claims, &headers),
+            "kiosk_devices",
+            device.id,
+            &device,
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
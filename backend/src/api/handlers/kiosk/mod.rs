@@ -0,0 +1,10 @@
+mod clock;
+mod devices;
+mod pins;
+
+pub use clock::{kiosk_clock_in, kiosk_clock_out, list_kiosk_employees};
+pub use devices::{
+    create_kiosk_device, delete_kiosk_device, get_kiosk_device, list_kiosk_devices,
+    update_kiosk_device,
+};
+pub use pins::{clear_kiosk_pin, set_kiosk_pin};
@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{KioskService, SetKioskPinRequest};
+
+/// PUT /api/v1/users/:id/kiosk-pin
+///
+/// Set or replace an employee's kiosk PIN (Admin+ only)
+pub async fn set_kiosk_pin(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(user_id): Path<Uuid>,
+    Json(body): Json<SetKioskPinRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can set kiosk PINs".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    service.set_pin(claims.org_id, user_id, body).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/users/:id/kiosk-pin
+///
+/// Remove an employee's kiosk PIN (Admin+ only)
+pub async fn clear_kiosk_pin(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can remove kiosk PINs".to_string(),
+        ));
+    }
+
+    let service = KioskService::new(state.db_pool.clone());
+    service.clear_pin(claims.org_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
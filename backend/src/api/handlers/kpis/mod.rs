@@ -2,6 +2,7 @@ mod charts;
 mod my_kpis;
 mod org_kpis;
 mod presence;
+mod presence_stream;
 mod team_kpis;
 mod user_kpis;
 
@@ -9,5 +10,6 @@ pub use charts::get_charts;
 pub use my_kpis::get_my_kpis;
 pub use org_kpis::get_org_kpis;
 pub use presence::get_presence;
+pub use presence_stream::stream_presence;
 pub use team_kpis::get_team_kpis;
 pub use user_kpis::get_user_kpis;
@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use serde::Deserialize;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tokio_stream::{Stream, StreamExt};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::PresenceService;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PresenceStreamQuery {
+    pub team_id: Option<Uuid>,
+}
+
+/// GET /api/v1/kpis/presence/stream
+///
+/// Stream presence changes as Server-Sent Events (Manager+ only)
+///
+/// Starts with a `snapshot` event holding the current presence overview, then
+/// sends a `presence` event on each clock in/out and break start/end. A `lagged`
+/// event means some changes were dropped and the snapshot should be refetched.
+/// Managers only receive the members of the teams they manage.
+pub async fn stream_presence(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<PresenceStreamQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers can view presence data".to_string(),
+        ));
+    }
+
+    let service = PresenceService::new(state.db_pool.clone());
+    let scope = service
+        .resolve_scope(claims.org_id, claims.sub, claims.role, query.team_id)
+        .await?;
+
+    // Subscribe before taking the snapshot so no change falls in between
+    let receiver = PresenceService::subscribe();
+    let snapshot = service.snapshot(&scope).await?;
+
+    let initial = tokio_stream::once(Event::default().event("snapshot").json_data(&snapshot));
+    let changes = BroadcastStream::new(receiver).filter_map(move |message| match message {
+        Ok(event) if scope.includes(&event) => {
+            Some(Event::default().event("presence").json_data(&event))
+        }
+        Ok(_) => None,
+        Err(BroadcastStreamRecvError::Lagged(skipped)) => Some(Ok(Event::default()
+            .event("lagged")
+            .data(skipped.to_string()))),
+    });
+
+    Ok(Sse::new(initial.chain(changes)).keep_alive(KeepAlive::default()))
+}
@@ -7,18 +7,26 @@ pub mod audit_logs;
 pub mod auth;
 pub mod balances;
 pub mod breaks;
+pub mod clock_anomalies;
+pub mod clock_approval_rules;
 pub mod clock_restrictions;
 pub mod clocks;
 pub mod closed_days;
 pub mod health;
+pub mod kiosk;
 pub mod kpis;
 pub mod metrics;
 pub mod notifications;
 pub mod organizations;
 pub mod password;
+pub mod pay_periods;
+pub mod projects;
 pub mod reports;
+pub mod roster;
 pub mod schedules;
 pub mod sessions;
+pub mod shift_swaps;
 pub mod system;
 pub mod teams;
 pub mod users;
+pub mod work_sites;
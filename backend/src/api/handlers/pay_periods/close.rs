@@ -0,0 +1,29 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{ClosePayPeriodRequest, PayPeriodService};
+
+/// POST /api/v1/pay-periods/close
+///
+/// Close the pay period containing a date (Admin+ only)
+/// Clock entries, breaks and absences inside it can no longer be modified
+pub async fn close_pay_period(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<ClosePayPeriodRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can close pay periods".to_string(),
+        ));
+    }
+
+    let service = PayPeriodService::new(state.db_pool.clone());
+    let period = service.close(claims.org_id, claims.sub, body).await?;
+
+    Ok((StatusCode::OK, Json(period)))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::PayPeriodFilter;
+use crate::services::PayPeriodService;
+
+/// GET /api/v1/pay-periods
+///
+/// List closed and reopened pay periods (Manager+ only)
+pub async fn list_pay_periods(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<PayPeriodFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view pay periods".to_string(),
+        ));
+    }
+
+    let service = PayPeriodService::new(state.db_pool.clone());
+    let periods = service.list(claims.org_id, filter).await?;
+
+    Ok((StatusCode::OK, Json(periods)))
+}
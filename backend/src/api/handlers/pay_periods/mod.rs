@@ -0,0 +1,11 @@
+mod close;
+mod list;
+mod reopen;
+mod settings;
+mod status;
+
+pub use close::close_pay_period;
+pub use list::list_pay_periods;
+pub use reopen::reopen_pay_period;
+pub use settings::{get_pay_period_settings, update_pay_period_settings};
+pub use status::get_pay_period_status;
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::PayPeriodService;
+
+/// POST /api/v1/pay-periods/:id/reopen
+///
+/// Reopen a closed pay period (Admin+ only)
+pub async fn reopen_pay_period(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(period_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can reopen pay periods".to_string(),
+        ));
+    }
+
+    let service = PayPeriodService::new(state.db_pool.clone());
+    let period = service.reopen(claims.org_id, period_id, claims.sub).await?;
+
+    Ok((StatusCode::OK, Json(period)))
+}
@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{PayPeriodService, PayPeriodStatusQuery};
+
+/// GET /api/v1/pay-periods/current
+///
+/// Bounds and status of the pay period containing `date` (today by default) (Manager+ only)
+pub async fn get_pay_period_status(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<PayPeriodStatusQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view pay periods".to_string(),
+        ));
+    }
+
+    let service = PayPeriodService::new(state.db_pool.clone());
+    let status = service.status(claims.org_id, query).await?;
+
+    Ok((StatusCode::OK, Json(status)))
+}
@@ -0,0 +1,28 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{CreateProjectRequest, ProjectService};
+
+/// POST /api/v1/projects
+///
+/// Create a new project (Admin+ only)
+pub async fn create_project(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<CreateProjectRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can create projects".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    let project = service.create(claims.org_id, body).await?;
+
+    Ok((StatusCode::CREATED, Json(project)))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ProjectService;
+
+/// DELETE /api/v1/projects/:id
+///
+/// Delete a project (Admin+ only)
+/// Projects with allocated time cannot be deleted, deactivate them instead
+pub async fn delete_project(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can delete projects".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    service.delete(claims.org_id, project_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ProjectService;
+
+/// GET /api/v1/projects/:id
+///
+/// Get a project with its tasks
+pub async fn get_project(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ProjectService::new(state.db_pool.clone());
+    let project = service.get(claims.org_id, project_id).await?;
+
+    Ok((StatusCode::OK, Json(project)))
+}
@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::ProjectFilter;
+use crate::services::ProjectService;
+
+/// GET /api/v1/projects
+///
+/// List projects of the organization with their tasks
+pub async fn list_projects(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<ProjectFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ProjectService::new(state.db_pool.clone());
+    let projects = service.list(claims.org_id, filter).await?;
+
+    Ok((StatusCode::OK, Json(projects)))
+}
@@ -0,0 +1,13 @@
+mod create;
+mod delete;
+mod get;
+mod list;
+mod tasks;
+mod update;
+
+pub use create::create_project;
+pub use delete::delete_project;
+pub use get::get_project;
+pub use list::list_projects;
+pub use tasks::{create_project_task, delete_project_task, update_project_task};
+pub use update::update_project;
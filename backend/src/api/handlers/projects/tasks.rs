@@ -0,0 +1,82 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{CreateProjectTaskRequest, ProjectService, UpdateProjectTaskRequest};
+
+/// POST /api/v1/projects/:id/tasks
+///
+/// Add a task to a project (Admin+ only)
+pub async fn create_project_task(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(project_id): Path<Uuid>,
+    Json(body): Json<CreateProjectTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can manage project tasks".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    let task = service.create_task(claims.org_id, project_id, body).await?;
+
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+/// PUT /api/v1/projects/:id/tasks/:task_id
+///
+/// Update a task of a project (Admin+ only)
+pub async fn update_project_task(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((project_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateProjectTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can manage project tasks".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    let task = service
+        .update_task(claims.org_id, project_id, task_id, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(task)))
+}
+
+/// DELETE /api/v1/projects/:id/tasks/:task_id
+///
+/// Delete a task of a project (Admin+ only)
+pub async fn delete_project_task(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((project_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can manage project tasks".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    service
+        .delete_task(claims.org_id, project_id, task_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{ProjectService, UpdateProjectRequest};
+
+/// PUT /api/v1/projects/:id
+///
+/// Update a project (Admin+ only)
+pub async fn update_project(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(project_id): Path<Uuid>,
+    Json(body): Json<UpdateProjectRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update projects".to_string(),
+        ));
+    }
+
+    let service = ProjectService::new(state.db_pool.clone());
+    let project = service.update(claims.org_id, project_id, body).await?;
+
+    Ok((StatusCode::OK, Json(project)))
+}
@@ -14,11 +14,12 @@ use crate::extractors::{Admin, RoleGuard};
 use crate::repositories::{
     AbsenceRepository, AbsenceTypeRepository, ClockRepository, UserRepository,
 };
+use crate::services::{Granularity, TimeAllocationService};
 use crate::utils::{end_of_day, start_of_day};
 
 #[derive(Debug, Deserialize)]
 pub struct ExportReportsQuery {
-    /// Type of export: "clocks", "absences", or "users"
+    /// Type of export: "clocks", "absences", "users", or "projects"
     #[serde(rename = "type")]
     pub export_type: ExportType,
     /// Filter from date (YYYY-MM-DD)
@@ -27,6 +28,8 @@ pub struct ExportReportsQuery {
     pub end_date: Option<NaiveDate>,
     /// Filter by specific user
     pub user_id: Option<Uuid>,
+    /// Period of project hours: "day", "week" or "month" (default)
+    pub period: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Copy)]
@@ -35,6 +38,7 @@ pub enum ExportType {
     Clocks,
     Absences,
     Users,
+    Projects,
 }
 
 /// GET /api/v1/reports/export
@@ -63,6 +67,10 @@ pub async fn export_reports(
             let csv = export_users(&state, org_id).await?;
             (csv, "users")
         }
+        ExportType::Projects => {
+            let csv = export_projects(&state, org_id, &query).await?;
+            (csv, "projects")
+        }
     };
 
     // Log export
@@ -223,8 +231,9 @@ async fn export_absences(
     }
 
     // Build CSV
-    let mut csv =
-        String::from("Start Date,End Date,User Email,User Name,Type,Days,Status,Reason\n");
+    let mut csv = String::from(
+        "Start Date,Start Half,End Date,End Half,User Email,User Name,Type,Days,Hours,Status,Reason\n",
+    );
 
     for absence in absences {
         // Get user info from cache or fetch
@@ -247,7 +256,14 @@ async fn export_absences(
 
         let start_date = absence.start_date.format("%Y-%m-%d").to_string();
         let end_date = absence.end_date.format("%Y-%m-%d").to_string();
+        let start_half = format!("{:?}", absence.start_half);
+        let end_half = format!("{:?}", absence.end_half);
         let days = absence.days_count.to_string();
+        let hours = absence
+            .hours
+            .as_ref()
+            .map(|h| h.to_string())
+            .unwrap_or_default();
         let status = format!("{:?}", absence.status);
         let reason = absence
             .reason
@@ -256,13 +272,16 @@ async fn export_absences(
             .replace('\n', " ");
 
         csv.push_str(&format!(
-            "{},{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{},{},{},{}\n",
             start_date,
+            start_half,
             end_date,
+            end_half,
             escape_csv(&email),
             escape_csv(&name),
             escape_csv(&type_name),
             days,
+            hours,
             status,
             escape_csv(&reason)
         ));
@@ -311,6 +330,47 @@ async fn export_users(state: &AppState, org_id: Uuid) -> Result<String, AppError
     Ok(csv)
 }
 
+/// Export hours allocated per period, project and user to CSV
+async fn export_projects(
+    state: &AppState,
+    org_id: Uuid,
+    query: &ExportReportsQuery,
+) -> Result<String, AppError> {
+    let granularity = match query.period.as_deref() {
+        Some("day") => Granularity::Day,
+        Some("week") => Granularity::Week,
+        _ => Granularity::Month,
+    };
+
+    let service = TimeAllocationService::new(state.db_pool.clone());
+    let rows = service
+        .project_hours_report(
+            org_id,
+            query.start_date.map(start_of_day),
+            query.end_date.map(end_of_day),
+            query.user_id,
+            granularity,
+        )
+        .await?;
+
+    // Build CSV
+    let mut csv = String::from("Period,Project,Project Code,User Email,User Name,Hours\n");
+
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.2}\n",
+            row.period_start.format("%Y-%m-%d"),
+            escape_csv(&row.project_name),
+            escape_csv(&row.project_code.unwrap_or_default()),
+            escape_csv(&row.user_email),
+            escape_csv(&row.user_name),
+            row.minutes as f64 / 60.0
+        ));
+    }
+
+    Ok(csv)
+}
+
 /// Escape CSV field - wrap in quotes if contains comma, quote, or newline
 fn escape_csv(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
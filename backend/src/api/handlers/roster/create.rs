@@ -0,0 +1,28 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{CreateRosterShiftRequest, RosterService};
+
+/// POST /api/v1/roster/shifts
+///
+/// Plan a draft shift for an employee (Manager+ only)
+#[tracing::instrument(
+    name = "roster.create",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn create_roster_shift(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<CreateRosterShiftRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let shift = service
+        .create(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(shift)))
+}
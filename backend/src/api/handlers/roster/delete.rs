@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::RosterService;
+
+/// DELETE /api/v1/roster/shifts/:id
+///
+/// Delete a planned shift (Manager+ only)
+#[tracing::instrument(
+    name = "roster.delete",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, shift_id = %shift_id)
+)]
+pub async fn delete_roster_shift(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(shift_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    service
+        .delete(claims.org_id, shift_id, claims.sub, claims.role)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
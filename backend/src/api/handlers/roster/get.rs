@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::RosterService;
+
+/// GET /api/v1/roster/shifts/:id
+///
+/// Get a planned shift by ID (Manager+ only)
+#[tracing::instrument(
+    name = "roster.get",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, shift_id = %shift_id)
+)]
+pub async fn get_roster_shift(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(shift_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let shift = service
+        .get(claims.org_id, shift_id, claims.sub, claims.role)
+        .await?;
+
+    Ok((StatusCode::OK, Json(shift)))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::RosterShiftFilter;
+use crate::services::RosterService;
+
+/// GET /api/v1/roster/shifts
+///
+/// List planned shifts, drafts included (Manager+ only)
+#[tracing::instrument(
+    name = "roster.list",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn list_roster_shifts(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<RosterShiftFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let shifts = service
+        .list(claims.org_id, claims.sub, claims.role, filter)
+        .await?;
+
+    Ok((StatusCode::OK, Json(shifts)))
+}
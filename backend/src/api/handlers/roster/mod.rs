@@ -0,0 +1,17 @@
+mod create;
+mod delete;
+mod get;
+mod list;
+mod my_shifts;
+mod publish;
+mod settings;
+mod update;
+
+pub use create::create_roster_shift;
+pub use delete::delete_roster_shift;
+pub use get::get_roster_shift;
+pub use list::list_roster_shifts;
+pub use my_shifts::list_my_shifts;
+pub use publish::publish_roster_week;
+pub use settings::{get_roster_settings, update_roster_settings};
+pub use update::update_roster_shift;
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::RosterService;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct MyShiftsQuery {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+/// GET /api/v1/roster/me
+///
+/// Published upcoming shifts of the current user, the next two weeks by default
+#[tracing::instrument(
+    name = "roster.me",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn list_my_shifts(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<MyShiftsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let shifts = service
+        .my_shifts(claims.org_id, claims.sub, query.start_date, query.end_date)
+        .await?;
+
+    Ok((StatusCode::OK, Json(shifts)))
+}
@@ -0,0 +1,28 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{PublishRosterWeekRequest, RosterService};
+
+/// POST /api/v1/roster/publish
+///
+/// Publish all draft shifts of a week, optionally for one team (Manager+ only)
+#[tracing::instrument(
+    name = "roster.publish",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, week_start = %body.week_start)
+)]
+pub async fn publish_roster_week(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<PublishRosterWeekRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let result = service
+        .publish_week(claims.org_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(result)))
+}
@@ -0,0 +1,48 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{RosterService, UpdateRosterSettingsRequest};
+
+/// GET /api/v1/roster/settings
+///
+/// Get the shift swap approval and rest period settings (Manager+ only)
+pub async fn get_roster_settings(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Manager+ only
+    if claims.role < UserRole::Manager {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can view roster settings".to_string(),
+        ));
+    }
+
+    let service = RosterService::new(state.db_pool.clone());
+    let settings = service.get_settings(claims.org_id).await?;
+
+    Ok((StatusCode::OK, Json(settings)))
+}
+
+/// PUT /api/v1/roster/settings
+///
+/// Set the shift swap approval and rest period settings (Admin+ only)
+pub async fn update_roster_settings(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<UpdateRosterSettingsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update roster settings".to_string(),
+        ));
+    }
+
+    let service = RosterService::new(state.db_pool.clone());
+    let settings = service.update_settings(claims.org_id, body).await?;
+
+    Ok((StatusCode::OK, Json(settings)))
+}
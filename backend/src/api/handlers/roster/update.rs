@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{RosterService, UpdateRosterShiftRequest};
+
+/// PUT /api/v1/roster/shifts/:id
+///
+/// Update a planned shift (Manager+ only)
+#[tracing::instrument(
+    name = "roster.update",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, shift_id = %shift_id)
+)]
+pub async fn update_roster_shift(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(shift_id): Path<Uuid>,
+    Json(body): Json<UpdateRosterShiftRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = RosterService::new(state.db_pool.clone());
+
+    let shift = service
+        .update(claims.org_id, shift_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(shift)))
+}
@@ -0,0 +1,128 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::ScheduleExceptionFilter;
+use crate::services::{
+    CreateScheduleExceptionRequest, UpdateScheduleExceptionRequest, WorkScheduleService,
+};
+
+/// GET /api/v1/users/:user_id/schedule-exceptions
+///
+/// List a user's schedule exceptions (Manager+ only, or the user themselves)
+#[tracing::instrument(
+    name = "schedule_exceptions.list",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, target_user_id = %user_id)
+)]
+pub async fn list_schedule_exceptions(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(user_id): Path<Uuid>,
+    Query(filter): Query<ScheduleExceptionFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkScheduleService::new(state.db_pool.clone());
+
+    let exceptions = service
+        .list_exceptions(claims.org_id, claims.sub, claims.role, user_id, filter)
+        .await?;
+
+    Ok((StatusCode::OK, Json(exceptions)))
+}
+
+/// GET /api/v1/schedules/me/exceptions
+///
+/// List the authenticated user's schedule exceptions
+#[tracing::instrument(
+    name = "schedule_exceptions.mine",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn list_my_schedule_exceptions(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<ScheduleExceptionFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkScheduleService::new(state.db_pool.clone());
+
+    let exceptions = service
+        .list_exceptions(claims.org_id, claims.sub, claims.role, claims.sub, filter)
+        .await?;
+
+    Ok((StatusCode::OK, Json(exceptions)))
+}
+
+/// POST /api/v1/users/:user_id/schedule-exceptions
+///
+/// Replace a user's schedule on one date, or give them the day off (Manager+ only)
+#[tracing::instrument(
+    name = "schedule_exceptions.create",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, target_user_id = %user_id)
+)]
+pub async fn create_schedule_exception(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(user_id): Path<Uuid>,
+    Json(body): Json<CreateScheduleExceptionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkScheduleService::new(state.db_pool.clone());
+
+    let exception = service
+        .create_exception(claims.org_id, claims.sub, claims.role, user_id, body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(exception)))
+}
+
+/// PUT /api/v1/schedules/exceptions/:exception_id
+///
+/// Update a schedule exception (Manager+ only)
+#[tracing::instrument(
+    name = "schedule_exceptions.update",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, exception_id = %exception_id)
+)]
+pub async fn update_schedule_exception(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(exception_id): Path<Uuid>,
+    Json(body): Json<UpdateScheduleExceptionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkScheduleService::new(state.db_pool.clone());
+
+    let exception = service
+        .update_exception(claims.org_id, claims.sub, claims.role, exception_id, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(exception)))
+}
+
+/// DELETE /api/v1/schedules/exceptions/:exception_id
+///
+/// Delete a schedule exception, restoring the regular schedule day (Manager+ only)
+#[tracing::instrument(
+    name = "schedule_exceptions.delete",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, exception_id = %exception_id)
+)]
+pub async fn delete_schedule_exception(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(exception_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkScheduleService::new(state.db_pool.clone());
+
+    service
+        .delete_exception(claims.org_id, claims.sub, claims.role, exception_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
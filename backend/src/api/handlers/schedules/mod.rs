@@ -2,6 +2,7 @@ mod assign;
 mod create;
 mod days;
 mod delete;
+mod exceptions;
 mod get;
 mod list;
 mod my_schedule;
@@ -11,6 +12,10 @@ pub use assign::{assign_schedule, unassign_schedule};
 pub use create::create_schedule;
 pub use days::{add_day, remove_day, update_day};
 pub use delete::delete_schedule;
+pub use exceptions::{
+    create_schedule_exception, delete_schedule_exception, list_my_schedule_exceptions,
+    list_schedule_exceptions, update_schedule_exception,
+};
 pub use get::get_schedule;
 pub use list::list_schedules;
 pub use my_schedule::get_my_schedule;
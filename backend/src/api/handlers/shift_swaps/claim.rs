@@ -0,0 +1,32 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ShiftSwapService;
+
+/// POST /api/v1/roster/swaps/:id/claim
+///
+/// Claim an offered shift, subject to manager approval unless the organization waives it
+#[tracing::instrument(
+    name = "shift_swaps.claim",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, swap_id = %swap_id)
+)]
+pub async fn claim_shift_swap(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(swap_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swap = service.claim(claims.org_id, swap_id, claims.sub).await?;
+
+    Ok((StatusCode::OK, Json(swap)))
+}
@@ -0,0 +1,26 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{CreateShiftSwapRequest, ShiftSwapService};
+
+/// POST /api/v1/roster/swaps
+///
+/// Offer one of your upcoming shifts to a colleague or to the open pool
+#[tracing::instrument(
+    name = "shift_swaps.create",
+    skip(state, body),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, shift_id = %body.shift_id)
+)]
+pub async fn create_shift_swap(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<CreateShiftSwapRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swap = service.create(claims.org_id, claims.sub, body).await?;
+
+    Ok((StatusCode::CREATED, Json(swap)))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::ShiftSwapService;
+
+/// GET /api/v1/roster/swaps/:id
+///
+/// Get a shift swap request you take part in, or review as a manager
+#[tracing::instrument(
+    name = "shift_swaps.get",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id, swap_id = %swap_id)
+)]
+pub async fn get_shift_swap(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(swap_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swap = service
+        .get(claims.org_id, swap_id, claims.sub, claims.role)
+        .await?;
+
+    Ok((StatusCode::OK, Json(swap)))
+}
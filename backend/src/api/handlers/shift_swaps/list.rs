@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::ShiftSwapFilter;
+use crate::services::ShiftSwapService;
+
+/// GET /api/v1/roster/swaps
+///
+/// List shift swap requests: your own as an employee, your teams' as a manager
+#[tracing::instrument(
+    name = "shift_swaps.list",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn list_shift_swaps(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(filter): Query<ShiftSwapFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swaps = service
+        .list(claims.org_id, claims.sub, claims.role, filter)
+        .await?;
+
+    Ok((StatusCode::OK, Json(swaps)))
+}
+
+/// GET /api/v1/roster/swaps/open
+///
+/// Upcoming shifts you can claim: the open pool and shifts offered to you
+#[tracing::instrument(
+    name = "shift_swaps.open",
+    skip(state),
+    fields(user_id = %claims.sub, org_id = %claims.org_id)
+)]
+pub async fn list_open_shifts(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swaps = service.list_claimable(claims.org_id, claims.sub).await?;
+
+    Ok((StatusCode::OK, Json(swaps)))
+}
@@ -0,0 +1,13 @@
+mod cancel;
+mod claim;
+mod create;
+mod get;
+mod list;
+mod review;
+
+pub use cancel::cancel_shift_swap;
+pub use claim::claim_shift_swap;
+pub use create::create_shift_swap;
+pub use get::get_shift_swap;
+pub use list::{list_open_shifts, list_shift_swaps};
+pub use review::{approve_shift_swap, reject_shift_swap};
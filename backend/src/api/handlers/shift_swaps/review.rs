@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{ReviewShiftSwapRequest, ShiftSwapService};
+
+/// POST /api/v1/roster/swaps/:id/approve
+///
+/// Approve a claimed swap and hand the shift over (Manager+ only)
+#[tracing::instrument(
+    name = "shift_swaps.approve",
+    skip(state, body),
+    fields(reviewer_id = %claims.sub, org_id = %claims.org_id, swap_id = %swap_id)
+)]
+pub async fn approve_shift_swap(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(swap_id): Path<Uuid>,
+    Json(body): Json<ReviewShiftSwapRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swap = service
+        .approve(claims.org_id, swap_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(swap)))
+}
+
+/// POST /api/v1/roster/swaps/:id/reject
+///
+/// Reject a claimed swap, the shift stays with its employee (Manager+ only)
+#[tracing::instrument(
+    name = "shift_swaps.reject",
+    skip(state, body),
+    fields(reviewer_id = %claims.sub, org_id = %claims.org_id, swap_id = %swap_id)
+)]
+pub async fn reject_shift_swap(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(swap_id): Path<Uuid>,
+    Json(body): Json<ReviewShiftSwapRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = ShiftSwapService::new(state.db_pool.clone());
+
+    let swap = service
+        .reject(claims.org_id, swap_id, claims.sub, claims.role, body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(swap)))
+}
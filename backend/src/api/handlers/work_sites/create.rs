@@ -0,0 +1,28 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{CreateWorkSiteRequest, WorkSiteService};
+
+/// POST /api/v1/work-sites
+///
+/// Create a new work site (Admin+ only)
+pub async fn create_work_site(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(body): Json<CreateWorkSiteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can create work sites".to_string(),
+        ));
+    }
+
+    let service = WorkSiteService::new(state.db_pool.clone());
+    let site = service.create(claims.org_id, body).await?;
+
+    Ok((StatusCode::CREATED, Json(site)))
+}
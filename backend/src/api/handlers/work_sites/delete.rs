@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::WorkSiteService;
+
+/// DELETE /api/v1/work-sites/:id
+///
+/// Delete a work site (Admin+ only)
+pub async fn delete_work_site(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(site_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can delete work sites".to_string(),
+        ));
+    }
+
+    let service = WorkSiteService::new(state.db_pool.clone());
+    service.delete(claims.org_id, site_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::WorkSiteService;
+
+/// GET /api/v1/work-sites/:id
+///
+/// Get a work site by ID
+pub async fn get_work_site(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(site_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let service = WorkSiteService::new(state.db_pool.clone());
+    let site = service.get(claims.org_id, site_id).await?;
+
+    Ok((StatusCode::OK, Json(site)))
+}
@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::models::WorkSiteFilter;
+use crate::services::WorkSiteService;
+
+#[derive(Debug, Deserialize)]
+pub struct ListWorkSitesQuery {
+    pub is_active: Option<bool>,
+    /// Filter by organization (SuperAdmin only)
+    pub organization_id: Option<Uuid>,
+}
+
+/// GET /api/v1/work-sites
+///
+/// List work sites for the organization
+pub async fn list_work_sites(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(query): Query<ListWorkSitesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    // Determine organization ID - SuperAdmin can filter by organization
+    let org_id = if claims.role == UserRole::SuperAdmin {
+        query.organization_id.unwrap_or(claims.org_id)
+    } else {
+        claims.org_id
+    };
+
+    let service = WorkSiteService::new(state.db_pool.clone());
+    let filter = WorkSiteFilter {
+        is_active: query.is_active,
+    };
+    let sites = service.list(org_id, filter).await?;
+
+    Ok((StatusCode::OK, Json(sites)))
+}
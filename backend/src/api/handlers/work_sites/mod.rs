@@ -0,0 +1,11 @@
+mod create;
+mod delete;
+mod get;
+mod list;
+mod update;
+
+pub use create::create_work_site;
+pub use delete::delete_work_site;
+pub use get::get_work_site;
+pub use list::list_work_sites;
+pub use update::update_work_site;
@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::extractors::AuthenticatedUser;
+use crate::services::{UpdateWorkSiteRequest, WorkSiteService};
+
+/// PUT /api/v1/work-sites/:id
+///
+/// Update a work site (Admin+ only)
+pub async fn update_work_site(
+    State(state): State<AppState>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(site_id): Path<Uuid>,
+    Json(body): Json<UpdateWorkSiteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Check authorization - Admin+ only
+    if claims.role < UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only admins can update work sites".to_string(),
+        ));
+    }
+
+    let service = WorkSiteService::new(state.db_pool.clone());
+    let site = service.update(claims.org_id, site_id, body).await?;
+
+    Ok((StatusCode::OK, Json(site)))
+}
@@ -13,20 +13,28 @@ use super::handlers::audit_logs;
 use super::handlers::auth;
 use super::handlers::balances;
 use super::handlers::breaks;
+use super::handlers::clock_anomalies;
+use super::handlers::clock_approval_rules;
 use super::handlers::clock_restrictions;
 use super::handlers::clocks;
 use super::handlers::closed_days;
 use super::handlers::health::health_check;
+use super::handlers::kiosk;
 use super::handlers::kpis;
 use super::handlers::metrics;
 use super::handlers::notifications;
 use super::handlers::organizations;
 use super::handlers::password;
+use super::handlers::pay_periods;
+use super::handlers::projects;
 use super::handlers::reports;
+use super::handlers::roster;
 use super::handlers::schedules;
+use super::handlers::shift_swaps;
 use super::handlers::system;
 use super::handlers::teams;
 use super::handlers::users;
+use super::handlers::work_sites;
 use crate::config::AppState;
 
 /// Creates the main application router with all endpoints
@@ -90,17 +98,43 @@ pub fn create_router(state: AppState) -> Router {
         .route(
             "/:id/schedule",
             put(schedules::assign_schedule).delete(schedules::unassign_schedule),
+        )
+        .route(
+            "/:id/schedule-exceptions",
+            get(schedules::list_schedule_exceptions).post(schedules::create_schedule_exception),
+        )
+        .route(
+            "/:id/kiosk-pin",
+            put(kiosk::set_kiosk_pin).delete(kiosk::clear_kiosk_pin),
         );
 
     // Clock in/out routes
     let clock_routes = Router::new()
         .route("/in", post(clocks::clock_in))
         .route("/out", post(clocks::clock_out))
+        .route("/sync", post(clocks::sync_clock_events))
         .route("/status", get(clocks::get_status))
         .route("/history", get(clocks::get_history))
+        .route("/manual", post(clocks::create_manual_entry))
         .route("/pending", get(clocks::list_pending))
+        .route("/bulk-approve", post(clocks::bulk_approve_entries))
+        .route("/bulk-reject", post(clocks::bulk_reject_entries))
         .route("/:id/approve", post(clocks::approve_entry))
-        .route("/:id/reject", post(clocks::reject_entry));
+        .route("/:id/reject", post(clocks::reject_entry))
+        .route(
+            "/:id/allocations",
+            get(clocks::get_allocations).put(clocks::set_allocations),
+        )
+        .route(
+            "/:id/corrections",
+            get(clocks::list_corrections).post(clocks::create_correction),
+        )
+        .route(
+            "/corrections/pending",
+            get(clocks::list_pending_corrections),
+        )
+        .route("/corrections/:id/approve", post(clocks::approve_correction))
+        .route("/corrections/:id/reject", post(clocks::reject_correction));
 
     // Team management routes
     let team_routes = Router::new()
@@ -122,6 +156,10 @@ pub fn create_router(state: AppState) -> Router {
             get(schedules::list_schedules).post(schedules::create_schedule),
         )
         .route("/me", get(schedules::get_my_schedule))
+        .route(
+            "/me/exceptions",
+            get(schedules::list_my_schedule_exceptions),
+        )
         .route(
             "/:id",
             get(schedules::get_schedule)
@@ -132,6 +170,10 @@ pub fn create_router(state: AppState) -> Router {
         .route(
             "/days/:day_id",
             put(schedules::update_day).delete(schedules::remove_day),
+        )
+        .route(
+            "/exceptions/:exception_id",
+            put(schedules::update_schedule_exception).delete(schedules::delete_schedule_exception),
         );
 
     // KPI routes
@@ -141,6 +183,7 @@ pub fn create_router(state: AppState) -> Router {
         .route("/teams/:id", get(kpis::get_team_kpis))
         .route("/organization", get(kpis::get_org_kpis))
         .route("/presence", get(kpis::get_presence))
+        .route("/presence/stream", get(kpis::stream_presence))
         .route("/charts", get(kpis::get_charts));
 
     // Absence type routes
@@ -154,6 +197,10 @@ pub fn create_router(state: AppState) -> Router {
             get(absence_types::get_absence_type)
                 .put(absence_types::update_absence_type)
                 .delete(absence_types::delete_absence_type),
+        )
+        .route(
+            "/:id/approval-steps",
+            get(absence_types::get_approval_steps).put(absence_types::update_approval_steps),
         );
 
     // Absence routes
@@ -163,6 +210,13 @@ pub fn create_router(state: AppState) -> Router {
             get(absences::list_absences).post(absences::create_absence),
         )
         .route("/pending", get(absences::list_pending_absences))
+        .route("/preview", get(absences::preview_absence))
+        .route(
+            "/settings",
+            get(absences::get_absence_settings).put(absences::update_absence_settings),
+        )
+        .route("/bulk-approve", post(absences::bulk_approve_absences))
+        .route("/bulk-reject", post(absences::bulk_reject_absences))
         .route("/:id", get(absences::get_absence))
         .route("/:id/approve", post(absences::approve_absence))
         .route("/:id/reject", post(absences::reject_absence))
@@ -187,6 +241,56 @@ pub fn create_router(state: AppState) -> Router {
                 .delete(closed_days::delete_closed_day),
         );
 
+    // Work site routes (geofencing)
+    let work_site_routes = Router::new()
+        .route(
+            "/",
+            get(work_sites::list_work_sites).post(work_sites::create_work_site),
+        )
+        .route(
+            "/:id",
+            get(work_sites::get_work_site)
+                .put(work_sites::update_work_site)
+                .delete(work_sites::delete_work_site),
+        );
+
+    // Project catalog routes (time allocation)
+    let project_routes = Router::new()
+        .route(
+            "/",
+            get(projects::list_projects).post(projects::create_project),
+        )
+        .route(
+            "/:id",
+            get(projects::get_project)
+                .put(projects::update_project)
+                .delete(projects::delete_project),
+        )
+        .route("/:id/tasks", post(projects::create_project_task))
+        .route(
+            "/:id/tasks/:task_id",
+            put(projects::update_project_task).delete(projects::delete_project_task),
+        );
+
+    // Kiosk device management routes
+    let kiosk_device_routes = Router::new()
+        .route(
+            "/",
+            get(kiosk::list_kiosk_devices).post(kiosk::create_kiosk_device),
+        )
+        .route(
+            "/:id",
+            get(kiosk::get_kiosk_device)
+                .put(kiosk::update_kiosk_device)
+                .delete(kiosk::delete_kiosk_device),
+        );
+
+    // Kiosk terminal routes (device credential + employee PIN)
+    let kiosk_routes = Router::new()
+        .route("/employees", get(kiosk::list_kiosk_employees))
+        .route("/clock-in", post(kiosk::kiosk_clock_in))
+        .route("/clock-out", post(kiosk::kiosk_clock_out));
+
     // Notification routes
     let notification_routes = Router::new()
         .route("/", get(notifications::list_notifications))
@@ -218,6 +322,72 @@ pub fn create_router(state: AppState) -> Router {
     // System routes (public)
     let system_routes = Router::new().route("/status", get(system::get_status));
 
+    // Pay period routes (Admin+ writes)
+    let pay_period_routes = Router::new()
+        .route("/", get(pay_periods::list_pay_periods))
+        .route("/current", get(pay_periods::get_pay_period_status))
+        .route(
+            "/settings",
+            get(pay_periods::get_pay_period_settings).put(pay_periods::update_pay_period_settings),
+        )
+        .route("/close", post(pay_periods::close_pay_period))
+        .route("/:id/reopen", post(pay_periods::reopen_pay_period));
+
+    // Clock anomaly review routes (Manager+, Admin+ settings writes)
+    let clock_anomaly_routes = Router::new()
+        .route("/", get(clock_anomalies::list_clock_anomalies))
+        .route(
+            "/settings",
+            get(clock_anomalies::get_anomaly_settings)
+                .put(clock_anomalies::update_anomaly_settings),
+        )
+        .route("/:id", get(clock_anomalies::get_clock_anomaly))
+        .route("/:id/dismiss", post(clock_anomalies::dismiss_clock_anomaly))
+        .route("/:id/resolve", post(clock_anomalies::resolve_clock_anomaly));
+
+    // Shift roster routes (Manager+ planning, swaps and published shifts for everyone)
+    let roster_routes = Router::new()
+        .route(
+            "/shifts",
+            get(roster::list_roster_shifts).post(roster::create_roster_shift),
+        )
+        .route(
+            "/shifts/:id",
+            get(roster::get_roster_shift)
+                .put(roster::update_roster_shift)
+                .delete(roster::delete_roster_shift),
+        )
+        .route("/publish", post(roster::publish_roster_week))
+        .route("/me", get(roster::list_my_shifts))
+        .route(
+            "/settings",
+            get(roster::get_roster_settings).put(roster::update_roster_settings),
+        )
+        .route(
+            "/swaps",
+            get(shift_swaps::list_shift_swaps).post(shift_swaps::create_shift_swap),
+        )
+        .route("/swaps/open", get(shift_swaps::list_open_shifts))
+        .route("/swaps/:id", get(shift_swaps::get_shift_swap))
+        .route("/swaps/:id/claim", post(shift_swaps::claim_shift_swap))
+        .route("/swaps/:id/approve", post(shift_swaps::approve_shift_swap))
+        .route("/swaps/:id/reject", post(shift_swaps::reject_shift_swap))
+        .route("/swaps/:id/cancel", post(shift_swaps::cancel_shift_swap));
+
+    // Clock auto-approval rule routes (Admin+ writes)
+    let clock_approval_rule_routes = Router::new()
+        .route(
+            "/",
+            get(clock_approval_rules::list_approval_rules)
+                .post(clock_approval_rules::create_approval_rule),
+        )
+        .route(
+            "/:id",
+            get(clock_approval_rules::get_approval_rule)
+                .put(clock_approval_rules::update_approval_rule)
+                .delete(clock_approval_rules::delete_approval_rule),
+        );
+
     // Clock restrictions routes (Admin+ only)
     let clock_restriction_routes = Router::new()
         .route(
@@ -294,7 +464,15 @@ pub fn create_router(state: AppState) -> Router {
         .nest("/v1/absences", absence_routes)
         .nest("/v1/balances", balance_routes)
         .nest("/v1/closed-days", closed_day_routes)
+        .nest("/v1/work-sites", work_site_routes)
+        .nest("/v1/projects", project_routes)
+        .nest("/v1/kiosk-devices", kiosk_device_routes)
+        .nest("/v1/kiosk", kiosk_routes)
+        .nest("/v1/clock-anomalies", clock_anomaly_routes)
+        .nest("/v1/clock-approval-rules", clock_approval_rule_routes)
         .nest("/v1/clock-restrictions", clock_restriction_routes)
+        .nest("/v1/pay-periods", pay_period_routes)
+        .nest("/v1/roster", roster_routes)
         .nest("/v1/breaks", break_routes)
         .nest("/v1/notifications", notification_routes)
         .nest("/v1/reports", reports_routes)
@@ -322,8 +500,10 @@ mod tests {
     use tower::ServiceExt;
 
     use crate::config::app::{AppConfig, AppState};
+    use crate::config::auto_close::AutoCloseConfig;
     use crate::config::email::EmailConfig;
     use crate::config::hibp::HibpConfig;
+    use crate::config::network::NetworkConfig;
     use crate::services::{EmailService, EndpointRateLimiter, HibpService, MetricsService};
 
     const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
@@ -363,6 +543,8 @@ mod tests {
                 enabled: false,
             },
             hibp: HibpConfig::disabled(),
+            auto_close: AutoCloseConfig::disabled(),
+            network: NetworkConfig::default(),
         }
     }
 
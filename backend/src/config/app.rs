@@ -1,6 +1,8 @@
+use crate::config::auto_close::AutoCloseConfig;
 use crate::config::database::DbPool;
 use crate::config::email::EmailConfig;
 use crate::config::hibp::HibpConfig;
+use crate::config::network::NetworkConfig;
 use crate::services::{EmailService, EndpointRateLimiter, HibpService, MetricsService};
 use anyhow::{Context, Result};
 use dotenvy::dotenv;
@@ -32,6 +34,8 @@ pub struct AppConfig {
     pub metrics_enabled: bool,
     pub email: EmailConfig,
     pub hibp: HibpConfig,
+    pub auto_close: AutoCloseConfig,
+    pub network: NetworkConfig,
 }
 
 impl AppConfig {
@@ -82,6 +86,8 @@ impl AppConfig {
 
         let email = EmailConfig::from_env()?;
         let hibp = HibpConfig::from_env()?;
+        let auto_close = AutoCloseConfig::from_env()?;
+        let network = NetworkConfig::from_env()?;
 
         Ok(Self {
             app_host,
@@ -96,6 +102,8 @@ impl AppConfig {
             metrics_enabled,
             email,
             hibp,
+            auto_close,
+            network,
         })
     }
 }
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use std::env;
+
+/// Configuration for the background job closing forgotten open clock entries
+#[derive(Debug, Clone)]
+pub struct AutoCloseConfig {
+    pub enabled: bool,
+    /// How often the job runs
+    pub interval_seconds: u64,
+    /// Entries without a scheduled end are closed after this shift length
+    pub max_shift_hours: i64,
+    /// Delay after the cutoff before an entry is considered forgotten
+    pub grace_minutes: i64,
+}
+
+impl AutoCloseConfig {
+    pub fn from_env() -> Result<Self> {
+        let enabled = env::var("AUTO_CLOSE_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse::<bool>()
+            .unwrap_or(true);
+
+        let interval_seconds = env::var("AUTO_CLOSE_INTERVAL_SECONDS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse::<u64>()
+            .unwrap_or(3600);
+
+        let max_shift_hours = env::var("AUTO_CLOSE_MAX_SHIFT_HOURS")
+            .unwrap_or_else(|_| "12".to_string())
+            .parse::<i64>()
+            .unwrap_or(12);
+
+        let grace_minutes = env::var("AUTO_CLOSE_GRACE_MINUTES")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<i64>()
+            .unwrap_or(60);
+
+        Ok(Self {
+            enabled,
+            interval_seconds,
+            max_shift_hours,
+            grace_minutes,
+        })
+    }
+
+    /// Create a disabled auto-close config (for testing)
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            interval_seconds: 3600,
+            max_shift_hours: 12,
+            grace_minutes: 60,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_close_config_defaults() {
+        env::remove_var("AUTO_CLOSE_ENABLED");
+        env::remove_var("AUTO_CLOSE_MAX_SHIFT_HOURS");
+
+        let config = AutoCloseConfig::from_env().unwrap();
+
+        assert!(config.enabled);
+        assert_eq!(config.interval_seconds, 3600);
+        assert_eq!(config.max_shift_hours, 12);
+        assert_eq!(config.grace_minutes, 60);
+    }
+
+    #[test]
+    fn test_auto_close_config_disabled() {
+        let config = AutoCloseConfig::disabled();
+        assert!(!config.enabled);
+    }
+}
@@ -1,9 +1,13 @@
 pub mod app;
+pub mod auto_close;
 pub mod database;
 pub mod email;
 pub mod hibp;
+pub mod network;
 
 // Re-export commonly used types
 pub use app::{AppConfig, AppState};
+pub use auto_close::AutoCloseConfig;
 pub use email::EmailConfig;
 pub use hibp::HibpConfig;
+pub use network::NetworkConfig;
@@ -0,0 +1,50 @@
+use anyhow::{anyhow, Result};
+use ipnet::IpNet;
+use std::env;
+
+use crate::utils::network::parse_network;
+
+/// Network configuration for resolving client addresses
+#[derive(Debug, Clone, Default)]
+pub struct NetworkConfig {
+    /// Reverse proxies whose `X-Forwarded-For` header is honored
+    pub trusted_proxies: Vec<IpNet>,
+}
+
+impl NetworkConfig {
+    pub fn from_env() -> Result<Self> {
+        let trusted_proxies = match env::var("TRUSTED_PROXIES") {
+            Ok(value) => Self::parse_proxies(&value)?,
+            Err(_) => Vec::new(),
+        };
+
+        Ok(Self { trusted_proxies })
+    }
+
+    /// Parse a comma-separated list of proxy addresses or CIDR ranges
+    fn parse_proxies(value: &str) -> Result<Vec<IpNet>> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                parse_network(entry)
+                    .ok_or_else(|| anyhow!("Invalid TRUSTED_PROXIES entry: {}", entry))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proxies() {
+        let proxies = NetworkConfig::parse_proxies("10.0.0.0/8, 127.0.0.1,").unwrap();
+        assert_eq!(proxies.len(), 2);
+        assert_eq!(proxies[1].to_string(), "127.0.0.1/32");
+
+        assert!(NetworkConfig::parse_proxies("10.0.0.0/8,proxy").is_err());
+    }
+}
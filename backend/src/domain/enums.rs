@@ -5,13 +5,25 @@ use diesel::{AsExpression, FromSqlRow};
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 
+use crate::schema::sql_types::AbsenceHalfDay as AbsenceHalfDaySqlType;
 use crate::schema::sql_types::AbsenceStatus as AbsenceStatusSqlType;
+use crate::schema::sql_types::AbsenceUnit as AbsenceUnitSqlType;
+use crate::schema::sql_types::AnomalyKind as AnomalyKindSqlType;
+use crate::schema::sql_types::AnomalySeverity as AnomalySeveritySqlType;
+use crate::schema::sql_types::AnomalyStatus as AnomalyStatusSqlType;
 use crate::schema::sql_types::AuditAction as AuditActionSqlType;
 use crate::schema::sql_types::BreakTrackingMode as BreakTrackingModeSqlType;
+use crate::schema::sql_types::ClockCorrectionStatus as ClockCorrectionStatusSqlType;
+use crate::schema::sql_types::ClockEntrySource as ClockEntrySourceSqlType;
 use crate::schema::sql_types::ClockEntryStatus as ClockEntryStatusSqlType;
 use crate::schema::sql_types::ClockOverrideStatus as ClockOverrideStatusSqlType;
 use crate::schema::sql_types::ClockRestrictionMode as ClockRestrictionModeSqlType;
+use crate::schema::sql_types::ClockSyncStatus as ClockSyncStatusSqlType;
+use crate::schema::sql_types::GeofenceMode as GeofenceModeSqlType;
 use crate::schema::sql_types::NotificationType as NotificationTypeSqlType;
+use crate::schema::sql_types::PayPeriodFrequency as PayPeriodFrequencySqlType;
+use crate::schema::sql_types::RosterShiftStatus as RosterShiftStatusSqlType;
+use crate::schema::sql_types::ShiftSwapStatus as ShiftSwapStatusSqlType;
 use crate::schema::sql_types::UserRole as UserRoleSqlType;
 
 /// User role enumeration matching the database user_role ENUM
@@ -114,6 +126,49 @@ impl FromSql<ClockEntryStatusSqlType, Pg> for ClockEntryStatus {
     }
 }
 
+/// Clock entry source enumeration matching the database clock_entry_source ENUM
+/// - Clock: Created by the user clocking in/out
+/// - Manual: Recorded retroactively by a manager or admin
+/// - Kiosk: Created on a shared kiosk device with the employee PIN
+/// - Sync: Recorded offline on a mobile device and replayed on upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = ClockEntrySourceSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum ClockEntrySource {
+    #[default]
+    Clock,
+    Manual,
+    Kiosk,
+    Sync,
+}
+
+impl ToSql<ClockEntrySourceSqlType, Pg> for ClockEntrySource {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let source_str = match self {
+            ClockEntrySource::Clock => "clock",
+            ClockEntrySource::Manual => "manual",
+            ClockEntrySource::Kiosk => "kiosk",
+            ClockEntrySource::Sync => "sync",
+        };
+        out.write_all(source_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ClockEntrySourceSqlType, Pg> for ClockEntrySource {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let source_str = std::str::from_utf8(bytes.as_bytes())?;
+        match source_str {
+            "clock" => Ok(ClockEntrySource::Clock),
+            "manual" => Ok(ClockEntrySource::Manual),
+            "kiosk" => Ok(ClockEntrySource::Kiosk),
+            "sync" => Ok(ClockEntrySource::Sync),
+            _ => Err(format!("Unrecognized clock entry source: {}", source_str).into()),
+        }
+    }
+}
+
 /// Absence status enumeration matching the database absence_status ENUM
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
 #[diesel(sql_type = AbsenceStatusSqlType)]
@@ -164,6 +219,11 @@ pub enum NotificationType {
     ClockCorrection,
     ClockApproved,
     ClockRejected,
+    ClockAutoClosed,
+    ShiftSwapOffered,
+    ShiftSwapClaimed,
+    ShiftSwapApproved,
+    ShiftSwapRejected,
 }
 
 impl ToSql<NotificationTypeSqlType, Pg> for NotificationType {
@@ -175,6 +235,11 @@ impl ToSql<NotificationTypeSqlType, Pg> for NotificationType {
             NotificationType::ClockCorrection => "clock_correction",
             NotificationType::ClockApproved => "clock_approved",
             NotificationType::ClockRejected => "clock_rejected",
+            NotificationType::ClockAutoClosed => "clock_auto_closed",
+            NotificationType::ShiftSwapOffered => "shift_swap_offered",
+            NotificationType::ShiftSwapClaimed => "shift_swap_claimed",
+            NotificationType::ShiftSwapApproved => "shift_swap_approved",
+            NotificationType::ShiftSwapRejected => "shift_swap_rejected",
         };
         out.write_all(type_str.as_bytes())?;
         Ok(IsNull::No)
@@ -191,6 +256,11 @@ impl FromSql<NotificationTypeSqlType, Pg> for NotificationType {
             "clock_correction" => Ok(NotificationType::ClockCorrection),
             "clock_approved" => Ok(NotificationType::ClockApproved),
             "clock_rejected" => Ok(NotificationType::ClockRejected),
+            "clock_auto_closed" => Ok(NotificationType::ClockAutoClosed),
+            "shift_swap_offered" => Ok(NotificationType::ShiftSwapOffered),
+            "shift_swap_claimed" => Ok(NotificationType::ShiftSwapClaimed),
+            "shift_swap_approved" => Ok(NotificationType::ShiftSwapApproved),
+            "shift_swap_rejected" => Ok(NotificationType::ShiftSwapRejected),
             _ => Err(format!("Unrecognized notification type: {}", type_str).into()),
         }
     }
@@ -312,6 +382,86 @@ impl FromSql<ClockOverrideStatusSqlType, Pg> for ClockOverrideStatus {
     }
 }
 
+/// Clock correction request status enumeration matching the database clock_correction_status ENUM
+/// - Pending: Waiting for manager review
+/// - Approved: Manager approved the correction, the clock entry was updated
+/// - Rejected: Manager rejected the correction, the clock entry is unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = ClockCorrectionStatusSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum ClockCorrectionStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ToSql<ClockCorrectionStatusSqlType, Pg> for ClockCorrectionStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let status_str = match self {
+            ClockCorrectionStatus::Pending => "pending",
+            ClockCorrectionStatus::Approved => "approved",
+            ClockCorrectionStatus::Rejected => "rejected",
+        };
+        out.write_all(status_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ClockCorrectionStatusSqlType, Pg> for ClockCorrectionStatus {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status_str = std::str::from_utf8(bytes.as_bytes())?;
+        match status_str {
+            "pending" => Ok(ClockCorrectionStatus::Pending),
+            "approved" => Ok(ClockCorrectionStatus::Approved),
+            "rejected" => Ok(ClockCorrectionStatus::Rejected),
+            _ => Err(format!("Unrecognized clock correction status: {}", status_str).into()),
+        }
+    }
+}
+
+/// Offline clock sync event status enumeration matching the database clock_sync_status ENUM
+/// - Applied: The event was replayed and created or closed a clock entry
+/// - Flagged: Applied, but the device clock drifted and the entry needs manager approval
+/// - Rejected: The event was not replayed (impossible sequence, restriction, ...)
+/// - Pending: Reserved by an upload that is still replaying it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = ClockSyncStatusSqlType)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockSyncStatus {
+    Applied,
+    Flagged,
+    Rejected,
+    Pending,
+}
+
+impl ToSql<ClockSyncStatusSqlType, Pg> for ClockSyncStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let status_str = match self {
+            ClockSyncStatus::Applied => "applied",
+            ClockSyncStatus::Flagged => "flagged",
+            ClockSyncStatus::Rejected => "rejected",
+            ClockSyncStatus::Pending => "pending",
+        };
+        out.write_all(status_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ClockSyncStatusSqlType, Pg> for ClockSyncStatus {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status_str = std::str::from_utf8(bytes.as_bytes())?;
+        match status_str {
+            "applied" => Ok(ClockSyncStatus::Applied),
+            "flagged" => Ok(ClockSyncStatus::Flagged),
+            "rejected" => Ok(ClockSyncStatus::Rejected),
+            "pending" => Ok(ClockSyncStatus::Pending),
+            _ => Err(format!("Unrecognized clock sync status: {}", status_str).into()),
+        }
+    }
+}
+
 /// Break tracking mode enumeration matching the database break_tracking_mode ENUM
 /// - AutoDeduct: Breaks are automatically deducted from worked hours based on policy
 /// - ExplicitTracking: Users must explicitly start/end breaks
@@ -347,6 +497,391 @@ impl FromSql<BreakTrackingModeSqlType, Pg> for BreakTrackingMode {
     }
 }
 
+/// Geofence mode enumeration matching the database geofence_mode ENUM
+/// - Off: Position is neither required nor checked
+/// - RecordOnly: Position is checked and stored, entries outside every work site are flagged
+/// - Enforce: Clocking in/out is refused outside every active work site
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = GeofenceModeSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum GeofenceMode {
+    #[default]
+    Off,
+    RecordOnly,
+    Enforce,
+}
+
+impl ToSql<GeofenceModeSqlType, Pg> for GeofenceMode {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let mode_str = match self {
+            GeofenceMode::Off => "off",
+            GeofenceMode::RecordOnly => "record_only",
+            GeofenceMode::Enforce => "enforce",
+        };
+        out.write_all(mode_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<GeofenceModeSqlType, Pg> for GeofenceMode {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let mode_str = std::str::from_utf8(bytes.as_bytes())?;
+        match mode_str {
+            "off" => Ok(GeofenceMode::Off),
+            "record_only" => Ok(GeofenceMode::RecordOnly),
+            "enforce" => Ok(GeofenceMode::Enforce),
+            _ => Err(format!("Unrecognized geofence mode: {}", mode_str).into()),
+        }
+    }
+}
+
+/// Pay period frequency enumeration matching the database pay_period_frequency ENUM
+/// - Weekly / BiWeekly: Periods of 7 or 14 days counted from an anchor date
+/// - SemiMonthly: 1st to 15th, then 16th to the end of the month
+/// - Monthly: Calendar months
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = PayPeriodFrequencySqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum PayPeriodFrequency {
+    Weekly,
+    BiWeekly,
+    SemiMonthly,
+    #[default]
+    Monthly,
+}
+
+impl ToSql<PayPeriodFrequencySqlType, Pg> for PayPeriodFrequency {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let frequency_str = match self {
+            PayPeriodFrequency::Weekly => "weekly",
+            PayPeriodFrequency::BiWeekly => "bi_weekly",
+            PayPeriodFrequency::SemiMonthly => "semi_monthly",
+            PayPeriodFrequency::Monthly => "monthly",
+        };
+        out.write_all(frequency_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<PayPeriodFrequencySqlType, Pg> for PayPeriodFrequency {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let frequency_str = std::str::from_utf8(bytes.as_bytes())?;
+        match frequency_str {
+            "weekly" => Ok(PayPeriodFrequency::Weekly),
+            "bi_weekly" => Ok(PayPeriodFrequency::BiWeekly),
+            "semi_monthly" => Ok(PayPeriodFrequency::SemiMonthly),
+            "monthly" => Ok(PayPeriodFrequency::Monthly),
+            _ => Err(format!("Unrecognized pay period frequency: {}", frequency_str).into()),
+        }
+    }
+}
+
+/// Anomaly kind enumeration matching the database anomaly_kind ENUM
+/// - LongShift: Shift longer than the configured maximum
+/// - AbsenceOverlap: Entry on a day covered by an approved absence
+/// - ClosedDayOverlap: Entry on an organization closed day
+/// - RapidClocking: Many clock-ins within a short window
+/// - NewIp: Clock-in from an address never used by the user
+/// - LongBreak: Break longer than the policy's maximum duration
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = AnomalyKindSqlType)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    LongShift,
+    AbsenceOverlap,
+    ClosedDayOverlap,
+    RapidClocking,
+    NewIp,
+    LongBreak,
+}
+
+impl ToSql<AnomalyKindSqlType, Pg> for AnomalyKind {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let kind_str = match self {
+            AnomalyKind::LongShift => "long_shift",
+            AnomalyKind::AbsenceOverlap => "absence_overlap",
+            AnomalyKind::ClosedDayOverlap => "closed_day_overlap",
+            AnomalyKind::RapidClocking => "rapid_clocking",
+            AnomalyKind::NewIp => "new_ip",
+            AnomalyKind::LongBreak => "long_break",
+        };
+        out.write_all(kind_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AnomalyKindSqlType, Pg> for AnomalyKind {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let kind_str = std::str::from_utf8(bytes.as_bytes())?;
+        match kind_str {
+            "long_shift" => Ok(AnomalyKind::LongShift),
+            "absence_overlap" => Ok(AnomalyKind::AbsenceOverlap),
+            "closed_day_overlap" => Ok(AnomalyKind::ClosedDayOverlap),
+            "rapid_clocking" => Ok(AnomalyKind::RapidClocking),
+            "new_ip" => Ok(AnomalyKind::NewIp),
+            "long_break" => Ok(AnomalyKind::LongBreak),
+            _ => Err(format!("Unrecognized anomaly kind: {}", kind_str).into()),
+        }
+    }
+}
+
+/// Anomaly severity enumeration matching the database anomaly_severity ENUM
+/// Ordered from least to most severe
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    AsExpression,
+    FromSqlRow,
+)]
+#[diesel(sql_type = AnomalySeveritySqlType)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalySeverity {
+    Low,
+    Medium,
+    High,
+}
+
+impl ToSql<AnomalySeveritySqlType, Pg> for AnomalySeverity {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let severity_str = match self {
+            AnomalySeverity::Low => "low",
+            AnomalySeverity::Medium => "medium",
+            AnomalySeverity::High => "high",
+        };
+        out.write_all(severity_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AnomalySeveritySqlType, Pg> for AnomalySeverity {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let severity_str = std::str::from_utf8(bytes.as_bytes())?;
+        match severity_str {
+            "low" => Ok(AnomalySeverity::Low),
+            "medium" => Ok(AnomalySeverity::Medium),
+            "high" => Ok(AnomalySeverity::High),
+            _ => Err(format!("Unrecognized anomaly severity: {}", severity_str).into()),
+        }
+    }
+}
+
+/// Anomaly status enumeration matching the database anomaly_status ENUM
+/// - Open: Awaiting review
+/// - Dismissed: Reviewed and found legitimate
+/// - Resolved: Reviewed and acted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AnomalyStatusSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum AnomalyStatus {
+    #[default]
+    Open,
+    Dismissed,
+    Resolved,
+}
+
+impl ToSql<AnomalyStatusSqlType, Pg> for AnomalyStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let status_str = match self {
+            AnomalyStatus::Open => "open",
+            AnomalyStatus::Dismissed => "dismissed",
+            AnomalyStatus::Resolved => "resolved",
+        };
+        out.write_all(status_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AnomalyStatusSqlType, Pg> for AnomalyStatus {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status_str = std::str::from_utf8(bytes.as_bytes())?;
+        match status_str {
+            "open" => Ok(AnomalyStatus::Open),
+            "dismissed" => Ok(AnomalyStatus::Dismissed),
+            "resolved" => Ok(AnomalyStatus::Resolved),
+            _ => Err(format!("Unrecognized anomaly status: {}", status_str).into()),
+        }
+    }
+}
+
+/// Roster shift status matching the database roster_shift_status ENUM
+/// - Draft: Planned, only visible to managers
+/// - Published: Visible to the employee and counted as their planned time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = RosterShiftStatusSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum RosterShiftStatus {
+    #[default]
+    Draft,
+    Published,
+}
+
+impl ToSql<RosterShiftStatusSqlType, Pg> for RosterShiftStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let status_str = match self {
+            RosterShiftStatus::Draft => "draft",
+            RosterShiftStatus::Published => "published",
+        };
+        out.write_all(status_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<RosterShiftStatusSqlType, Pg> for RosterShiftStatus {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status_str = std::str::from_utf8(bytes.as_bytes())?;
+        match status_str {
+            "draft" => Ok(RosterShiftStatus::Draft),
+            "published" => Ok(RosterShiftStatus::Published),
+            _ => Err(format!("Unrecognized roster shift status: {}", status_str).into()),
+        }
+    }
+}
+
+/// Shift swap status matching the database shift_swap_status ENUM
+/// - Open: Offered to a colleague or released to the open pool
+/// - PendingApproval: Claimed, awaiting manager approval
+/// - Approved: Applied, the shift belongs to the claimant
+/// - Rejected: Refused by a manager
+/// - Cancelled: Withdrawn by the requester
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = ShiftSwapStatusSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum ShiftSwapStatus {
+    #[default]
+    Open,
+    PendingApproval,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl ToSql<ShiftSwapStatusSqlType, Pg> for ShiftSwapStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let status_str = match self {
+            ShiftSwapStatus::Open => "open",
+            ShiftSwapStatus::PendingApproval => "pending_approval",
+            ShiftSwapStatus::Approved => "approved",
+            ShiftSwapStatus::Rejected => "rejected",
+            ShiftSwapStatus::Cancelled => "cancelled",
+        };
+        out.write_all(status_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ShiftSwapStatusSqlType, Pg> for ShiftSwapStatus {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status_str = std::str::from_utf8(bytes.as_bytes())?;
+        match status_str {
+            "open" => Ok(ShiftSwapStatus::Open),
+            "pending_approval" => Ok(ShiftSwapStatus::PendingApproval),
+            "approved" => Ok(ShiftSwapStatus::Approved),
+            "rejected" => Ok(ShiftSwapStatus::Rejected),
+            "cancelled" => Ok(ShiftSwapStatus::Cancelled),
+            _ => Err(format!("Unrecognized shift swap status: {}", status_str).into()),
+        }
+    }
+}
+
+/// Part of a date taken by an absence, matching the database absence_half_day ENUM
+/// - Full: The whole day
+/// - Morning: Only the morning
+/// - Afternoon: Only the afternoon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AbsenceHalfDaySqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum AbsenceHalfDay {
+    #[default]
+    Full,
+    Morning,
+    Afternoon,
+}
+
+impl AbsenceHalfDay {
+    /// Whether the morning and the afternoon are taken
+    pub fn parts(self) -> (bool, bool) {
+        match self {
+            AbsenceHalfDay::Full => (true, true),
+            AbsenceHalfDay::Morning => (true, false),
+            AbsenceHalfDay::Afternoon => (false, true),
+        }
+    }
+}
+
+impl ToSql<AbsenceHalfDaySqlType, Pg> for AbsenceHalfDay {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let half_str = match self {
+            AbsenceHalfDay::Full => "full",
+            AbsenceHalfDay::Morning => "morning",
+            AbsenceHalfDay::Afternoon => "afternoon",
+        };
+        out.write_all(half_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AbsenceHalfDaySqlType, Pg> for AbsenceHalfDay {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let half_str = std::str::from_utf8(bytes.as_bytes())?;
+        match half_str {
+            "full" => Ok(AbsenceHalfDay::Full),
+            "morning" => Ok(AbsenceHalfDay::Morning),
+            "afternoon" => Ok(AbsenceHalfDay::Afternoon),
+            _ => Err(format!("Unrecognized absence half day: {}", half_str).into()),
+        }
+    }
+}
+
+/// Unit of an absence type matching the database absence_unit ENUM
+/// - Days: Requested in whole or half days, balances held in days
+/// - Hours: Requested as a time range on a single date, balances held in hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AbsenceUnitSqlType)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum AbsenceUnit {
+    #[default]
+    Days,
+    Hours,
+}
+
+impl ToSql<AbsenceUnitSqlType, Pg> for AbsenceUnit {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let unit_str = match self {
+            AbsenceUnit::Days => "days",
+            AbsenceUnit::Hours => "hours",
+        };
+        out.write_all(unit_str.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AbsenceUnitSqlType, Pg> for AbsenceUnit {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let unit_str = std::str::from_utf8(bytes.as_bytes())?;
+        match unit_str {
+            "days" => Ok(AbsenceUnit::Days),
+            "hours" => Ok(AbsenceUnit::Hours),
+            _ => Err(format!("Unrecognized absence unit: {}", unit_str).into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,6 +903,9 @@ mod tests {
 
         let notif: NotificationType = serde_json::from_str("\"clock_approved\"").unwrap();
         assert_eq!(notif, NotificationType::ClockApproved);
+
+        let notif: NotificationType = serde_json::from_str("\"clock_auto_closed\"").unwrap();
+        assert_eq!(notif, NotificationType::ClockAutoClosed);
     }
 
     #[test]
@@ -406,6 +944,47 @@ mod tests {
         assert_eq!(json, "\"approved\"");
     }
 
+    #[test]
+    fn test_clock_entry_source_serialization() {
+        let json = serde_json::to_string(&ClockEntrySource::Manual).unwrap();
+        assert_eq!(json, "\"manual\"");
+
+        let source: ClockEntrySource = serde_json::from_str("\"kiosk\"").unwrap();
+        assert_eq!(source, ClockEntrySource::Kiosk);
+
+        assert_eq!(ClockEntrySource::default(), ClockEntrySource::Clock);
+    }
+
+    #[test]
+    fn test_clock_correction_status_serialization() {
+        let json = serde_json::to_string(&ClockCorrectionStatus::Pending).unwrap();
+        assert_eq!(json, "\"pending\"");
+
+        let status: ClockCorrectionStatus = serde_json::from_str("\"rejected\"").unwrap();
+        assert_eq!(status, ClockCorrectionStatus::Rejected);
+    }
+
+    #[test]
+    fn test_clock_sync_status_serialization() {
+        let json = serde_json::to_string(&ClockSyncStatus::Flagged).unwrap();
+        assert_eq!(json, "\"flagged\"");
+        let json = serde_json::to_string(&ClockSyncStatus::Pending).unwrap();
+        assert_eq!(json, "\"pending\"");
+
+        let source: ClockEntrySource = serde_json::from_str("\"sync\"").unwrap();
+        assert_eq!(source, ClockEntrySource::Sync);
+    }
+
+    #[test]
+    fn test_geofence_mode_serialization() {
+        let json = serde_json::to_string(&GeofenceMode::RecordOnly).unwrap();
+        assert_eq!(json, "\"record_only\"");
+
+        let mode: GeofenceMode = serde_json::from_str("\"enforce\"").unwrap();
+        assert_eq!(mode, GeofenceMode::Enforce);
+        assert_eq!(GeofenceMode::default(), GeofenceMode::Off);
+    }
+
     #[test]
     fn test_audit_action_serialization() {
         let json = serde_json::to_string(&AuditAction::Create).unwrap();
@@ -414,4 +993,64 @@ mod tests {
         let json = serde_json::to_string(&AuditAction::Update).unwrap();
         assert_eq!(json, "\"update\"");
     }
+
+    #[test]
+    fn test_pay_period_frequency_serialization() {
+        let json = serde_json::to_string(&PayPeriodFrequency::BiWeekly).unwrap();
+        assert_eq!(json, "\"bi_weekly\"");
+
+        let frequency: PayPeriodFrequency = serde_json::from_str("\"semi_monthly\"").unwrap();
+        assert_eq!(frequency, PayPeriodFrequency::SemiMonthly);
+        assert_eq!(PayPeriodFrequency::default(), PayPeriodFrequency::Monthly);
+    }
+
+    #[test]
+    fn test_anomaly_enums_serialization() {
+        let json = serde_json::to_string(&AnomalyKind::ClosedDayOverlap).unwrap();
+        assert_eq!(json, "\"closed_day_overlap\"");
+
+        let kind: AnomalyKind = serde_json::from_str("\"new_ip\"").unwrap();
+        assert_eq!(kind, AnomalyKind::NewIp);
+
+        assert!(AnomalySeverity::High > AnomalySeverity::Medium);
+        assert!(AnomalySeverity::Medium > AnomalySeverity::Low);
+        assert_eq!(AnomalyStatus::default(), AnomalyStatus::Open);
+    }
+
+    #[test]
+    fn test_roster_shift_status_serialization() {
+        let json = serde_json::to_string(&RosterShiftStatus::Published).unwrap();
+        assert_eq!(json, "\"published\"");
+
+        let status: RosterShiftStatus = serde_json::from_str("\"draft\"").unwrap();
+        assert_eq!(status, RosterShiftStatus::Draft);
+        assert_eq!(RosterShiftStatus::default(), RosterShiftStatus::Draft);
+    }
+
+    #[test]
+    fn test_shift_swap_status_serialization() {
+        let json = serde_json::to_string(&ShiftSwapStatus::PendingApproval).unwrap();
+        assert_eq!(json, "\"pending_approval\"");
+
+        let status: ShiftSwapStatus = serde_json::from_str("\"cancelled\"").unwrap();
+        assert_eq!(status, ShiftSwapStatus::Cancelled);
+
+        let notif: NotificationType = serde_json::from_str("\"shift_swap_claimed\"").unwrap();
+        assert_eq!(notif, NotificationType::ShiftSwapClaimed);
+    }
+
+    #[test]
+    fn test_absence_half_day_serialization() {
+        let json = serde_json::to_string(&AbsenceHalfDay::Afternoon).unwrap();
+        assert_eq!(json, "\"afternoon\"");
+
+        let half: AbsenceHalfDay = serde_json::from_str("\"morning\"").unwrap();
+        assert_eq!(half, AbsenceHalfDay::Morning);
+        assert_eq!(AbsenceHalfDay::default(), AbsenceHalfDay::Full);
+        assert_eq!(AbsenceHalfDay::Morning.parts(), (true, false));
+
+        let unit: AbsenceUnit = serde_json::from_str("\"hours\"").unwrap();
+        assert_eq!(unit, AbsenceUnit::Hours);
+        assert_eq!(AbsenceUnit::default(), AbsenceUnit::Days);
+    }
 }
@@ -0,0 +1,47 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+    response::{IntoResponse, Response},
+    RequestPartsExt,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+
+use crate::config::AppState;
+use crate::extractors::authenticated_user::AuthError;
+use crate::models::KioskDevice;
+use crate::services::KioskService;
+
+/// Extractor for authenticated kiosk devices
+///
+/// Validates the device credential from the Authorization header
+/// Usage: `async fn handler(AuthenticatedKiosk(device): AuthenticatedKiosk)`
+#[derive(Debug)]
+pub struct AuthenticatedKiosk(pub KioskDevice);
+
+#[async_trait]
+impl FromRequestParts<AppState> for AuthenticatedKiosk {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| AuthError::MissingToken.into_response())?;
+
+        let service = KioskService::new(state.db_pool.clone());
+        let device = service
+            .authenticate(bearer.token())
+            .await
+            .map_err(IntoResponse::into_response)?
+            .ok_or_else(|| AuthError::InvalidToken.into_response())?;
+
+        Ok(AuthenticatedKiosk(device))
+    }
+}
@@ -0,0 +1,44 @@
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::request::Parts,
+};
+
+use crate::config::AppState;
+use crate::utils::network::resolve_client_ip;
+
+/// Extractor for the client address of a request
+///
+/// Honors `X-Forwarded-For` only when the connection comes from a trusted proxy
+/// (see `TRUSTED_PROXIES`). None when the address cannot be determined.
+/// Usage: `async fn handler(ClientIp(ip): ClientIp)`
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let forwarded_for = parts
+            .headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok());
+
+        Ok(ClientIp(resolve_client_ip(
+            peer,
+            forwarded_for,
+            &state.config.network.trusted_proxies,
+        )))
+    }
+}
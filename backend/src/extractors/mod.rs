@@ -1,11 +1,15 @@
 // Request extractors for authentication and authorization
 // This module contains Axum extractors for JWT validation and role-based access control
 
+pub mod authenticated_kiosk;
 pub mod authenticated_user;
+pub mod client_ip;
 pub mod role_guard;
 pub mod validated_json;
 
 // Re-export commonly used types
+pub use authenticated_kiosk::AuthenticatedKiosk;
 pub use authenticated_user::{AuthError, AuthenticatedUser};
+pub use client_ip::ClientIp;
 pub use role_guard::{Admin, Employee, Manager, RequiredRole, RoleError, RoleGuard, SuperAdmin};
 pub use validated_json::ValidatedJson;
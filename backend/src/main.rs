@@ -10,12 +10,16 @@ use std::time::Duration;
 use timemanager_backend::{
     api::router::create_router,
     config::app::{AppConfig, AppState},
+    config::auto_close::AutoCloseConfig,
     config::database::{create_pool, DbPool},
     repositories::{
         InviteTokenRepository, LoginAttemptRepository, PasswordResetRepository,
         RefreshTokenRepository, UserSessionRepository,
     },
-    services::{EmailService, EndpointRateLimiter, HibpService, MetricsService},
+    services::{
+        ClockAnomalyService, ClockService, EmailService, EndpointRateLimiter, HibpService,
+        MetricsService,
+    },
 };
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::{fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt};
@@ -26,6 +30,10 @@ const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 /// Cleanup interval: 24 hours
 const CLEANUP_INTERVAL_SECS: u64 = 86400;
 
+/// Overlap between two clock anomaly evaluation windows: 1 hour
+/// Entries committed late are evaluated again, already recorded findings are skipped
+const ANOMALY_WINDOW_OVERLAP_SECS: i64 = 3600;
+
 /// Initialize tracing with OpenTelemetry support for Tempo and JSON logging for Loki
 fn init_tracing() -> anyhow::Result<()> {
     // Check if OTLP endpoint is configured
@@ -110,6 +118,11 @@ async fn run_cleanup_jobs(pool: DbPool, rate_limiter: Arc<EndpointRateLimiter>)
     // Skip the first immediate tick
     interval.tick().await;
 
+    // Start of the next clock anomaly window, only moved forward by successful runs
+    // The first window also covers the interval before startup
+    let mut anomalies_since = chrono::Utc::now()
+        - chrono::Duration::seconds(CLEANUP_INTERVAL_SECS as i64 + ANOMALY_WINDOW_OVERLAP_SECS);
+
     loop {
         interval.tick().await;
         tracing::info!("Starting scheduled cleanup jobs...");
@@ -149,6 +162,18 @@ async fn run_cleanup_jobs(pool: DbPool, rate_limiter: Arc<EndpointRateLimiter>)
             Err(e) => tracing::error!("Failed to cleanup invite tokens: {}", e),
         }
 
+        // Evaluate clock anomalies of entries closed or changed since the previous run
+        let anomaly_service = ClockAnomalyService::new(pool.clone());
+        let run_started = chrono::Utc::now();
+        match anomaly_service.evaluate_since(anomalies_since).await {
+            Ok(count) => {
+                tracing::info!("Recorded {} new clock anomalies", count);
+                anomalies_since =
+                    run_started - chrono::Duration::seconds(ANOMALY_WINDOW_OVERLAP_SECS);
+            }
+            Err(e) => tracing::error!("Failed to evaluate clock anomalies: {}", e),
+        }
+
         // Cleanup in-memory rate limiter
         match rate_limiter.cleanup() {
             Ok(count) => tracing::info!("Cleaned up {} rate limiter entries", count),
@@ -159,6 +184,25 @@ async fn run_cleanup_jobs(pool: DbPool, rate_limiter: Arc<EndpointRateLimiter>)
     }
 }
 
+/// Background job closing clock entries that were left open
+async fn run_auto_close_job(pool: DbPool, config: AutoCloseConfig) {
+    let mut interval = tokio::time::interval(Duration::from_secs(config.interval_seconds));
+
+    // Skip the first immediate tick
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let clock_service = ClockService::new(pool.clone());
+        match clock_service.auto_close_stale_entries(&config).await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Auto-closed {} forgotten clock entries", count),
+            Err(e) => tracing::error!("Failed to auto-close clock entries: {}", e),
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize tracing with OpenTelemetry support
@@ -222,6 +266,18 @@ async fn main() -> anyhow::Result<()> {
         metrics_service,
     };
 
+    // Spawn background auto-close job for forgotten clock entries
+    if config.auto_close.enabled {
+        tokio::spawn(run_auto_close_job(
+            db_pool.clone(),
+            config.auto_close.clone(),
+        ));
+        tracing::info!(
+            "Clock auto-close job scheduled (runs every {} seconds)",
+            config.auto_close.interval_seconds
+        );
+    }
+
     // Spawn background cleanup job
     tokio::spawn(run_cleanup_jobs(db_pool, rate_limiter));
     tracing::info!("Background cleanup job scheduled (runs every 24 hours)");
@@ -238,7 +294,11 @@ async fn main() -> anyhow::Result<()> {
 
     // Start server
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -1,11 +1,55 @@
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::enums::AbsenceStatus;
-use crate::schema::absences;
+use crate::domain::enums::{AbsenceHalfDay, AbsenceStatus, AbsenceUnit, UserRole};
+use crate::models::AbsenceApprovalResponse;
+use crate::schema::{absence_settings, absences};
+
+/// AbsenceSettings entity from database
+/// Organizations without settings use the defaults
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = absence_settings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AbsenceSettings {
+    pub organization_id: Uuid,
+    /// Working week of users without a work schedule (0 = Monday)
+    pub working_days: Vec<i16>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewAbsenceSettings for creating or replacing settings
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = absence_settings)]
+pub struct NewAbsenceSettings {
+    pub organization_id: Uuid,
+    pub working_days: Vec<i16>,
+}
+
+/// AbsenceSettings response for API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AbsenceSettingsResponse {
+    pub working_days: Vec<i16>,
+}
+
+impl Default for AbsenceSettingsResponse {
+    fn default() -> Self {
+        Self {
+            working_days: vec![0, 1, 2, 3, 4],
+        }
+    }
+}
+
+impl From<AbsenceSettings> for AbsenceSettingsResponse {
+    fn from(settings: AbsenceSettings) -> Self {
+        Self {
+            working_days: settings.working_days,
+        }
+    }
+}
 
 /// Absence entity from database
 #[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
@@ -26,6 +70,35 @@ pub struct Absence {
     pub approved_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Part of `start_date` taken
+    pub start_half: AbsenceHalfDay,
+    /// Part of `end_date` taken
+    pub end_half: AbsenceHalfDay,
+    /// Set for absences of an hourly type, on a single date
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    /// Requested hours, set for absences of an hourly type
+    pub hours: Option<BigDecimal>,
+}
+
+impl Absence {
+    /// Dates, halves and times covered by the absence
+    pub fn period(&self) -> AbsencePeriod {
+        AbsencePeriod {
+            start_date: self.start_date,
+            end_date: self.end_date,
+            start_half: self.start_half,
+            end_half: self.end_half,
+            times: self.start_time.zip(self.end_time),
+        }
+    }
+
+    /// Amount taken from the leave balance: hours for hourly absences, days otherwise
+    pub fn balance_amount(&self) -> BigDecimal {
+        self.hours
+            .clone()
+            .unwrap_or_else(|| self.days_count.clone())
+    }
 }
 
 /// NewAbsence for creating absences
@@ -40,6 +113,11 @@ pub struct NewAbsence {
     pub days_count: BigDecimal,
     pub status: AbsenceStatus,
     pub reason: Option<String>,
+    pub start_half: AbsenceHalfDay,
+    pub end_half: AbsenceHalfDay,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub hours: Option<BigDecimal>,
 }
 
 /// Absence update struct for partial updates
@@ -70,6 +148,11 @@ pub struct AbsenceResponse {
     pub type_color: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    pub start_half: AbsenceHalfDay,
+    pub end_half: AbsenceHalfDay,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub hours: Option<f64>,
     pub days_count: f64,
     pub status: AbsenceStatus,
     pub reason: Option<String>,
@@ -77,9 +160,31 @@ pub struct AbsenceResponse {
     pub approved_by: Option<Uuid>,
     pub approver_name: Option<String>,
     pub approved_at: Option<DateTime<Utc>>,
+    /// Roles approving the absence, one per step
+    pub required_approvals: Vec<UserRole>,
+    /// Steps approved so far
+    pub approvals: Vec<AbsenceApprovalResponse>,
     pub created_at: DateTime<Utc>,
 }
 
+/// Cost of an absence request, computed before it is submitted
+#[derive(Debug, Clone, Serialize)]
+pub struct AbsencePreview {
+    pub type_id: Uuid,
+    pub unit: AbsenceUnit,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Dates charged, at least partly
+    pub working_dates: Vec<NaiveDate>,
+    pub days_count: f64,
+    /// Set for hourly absence types
+    pub hours: Option<f64>,
+    /// Remaining balance before the request, None when the type has no balance
+    pub remaining_balance: Option<f64>,
+    /// Remaining balance once the request is approved
+    pub remaining_after: Option<f64>,
+}
+
 /// Absence filter options
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct AbsenceFilter {
@@ -109,3 +214,149 @@ pub struct PaginatedAbsences {
     pub per_page: i64,
     pub total_pages: i64,
 }
+
+/// Dates and parts of dates covered by an absence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbsencePeriod {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Part of `start_date` taken
+    pub start_half: AbsenceHalfDay,
+    /// Part of `end_date` taken
+    pub end_half: AbsenceHalfDay,
+    /// Time range of an hourly absence, on a single date
+    pub times: Option<(NaiveTime, NaiveTime)>,
+}
+
+impl AbsencePeriod {
+    /// Full days from `start_date` to `end_date`
+    pub fn days(start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        Self {
+            start_date,
+            end_date,
+            start_half: AbsenceHalfDay::Full,
+            end_half: AbsenceHalfDay::Full,
+            times: None,
+        }
+    }
+
+    /// Whether the morning and the afternoon of `date` are taken
+    /// An hourly absence takes the halves its time range reaches, split at noon
+    pub fn parts_on(&self, date: NaiveDate) -> (bool, bool) {
+        if date < self.start_date || date > self.end_date {
+            return (false, false);
+        }
+        if let Some((start, end)) = self.times {
+            let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default();
+            return (start < noon, end > noon);
+        }
+        let mut parts = (true, true);
+        if date == self.start_date {
+            let (morning, afternoon) = self.start_half.parts();
+            parts = (parts.0 && morning, parts.1 && afternoon);
+        }
+        if date == self.end_date {
+            let (morning, afternoon) = self.end_half.parts();
+            parts = (parts.0 && morning, parts.1 && afternoon);
+        }
+        parts
+    }
+
+    /// Whether both periods take the same part of a date
+    ///
+    /// Two half days on the same date only overlap when they take the same half,
+    /// and two hourly absences when their time ranges intersect.
+    pub fn overlaps(&self, other: &AbsencePeriod) -> bool {
+        let first = self.start_date.max(other.start_date);
+        let last = self.end_date.min(other.end_date);
+        if first > last {
+            return false;
+        }
+        if let (Some((start, end)), Some((other_start, other_end))) = (self.times, other.times) {
+            return start < other_end && other_start < end;
+        }
+        // Dates strictly inside both periods are taken in full by both
+        if (last - first).num_days() >= 2 {
+            return true;
+        }
+        [first, last].into_iter().any(|date| {
+            let (morning, afternoon) = self.parts_on(date);
+            let (other_morning, other_afternoon) = other.parts_on(date);
+            (morning && other_morning) || (afternoon && other_afternoon)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn period(
+        start: u32,
+        end: u32,
+        start_half: AbsenceHalfDay,
+        end_half: AbsenceHalfDay,
+    ) -> AbsencePeriod {
+        AbsencePeriod {
+            start_half,
+            end_half,
+            ..AbsencePeriod::days(date(start), date(end))
+        }
+    }
+
+    fn hourly(day: u32, start: u32, end: u32) -> AbsencePeriod {
+        AbsencePeriod {
+            times: Some((
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            )),
+            ..AbsencePeriod::days(date(day), date(day))
+        }
+    }
+
+    #[test]
+    fn test_parts_on() {
+        use AbsenceHalfDay::*;
+
+        // From the 2nd at noon to the 4th at noon
+        let leave = period(2, 4, Afternoon, Morning);
+        assert_eq!(leave.parts_on(date(1)), (false, false));
+        assert_eq!(leave.parts_on(date(2)), (false, true));
+        assert_eq!(leave.parts_on(date(3)), (true, true));
+        assert_eq!(leave.parts_on(date(4)), (true, false));
+
+        let morning = period(5, 5, Morning, Morning);
+        assert_eq!(morning.parts_on(date(5)), (true, false));
+
+        assert_eq!(hourly(5, 9, 11).parts_on(date(5)), (true, false));
+        assert_eq!(hourly(5, 11, 14).parts_on(date(5)), (true, true));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        use AbsenceHalfDay::*;
+
+        // Morning and afternoon of the same date
+        let morning = period(5, 5, Morning, Morning);
+        assert!(!morning.overlaps(&period(5, 5, Afternoon, Afternoon)));
+        assert!(morning.overlaps(&period(5, 5, Morning, Morning)));
+        assert!(morning.overlaps(&AbsencePeriod::days(date(5), date(5))));
+
+        // A leave ending on a morning and one starting that afternoon
+        let leave = period(2, 4, Full, Morning);
+        assert!(!leave.overlaps(&period(4, 6, Afternoon, Full)));
+        assert!(leave.overlaps(&AbsencePeriod::days(date(4), date(6))));
+        assert!(leave.overlaps(&AbsencePeriod::days(date(1), date(6))));
+        assert!(!leave.overlaps(&AbsencePeriod::days(date(5), date(6))));
+
+        // Hourly absences compare their time ranges
+        assert!(!hourly(5, 9, 11).overlaps(&hourly(5, 11, 12)));
+        assert!(hourly(5, 9, 11).overlaps(&hourly(5, 10, 12)));
+        assert!(!hourly(5, 14, 16).overlaps(&morning));
+        assert!(hourly(5, 9, 10).overlaps(&morning));
+    }
+}
@@ -0,0 +1,155 @@
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::UserRole;
+use crate::schema::{absence_approval_steps, absence_approvals};
+
+/// AbsenceApprovalStep entity from database
+/// One step of the approval chain of an absence type
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = absence_approval_steps)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AbsenceApprovalStep {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub absence_type_id: Uuid,
+    pub step_order: i16,
+    /// Lowest role allowed to approve the step
+    pub approver_role: UserRole,
+    /// The step only applies to absences of more than this many days
+    pub min_days: Option<BigDecimal>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// NewAbsenceApprovalStep for replacing the chain of a type
+#[derive(Debug, Insertable)]
+#[diesel(table_name = absence_approval_steps)]
+pub struct NewAbsenceApprovalStep {
+    pub organization_id: Uuid,
+    pub absence_type_id: Uuid,
+    pub step_order: i16,
+    pub approver_role: UserRole,
+    pub min_days: Option<BigDecimal>,
+}
+
+/// AbsenceApprovalStep response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct AbsenceApprovalStepResponse {
+    pub step_order: i16,
+    pub approver_role: UserRole,
+    pub min_days: Option<f64>,
+}
+
+impl From<AbsenceApprovalStep> for AbsenceApprovalStepResponse {
+    fn from(step: AbsenceApprovalStep) -> Self {
+        Self {
+            step_order: step.step_order,
+            approver_role: step.approver_role,
+            min_days: step.min_days.and_then(|d| d.to_f64()),
+        }
+    }
+}
+
+/// AbsenceApproval entity from database
+/// An approved step of an absence
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = absence_approvals)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AbsenceApproval {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub absence_id: Uuid,
+    pub step_order: i16,
+    pub approver_id: Option<Uuid>,
+    pub comment: Option<String>,
+    pub approved_at: DateTime<Utc>,
+}
+
+/// NewAbsenceApproval for recording an approved step
+#[derive(Debug, Insertable)]
+#[diesel(table_name = absence_approvals)]
+pub struct NewAbsenceApproval {
+    pub organization_id: Uuid,
+    pub absence_id: Uuid,
+    pub step_order: i16,
+    pub approver_id: Option<Uuid>,
+    pub comment: Option<String>,
+}
+
+/// AbsenceApproval response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct AbsenceApprovalResponse {
+    pub step_order: i16,
+    pub approver_id: Option<Uuid>,
+    pub approver_name: Option<String>,
+    pub comment: Option<String>,
+    pub approved_at: DateTime<Utc>,
+}
+
+/// Roles approving an absence of `days_count` days, one per step in order
+///
+/// Steps whose `min_days` is not exceeded are skipped. Types without any
+/// applicable step need a single approval from a manager or above.
+pub fn approval_chain(steps: &[AbsenceApprovalStep], days_count: f64) -> Vec<UserRole> {
+    let mut steps: Vec<&AbsenceApprovalStep> = steps
+        .iter()
+        .filter(|step| {
+            step.min_days
+                .as_ref()
+                .and_then(|d| d.to_f64())
+                .is_none_or(|min_days| days_count > min_days)
+        })
+        .collect();
+    steps.sort_by_key(|step| step.step_order);
+
+    let chain: Vec<UserRole> = steps.iter().map(|step| step.approver_role).collect();
+    if chain.is_empty() {
+        vec![UserRole::Manager]
+    } else {
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(
+        step_order: i16,
+        approver_role: UserRole,
+        min_days: Option<&str>,
+    ) -> AbsenceApprovalStep {
+        AbsenceApprovalStep {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            absence_type_id: Uuid::nil(),
+            step_order,
+            approver_role,
+            min_days: min_days.map(|d| d.parse().unwrap()),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_approval_chain() {
+        assert_eq!(approval_chain(&[], 10.0), vec![UserRole::Manager]);
+
+        // Team manager, then HR for absences of more than 5 days
+        let steps = vec![
+            step(2, UserRole::Admin, Some("5")),
+            step(1, UserRole::Manager, None),
+        ];
+        assert_eq!(approval_chain(&steps, 5.0), vec![UserRole::Manager]);
+        assert_eq!(
+            approval_chain(&steps, 5.5),
+            vec![UserRole::Manager, UserRole::Admin]
+        );
+
+        // Only conditional steps, none applying
+        let steps = vec![step(1, UserRole::Admin, Some("10"))];
+        assert_eq!(approval_chain(&steps, 3.0), vec![UserRole::Manager]);
+    }
+}
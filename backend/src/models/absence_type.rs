@@ -3,6 +3,7 @@ use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::enums::AbsenceUnit;
 use crate::schema::absence_types;
 
 /// AbsenceType entity from database
@@ -20,6 +21,8 @@ pub struct AbsenceType {
     pub is_paid: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Unit of requests and balances
+    pub unit: AbsenceUnit,
 }
 
 /// NewAbsenceType for creating absence types
@@ -33,6 +36,7 @@ pub struct NewAbsenceType {
     pub requires_approval: bool,
     pub affects_balance: bool,
     pub is_paid: bool,
+    pub unit: AbsenceUnit,
 }
 
 /// AbsenceType update struct for partial updates
@@ -45,6 +49,7 @@ pub struct AbsenceTypeUpdate {
     pub requires_approval: Option<bool>,
     pub affects_balance: Option<bool>,
     pub is_paid: Option<bool>,
+    pub unit: Option<AbsenceUnit>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -58,6 +63,7 @@ pub struct AbsenceTypeResponse {
     pub requires_approval: bool,
     pub affects_balance: bool,
     pub is_paid: bool,
+    pub unit: AbsenceUnit,
     pub created_at: DateTime<Utc>,
 }
 
@@ -71,6 +77,7 @@ impl From<AbsenceType> for AbsenceTypeResponse {
             requires_approval: at.requires_approval,
             affects_balance: at.affects_balance,
             is_paid: at.is_paid,
+            unit: at.unit,
             created_at: at.created_at,
         }
     }
@@ -96,6 +96,8 @@ pub struct BreakWindow {
     pub max_duration_minutes: i32,
     pub is_mandatory: bool,
     pub created_at: DateTime<Utc>,
+    /// Rotation week of the user's schedule (None applies to every week)
+    pub week_index: Option<i16>,
 }
 
 /// New break window for insert
@@ -109,6 +111,7 @@ pub struct NewBreakWindow {
     pub min_duration_minutes: i32,
     pub max_duration_minutes: i32,
     pub is_mandatory: bool,
+    pub week_index: Option<i16>,
 }
 
 /// Break window response
@@ -121,6 +124,7 @@ pub struct BreakWindowResponse {
     pub min_duration_minutes: i32,
     pub max_duration_minutes: i32,
     pub is_mandatory: bool,
+    pub week_index: Option<i16>,
 }
 
 // ============================================================================
@@ -207,6 +211,8 @@ pub struct UpdateBreakPolicyRequest {
 /// Create break window request
 #[derive(Debug, Deserialize, Clone)]
 pub struct CreateBreakWindowRequest {
+    /// Rotation week the window applies to (every week when omitted)
+    pub week_index: Option<i16>,
     pub day_of_week: i16,
     pub window_start: String,
     pub window_end: String,
@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Selection of pending items by filter
+#[derive(Debug, Default, Deserialize)]
+pub struct BulkReviewFilter {
+    pub team_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    /// First day included (organization timezone)
+    pub start_date: Option<NaiveDate>,
+    /// Last day included (organization timezone)
+    pub end_date: Option<NaiveDate>,
+}
+
+/// Request to approve or reject several items at once
+///
+/// Items are selected either by `ids` or by `filter`, not both.
+#[derive(Debug, Deserialize)]
+pub struct BulkReviewRequest {
+    pub ids: Option<Vec<Uuid>>,
+    pub filter: Option<BulkReviewFilter>,
+    /// Rejection reason, ignored on approval
+    pub reason: Option<String>,
+}
+
+/// Outcome of one item of a bulk review
+#[derive(Debug, Serialize)]
+pub struct BulkReviewItemResult {
+    pub id: Uuid,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of a bulk review
+#[derive(Debug, Serialize)]
+pub struct BulkReviewResponse {
+    pub processed: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkReviewItemResult>,
+}
+
+impl BulkReviewResponse {
+    pub fn from_results(results: Vec<BulkReviewItemResult>) -> Self {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Self {
+            processed: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+}
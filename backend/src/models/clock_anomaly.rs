@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::{AnomalyKind, AnomalySeverity, AnomalyStatus};
+use crate::schema::{anomaly_settings, clock_anomalies};
+
+/// AnomalySettings entity from database
+/// Organizations without settings use the default thresholds
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = anomaly_settings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AnomalySettings {
+    pub organization_id: Uuid,
+    /// Shifts longer than this are flagged
+    pub max_shift_hours: i32,
+    /// Clock-ins allowed within the window before flagging
+    pub rapid_clock_max_entries: i32,
+    pub rapid_clock_window_minutes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewAnomalySettings for creating or replacing settings
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = anomaly_settings)]
+pub struct NewAnomalySettings {
+    pub organization_id: Uuid,
+    pub max_shift_hours: i32,
+    pub rapid_clock_max_entries: i32,
+    pub rapid_clock_window_minutes: i32,
+}
+
+/// AnomalySettings response for API
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct AnomalySettingsResponse {
+    pub max_shift_hours: i32,
+    pub rapid_clock_max_entries: i32,
+    pub rapid_clock_window_minutes: i32,
+}
+
+impl Default for AnomalySettingsResponse {
+    fn default() -> Self {
+        Self {
+            max_shift_hours: 12,
+            rapid_clock_max_entries: 3,
+            rapid_clock_window_minutes: 60,
+        }
+    }
+}
+
+impl From<AnomalySettings> for AnomalySettingsResponse {
+    fn from(settings: AnomalySettings) -> Self {
+        Self {
+            max_shift_hours: settings.max_shift_hours,
+            rapid_clock_max_entries: settings.rapid_clock_max_entries,
+            rapid_clock_window_minutes: settings.rapid_clock_window_minutes,
+        }
+    }
+}
+
+/// ClockAnomaly entity from database
+/// Suspicious time data found by the anomaly engine, awaiting manager review
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = clock_anomalies)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ClockAnomaly {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub clock_entry_id: Uuid,
+    /// Offending break (long breaks only)
+    pub break_entry_id: Option<Uuid>,
+    pub kind: AnomalyKind,
+    pub severity: AnomalySeverity,
+    pub message: String,
+    pub status: AnomalyStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub detected_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewClockAnomaly for recording a finding
+#[derive(Debug, Insertable)]
+#[diesel(table_name = clock_anomalies)]
+pub struct NewClockAnomaly {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub clock_entry_id: Uuid,
+    pub break_entry_id: Option<Uuid>,
+    pub kind: AnomalyKind,
+    pub severity: AnomalySeverity,
+    pub message: String,
+}
+
+/// ClockAnomaly response with user info
+#[derive(Debug, Serialize)]
+pub struct ClockAnomalyResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub user_email: String,
+    pub clock_entry_id: Uuid,
+    pub break_entry_id: Option<Uuid>,
+    pub kind: AnomalyKind,
+    pub severity: AnomalySeverity,
+    pub message: String,
+    pub status: AnomalyStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewer_name: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl ClockAnomalyResponse {
+    pub fn from_anomaly(
+        anomaly: &ClockAnomaly,
+        user_name: String,
+        user_email: String,
+        reviewer_name: Option<String>,
+    ) -> Self {
+        Self {
+            id: anomaly.id,
+            user_id: anomaly.user_id,
+            user_name,
+            user_email,
+            clock_entry_id: anomaly.clock_entry_id,
+            break_entry_id: anomaly.break_entry_id,
+            kind: anomaly.kind,
+            severity: anomaly.severity,
+            message: anomaly.message.clone(),
+            status: anomaly.status,
+            reviewed_by: anomaly.reviewed_by,
+            reviewer_name,
+            reviewed_at: anomaly.reviewed_at,
+            review_note: anomaly.review_note.clone(),
+            detected_at: anomaly.detected_at,
+        }
+    }
+}
+
+/// Paginated clock anomalies response
+#[derive(Debug, Serialize)]
+pub struct PaginatedClockAnomalies {
+    pub data: Vec<ClockAnomalyResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+}
+
+/// Filter for the anomaly review queue
+#[derive(Debug, Default, Deserialize)]
+pub struct ClockAnomalyFilter {
+    /// Open findings by default
+    pub status: Option<AnomalyStatus>,
+    pub kind: Option<AnomalyKind>,
+    /// Minimum severity
+    pub severity: Option<AnomalySeverity>,
+    pub user_id: Option<Uuid>,
+    pub team_id: Option<Uuid>,
+}
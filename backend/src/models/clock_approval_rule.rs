@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::clock_approval_rules;
+
+/// ClockApprovalRule entity from database
+/// Closed entries matching an active rule are approved automatically on clock out
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = clock_approval_rules)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ClockApprovalRule {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    /// Team whose members the rule applies to (None for the whole organization)
+    pub team_id: Option<Uuid>,
+    pub name: String,
+    /// Clock in and out within this many minutes of the scheduled shift
+    pub max_schedule_deviation_minutes: Option<i32>,
+    /// Longest entry duration
+    pub max_duration_minutes: Option<i32>,
+    /// Whether entries made through a restriction override can match
+    pub allow_override: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewClockApprovalRule for creating rules
+#[derive(Debug, Insertable)]
+#[diesel(table_name = clock_approval_rules)]
+pub struct NewClockApprovalRule {
+    pub organization_id: Uuid,
+    pub team_id: Option<Uuid>,
+    pub name: String,
+    pub max_schedule_deviation_minutes: Option<i32>,
+    pub max_duration_minutes: Option<i32>,
+    pub allow_override: bool,
+    pub is_active: bool,
+}
+
+/// ClockApprovalRule update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = clock_approval_rules)]
+pub struct ClockApprovalRuleUpdate {
+    pub team_id: Option<Option<Uuid>>,
+    pub name: Option<String>,
+    pub max_schedule_deviation_minutes: Option<Option<i32>>,
+    pub max_duration_minutes: Option<Option<i32>>,
+    pub allow_override: Option<bool>,
+    pub is_active: Option<bool>,
+}
+
+/// ClockApprovalRule response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct ClockApprovalRuleResponse {
+    pub id: Uuid,
+    pub team_id: Option<Uuid>,
+    pub name: String,
+    pub max_schedule_deviation_minutes: Option<i32>,
+    pub max_duration_minutes: Option<i32>,
+    pub allow_override: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ClockApprovalRule> for ClockApprovalRuleResponse {
+    fn from(rule: ClockApprovalRule) -> Self {
+        Self {
+            id: rule.id,
+            team_id: rule.team_id,
+            name: rule.name,
+            max_schedule_deviation_minutes: rule.max_schedule_deviation_minutes,
+            max_duration_minutes: rule.max_duration_minutes,
+            allow_override: rule.allow_override,
+            is_active: rule.is_active,
+            created_at: rule.created_at,
+            updated_at: rule.updated_at,
+        }
+    }
+}
+
+/// ClockApprovalRule filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ClockApprovalRuleFilter {
+    pub team_id: Option<Uuid>,
+    pub is_active: Option<bool>,
+}
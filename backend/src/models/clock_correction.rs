@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::ClockCorrectionStatus;
+use crate::schema::clock_correction_requests;
+
+/// ClockCorrectionRequest entity from database
+/// Employee-proposed correction of a clock entry, keeping the original values
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = clock_correction_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ClockCorrectionRequest {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub clock_entry_id: Uuid,
+    pub original_clock_in: DateTime<Utc>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub requested_clock_in: DateTime<Utc>,
+    pub requested_clock_out: DateTime<Utc>,
+    pub reason: String,
+    pub status: ClockCorrectionStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// NewClockCorrectionRequest for creating correction requests
+#[derive(Debug, Insertable)]
+#[diesel(table_name = clock_correction_requests)]
+pub struct NewClockCorrectionRequest {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub clock_entry_id: Uuid,
+    pub original_clock_in: DateTime<Utc>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub requested_clock_in: DateTime<Utc>,
+    pub requested_clock_out: DateTime<Utc>,
+    pub reason: String,
+    pub status: ClockCorrectionStatus,
+}
+
+/// ClockCorrectionRequest update struct for review
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = clock_correction_requests)]
+pub struct ClockCorrectionRequestUpdate {
+    pub status: Option<ClockCorrectionStatus>,
+    pub reviewed_by: Option<Option<Uuid>>,
+    pub reviewed_at: Option<Option<DateTime<Utc>>>,
+    pub review_notes: Option<Option<String>>,
+}
+
+/// ClockCorrectionRequest response with user info
+#[derive(Debug, Serialize)]
+pub struct ClockCorrectionResponse {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub user_email: String,
+    pub clock_entry_id: Uuid,
+    pub original_clock_in: DateTime<Utc>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub requested_clock_in: DateTime<Utc>,
+    pub requested_clock_out: DateTime<Utc>,
+    pub reason: String,
+    pub status: ClockCorrectionStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewer_name: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ClockCorrectionResponse {
+    pub fn from_request(
+        request: &ClockCorrectionRequest,
+        user_name: String,
+        user_email: String,
+        reviewer_name: Option<String>,
+    ) -> Self {
+        Self {
+            id: request.id,
+            organization_id: request.organization_id,
+            user_id: request.user_id,
+            user_name,
+            user_email,
+            clock_entry_id: request.clock_entry_id,
+            original_clock_in: request.original_clock_in,
+            original_clock_out: request.original_clock_out,
+            requested_clock_in: request.requested_clock_in,
+            requested_clock_out: request.requested_clock_out,
+            reason: request.reason.clone(),
+            status: request.status,
+            reviewed_by: request.reviewed_by,
+            reviewer_name,
+            reviewed_at: request.reviewed_at,
+            review_notes: request.review_notes.clone(),
+            created_at: request.created_at,
+        }
+    }
+}
+
+/// Paginated clock correction requests response
+#[derive(Debug, Serialize)]
+pub struct PaginatedClockCorrections {
+    pub data: Vec<ClockCorrectionResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+}
+
+/// Create correction request
+/// Omitted values keep the entry's current clock_in/clock_out
+#[derive(Debug, Deserialize)]
+pub struct CreateClockCorrectionRequest {
+    pub clock_in: Option<DateTime<Utc>>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub reason: String,
+}
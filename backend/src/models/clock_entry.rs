@@ -1,11 +1,15 @@
+use std::net::IpAddr;
+
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::enums::{ClockEntryStatus, ClockOverrideStatus};
+use crate::domain::enums::{ClockEntrySource, ClockEntryStatus, ClockOverrideStatus};
 use crate::schema::clock_entries;
 
+use super::work_site::GeoPosition;
+
 /// ClockEntry entity from database
 #[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
 #[diesel(table_name = clock_entries)]
@@ -22,27 +26,106 @@ pub struct ClockEntry {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub source: ClockEntrySource,
+    pub created_by: Option<Uuid>,
+    pub auto_closed: bool,
+    pub clock_in_latitude: Option<f64>,
+    pub clock_in_longitude: Option<f64>,
+    pub clock_in_accuracy: Option<f64>,
+    pub clock_out_latitude: Option<f64>,
+    pub clock_out_longitude: Option<f64>,
+    pub clock_out_accuracy: Option<f64>,
+    pub work_site_id: Option<Uuid>,
+    pub outside_geofence: bool,
+    pub kiosk_device_id: Option<Uuid>,
+    pub clock_drift_seconds: Option<i32>,
+    pub auto_approval_rule_id: Option<Uuid>,
+    pub clock_in_ip: Option<String>,
+}
+
+impl ClockEntry {
+    /// Position reported when clocking in
+    pub fn clock_in_position(&self) -> Option<GeoPosition> {
+        Some(GeoPosition {
+            latitude: self.clock_in_latitude?,
+            longitude: self.clock_in_longitude?,
+            accuracy: self.clock_in_accuracy,
+        })
+    }
+
+    /// Position reported when clocking out
+    pub fn clock_out_position(&self) -> Option<GeoPosition> {
+        Some(GeoPosition {
+            latitude: self.clock_out_latitude?,
+            longitude: self.clock_out_longitude?,
+            accuracy: self.clock_out_accuracy,
+        })
+    }
 }
 
-/// NewClockEntry for creating clock entries (clock in)
+/// NewClockEntry for creating clock entries (clock in or manual entry)
 #[derive(Debug, Insertable)]
 #[diesel(table_name = clock_entries)]
 pub struct NewClockEntry {
     pub organization_id: Uuid,
     pub user_id: Uuid,
     pub clock_in: DateTime<Utc>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub status: ClockEntryStatus,
+    pub approved_by: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
     pub notes: Option<String>,
+    pub source: ClockEntrySource,
+    pub created_by: Option<Uuid>,
+    pub clock_in_latitude: Option<f64>,
+    pub clock_in_longitude: Option<f64>,
+    pub clock_in_accuracy: Option<f64>,
+    pub work_site_id: Option<Uuid>,
+    pub outside_geofence: bool,
+    pub kiosk_device_id: Option<Uuid>,
+    pub clock_drift_seconds: Option<i32>,
+    pub clock_in_ip: Option<String>,
+}
+
+/// How a clock in/out was captured
+#[derive(Debug, Clone, Default)]
+pub struct ClockContext {
+    /// Position reported by the device
+    pub position: Option<GeoPosition>,
+    /// Shared kiosk device the employee used
+    pub kiosk_device_id: Option<Uuid>,
+    /// When the action happened, for events recorded offline (None means now)
+    pub recorded_at: Option<DateTime<Utc>>,
+    /// Device clock drift beyond tolerance, the entry then needs manager approval
+    pub clock_drift_seconds: Option<i32>,
+    /// Address the request came from (recorded at clock in)
+    pub client_ip: Option<IpAddr>,
+}
+
+impl ClockContext {
+    /// Source recorded on entries created with this context
+    pub fn source(&self) -> ClockEntrySource {
+        if self.kiosk_device_id.is_some() {
+            ClockEntrySource::Kiosk
+        } else if self.recorded_at.is_some() {
+            ClockEntrySource::Sync
+        } else {
+            ClockEntrySource::Clock
+        }
+    }
 }
 
 /// ClockEntry update struct for partial updates
 #[derive(Debug, AsChangeset, Default)]
 #[diesel(table_name = clock_entries)]
 pub struct ClockEntryUpdate {
+    pub clock_in: Option<DateTime<Utc>>,
     pub clock_out: Option<Option<DateTime<Utc>>>,
     pub status: Option<ClockEntryStatus>,
     pub approved_by: Option<Option<Uuid>>,
     pub approved_at: Option<Option<DateTime<Utc>>>,
     pub notes: Option<Option<String>>,
+    pub auto_closed: Option<bool>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -67,6 +150,22 @@ pub struct ClockEntryResponse {
     pub approver_name: Option<String>,
     pub approved_at: Option<DateTime<Utc>>,
     pub notes: Option<String>,
+    pub source: ClockEntrySource,
+    pub created_by: Option<Uuid>,
+    /// True when the entry was closed automatically and needs review
+    pub auto_closed: bool,
+    /// Positions captured when clocking in/out (None when not reported)
+    pub clock_in_position: Option<GeoPosition>,
+    pub clock_out_position: Option<GeoPosition>,
+    /// Work site matched at clock in
+    pub work_site_id: Option<Uuid>,
+    /// True when a geofenced clock in/out happened outside every work site
+    pub outside_geofence: bool,
+    pub kiosk_device_id: Option<Uuid>,
+    /// Device clock drift of an offline-synced entry (set when it needs approval)
+    pub clock_drift_seconds: Option<i32>,
+    /// Approval rule that approved the entry on clock out
+    pub auto_approval_rule_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     /// Override information (if entry was made via override)
     pub override_id: Option<Uuid>,
@@ -114,6 +213,16 @@ impl ClockEntryResponse {
             approver_name,
             approved_at: entry.approved_at,
             notes: entry.notes.clone(),
+            source: entry.source,
+            created_by: entry.created_by,
+            auto_closed: entry.auto_closed,
+            clock_in_position: entry.clock_in_position(),
+            clock_out_position: entry.clock_out_position(),
+            work_site_id: entry.work_site_id,
+            outside_geofence: entry.outside_geofence,
+            kiosk_device_id: entry.kiosk_device_id,
+            clock_drift_seconds: entry.clock_drift_seconds,
+            auto_approval_rule_id: entry.auto_approval_rule_id,
             created_at: entry.created_at,
             override_id,
             override_reason,
@@ -122,6 +231,15 @@ impl ClockEntryResponse {
     }
 }
 
+/// Create a closed clock entry on behalf of a user (Manager+ only)
+#[derive(Debug, Deserialize)]
+pub struct CreateManualClockEntryRequest {
+    pub user_id: Uuid,
+    pub clock_in: DateTime<Utc>,
+    pub clock_out: DateTime<Utc>,
+    pub notes: Option<String>,
+}
+
 /// Current clock status for a user
 #[derive(Debug, Serialize)]
 pub struct ClockStatus {
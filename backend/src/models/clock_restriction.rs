@@ -3,9 +3,11 @@ use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::enums::{ClockOverrideStatus, ClockRestrictionMode};
+use crate::domain::enums::{ClockOverrideStatus, ClockRestrictionMode, GeofenceMode};
 use crate::schema::{clock_override_requests, clock_restrictions};
 
+use super::work_site::GeofenceCheck;
+
 /// ClockRestriction entity from database
 /// Defines when users can clock in/out with cascade logic: User > Team > Org
 #[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
@@ -27,6 +29,15 @@ pub struct ClockRestriction {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub max_daily_clock_events: Option<i32>,
+    pub geofence_mode: GeofenceMode,
+    /// CIDR ranges clock actions must come from (empty allows any network)
+    pub allowed_networks: Vec<String>,
+    /// With `enforce_schedule`, how early before a planned shift start or end
+    /// clocking in or out is allowed
+    pub schedule_early_minutes: i32,
+    /// With `enforce_schedule`, how late after a planned shift start or end
+    /// clocking in or out is allowed
+    pub schedule_late_minutes: i32,
 }
 
 /// NewClockRestriction for creating clock restrictions
@@ -45,6 +56,10 @@ pub struct NewClockRestriction {
     pub require_manager_approval: bool,
     pub is_active: bool,
     pub max_daily_clock_events: Option<i32>,
+    pub geofence_mode: GeofenceMode,
+    pub allowed_networks: Vec<String>,
+    pub schedule_early_minutes: i32,
+    pub schedule_late_minutes: i32,
 }
 
 /// ClockRestriction update struct for partial updates
@@ -60,6 +75,10 @@ pub struct ClockRestrictionUpdate {
     pub require_manager_approval: Option<bool>,
     pub is_active: Option<bool>,
     pub max_daily_clock_events: Option<Option<i32>>,
+    pub geofence_mode: Option<GeofenceMode>,
+    pub allowed_networks: Option<Vec<String>>,
+    pub schedule_early_minutes: Option<i32>,
+    pub schedule_late_minutes: Option<i32>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -82,6 +101,10 @@ pub struct ClockRestrictionResponse {
     pub require_manager_approval: bool,
     pub is_active: bool,
     pub max_daily_clock_events: Option<i32>,
+    pub geofence_mode: GeofenceMode,
+    pub allowed_networks: Vec<String>,
+    pub schedule_early_minutes: i32,
+    pub schedule_late_minutes: i32,
     pub scope_level: String, // "organization", "team", or "user"
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -119,6 +142,10 @@ impl ClockRestrictionResponse {
             require_manager_approval: restriction.require_manager_approval,
             is_active: restriction.is_active,
             max_daily_clock_events: restriction.max_daily_clock_events,
+            geofence_mode: restriction.geofence_mode,
+            allowed_networks: restriction.allowed_networks.clone(),
+            schedule_early_minutes: restriction.schedule_early_minutes,
+            schedule_late_minutes: restriction.schedule_late_minutes,
             scope_level,
             created_at: restriction.created_at,
             updated_at: restriction.updated_at,
@@ -258,6 +285,8 @@ pub struct ClockValidationResult {
     pub message: Option<String>,
     pub can_request_override: bool,
     pub effective_restriction: Option<EffectiveRestriction>,
+    /// Position check against work sites (None when geofencing is off)
+    pub geofence: Option<GeofenceCheck>,
 }
 
 /// Create clock restriction request
@@ -270,10 +299,18 @@ pub struct CreateClockRestrictionRequest {
     pub clock_in_latest: Option<NaiveTime>,
     pub clock_out_earliest: Option<NaiveTime>,
     pub clock_out_latest: Option<NaiveTime>,
+    /// Derive the clock windows from the user's planned shifts (false when omitted)
     pub enforce_schedule: Option<bool>,
     pub require_manager_approval: Option<bool>,
     pub is_active: Option<bool>,
     pub max_daily_clock_events: Option<i32>,
+    pub geofence_mode: Option<GeofenceMode>,
+    /// CIDR ranges or single addresses
+    pub allowed_networks: Option<Vec<String>>,
+    /// Minutes allowed before a planned shift start or end (15 when omitted)
+    pub schedule_early_minutes: Option<i32>,
+    /// Minutes allowed after a planned shift start or end (15 when omitted)
+    pub schedule_late_minutes: Option<i32>,
 }
 
 /// Update clock restriction request
@@ -288,6 +325,10 @@ pub struct UpdateClockRestrictionRequest {
     pub require_manager_approval: Option<bool>,
     pub is_active: Option<bool>,
     pub max_daily_clock_events: Option<Option<i32>>,
+    pub geofence_mode: Option<GeofenceMode>,
+    pub allowed_networks: Option<Vec<String>>,
+    pub schedule_early_minutes: Option<i32>,
+    pub schedule_late_minutes: Option<i32>,
 }
 
 /// Create override request
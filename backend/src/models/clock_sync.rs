@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::ClockSyncStatus;
+use crate::schema::clock_sync_events;
+
+/// Clock action recorded offline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockSyncAction {
+    ClockIn,
+    ClockOut,
+}
+
+impl ClockSyncAction {
+    /// Name stored in the database and used for restriction checks
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClockSyncAction::ClockIn => "clock_in",
+            ClockSyncAction::ClockOut => "clock_out",
+        }
+    }
+}
+
+/// ClockSyncEvent entity from database
+/// An offline clock event that has been processed, kept for idempotency
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = clock_sync_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ClockSyncEvent {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub device_id: String,
+    pub idempotency_key: String,
+    pub action: String,
+    pub client_timestamp: DateTime<Utc>,
+    pub clock_drift_seconds: i32,
+    pub status: ClockSyncStatus,
+    pub clock_entry_id: Option<Uuid>,
+    pub message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// When the event was last reserved for replay
+    pub reserved_at: DateTime<Utc>,
+}
+
+/// NewClockSyncEvent for recording a processed event
+#[derive(Debug, Insertable)]
+#[diesel(table_name = clock_sync_events)]
+pub struct NewClockSyncEvent {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub device_id: String,
+    pub idempotency_key: String,
+    pub action: String,
+    pub client_timestamp: DateTime<Utc>,
+    pub clock_drift_seconds: i32,
+    pub status: ClockSyncStatus,
+    pub clock_entry_id: Option<Uuid>,
+    pub message: Option<String>,
+}
+
+/// A clock event recorded while the device was offline
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClockSyncEventRequest {
+    /// Unique per user, generated by the device; retried uploads reuse it
+    pub idempotency_key: String,
+    pub device_id: String,
+    pub action: ClockSyncAction,
+    /// When the event happened, by the device clock
+    pub client_timestamp: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub accuracy: Option<f64>,
+}
+
+/// Batch of offline clock events
+#[derive(Debug, Deserialize)]
+pub struct ClockSyncBatchRequest {
+    /// Device clock when the batch was sent, used to measure drift
+    pub sent_at: DateTime<Utc>,
+    pub events: Vec<ClockSyncEventRequest>,
+}
+
+/// Outcome of one offline clock event
+#[derive(Debug, Clone, Serialize)]
+pub struct ClockSyncEventResult {
+    pub idempotency_key: String,
+    pub action: String,
+    pub client_timestamp: DateTime<Utc>,
+    pub status: ClockSyncStatus,
+    /// True when the event had already been processed by an earlier upload
+    pub duplicate: bool,
+    pub clock_entry_id: Option<Uuid>,
+    pub message: Option<String>,
+}
+
+impl ClockSyncEventResult {
+    pub fn from_event(event: ClockSyncEvent, duplicate: bool) -> Self {
+        Self {
+            idempotency_key: event.idempotency_key,
+            action: event.action,
+            client_timestamp: event.client_timestamp,
+            status: event.status,
+            duplicate,
+            clock_entry_id: event.clock_entry_id,
+            message: event.message,
+        }
+    }
+}
+
+/// Result of a batch upload, in replay order
+#[derive(Debug, Serialize)]
+pub struct ClockSyncBatchResponse {
+    pub received_at: DateTime<Utc>,
+    /// Device clock minus server clock, in seconds
+    pub clock_drift_seconds: i32,
+    pub applied: usize,
+    pub flagged: usize,
+    pub rejected: usize,
+    pub duplicates: usize,
+    pub results: Vec<ClockSyncEventResult>,
+}
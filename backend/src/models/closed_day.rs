@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -18,6 +18,35 @@ pub struct ClosedDay {
     pub created_at: DateTime<Utc>,
 }
 
+/// Dates between two dates (inclusive) on which closed days fall
+/// Recurring closed days repeat on the same month and day every year
+pub fn closed_dates(
+    closed_days: &[ClosedDay],
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Vec<NaiveDate> {
+    let mut dates = Vec::new();
+
+    for closed_day in closed_days {
+        if !closed_day.is_recurring {
+            dates.push(closed_day.date);
+            continue;
+        }
+        for year in start_date.year()..=end_date.year() {
+            if let Some(date) =
+                NaiveDate::from_ymd_opt(year, closed_day.date.month(), closed_day.date.day())
+            {
+                dates.push(date);
+            }
+        }
+    }
+
+    dates.retain(|date| *date >= start_date && *date <= end_date);
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
 /// NewClosedDay for creating closed days
 #[derive(Debug, Insertable)]
 #[diesel(table_name = closed_days)]
@@ -66,3 +95,38 @@ pub struct ClosedDayFilter {
     pub end_date: Option<NaiveDate>,
     pub is_recurring: Option<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closed_day(date: NaiveDate, is_recurring: bool) -> ClosedDay {
+        ClosedDay {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            name: "Holiday".to_string(),
+            date,
+            is_recurring,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_closed_dates_expands_recurring_days() {
+        let closed_days = vec![
+            closed_day(date(2020, 12, 25), true),
+            closed_day(date(2025, 12, 26), false),
+            closed_day(date(2026, 12, 28), false),
+        ];
+
+        assert_eq!(
+            closed_dates(&closed_days, date(2025, 12, 1), date(2026, 12, 25)),
+            vec![date(2025, 12, 25), date(2025, 12, 26), date(2026, 12, 25)]
+        );
+        assert!(closed_dates(&closed_days, date(2026, 1, 1), date(2026, 6, 30)).is_empty());
+    }
+}
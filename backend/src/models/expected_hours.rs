@@ -0,0 +1,265 @@
+use bigdecimal::ToPrimitive;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::domain::enums::AbsenceStatus;
+use crate::models::{planned_minutes_on, Absence, RosterShift, WorkScheduleWithDays};
+
+/// Expected working hours of a user over a period, with what was deducted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ExpectedHours {
+    /// Hours planned by the schedule and the published roster
+    pub planned_hours: f64,
+    /// Planned hours falling on closed days
+    pub closed_day_hours: f64,
+    /// Planned hours covered by approved absences
+    pub absence_hours: f64,
+    /// Planned hours left once closed days and absences are deducted
+    pub expected_hours: f64,
+    /// Planned days falling on closed days
+    pub closed_days: i32,
+    /// Planned days covered by approved absences, including partial days
+    pub absence_days: f64,
+}
+
+impl ExpectedHours {
+    /// Expected hours between two local dates (inclusive)
+    ///
+    /// Schedule days on closed days are not expected, unless a roster shift or a
+    /// schedule exception explicitly plans work on that date. Approved absences then
+    /// deduct the part of each date they take: the whole day, or half of it on a
+    /// half-day start or end. Hourly absences deduct their hours from the date they
+    /// fall on.
+    pub fn compute(
+        schedule: Option<&WorkScheduleWithDays>,
+        shifts: &[RosterShift],
+        closed_dates: &[NaiveDate],
+        absences: &[Absence],
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        let mut planned_minutes: i64 = 0;
+        let mut closed_day_minutes: i64 = 0;
+        let mut absence_minutes = 0.0;
+        let mut closed_days = 0;
+        let mut absence_days = 0.0;
+
+        let mut current = start_date;
+        while current <= end_date {
+            let minutes = planned_minutes_on(schedule, shifts, current);
+            planned_minutes += minutes;
+
+            let explicitly_planned = shifts.iter().any(|s| s.shift_date == current)
+                || schedule.is_some_and(|s| s.exceptions.contains_key(&current));
+            if minutes > 0 && !explicitly_planned && closed_dates.contains(&current) {
+                closed_day_minutes += minutes;
+                closed_days += 1;
+            } else if minutes > 0 {
+                let approved = absences
+                    .iter()
+                    .filter(|a| a.status == AbsenceStatus::Approved)
+                    .filter(|a| a.start_date <= current && current <= a.end_date);
+                let share = approved
+                    .clone()
+                    .filter(|a| a.hours.is_none())
+                    .map(|a| absence_share(a, current))
+                    .fold(0.0, f64::max);
+                let hourly_minutes: f64 = approved
+                    .filter_map(|a| a.hours.as_ref())
+                    .map(|h| h.to_f64().unwrap_or(0.0) * 60.0)
+                    .sum();
+                let deducted = (minutes as f64 * share + hourly_minutes).min(minutes as f64);
+                absence_minutes += deducted;
+                absence_days += deducted / minutes as f64;
+            }
+
+            current = current.succ_opt().unwrap_or(current);
+        }
+
+        let planned_hours = planned_minutes as f64 / 60.0;
+        let closed_day_hours = closed_day_minutes as f64 / 60.0;
+        let absence_hours = absence_minutes / 60.0;
+        Self {
+            planned_hours,
+            closed_day_hours,
+            absence_hours,
+            expected_hours: (planned_hours - closed_day_hours - absence_hours).max(0.0),
+            closed_days,
+            absence_days,
+        }
+    }
+}
+
+/// Share of a date an absence takes: 1 for a full day, 0.5 for a half day
+fn absence_share(absence: &Absence, date: NaiveDate) -> f64 {
+    let (morning, afternoon) = absence.period().parts_on(date);
+    (morning as u8 + afternoon as u8) as f64 / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Utc};
+    use uuid::Uuid;
+
+    use crate::domain::enums::{AbsenceHalfDay, RosterShiftStatus};
+    use crate::models::{WorkSchedule, WorkScheduleDay};
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        // June 2025: the 2nd is a Monday
+        NaiveDate::from_ymd_opt(2025, 6, d).unwrap()
+    }
+
+    fn shift(d: u32, start: NaiveTime, end: NaiveTime, break_minutes: i32) -> RosterShift {
+        RosterShift {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            team_id: None,
+            shift_date: date(d),
+            start_time: start,
+            end_time: end,
+            break_minutes,
+            position: None,
+            notes: None,
+            status: RosterShiftStatus::Published,
+            published_at: None,
+            created_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn absence(start: u32, end: u32, days_count: &str, status: AbsenceStatus) -> Absence {
+        Absence {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            type_id: Uuid::nil(),
+            start_date: date(start),
+            end_date: date(end),
+            days_count: days_count.parse().unwrap(),
+            status,
+            reason: None,
+            rejection_reason: None,
+            approved_by: None,
+            approved_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            start_half: Default::default(),
+            end_half: Default::default(),
+            start_time: None,
+            end_time: None,
+            hours: None,
+        }
+    }
+
+    /// Weekdays 09:00 to 17:00 with a one hour break
+    fn weekdays() -> WorkScheduleWithDays {
+        let schedule_id = Uuid::new_v4();
+        WorkScheduleWithDays {
+            schedule: WorkSchedule {
+                id: schedule_id,
+                organization_id: Uuid::nil(),
+                name: "Office".to_string(),
+                description: None,
+                is_default: true,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                cycle_weeks: 1,
+                cycle_anchor_date: date(2),
+            },
+            days: (0..5)
+                .map(|day_of_week| WorkScheduleDay {
+                    id: Uuid::new_v4(),
+                    work_schedule_id: schedule_id,
+                    day_of_week,
+                    start_time: time(9, 0),
+                    end_time: time(17, 0),
+                    break_minutes: 60,
+                    week_index: 0,
+                })
+                .collect(),
+            exceptions: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_expected_hours_deducts_closed_days_and_absences() {
+        let schedule = weekdays();
+        let closed = vec![date(4), date(11)];
+        let absences = vec![
+            // Monday to Wednesday, the closed Wednesday not booked
+            absence(9, 11, "2", AbsenceStatus::Approved),
+            // Half day on Thursday
+            Absence {
+                start_half: AbsenceHalfDay::Morning,
+                end_half: AbsenceHalfDay::Morning,
+                ..absence(12, 12, "0.5", AbsenceStatus::Approved)
+            },
+            absence(13, 13, "1", AbsenceStatus::Pending),
+        ];
+        // Work explicitly planned on the closed Wednesday of the first week
+        let shifts = vec![shift(4, time(10, 0), time(14, 0), 0)];
+
+        // Two weeks of 7 hour weekdays, the first closed day planned for 4 hours
+        let hours = ExpectedHours::compute(
+            Some(&schedule),
+            &shifts,
+            &closed,
+            &absences,
+            date(2),
+            date(15),
+        );
+        assert_eq!(hours.planned_hours, 67.0);
+        assert_eq!(hours.closed_days, 1);
+        assert_eq!(hours.closed_day_hours, 7.0);
+        assert_eq!(hours.absence_days, 2.5);
+        assert_eq!(hours.absence_hours, 17.5);
+        assert_eq!(hours.expected_hours, 42.5);
+
+        let none = ExpectedHours::compute(None, &[], &closed, &absences, date(2), date(15));
+        assert_eq!(none, ExpectedHours::default());
+
+        // Monday afternoon to Friday: half of Monday, then full days
+        let from_afternoon = Absence {
+            start_half: AbsenceHalfDay::Afternoon,
+            ..absence(2, 6, "4.5", AbsenceStatus::Approved)
+        };
+        let monday = ExpectedHours::compute(
+            Some(&schedule),
+            &[],
+            &[],
+            std::slice::from_ref(&from_afternoon),
+            date(2),
+            date(2),
+        );
+        assert_eq!(monday.absence_hours, 3.5);
+        let week = ExpectedHours::compute(
+            Some(&schedule),
+            &[],
+            &[],
+            &[from_afternoon],
+            date(2),
+            date(6),
+        );
+        assert_eq!(week.absence_days, 4.5);
+        assert_eq!(week.absence_hours, 31.5);
+
+        // Two hours of doctor appointment on Friday
+        let hourly = Absence {
+            start_time: Some(time(9, 0)),
+            end_time: Some(time(11, 0)),
+            hours: Some("2".parse().unwrap()),
+            ..absence(6, 6, "0.29", AbsenceStatus::Approved)
+        };
+        let hours =
+            ExpectedHours::compute(Some(&schedule), &[], &closed, &[hourly], date(6), date(6));
+        assert_eq!(hours.absence_hours, 2.0);
+        assert_eq!(hours.expected_hours, 5.0);
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{kiosk_devices, kiosk_pins};
+
+/// KioskDevice entity from database
+/// A shared terminal on which employees clock in/out with their PIN
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = kiosk_devices)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct KioskDevice {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+    pub work_site_id: Option<Uuid>,
+    pub is_active: bool,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewKioskDevice for registering kiosk devices
+#[derive(Debug, Insertable)]
+#[diesel(table_name = kiosk_devices)]
+pub struct NewKioskDevice {
+    pub organization_id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub work_site_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+}
+
+/// KioskDevice update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = kiosk_devices)]
+pub struct KioskDeviceUpdate {
+    pub name: Option<String>,
+    pub work_site_id: Option<Option<Uuid>>,
+    pub is_active: Option<bool>,
+}
+
+/// KioskDevice response for API (never includes the credential)
+#[derive(Debug, Clone, Serialize)]
+pub struct KioskDeviceResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub work_site_id: Option<Uuid>,
+    pub is_active: bool,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<KioskDevice> for KioskDeviceResponse {
+    fn from(device: KioskDevice) -> Self {
+        Self {
+            id: device.id,
+            name: device.name,
+            work_site_id: device.work_site_id,
+            is_active: device.is_active,
+            last_used_at: device.last_used_at,
+            created_by: device.created_by,
+            created_at: device.created_at,
+        }
+    }
+}
+
+/// Response when registering a device
+/// The token is only returned once and must be stored on the device
+#[derive(Debug, Serialize)]
+pub struct KioskDeviceCreated {
+    pub device: KioskDeviceResponse,
+    pub token: String,
+}
+
+/// KioskPin entity from database (one PIN per employee)
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = kiosk_pins)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct KioskPin {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub pin_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewKioskPin for setting an employee PIN
+#[derive(Debug, Insertable)]
+#[diesel(table_name = kiosk_pins)]
+pub struct NewKioskPin {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub pin_hash: String,
+}
+
+/// Employee shown on a kiosk device for selection
+#[derive(Debug, Clone, Serialize)]
+pub struct KioskEmployee {
+    pub id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+}
@@ -4,6 +4,7 @@ use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::enums::AbsenceUnit;
 use crate::schema::leave_balances;
 
 /// LeaveBalance entity from database
@@ -53,6 +54,8 @@ pub struct LeaveBalanceResponse {
     pub type_name: String,
     pub type_code: String,
     pub type_color: String,
+    /// Unit the amounts are held in
+    pub unit: AbsenceUnit,
     pub year: i32,
     pub initial_balance: f64,
     pub used: f64,
@@ -66,6 +69,7 @@ impl LeaveBalanceResponse {
         type_name: String,
         type_code: String,
         type_color: String,
+        unit: AbsenceUnit,
     ) -> Self {
         use bigdecimal::ToPrimitive;
 
@@ -80,6 +84,7 @@ impl LeaveBalanceResponse {
             type_name,
             type_code,
             type_color,
+            unit,
             year: balance.year,
             initial_balance: initial,
             used,
@@ -2,33 +2,53 @@
 // This module contains Diesel models for database entities
 
 pub mod absence;
+pub mod absence_approval;
 pub mod absence_type;
 pub mod audit_log;
 pub mod break_policy;
+pub mod bulk_review;
 pub mod claims;
+pub mod clock_anomaly;
+pub mod clock_approval_rule;
+pub mod clock_correction;
 pub mod clock_entry;
 pub mod clock_restriction;
+pub mod clock_sync;
 pub mod closed_day;
+pub mod expected_hours;
 pub mod invite_token;
+pub mod kiosk;
 pub mod leave_balance;
 pub mod login_attempt;
 pub mod notification;
 pub mod organization;
 pub mod password_history;
 pub mod password_reset_token;
+pub mod pay_period;
+pub mod project;
 pub mod refresh_token;
+pub mod roster_shift;
+pub mod schedule_exception;
+pub mod shift_swap;
 pub mod team;
 pub mod team_member;
+pub mod time_allocation;
 pub mod token_pair;
 pub mod user;
 pub mod user_session;
 pub mod work_schedule;
+pub mod work_site;
 
 // Re-export commonly used types
 pub use absence::{
-    Absence, AbsenceFilter, AbsenceResponse, AbsenceUpdate, NewAbsence, PaginatedAbsences,
+    Absence, AbsenceFilter, AbsencePeriod, AbsencePreview, AbsenceResponse, AbsenceSettings,
+    AbsenceSettingsResponse, AbsenceUpdate, NewAbsence, NewAbsenceSettings, PaginatedAbsences,
     PendingAbsenceFilter,
 };
+pub use absence_approval::{
+    AbsenceApproval, AbsenceApprovalResponse, AbsenceApprovalStep, AbsenceApprovalStepResponse,
+    NewAbsenceApproval, NewAbsenceApprovalStep,
+};
 pub use absence_type::{AbsenceType, AbsenceTypeResponse, AbsenceTypeUpdate, NewAbsenceType};
 pub use audit_log::{
     AuditContext, AuditLog, AuditLogFilter, AuditLogResponse, AuditUserInfo, NewAuditLog,
@@ -41,10 +61,25 @@ pub use break_policy::{
     EffectiveBreakPolicy, EndBreakRequest, NewBreakEntry, NewBreakPolicy, NewBreakWindow,
     PaginatedBreakEntries, PaginatedBreakPolicies, StartBreakRequest, UpdateBreakPolicyRequest,
 };
+pub use bulk_review::{
+    BulkReviewFilter, BulkReviewItemResult, BulkReviewRequest, BulkReviewResponse,
+};
 pub use claims::Claims;
+pub use clock_anomaly::{
+    AnomalySettings, AnomalySettingsResponse, ClockAnomaly, ClockAnomalyFilter,
+    ClockAnomalyResponse, NewAnomalySettings, NewClockAnomaly, PaginatedClockAnomalies,
+};
+pub use clock_approval_rule::{
+    ClockApprovalRule, ClockApprovalRuleFilter, ClockApprovalRuleResponse, ClockApprovalRuleUpdate,
+    NewClockApprovalRule,
+};
+pub use clock_correction::{
+    ClockCorrectionRequest, ClockCorrectionRequestUpdate, ClockCorrectionResponse,
+    CreateClockCorrectionRequest, NewClockCorrectionRequest, PaginatedClockCorrections,
+};
 pub use clock_entry::{
-    ClockEntry, ClockEntryResponse, ClockEntryUpdate, ClockFilter, ClockStatus, NewClockEntry,
-    PaginatedClockEntries, PendingClockFilter,
+    ClockContext, ClockEntry, ClockEntryResponse, ClockEntryUpdate, ClockFilter, ClockStatus,
+    CreateManualClockEntryRequest, NewClockEntry, PaginatedClockEntries, PendingClockFilter,
 };
 pub use clock_restriction::{
     ClockOverrideFilter, ClockOverrideRequest, ClockOverrideRequestResponse,
@@ -53,10 +88,19 @@ pub use clock_restriction::{
     CreateOverrideRequest, EffectiveRestriction, NewClockOverrideRequest, NewClockRestriction,
     PaginatedClockOverrideRequests, ReviewOverrideRequest, UpdateClockRestrictionRequest,
 };
+pub use clock_sync::{
+    ClockSyncAction, ClockSyncBatchRequest, ClockSyncBatchResponse, ClockSyncEvent,
+    ClockSyncEventRequest, ClockSyncEventResult, NewClockSyncEvent,
+};
 pub use closed_day::{
-    ClosedDay, ClosedDayFilter, ClosedDayResponse, ClosedDayUpdate, NewClosedDay,
+    closed_dates, ClosedDay, ClosedDayFilter, ClosedDayResponse, ClosedDayUpdate, NewClosedDay,
 };
+pub use expected_hours::ExpectedHours;
 pub use invite_token::{InviteToken, NewInviteToken};
+pub use kiosk::{
+    KioskDevice, KioskDeviceCreated, KioskDeviceResponse, KioskDeviceUpdate, KioskEmployee,
+    KioskPin, NewKioskDevice, NewKioskPin,
+};
 pub use leave_balance::{
     LeaveBalance, LeaveBalanceFilter, LeaveBalanceResponse, LeaveBalanceUpdate, NewLeaveBalance,
 };
@@ -72,13 +116,42 @@ pub use organization::{
 };
 pub use password_history::{NewPasswordHistory, PasswordHistory};
 pub use password_reset_token::{NewPasswordResetToken, PasswordResetToken};
+pub use pay_period::{
+    NewPayPeriod, NewPayPeriodSettings, PayPeriod, PayPeriodFilter, PayPeriodResponse,
+    PayPeriodSettings, PayPeriodSettingsResponse, PayPeriodStatusResponse,
+};
+pub use project::{
+    NewProject, NewProjectTask, Project, ProjectFilter, ProjectResponse, ProjectTask,
+    ProjectTaskResponse, ProjectTaskUpdate, ProjectUpdate,
+};
 pub use refresh_token::{NewRefreshToken, RefreshToken};
+pub use roster_shift::{
+    planned_minutes_on, planned_shifts_on, resolve_planned_shift, NewRosterSettings,
+    NewRosterShift, RosterSettings, RosterSettingsResponse, RosterShift, RosterShiftFilter,
+    RosterShiftResponse, RosterShiftUpdate,
+};
+pub use schedule_exception::{
+    NewScheduleException, ScheduleException, ScheduleExceptionFilter, ScheduleExceptionResponse,
+    ScheduleExceptionUpdate,
+};
+pub use shift_swap::{
+    NewShiftSwapRequest, ShiftSwapFilter, ShiftSwapNames, ShiftSwapRequest, ShiftSwapRequestUpdate,
+    ShiftSwapResponse,
+};
 pub use team::{NewTeam, Team, TeamFilter, TeamResponse, TeamUpdate, TeamWithMembers};
 pub use team_member::{NewTeamMember, TeamMember};
+pub use time_allocation::{
+    ClockEntryAllocations, NewTimeAllocation, ProjectHoursRow, SetTimeAllocationsRequest,
+    TimeAllocation, TimeAllocationInput, TimeAllocationResponse,
+};
 pub use token_pair::TokenPair;
 pub use user::{NewUser, PaginatedUsers, Pagination, UserFilter, UserResponse, UserUpdate};
 pub use user_session::{NewUserSession, UserSession};
 pub use work_schedule::{
-    DayConfig, NewWorkSchedule, NewWorkScheduleDay, WorkSchedule, WorkScheduleDay,
-    WorkScheduleDayUpdate, WorkScheduleUpdate, WorkScheduleWithDays,
+    resolve_shift_day, DayConfig, NewWorkSchedule, NewWorkScheduleDay, WorkSchedule,
+    WorkScheduleDay, WorkScheduleDayUpdate, WorkScheduleUpdate, WorkScheduleWithDays,
+};
+pub use work_site::{
+    GeoPosition, GeofenceCheck, NewWorkSite, WorkSite, WorkSiteFilter, WorkSiteResponse,
+    WorkSiteUpdate,
 };
@@ -1,4 +1,5 @@
 use chrono::NaiveDateTime;
+use chrono_tz::Tz;
 use diesel::prelude::*;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -7,6 +8,7 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::schema::organizations;
+use crate::utils::timezone::{is_valid_timezone, parse_timezone};
 
 /// Organization database model
 #[derive(Debug, Clone, Queryable, Identifiable, Selectable, Serialize, Deserialize)]
@@ -21,6 +23,13 @@ pub struct Organization {
     pub updated_at: NaiveDateTime,
 }
 
+impl Organization {
+    /// Parsed IANA timezone of the organization, used to resolve local times
+    pub fn tz(&self) -> Tz {
+        parse_timezone(&self.timezone)
+    }
+}
+
 /// Regex pattern for valid slug: lowercase alphanumeric with optional hyphens
 pub static SLUG_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[a-z0-9][a-z0-9-]*[a-z0-9]$|^[a-z0-9]$").unwrap());
@@ -37,6 +46,17 @@ fn validate_slug(slug: &str) -> Result<(), validator::ValidationError> {
     }
 }
 
+/// Custom validator for IANA timezone names (e.g. "Europe/Paris")
+pub fn validate_timezone(timezone: &str) -> Result<(), validator::ValidationError> {
+    if is_valid_timezone(timezone) {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("timezone_format");
+        error.message = Some("Timezone must be a valid IANA timezone name".into());
+        Err(error)
+    }
+}
+
 /// Request to create a new organization
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateOrganizationRequest {
@@ -54,6 +74,7 @@ pub struct CreateOrganizationRequest {
     #[validate(custom(function = "validate_slug"))]
     pub slug: String,
     #[validate(length(max = 100))]
+    #[validate(custom(function = "validate_timezone"))]
     pub timezone: Option<String>,
 }
 
@@ -86,6 +107,7 @@ pub struct UpdateOrganizationRequest {
     ))]
     pub name: Option<String>,
     #[validate(length(max = 100))]
+    #[validate(custom(function = "validate_timezone"))]
     pub timezone: Option<String>,
 }
 
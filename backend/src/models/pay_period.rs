@@ -0,0 +1,115 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::PayPeriodFrequency;
+use crate::schema::{pay_period_settings, pay_periods};
+
+/// PayPeriodSettings entity from database
+/// Organizations without settings use calendar months
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = pay_period_settings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PayPeriodSettings {
+    pub organization_id: Uuid,
+    pub frequency: PayPeriodFrequency,
+    /// First day of a reference period (weekly and bi-weekly frequencies)
+    pub anchor_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewPayPeriodSettings for creating or replacing settings
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = pay_period_settings)]
+pub struct NewPayPeriodSettings {
+    pub organization_id: Uuid,
+    pub frequency: PayPeriodFrequency,
+    pub anchor_date: NaiveDate,
+}
+
+/// PayPeriodSettings response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct PayPeriodSettingsResponse {
+    pub frequency: PayPeriodFrequency,
+    pub anchor_date: NaiveDate,
+}
+
+/// PayPeriod entity from database
+/// A period closed for payroll; reopened periods are kept for history
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = pay_periods)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PayPeriod {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    /// First day of the period, local to the organization
+    pub start_date: NaiveDate,
+    /// Last day of the period (inclusive)
+    pub end_date: NaiveDate,
+    pub is_closed: bool,
+    pub closed_by: Option<Uuid>,
+    pub closed_at: DateTime<Utc>,
+    pub reopened_by: Option<Uuid>,
+    pub reopened_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewPayPeriod for closing a period
+#[derive(Debug, Insertable)]
+#[diesel(table_name = pay_periods)]
+pub struct NewPayPeriod {
+    pub organization_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub closed_by: Option<Uuid>,
+}
+
+/// PayPeriod response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct PayPeriodResponse {
+    pub id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub is_closed: bool,
+    pub closed_by: Option<Uuid>,
+    pub closed_at: DateTime<Utc>,
+    pub reopened_by: Option<Uuid>,
+    pub reopened_at: Option<DateTime<Utc>>,
+}
+
+impl From<PayPeriod> for PayPeriodResponse {
+    fn from(period: PayPeriod) -> Self {
+        Self {
+            id: period.id,
+            start_date: period.start_date,
+            end_date: period.end_date,
+            is_closed: period.is_closed,
+            closed_by: period.closed_by,
+            closed_at: period.closed_at,
+            reopened_by: period.reopened_by,
+            reopened_at: period.reopened_at,
+        }
+    }
+}
+
+/// Bounds and status of the period containing a date
+#[derive(Debug, Clone, Serialize)]
+pub struct PayPeriodStatusResponse {
+    pub frequency: PayPeriodFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub is_closed: bool,
+    /// Recorded period, if it was ever closed
+    pub period: Option<PayPeriodResponse>,
+}
+
+/// PayPeriod filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PayPeriodFilter {
+    pub is_closed: Option<bool>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
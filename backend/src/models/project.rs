@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{project_tasks, projects};
+
+/// Project entity from database
+/// A billable project that worked time can be allocated to
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = projects)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct Project {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub code: Option<String>,
+    pub client_name: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewProject for creating projects
+#[derive(Debug, Insertable)]
+#[diesel(table_name = projects)]
+pub struct NewProject {
+    pub organization_id: Uuid,
+    pub name: String,
+    pub code: Option<String>,
+    pub client_name: Option<String>,
+    pub is_active: bool,
+}
+
+/// Project update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = projects)]
+pub struct ProjectUpdate {
+    pub name: Option<String>,
+    pub code: Option<Option<String>>,
+    pub client_name: Option<Option<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// Project response for API, with its tasks
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub code: Option<String>,
+    pub client_name: Option<String>,
+    pub is_active: bool,
+    pub tasks: Vec<ProjectTaskResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectResponse {
+    pub fn from_project(project: Project, tasks: Vec<ProjectTask>) -> Self {
+        Self {
+            id: project.id,
+            name: project.name,
+            code: project.code,
+            client_name: project.client_name,
+            is_active: project.is_active,
+            tasks: tasks.into_iter().map(ProjectTaskResponse::from).collect(),
+            created_at: project.created_at,
+            updated_at: project.updated_at,
+        }
+    }
+}
+
+/// Project filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectFilter {
+    pub is_active: Option<bool>,
+}
+
+/// ProjectTask entity from database
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = project_tasks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ProjectTask {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewProjectTask for creating tasks
+#[derive(Debug, Insertable)]
+#[diesel(table_name = project_tasks)]
+pub struct NewProjectTask {
+    pub organization_id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub is_active: bool,
+}
+
+/// ProjectTask update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = project_tasks)]
+pub struct ProjectTaskUpdate {
+    pub name: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// ProjectTask response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectTaskResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub is_active: bool,
+}
+
+impl From<ProjectTask> for ProjectTaskResponse {
+    fn from(task: ProjectTask) -> Self {
+        Self {
+            id: task.id,
+            project_id: task.project_id,
+            name: task.name,
+            is_active: task.is_active,
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::RosterShiftStatus;
+use crate::models::WorkScheduleWithDays;
+use crate::schema::{roster_settings, roster_shifts};
+use crate::utils::datetime::{time_range_on, time_span_minutes};
+
+/// RosterSettings entity from database
+/// Organizations without settings use the defaults
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = roster_settings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RosterSettings {
+    pub organization_id: Uuid,
+    /// Whether claimed shift swaps wait for a manager before being applied
+    pub swap_requires_approval: bool,
+    /// Minimum rest between two shifts of the same employee
+    pub min_rest_hours: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewRosterSettings for creating or replacing settings
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = roster_settings)]
+pub struct NewRosterSettings {
+    pub organization_id: Uuid,
+    pub swap_requires_approval: bool,
+    pub min_rest_hours: i32,
+}
+
+/// RosterSettings response for API
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RosterSettingsResponse {
+    pub swap_requires_approval: bool,
+    pub min_rest_hours: i32,
+}
+
+impl Default for RosterSettingsResponse {
+    fn default() -> Self {
+        Self {
+            swap_requires_approval: true,
+            min_rest_hours: 11,
+        }
+    }
+}
+
+impl From<RosterSettings> for RosterSettingsResponse {
+    fn from(settings: RosterSettings) -> Self {
+        Self {
+            swap_requires_approval: settings.swap_requires_approval,
+            min_rest_hours: settings.min_rest_hours,
+        }
+    }
+}
+
+/// RosterShift entity from database
+/// A concrete shift planned for a date, overriding the weekly schedule once published
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = roster_shifts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RosterShift {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub team_id: Option<Uuid>,
+    pub shift_date: NaiveDate,
+    pub start_time: NaiveTime,
+    /// Before `start_time` when the shift ends the next day
+    pub end_time: NaiveTime,
+    pub break_minutes: i32,
+    /// Role or position worked during the shift
+    pub position: Option<String>,
+    pub notes: Option<String>,
+    pub status: RosterShiftStatus,
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RosterShift {
+    /// Whether the shift ends on the next day
+    pub fn is_overnight(&self) -> bool {
+        self.end_time < self.start_time
+    }
+
+    /// Planned working minutes, excluding the break
+    pub fn work_minutes(&self) -> i64 {
+        (time_span_minutes(self.start_time, self.end_time) - self.break_minutes as i64).max(0)
+    }
+
+    /// Local start and end of the shift
+    pub fn shift_bounds(&self) -> (NaiveDateTime, NaiveDateTime) {
+        time_range_on(self.shift_date, self.start_time, self.end_time)
+    }
+}
+
+/// Resolve the planned shift a local timestamp belongs to
+///
+/// Published roster shifts take precedence over the weekly schedule: on a date
+/// with roster shifts the schedule is ignored. A timestamp after midnight within
+/// the previous day's overnight shift is attributed to that previous day. When a
+/// date has several roster shifts, the one starting closest to the timestamp wins.
+/// Returns the shift date and the local bounds of the planned shift, if any.
+pub fn resolve_planned_shift(
+    schedule: Option<&WorkScheduleWithDays>,
+    shifts: &[RosterShift],
+    local: NaiveDateTime,
+) -> (NaiveDate, Option<(NaiveDateTime, NaiveDateTime)>) {
+    let date = local.date();
+    let previous = date - Duration::days(1);
+
+    let previous_shifts: Vec<&RosterShift> =
+        shifts.iter().filter(|s| s.shift_date == previous).collect();
+    let previous_bounds = if previous_shifts.is_empty() {
+        schedule
+            .and_then(|s| s.day_for(previous))
+            .filter(|d| d.is_overnight())
+            .map(|d| d.shift_bounds(previous))
+    } else {
+        previous_shifts
+            .iter()
+            .filter(|s| s.is_overnight())
+            .map(|s| s.shift_bounds())
+            .max_by_key(|(_, end)| *end)
+    };
+    if let Some(bounds) = previous_bounds.filter(|(_, end)| local < *end) {
+        return (previous, Some(bounds));
+    }
+
+    let day_shifts: Vec<&RosterShift> = shifts.iter().filter(|s| s.shift_date == date).collect();
+    let bounds = if day_shifts.is_empty() {
+        schedule
+            .and_then(|s| s.day_for(date))
+            .map(|d| d.shift_bounds(date))
+    } else {
+        day_shifts
+            .iter()
+            .map(|s| s.shift_bounds())
+            .min_by_key(|(start, _)| (*start - local).num_minutes().abs())
+    };
+    (date, bounds)
+}
+
+/// Planned working minutes of a date
+/// Roster shifts on the date replace the schedule's day entirely
+pub fn planned_minutes_on(
+    schedule: Option<&WorkScheduleWithDays>,
+    shifts: &[RosterShift],
+    date: NaiveDate,
+) -> i64 {
+    let mut day_shifts = shifts.iter().filter(|s| s.shift_date == date).peekable();
+    if day_shifts.peek().is_some() {
+        return day_shifts.map(RosterShift::work_minutes).sum();
+    }
+    schedule
+        .and_then(|s| s.day_for(date))
+        .map(|d| d.work_minutes())
+        .unwrap_or(0)
+}
+
+/// Local bounds of the shifts planned on a date
+/// Roster shifts on the date replace the schedule's day entirely
+pub fn planned_shifts_on(
+    schedule: Option<&WorkScheduleWithDays>,
+    shifts: &[RosterShift],
+    date: NaiveDate,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let day_shifts: Vec<_> = shifts
+        .iter()
+        .filter(|s| s.shift_date == date)
+        .map(RosterShift::shift_bounds)
+        .collect();
+    if !day_shifts.is_empty() {
+        return day_shifts;
+    }
+    schedule
+        .and_then(|s| s.day_for(date))
+        .map(|d| vec![d.shift_bounds(date)])
+        .unwrap_or_default()
+}
+
+/// NewRosterShift for creating shifts
+#[derive(Debug, Insertable)]
+#[diesel(table_name = roster_shifts)]
+pub struct NewRosterShift {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub team_id: Option<Uuid>,
+    pub shift_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub break_minutes: i32,
+    pub position: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+}
+
+/// RosterShift update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = roster_shifts)]
+pub struct RosterShiftUpdate {
+    pub team_id: Option<Option<Uuid>>,
+    pub shift_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: Option<i32>,
+    pub position: Option<Option<String>>,
+    pub notes: Option<Option<String>>,
+}
+
+/// RosterShift response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct RosterShiftResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub team_id: Option<Uuid>,
+    pub shift_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub break_minutes: i32,
+    pub work_minutes: i64,
+    pub position: Option<String>,
+    pub notes: Option<String>,
+    pub status: RosterShiftStatus,
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RosterShiftResponse {
+    pub fn from_shift(shift: RosterShift, user_name: String) -> Self {
+        Self {
+            id: shift.id,
+            user_id: shift.user_id,
+            user_name,
+            team_id: shift.team_id,
+            shift_date: shift.shift_date,
+            start_time: shift.start_time,
+            end_time: shift.end_time,
+            break_minutes: shift.break_minutes,
+            work_minutes: shift.work_minutes(),
+            position: shift.position,
+            notes: shift.notes,
+            status: shift.status,
+            published_at: shift.published_at,
+            created_at: shift.created_at,
+            updated_at: shift.updated_at,
+        }
+    }
+}
+
+/// RosterShift filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RosterShiftFilter {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub user_id: Option<Uuid>,
+    pub team_id: Option<Uuid>,
+    pub status: Option<RosterShiftStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{WorkSchedule, WorkScheduleDay};
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        // June 2025: the 2nd is a Monday
+        NaiveDate::from_ymd_opt(2025, 6, d).unwrap()
+    }
+
+    fn shift(d: u32, start: NaiveTime, end: NaiveTime, break_minutes: i32) -> RosterShift {
+        RosterShift {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            team_id: None,
+            shift_date: date(d),
+            start_time: start,
+            end_time: end,
+            break_minutes,
+            position: None,
+            notes: None,
+            status: RosterShiftStatus::Published,
+            published_at: None,
+            created_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Weekdays 09:00 to 17:00 with a one hour break
+    fn weekdays() -> WorkScheduleWithDays {
+        let schedule_id = Uuid::new_v4();
+        WorkScheduleWithDays {
+            schedule: WorkSchedule {
+                id: schedule_id,
+                organization_id: Uuid::nil(),
+                name: "Office".to_string(),
+                description: None,
+                is_default: true,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                cycle_weeks: 1,
+                cycle_anchor_date: date(2),
+            },
+            days: (0..5)
+                .map(|day_of_week| WorkScheduleDay {
+                    id: Uuid::new_v4(),
+                    work_schedule_id: schedule_id,
+                    day_of_week,
+                    start_time: time(9, 0),
+                    end_time: time(17, 0),
+                    break_minutes: 60,
+                    week_index: 0,
+                })
+                .collect(),
+            exceptions: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_work_minutes_overnight() {
+        let night = shift(2, time(22, 0), time(6, 0), 30);
+        assert!(night.is_overnight());
+        assert_eq!(night.work_minutes(), 450);
+        assert_eq!(night.shift_bounds().1, date(3).and_time(time(6, 0)));
+    }
+
+    #[test]
+    fn test_planned_minutes_roster_takes_precedence() {
+        let schedule = weekdays();
+        let shifts = vec![
+            shift(2, time(6, 0), time(10, 0), 0),
+            shift(2, time(14, 0), time(18, 0), 0),
+            shift(7, time(10, 0), time(14, 0), 0),
+        ];
+
+        // Split shift replaces Monday's schedule
+        assert_eq!(planned_minutes_on(Some(&schedule), &shifts, date(2)), 480);
+        // Tuesday falls back to the schedule
+        assert_eq!(planned_minutes_on(Some(&schedule), &shifts, date(3)), 420);
+        // Saturday has no schedule day but a roster shift
+        assert_eq!(planned_minutes_on(Some(&schedule), &shifts, date(7)), 240);
+        assert_eq!(planned_minutes_on(None, &[], date(7)), 0);
+    }
+
+    #[test]
+    fn test_resolve_planned_shift() {
+        let schedule = weekdays();
+        let shifts = vec![
+            shift(2, time(6, 0), time(10, 0), 0),
+            shift(2, time(14, 0), time(18, 0), 0),
+            shift(3, time(22, 0), time(6, 0), 0),
+        ];
+
+        // Closest roster shift of the day
+        let (shift_date, bounds) =
+            resolve_planned_shift(Some(&schedule), &shifts, date(2).and_time(time(13, 50)));
+        assert_eq!(shift_date, date(2));
+        assert_eq!(bounds.unwrap().0, date(2).and_time(time(14, 0)));
+
+        // After midnight within the previous day's overnight roster shift
+        let (shift_date, bounds) =
+            resolve_planned_shift(Some(&schedule), &shifts, date(4).and_time(time(1, 0)));
+        assert_eq!(shift_date, date(3));
+        assert_eq!(bounds.unwrap().0, date(3).and_time(time(22, 0)));
+
+        // No roster shift: the schedule applies
+        let (shift_date, bounds) =
+            resolve_planned_shift(Some(&schedule), &shifts, date(5).and_time(time(9, 10)));
+        assert_eq!(shift_date, date(5));
+        assert_eq!(bounds.unwrap().0, date(5).and_time(time(9, 0)));
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::WorkScheduleDay;
+use crate::schema::schedule_exceptions;
+
+/// ScheduleException entity from database
+/// Replaces a user's schedule day on a single date, or marks it as a day off
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = schedule_exceptions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ScheduleException {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub exception_date: NaiveDate,
+    pub is_day_off: bool,
+    /// Set unless the exception is a day off
+    pub start_time: Option<NaiveTime>,
+    /// Before `start_time` when the shift ends the next day
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: i32,
+    pub reason: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ScheduleException {
+    /// Schedule day worked on the exception date, None for a day off
+    ///
+    /// The day takes the exception's ID and belongs to `work_schedule_id`, so it
+    /// can stand in for the schedule's own day of that date.
+    pub fn as_schedule_day(&self, work_schedule_id: Uuid) -> Option<WorkScheduleDay> {
+        if self.is_day_off {
+            return None;
+        }
+        Some(WorkScheduleDay {
+            id: self.id,
+            work_schedule_id,
+            day_of_week: self.exception_date.weekday().num_days_from_monday() as i16,
+            start_time: self.start_time?,
+            end_time: self.end_time?,
+            break_minutes: self.break_minutes,
+            week_index: 0,
+        })
+    }
+}
+
+/// NewScheduleException for creating exceptions
+#[derive(Debug, Insertable)]
+#[diesel(table_name = schedule_exceptions)]
+pub struct NewScheduleException {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub exception_date: NaiveDate,
+    pub is_day_off: bool,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: i32,
+    pub reason: Option<String>,
+    pub created_by: Option<Uuid>,
+}
+
+/// ScheduleException update struct
+/// Times are always written so that switching to a day off clears them
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = schedule_exceptions)]
+#[diesel(treat_none_as_null = true)]
+pub struct ScheduleExceptionUpdate {
+    pub exception_date: NaiveDate,
+    pub is_day_off: bool,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: i32,
+    pub reason: Option<String>,
+}
+
+/// ScheduleException response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleExceptionResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub exception_date: NaiveDate,
+    pub is_day_off: bool,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: i32,
+    pub work_minutes: i64,
+    pub reason: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ScheduleException> for ScheduleExceptionResponse {
+    fn from(exception: ScheduleException) -> Self {
+        let work_minutes = exception
+            .as_schedule_day(Uuid::nil())
+            .map_or(0, |day| day.work_minutes());
+        Self {
+            id: exception.id,
+            user_id: exception.user_id,
+            exception_date: exception.exception_date,
+            is_day_off: exception.is_day_off,
+            start_time: exception.start_time,
+            end_time: exception.end_time,
+            break_minutes: exception.break_minutes,
+            work_minutes,
+            reason: exception.reason,
+            created_by: exception.created_by,
+            created_at: exception.created_at,
+            updated_at: exception.updated_at,
+        }
+    }
+}
+
+/// ScheduleException filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ScheduleExceptionFilter {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
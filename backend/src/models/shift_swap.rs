@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::enums::ShiftSwapStatus;
+use crate::models::RosterShiftResponse;
+use crate::schema::shift_swap_requests;
+
+/// ShiftSwapRequest entity from database
+/// A published shift offered by its employee to a colleague or to the open pool
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = shift_swap_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ShiftSwapRequest {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub shift_id: Uuid,
+    /// Employee giving the shift away
+    pub requester_id: Uuid,
+    /// Colleague the shift is offered to (None for the open pool)
+    pub target_user_id: Option<Uuid>,
+    /// Colleague taking the shift over
+    pub claimed_by: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub status: ShiftSwapStatus,
+    pub note: Option<String>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// NewShiftSwapRequest for creating swap requests
+#[derive(Debug, Insertable)]
+#[diesel(table_name = shift_swap_requests)]
+pub struct NewShiftSwapRequest {
+    pub organization_id: Uuid,
+    pub shift_id: Uuid,
+    pub requester_id: Uuid,
+    pub target_user_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+/// ShiftSwapRequest update struct for status changes
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = shift_swap_requests)]
+pub struct ShiftSwapRequestUpdate {
+    pub claimed_by: Option<Option<Uuid>>,
+    pub claimed_at: Option<Option<DateTime<Utc>>>,
+    pub status: Option<ShiftSwapStatus>,
+    pub reviewed_by: Option<Option<Uuid>>,
+    pub reviewed_at: Option<Option<DateTime<Utc>>>,
+    pub review_note: Option<Option<String>>,
+}
+
+/// ShiftSwapRequest response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct ShiftSwapResponse {
+    pub id: Uuid,
+    pub shift: RosterShiftResponse,
+    pub requester_id: Uuid,
+    pub requester_name: String,
+    pub target_user_id: Option<Uuid>,
+    pub target_user_name: Option<String>,
+    pub claimed_by: Option<Uuid>,
+    pub claimant_name: Option<String>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub status: ShiftSwapStatus,
+    pub note: Option<String>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Names of the people involved in a swap
+#[derive(Debug, Clone, Default)]
+pub struct ShiftSwapNames {
+    pub requester: String,
+    pub target_user: Option<String>,
+    pub claimant: Option<String>,
+}
+
+impl ShiftSwapResponse {
+    pub fn from_request(
+        request: ShiftSwapRequest,
+        shift: RosterShiftResponse,
+        names: ShiftSwapNames,
+    ) -> Self {
+        Self {
+            id: request.id,
+            shift,
+            requester_id: request.requester_id,
+            requester_name: names.requester,
+            target_user_id: request.target_user_id,
+            target_user_name: names.target_user,
+            claimed_by: request.claimed_by,
+            claimant_name: names.claimant,
+            claimed_at: request.claimed_at,
+            status: request.status,
+            note: request.note,
+            reviewed_by: request.reviewed_by,
+            reviewed_at: request.reviewed_at,
+            review_note: request.review_note,
+            created_at: request.created_at,
+            updated_at: request.updated_at,
+        }
+    }
+}
+
+/// ShiftSwapRequest filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ShiftSwapFilter {
+    pub status: Option<ShiftSwapStatus>,
+}
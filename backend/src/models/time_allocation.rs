@@ -0,0 +1,98 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::time_allocations;
+
+/// TimeAllocation entity from database
+/// Part of a clock entry's worked time spent on a project
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = time_allocations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TimeAllocation {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub clock_entry_id: Uuid,
+    pub user_id: Uuid,
+    pub project_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub segment_start: Option<DateTime<Utc>>,
+    pub segment_end: Option<DateTime<Utc>>,
+    pub minutes: i32,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// NewTimeAllocation for allocating time
+#[derive(Debug, Insertable)]
+#[diesel(table_name = time_allocations)]
+pub struct NewTimeAllocation {
+    pub organization_id: Uuid,
+    pub clock_entry_id: Uuid,
+    pub user_id: Uuid,
+    pub project_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub segment_start: Option<DateTime<Utc>>,
+    pub segment_end: Option<DateTime<Utc>>,
+    pub minutes: i32,
+    pub notes: Option<String>,
+}
+
+/// One allocation of a clock entry
+///
+/// Either `minutes`, or a sub-segment (`segment_start`/`segment_end`) of the entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeAllocationInput {
+    pub project_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub minutes: Option<i32>,
+    pub segment_start: Option<DateTime<Utc>>,
+    pub segment_end: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+}
+
+/// Request replacing all allocations of a clock entry
+#[derive(Debug, Deserialize)]
+pub struct SetTimeAllocationsRequest {
+    pub allocations: Vec<TimeAllocationInput>,
+}
+
+/// TimeAllocation response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeAllocationResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Option<Uuid>,
+    pub task_name: Option<String>,
+    pub segment_start: Option<DateTime<Utc>>,
+    pub segment_end: Option<DateTime<Utc>>,
+    pub minutes: i32,
+    pub notes: Option<String>,
+}
+
+/// Allocations of a clock entry with the remaining worked time
+#[derive(Debug, Serialize)]
+pub struct ClockEntryAllocations {
+    pub clock_entry_id: Uuid,
+    /// Entry duration minus breaks
+    pub worked_minutes: i64,
+    pub allocated_minutes: i64,
+    pub unallocated_minutes: i64,
+    pub allocations: Vec<TimeAllocationResponse>,
+}
+
+/// Hours allocated to a project by a user over a period (report row)
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectHoursRow {
+    /// First day of the period
+    pub period_start: NaiveDate,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub project_code: Option<String>,
+    pub user_id: Uuid,
+    pub user_email: String,
+    pub user_name: String,
+    pub minutes: i64,
+}
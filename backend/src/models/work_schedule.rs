@@ -1,9 +1,13 @@
-use chrono::{DateTime, NaiveTime, Utc};
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::ScheduleException;
 use crate::schema::{work_schedule_days, work_schedules};
+use crate::utils::datetime::{time_range_on, time_span_minutes};
 
 /// WorkSchedule entity from database
 #[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
@@ -17,6 +21,28 @@ pub struct WorkSchedule {
     pub is_default: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Number of weeks before the schedule repeats (1 for a weekly schedule)
+    pub cycle_weeks: i16,
+    /// A date in rotation week 0
+    pub cycle_anchor_date: NaiveDate,
+}
+
+impl WorkSchedule {
+    /// Rotation week a date falls in, from 0 to `cycle_weeks - 1`
+    ///
+    /// Weeks run Monday to Sunday; the week containing the anchor date is week 0.
+    pub fn rotation_week(&self, date: NaiveDate) -> i16 {
+        if self.cycle_weeks <= 1 {
+            return 0;
+        }
+        let weeks = (week_start(date) - week_start(self.cycle_anchor_date)).num_weeks();
+        weeks.rem_euclid(self.cycle_weeks as i64) as i16
+    }
+}
+
+/// Monday of the week containing a date
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
 }
 
 /// NewWorkSchedule for creating schedules
@@ -27,6 +53,8 @@ pub struct NewWorkSchedule {
     pub name: String,
     pub description: Option<String>,
     pub is_default: bool,
+    pub cycle_weeks: i16,
+    pub cycle_anchor_date: NaiveDate,
 }
 
 /// WorkSchedule update struct for partial updates
@@ -36,6 +64,8 @@ pub struct WorkScheduleUpdate {
     pub name: Option<String>,
     pub description: Option<Option<String>>,
     pub is_default: Option<bool>,
+    pub cycle_weeks: Option<i16>,
+    pub cycle_anchor_date: Option<NaiveDate>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -50,6 +80,49 @@ pub struct WorkScheduleDay {
     pub start_time: NaiveTime,
     pub end_time: NaiveTime,
     pub break_minutes: i32,
+    /// Rotation week the day belongs to (always 0 for weekly schedules)
+    pub week_index: i16,
+}
+
+impl WorkScheduleDay {
+    /// Whether the shift ends on the next day (e.g. 22:00 to 06:00)
+    pub fn is_overnight(&self) -> bool {
+        self.end_time < self.start_time
+    }
+
+    /// Scheduled working minutes, excluding the break
+    pub fn work_minutes(&self) -> i64 {
+        (time_span_minutes(self.start_time, self.end_time) - self.break_minutes as i64).max(0)
+    }
+
+    /// Local start and end of the shift when it starts on `date`
+    pub fn shift_bounds(&self, date: NaiveDate) -> (NaiveDateTime, NaiveDateTime) {
+        time_range_on(date, self.start_time, self.end_time)
+    }
+}
+
+/// Resolve the shift day a local timestamp belongs to
+///
+/// A timestamp after midnight that falls within the previous day's overnight shift
+/// is attributed to that previous day. Otherwise the timestamp's own date is used.
+/// Returns the shift date and its schedule day, if the schedule defines one.
+pub fn resolve_shift_day(
+    schedule: Option<&WorkScheduleWithDays>,
+    local: NaiveDateTime,
+) -> (NaiveDate, Option<&WorkScheduleDay>) {
+    let date = local.date();
+    let Some(schedule) = schedule else {
+        return (date, None);
+    };
+
+    let previous = date - Duration::days(1);
+    if let Some(prev_day) = schedule.day_for(previous).filter(|d| d.is_overnight()) {
+        if local < prev_day.shift_bounds(previous).1 {
+            return (previous, Some(prev_day));
+        }
+    }
+
+    (date, schedule.day_for(date))
 }
 
 /// NewWorkScheduleDay for creating schedule days
@@ -61,6 +134,7 @@ pub struct NewWorkScheduleDay {
     pub start_time: NaiveTime,
     pub end_time: NaiveTime,
     pub break_minutes: i32,
+    pub week_index: i16,
 }
 
 /// WorkScheduleDay update struct
@@ -78,13 +152,205 @@ pub struct WorkScheduleWithDays {
     #[serde(flatten)]
     pub schedule: WorkSchedule,
     pub days: Vec<WorkScheduleDay>,
+    /// Per-date days of one user replacing the schedule's, None for a day off
+    #[serde(skip)]
+    pub exceptions: HashMap<NaiveDate, Option<WorkScheduleDay>>,
+}
+
+impl WorkScheduleWithDays {
+    pub fn new(schedule: WorkSchedule, days: Vec<WorkScheduleDay>) -> Self {
+        Self {
+            schedule,
+            days,
+            exceptions: HashMap::new(),
+        }
+    }
+
+    /// Apply a user's schedule exceptions on top of the schedule
+    pub fn with_exceptions(mut self, exceptions: &[ScheduleException]) -> Self {
+        let schedule_id = self.schedule.id;
+        self.exceptions.extend(
+            exceptions
+                .iter()
+                .map(|e| (e.exception_date, e.as_schedule_day(schedule_id))),
+        );
+        self
+    }
+
+    /// Schedule day of a date, following the rotation
+    /// A schedule exception on the date takes precedence over the schedule's day
+    pub fn day_for(&self, date: NaiveDate) -> Option<&WorkScheduleDay> {
+        if let Some(exception) = self.exceptions.get(&date) {
+            return exception.as_ref();
+        }
+        let week = self.schedule.rotation_week(date);
+        let weekday = date.weekday().num_days_from_monday() as i16;
+        self.days
+            .iter()
+            .find(|d| d.week_index == week && d.day_of_week == weekday)
+    }
 }
 
 /// Day configuration for creating/updating schedules
 #[derive(Debug, Clone, Deserialize)]
 pub struct DayConfig {
+    /// Rotation week (0 when omitted)
+    #[serde(default)]
+    pub week_index: i16,
     pub day_of_week: i16,
     pub start_time: NaiveTime,
     pub end_time: NaiveTime,
     pub break_minutes: i32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn schedule(cycle_weeks: i16, anchor: NaiveDate) -> WorkSchedule {
+        WorkSchedule {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            name: "Rotation".to_string(),
+            description: None,
+            is_default: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle_weeks,
+            cycle_anchor_date: anchor,
+        }
+    }
+
+    fn day(week_index: i16, day_of_week: i16, start: u32, end: u32) -> WorkScheduleDay {
+        WorkScheduleDay {
+            id: Uuid::new_v4(),
+            work_schedule_id: Uuid::nil(),
+            day_of_week,
+            start_time: NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            break_minutes: 0,
+            week_index,
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_rotation_week() {
+        // Wednesday, so its whole Monday-Sunday week is week 0
+        let anchor = date(2026, 1, 7);
+        let fortnight = schedule(2, anchor);
+
+        assert_eq!(fortnight.rotation_week(date(2026, 1, 5)), 0);
+        assert_eq!(fortnight.rotation_week(date(2026, 1, 11)), 0);
+        assert_eq!(fortnight.rotation_week(date(2026, 1, 12)), 1);
+        assert_eq!(fortnight.rotation_week(date(2026, 1, 19)), 0);
+        // Dates before the anchor continue the cycle backwards
+        assert_eq!(fortnight.rotation_week(date(2026, 1, 4)), 1);
+        assert_eq!(schedule(4, anchor).rotation_week(date(2025, 12, 22)), 2);
+        assert_eq!(schedule(1, anchor).rotation_week(date(2026, 3, 2)), 0);
+    }
+
+    #[test]
+    fn test_day_for_follows_rotation() {
+        let schedule = WorkScheduleWithDays {
+            schedule: schedule(2, date(2026, 1, 5)),
+            // Week A works Monday mornings, week B Monday evenings
+            days: vec![day(0, 0, 8, 16), day(1, 0, 14, 22)],
+            exceptions: HashMap::new(),
+        };
+
+        let week_a = schedule.day_for(date(2026, 1, 5)).unwrap();
+        assert_eq!(week_a.start_time.hour(), 8);
+        let week_b = schedule.day_for(date(2026, 1, 12)).unwrap();
+        assert_eq!(week_b.start_time.hour(), 14);
+        assert!(schedule.day_for(date(2026, 1, 13)).is_none());
+    }
+
+    #[test]
+    fn test_resolve_shift_day_across_rotation_weeks() {
+        let schedule = WorkScheduleWithDays {
+            schedule: schedule(2, date(2026, 1, 5)),
+            // Sunday night shift in week A only
+            days: vec![day(0, 6, 22, 6)],
+            exceptions: HashMap::new(),
+        };
+
+        // Monday 02:00 of week B belongs to the Sunday shift of week A
+        let local = date(2026, 1, 12).and_hms_opt(2, 0, 0).unwrap();
+        let (shift_date, shift) = resolve_shift_day(Some(&schedule), local);
+        assert_eq!(shift_date, date(2026, 1, 11));
+        assert!(shift.is_some());
+
+        // Monday 02:00 of the next week A follows a week B Sunday without a shift
+        let local = date(2026, 1, 19).and_hms_opt(2, 0, 0).unwrap();
+        let (shift_date, shift) = resolve_shift_day(Some(&schedule), local);
+        assert_eq!(shift_date, date(2026, 1, 19));
+        assert!(shift.is_none());
+        assert_eq!(resolve_shift_day(None, local).0, date(2026, 1, 19));
+    }
+
+    fn exception(exception_date: NaiveDate, hours: Option<(u32, u32)>) -> ScheduleException {
+        ScheduleException {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            exception_date,
+            is_day_off: hours.is_none(),
+            start_time: hours.map(|(start, _)| NaiveTime::from_hms_opt(start, 0, 0).unwrap()),
+            end_time: hours.map(|(_, end)| NaiveTime::from_hms_opt(end, 0, 0).unwrap()),
+            break_minutes: 0,
+            reason: None,
+            created_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_day_for_applies_exceptions() {
+        // Monday and Tuesday 08:00 to 16:00
+        let schedule = WorkScheduleWithDays::new(
+            schedule(1, date(2026, 1, 5)),
+            vec![day(0, 0, 8, 16), day(0, 1, 8, 16)],
+        )
+        .with_exceptions(&[
+            // Late start on a Monday, a Tuesday off and a Saturday of training
+            exception(date(2026, 1, 12), Some((11, 19))),
+            exception(date(2026, 1, 13), None),
+            exception(date(2026, 1, 17), Some((9, 12))),
+        ]);
+
+        assert_eq!(
+            schedule
+                .day_for(date(2026, 1, 5))
+                .unwrap()
+                .start_time
+                .hour(),
+            8
+        );
+        assert_eq!(
+            schedule
+                .day_for(date(2026, 1, 12))
+                .unwrap()
+                .start_time
+                .hour(),
+            11
+        );
+        assert!(schedule.day_for(date(2026, 1, 13)).is_none());
+        assert_eq!(
+            schedule
+                .day_for(date(2026, 1, 20))
+                .unwrap()
+                .start_time
+                .hour(),
+            8
+        );
+        let saturday = schedule.day_for(date(2026, 1, 17)).unwrap();
+        assert_eq!(saturday.day_of_week, 5);
+        assert_eq!(saturday.work_minutes(), 180);
+    }
+}
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::work_sites;
+use crate::utils::geo::distance_meters;
+
+/// WorkSite entity from database
+/// A physical location of the organization used for geofenced clock in/out
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = work_sites)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WorkSite {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkSite {
+    /// Distance in meters between the site center and a position
+    pub fn distance_to(&self, position: &GeoPosition) -> f64 {
+        distance_meters(
+            self.latitude,
+            self.longitude,
+            position.latitude,
+            position.longitude,
+        )
+    }
+}
+
+/// NewWorkSite for creating work sites
+#[derive(Debug, Insertable)]
+#[diesel(table_name = work_sites)]
+pub struct NewWorkSite {
+    pub organization_id: Uuid,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: i32,
+    pub is_active: bool,
+}
+
+/// WorkSite update struct for partial updates
+#[derive(Debug, AsChangeset, Default)]
+#[diesel(table_name = work_sites)]
+pub struct WorkSiteUpdate {
+    pub name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius_meters: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+/// WorkSite response for API
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkSiteResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WorkSite> for WorkSiteResponse {
+    fn from(site: WorkSite) -> Self {
+        Self {
+            id: site.id,
+            name: site.name,
+            latitude: site.latitude,
+            longitude: site.longitude,
+            radius_meters: site.radius_meters,
+            is_active: site.is_active,
+            created_at: site.created_at,
+            updated_at: site.updated_at,
+        }
+    }
+}
+
+/// WorkSite filter options
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkSiteFilter {
+    pub is_active: Option<bool>,
+}
+
+/// Position reported by a client when clocking in or out
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Accuracy radius in meters, as reported by the device
+    pub accuracy: Option<f64>,
+}
+
+impl GeoPosition {
+    /// Check that the coordinates are within range and the accuracy is not negative
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude)
+            && (-180.0..=180.0).contains(&self.longitude)
+            && self.accuracy.is_none_or(|a| a.is_finite() && a >= 0.0)
+    }
+}
+
+/// Result of checking a position against the organization's work sites
+#[derive(Debug, Clone, Serialize)]
+pub struct GeofenceCheck {
+    /// True when the position falls within an active work site
+    pub within: bool,
+    /// Work site containing the position
+    pub work_site_id: Option<Uuid>,
+    /// Name of the matched work site, or of the nearest one when outside
+    pub work_site_name: Option<String>,
+    /// Distance in meters to the center of the matched or nearest work site
+    pub distance_meters: Option<f64>,
+}
@@ -7,10 +7,24 @@ use uuid::Uuid;
 use crate::config::database::DbPool;
 use crate::domain::enums::AbsenceStatus;
 use crate::error::AppError;
-use crate::models::{Absence, AbsenceFilter, AbsenceUpdate, NewAbsence, Pagination};
-use crate::schema::{absences, team_members};
+use crate::models::{
+    Absence, AbsenceApproval, AbsenceFilter, AbsencePeriod, AbsenceSettings, AbsenceUpdate,
+    NewAbsence, NewAbsenceApproval, NewAbsenceSettings, Pagination,
+};
+use crate::schema::{absence_approvals, absence_settings, absences, team_members};
 use crate::utils::{end_of_year, start_of_year};
 
+/// Map the one-approval-per-step violation to a conflict
+fn map_approval_write_error(e: diesel::result::Error) -> AppError {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => AppError::Conflict("This approval step has already been recorded".to_string()),
+        _ => AppError::DatabaseError(e),
+    }
+}
+
 /// Absence repository for database operations
 pub struct AbsenceRepository {
     pub(crate) pool: DbPool,
@@ -26,6 +40,43 @@ impl AbsenceRepository {
         &self.pool
     }
 
+    /// Find the absence settings of an organization
+    pub async fn find_settings(&self, org_id: Uuid) -> Result<Option<AbsenceSettings>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        absence_settings::table
+            .find(org_id)
+            .first::<AbsenceSettings>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Create or replace the absence settings of an organization
+    pub async fn upsert_settings(
+        &self,
+        settings: NewAbsenceSettings,
+    ) -> Result<AbsenceSettings, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(absence_settings::table)
+            .values(&settings)
+            .on_conflict(absence_settings::organization_id)
+            .do_update()
+            .set((&settings, absence_settings::updated_at.eq(Utc::now())))
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
     /// Create a new absence request
     pub async fn create(&self, new_absence: NewAbsence) -> Result<Absence, AppError> {
         let mut conn = self
@@ -148,6 +199,46 @@ impl AbsenceRepository {
         Ok((absences, total))
     }
 
+    /// IDs of pending absences matching a bulk review selection
+    /// Absences overlapping `[start_date, end_date]`, oldest request first, at most `limit`
+    pub async fn list_pending_ids(
+        &self,
+        org_id: Uuid,
+        user_ids: Option<&[Uuid]>,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = absences::table
+            .filter(absences::organization_id.eq(org_id))
+            .filter(absences::status.eq(AbsenceStatus::Pending))
+            .into_boxed();
+
+        if let Some(ids) = user_ids {
+            query = query.filter(absences::user_id.eq_any(ids.to_vec()));
+        }
+        if let Some(start_date) = start_date {
+            query = query.filter(absences::end_date.ge(start_date));
+        }
+        if let Some(end_date) = end_date {
+            query = query.filter(absences::start_date.le(end_date));
+        }
+
+        query
+            .select(absences::id)
+            .order(absences::created_at.asc())
+            .limit(limit)
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
     /// List pending absences for approval (for managers)
     pub async fn list_pending(
         &self,
@@ -231,12 +322,14 @@ impl AbsenceRepository {
     }
 
     /// Check for overlapping absences
+    ///
+    /// A morning and an afternoon taken on the same date do not overlap, nor do
+    /// hourly absences with separate time ranges.
     pub async fn check_overlap(
         &self,
         org_id: Uuid,
         user_id: Uuid,
-        start_date: NaiveDate,
-        end_date: NaiveDate,
+        period: &AbsencePeriod,
         exclude_id: Option<Uuid>,
     ) -> Result<bool, AppError> {
         let mut conn = self
@@ -250,21 +343,22 @@ impl AbsenceRepository {
             .filter(absences::user_id.eq(user_id))
             .filter(absences::status.ne(AbsenceStatus::Rejected))
             .filter(absences::status.ne(AbsenceStatus::Cancelled))
-            .filter(absences::start_date.le(end_date))
-            .filter(absences::end_date.ge(start_date))
+            .filter(absences::start_date.le(period.end_date))
+            .filter(absences::end_date.ge(period.start_date))
             .into_boxed();
 
         if let Some(id) = exclude_id {
             query = query.filter(absences::id.ne(id));
         }
 
-        let count: i64 = query
-            .count()
-            .get_result(&mut conn)
+        let candidates = query
+            .load::<Absence>(&mut conn)
             .await
             .map_err(AppError::DatabaseError)?;
 
-        Ok(count > 0)
+        Ok(candidates
+            .iter()
+            .any(|existing| existing.period().overlaps(period)))
     }
 
     /// Get absences for a date range (for calendar view)
@@ -357,4 +451,43 @@ impl AbsenceRepository {
 
         Ok(())
     }
+
+    /// Approved steps of an absence, in order
+    pub async fn list_approvals(
+        &self,
+        org_id: Uuid,
+        absence_id: Uuid,
+    ) -> Result<Vec<AbsenceApproval>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        absence_approvals::table
+            .filter(absence_approvals::organization_id.eq(org_id))
+            .filter(absence_approvals::absence_id.eq(absence_id))
+            .order(absence_approvals::step_order.asc())
+            .load::<AbsenceApproval>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Record an approved step of an absence
+    pub async fn record_approval(
+        &self,
+        approval: NewAbsenceApproval,
+    ) -> Result<AbsenceApproval, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(absence_approvals::table)
+            .values(&approval)
+            .get_result(&mut conn)
+            .await
+            .map_err(map_approval_write_error)
+    }
 }
@@ -1,12 +1,16 @@
 use chrono::Utc;
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
+use crate::domain::enums::AbsenceStatus;
 use crate::error::AppError;
-use crate::models::{AbsenceType, AbsenceTypeUpdate, NewAbsenceType};
-use crate::schema::absence_types;
+use crate::models::{
+    AbsenceApprovalStep, AbsenceType, AbsenceTypeUpdate, NewAbsenceApprovalStep, NewAbsenceType,
+};
+use crate::schema::{absence_approval_steps, absence_types, absences, leave_balances};
 
 /// Absence type repository for database operations
 pub struct AbsenceTypeRepository {
@@ -99,6 +103,9 @@ impl AbsenceTypeRepository {
     }
 
     /// Update an absence type
+    ///
+    /// Fails with a conflict when the unit changes while pending or approved absences
+    /// or leave balances of the type are counted in the current unit.
     pub async fn update(
         &self,
         org_id: Uuid,
@@ -113,21 +120,55 @@ impl AbsenceTypeRepository {
 
         update.updated_at = Some(Utc::now());
 
-        let affected = diesel::update(
-            absence_types::table
-                .filter(absence_types::organization_id.eq(org_id))
-                .filter(absence_types::id.eq(type_id)),
-        )
-        .set(&update)
-        .execute(&mut conn)
-        .await
-        .map_err(AppError::DatabaseError)?;
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                // Locking the type holds off absences and balances created concurrently
+                let absence_type: AbsenceType = absence_types::table
+                    .filter(absence_types::organization_id.eq(org_id))
+                    .filter(absence_types::id.eq(type_id))
+                    .for_update()
+                    .first(conn)
+                    .await
+                    .optional()
+                    .map_err(AppError::DatabaseError)?
+                    .ok_or_else(|| AppError::NotFound("Absence type not found".to_string()))?;
 
-        if affected == 0 {
-            return Err(AppError::NotFound("Absence type not found".to_string()));
-        }
+                if update.unit.is_some_and(|unit| unit != absence_type.unit) {
+                    let has_absences: bool = diesel::select(diesel::dsl::exists(
+                        absences::table
+                            .filter(absences::type_id.eq(type_id))
+                            .filter(
+                                absences::status
+                                    .eq_any([AbsenceStatus::Pending, AbsenceStatus::Approved]),
+                            ),
+                    ))
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)?;
+                    let has_balances: bool = diesel::select(diesel::dsl::exists(
+                        leave_balances::table
+                            .filter(leave_balances::absence_type_id.eq(type_id)),
+                    ))
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)?;
+
+                    if has_absences || has_balances {
+                        return Err(AppError::Conflict(
+                            "The unit of an absence type cannot change once it has absences or leave balances".to_string(),
+                        ));
+                    }
+                }
 
-        self.find_by_id(org_id, type_id).await
+                diesel::update(absence_types::table.find(type_id))
+                    .set(&update)
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
     }
 
     /// Delete an absence type
@@ -172,4 +213,63 @@ impl AbsenceTypeRepository {
 
         Ok(types)
     }
+
+    /// Approval steps configured for an absence type, in order
+    pub async fn list_approval_steps(
+        &self,
+        org_id: Uuid,
+        type_id: Uuid,
+    ) -> Result<Vec<AbsenceApprovalStep>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        absence_approval_steps::table
+            .filter(absence_approval_steps::organization_id.eq(org_id))
+            .filter(absence_approval_steps::absence_type_id.eq(type_id))
+            .order(absence_approval_steps::step_order.asc())
+            .load::<AbsenceApprovalStep>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Replace the approval chain of an absence type in one transaction
+    pub async fn replace_approval_steps(
+        &self,
+        org_id: Uuid,
+        type_id: Uuid,
+        steps: Vec<NewAbsenceApprovalStep>,
+    ) -> Result<Vec<AbsenceApprovalStep>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            async move {
+                diesel::delete(
+                    absence_approval_steps::table
+                        .filter(absence_approval_steps::organization_id.eq(org_id))
+                        .filter(absence_approval_steps::absence_type_id.eq(type_id)),
+                )
+                .execute(conn)
+                .await?;
+
+                if steps.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                diesel::insert_into(absence_approval_steps::table)
+                    .values(&steps)
+                    .get_results::<AbsenceApprovalStep>(conn)
+                    .await
+            }
+            .scope_boxed()
+        })
+        .await
+        .map_err(AppError::DatabaseError)
+    }
 }
@@ -1,6 +1,7 @@
 use chrono::{DateTime, NaiveTime, Utc};
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
@@ -10,6 +11,7 @@ use crate::models::{
     BreakEntry, BreakEntryFilter, BreakEntryUpdate, BreakPolicy, BreakPolicyFilter,
     BreakPolicyUpdate, BreakWindow, NewBreakEntry, NewBreakPolicy, NewBreakWindow, Pagination,
 };
+use crate::repositories::pay_period_repository::ensure_instants_open;
 use crate::schema::{break_entries, break_policies, break_windows, team_members};
 use crate::utils::{end_of_day, start_of_day};
 
@@ -311,7 +313,10 @@ impl BreakRepository {
 
         break_windows::table
             .filter(break_windows::break_policy_id.eq(policy_id))
-            .order(break_windows::day_of_week.asc())
+            .order((
+                break_windows::day_of_week.asc(),
+                break_windows::week_index.asc().nulls_first(),
+            ))
             .load::<BreakWindow>(&mut conn)
             .await
             .map_err(AppError::DatabaseError)
@@ -367,10 +372,12 @@ impl BreakRepository {
     }
 
     /// Get window for a specific day of week for a policy
+    /// A window of the given rotation week takes precedence over an every-week window
     pub async fn get_window_for_day(
         &self,
         policy_id: Uuid,
         day_of_week: i16,
+        rotation_week: i16,
     ) -> Result<Option<BreakWindow>, AppError> {
         let mut conn = self
             .pool
@@ -381,6 +388,12 @@ impl BreakRepository {
         break_windows::table
             .filter(break_windows::break_policy_id.eq(policy_id))
             .filter(break_windows::day_of_week.eq(day_of_week))
+            .filter(
+                break_windows::week_index
+                    .eq(rotation_week)
+                    .or(break_windows::week_index.is_null()),
+            )
+            .order(break_windows::week_index.asc().nulls_last())
             .first::<BreakWindow>(&mut conn)
             .await
             .optional()
@@ -392,6 +405,7 @@ impl BreakRepository {
     // =====================
 
     /// Create a new break entry (start a break)
+    /// Fails when the break starts in a closed pay period
     pub async fn create_entry(&self, entry: NewBreakEntry) -> Result<BreakEntry, AppError> {
         let mut conn = self
             .pool
@@ -399,11 +413,19 @@ impl BreakRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::insert_into(break_entries::table)
-            .values(&entry)
-            .get_result(&mut conn)
-            .await
-            .map_err(AppError::DatabaseError)
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_instants_open(conn, entry.organization_id, &[entry.break_start]).await?;
+
+                diesel::insert_into(break_entries::table)
+                    .values(&entry)
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
     }
 
     /// Get entry by ID
@@ -506,6 +528,7 @@ impl BreakRepository {
     }
 
     /// Update a break entry (end a break)
+    /// Fails when the break starts or the new end falls in a closed pay period
     pub async fn update_entry(
         &self,
         org_id: Uuid,
@@ -518,20 +541,38 @@ impl BreakRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::update(
-            break_entries::table
-                .filter(break_entries::organization_id.eq(org_id))
-                .filter(break_entries::id.eq(entry_id)),
-        )
-        .set(&update)
-        .get_result(&mut conn)
-        .await
-        .map_err(|e| match e {
-            diesel::result::Error::NotFound => {
-                AppError::NotFound("Break entry not found".to_string())
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                let break_start: Option<DateTime<Utc>> = break_entries::table
+                    .filter(break_entries::organization_id.eq(org_id))
+                    .filter(break_entries::id.eq(entry_id))
+                    .select(break_entries::break_start)
+                    .first(conn)
+                    .await
+                    .optional()
+                    .map_err(AppError::DatabaseError)?;
+                let instants: Vec<DateTime<Utc>> =
+                    break_start.into_iter().chain(update.break_end).collect();
+                ensure_instants_open(conn, org_id, &instants).await?;
+
+                diesel::update(
+                    break_entries::table
+                        .filter(break_entries::organization_id.eq(org_id))
+                        .filter(break_entries::id.eq(entry_id)),
+                )
+                .set(&update)
+                .get_result(conn)
+                .await
+                .map_err(|e| match e {
+                    diesel::result::Error::NotFound => {
+                        AppError::NotFound("Break entry not found".to_string())
+                    }
+                    _ => AppError::DatabaseError(e),
+                })
             }
-            _ => AppError::DatabaseError(e),
+            .scope_boxed()
         })
+        .await
     }
 
     /// Get current active break for user (break_end is NULL)
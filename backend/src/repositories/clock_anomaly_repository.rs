@@ -0,0 +1,229 @@
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::AnomalyStatus;
+use crate::error::AppError;
+use crate::models::{
+    AnomalySettings, ClockAnomaly, ClockAnomalyFilter, NewAnomalySettings, NewClockAnomaly,
+    Pagination,
+};
+use crate::schema::{anomaly_settings, clock_anomalies};
+
+/// Clock anomaly repository for database operations
+pub struct ClockAnomalyRepository {
+    pool: DbPool,
+}
+
+impl ClockAnomalyRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Get anomaly settings of an organization, if any
+    pub async fn find_settings(&self, org_id: Uuid) -> Result<Option<AnomalySettings>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        anomaly_settings::table
+            .find(org_id)
+            .first::<AnomalySettings>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Create or replace anomaly settings of an organization
+    pub async fn upsert_settings(
+        &self,
+        settings: NewAnomalySettings,
+    ) -> Result<AnomalySettings, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(anomaly_settings::table)
+            .values(&settings)
+            .on_conflict(anomaly_settings::organization_id)
+            .do_update()
+            .set((&settings, anomaly_settings::updated_at.eq(Utc::now())))
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Record findings, skipping those already recorded for the same entry and kind
+    /// Returns the findings actually inserted
+    pub async fn insert_new(
+        &self,
+        findings: Vec<NewClockAnomaly>,
+    ) -> Result<Vec<ClockAnomaly>, AppError> {
+        if findings.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(clock_anomalies::table)
+            .values(&findings)
+            .on_conflict((clock_anomalies::clock_entry_id, clock_anomalies::kind))
+            .do_nothing()
+            .get_results(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find anomaly by ID within organization
+    pub async fn find_by_id(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+    ) -> Result<ClockAnomaly, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_anomalies::table
+            .filter(clock_anomalies::organization_id.eq(org_id))
+            .find(anomaly_id)
+            .first::<ClockAnomaly>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Clock anomaly not found".to_string()))
+    }
+
+    /// List anomalies matching a filter, most severe and most recent first
+    ///
+    /// `user_ids` restricts the list to some users (None for the whole organization).
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &ClockAnomalyFilter,
+        user_ids: Option<&[Uuid]>,
+        pagination: &Pagination,
+    ) -> Result<(Vec<ClockAnomaly>, i64), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let total: i64 = filtered(org_id, filter, user_ids)
+            .count()
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        let offset = (pagination.page - 1) * pagination.per_page;
+        let anomalies = filtered(org_id, filter, user_ids)
+            .order((
+                clock_anomalies::severity.desc(),
+                clock_anomalies::detected_at.desc(),
+            ))
+            .limit(pagination.per_page)
+            .offset(offset)
+            .load::<ClockAnomaly>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok((anomalies, total))
+    }
+
+    /// Close open anomalies with a review outcome
+    ///
+    /// Only open anomalies among `anomaly_ids` are updated; the updated rows are returned.
+    pub async fn review(
+        &self,
+        org_id: Uuid,
+        anomaly_ids: &[Uuid],
+        status: AnomalyStatus,
+        reviewer_id: Uuid,
+        note: Option<String>,
+    ) -> Result<Vec<ClockAnomaly>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            clock_anomalies::table
+                .filter(clock_anomalies::organization_id.eq(org_id))
+                .filter(clock_anomalies::id.eq_any(anomaly_ids))
+                .filter(clock_anomalies::status.eq(AnomalyStatus::Open)),
+        )
+        .set((
+            clock_anomalies::status.eq(status),
+            clock_anomalies::reviewed_by.eq(Some(reviewer_id)),
+            clock_anomalies::reviewed_at.eq(Some(Utc::now())),
+            clock_anomalies::review_note.eq(note),
+        ))
+        .get_results(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)
+    }
+
+    /// IDs of the open anomalies of a clock entry
+    pub async fn list_open_ids_for_entry(
+        &self,
+        org_id: Uuid,
+        clock_entry_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_anomalies::table
+            .filter(clock_anomalies::organization_id.eq(org_id))
+            .filter(clock_anomalies::clock_entry_id.eq(clock_entry_id))
+            .filter(clock_anomalies::status.eq(AnomalyStatus::Open))
+            .select(clock_anomalies::id)
+            .load::<Uuid>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+}
+
+/// Anomalies of an organization matching a filter
+fn filtered<'a>(
+    org_id: Uuid,
+    filter: &ClockAnomalyFilter,
+    user_ids: Option<&'a [Uuid]>,
+) -> clock_anomalies::BoxedQuery<'a, Pg> {
+    let mut query = clock_anomalies::table
+        .filter(clock_anomalies::organization_id.eq(org_id))
+        .filter(clock_anomalies::status.eq(filter.status.unwrap_or_default()))
+        .into_boxed();
+
+    if let Some(kind) = filter.kind {
+        query = query.filter(clock_anomalies::kind.eq(kind));
+    }
+    if let Some(severity) = filter.severity {
+        query = query.filter(clock_anomalies::severity.ge(severity));
+    }
+    if let Some(user_id) = filter.user_id {
+        query = query.filter(clock_anomalies::user_id.eq(user_id));
+    }
+    if let Some(ids) = user_ids {
+        query = query.filter(clock_anomalies::user_id.eq_any(ids));
+    }
+
+    query
+}
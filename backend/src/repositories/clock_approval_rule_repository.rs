@@ -0,0 +1,192 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    ClockApprovalRule, ClockApprovalRuleFilter, ClockApprovalRuleUpdate, NewClockApprovalRule,
+};
+use crate::schema::{clock_approval_rules, team_members};
+
+/// Clock approval rule repository for database operations
+pub struct ClockApprovalRuleRepository {
+    pool: DbPool,
+}
+
+/// Map unique name violations to a conflict
+fn map_write_error(e: diesel::result::Error) -> AppError {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => AppError::Conflict("An approval rule with this name already exists".to_string()),
+        _ => AppError::DatabaseError(e),
+    }
+}
+
+impl ClockApprovalRuleRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new approval rule
+    pub async fn create(
+        &self,
+        new_rule: NewClockApprovalRule,
+    ) -> Result<ClockApprovalRule, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(clock_approval_rules::table)
+            .values(&new_rule)
+            .get_result(&mut conn)
+            .await
+            .map_err(map_write_error)
+    }
+
+    /// Find approval rule by ID within organization
+    pub async fn find_by_id(
+        &self,
+        org_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<ClockApprovalRule, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_approval_rules::table
+            .filter(clock_approval_rules::organization_id.eq(org_id))
+            .find(rule_id)
+            .first::<ClockApprovalRule>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Approval rule not found".to_string()))
+    }
+
+    /// List approval rules with filters
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &ClockApprovalRuleFilter,
+    ) -> Result<Vec<ClockApprovalRule>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = clock_approval_rules::table
+            .filter(clock_approval_rules::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(team_id) = filter.team_id {
+            query = query.filter(clock_approval_rules::team_id.eq(team_id));
+        }
+        if let Some(is_active) = filter.is_active {
+            query = query.filter(clock_approval_rules::is_active.eq(is_active));
+        }
+
+        query
+            .order(clock_approval_rules::name.asc())
+            .load::<ClockApprovalRule>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update an approval rule
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        rule_id: Uuid,
+        update: ClockApprovalRuleUpdate,
+    ) -> Result<ClockApprovalRule, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            clock_approval_rules::table
+                .filter(clock_approval_rules::organization_id.eq(org_id))
+                .filter(clock_approval_rules::id.eq(rule_id)),
+        )
+        .set((&update, clock_approval_rules::updated_at.eq(Utc::now())))
+        .get_result::<ClockApprovalRule>(&mut conn)
+        .await
+        .optional()
+        .map_err(map_write_error)?
+        .ok_or_else(|| AppError::NotFound("Approval rule not found".to_string()))
+    }
+
+    /// Delete an approval rule
+    /// Entries it approved keep their status
+    pub async fn delete(&self, org_id: Uuid, rule_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            clock_approval_rules::table
+                .filter(clock_approval_rules::organization_id.eq(org_id))
+                .filter(clock_approval_rules::id.eq(rule_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Approval rule not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Active rules applying to a user
+    /// Rules of the user's teams come first, then organization-wide rules
+    pub async fn list_applicable(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<ClockApprovalRule>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let team_ids: Vec<Uuid> = team_members::table
+            .filter(team_members::user_id.eq(user_id))
+            .select(team_members::team_id)
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        let mut rules = clock_approval_rules::table
+            .filter(clock_approval_rules::organization_id.eq(org_id))
+            .filter(clock_approval_rules::is_active.eq(true))
+            .filter(
+                clock_approval_rules::team_id
+                    .eq_any(team_ids)
+                    .or(clock_approval_rules::team_id.is_null()),
+            )
+            .order(clock_approval_rules::name.asc())
+            .load::<ClockApprovalRule>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        // Stable: team rules first, each group by name
+        rules.sort_by_key(|rule| rule.team_id.is_none());
+        Ok(rules)
+    }
+}
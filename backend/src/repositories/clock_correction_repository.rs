@@ -0,0 +1,211 @@
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::ClockCorrectionStatus;
+use crate::error::AppError;
+use crate::models::{
+    ClockCorrectionRequest, ClockCorrectionRequestUpdate, NewClockCorrectionRequest, Pagination,
+};
+use crate::schema::clock_correction_requests;
+
+/// Repository for clock entry correction requests
+pub struct ClockCorrectionRepository {
+    pool: DbPool,
+}
+
+impl ClockCorrectionRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new correction request
+    pub async fn create(
+        &self,
+        request: NewClockCorrectionRequest,
+    ) -> Result<ClockCorrectionRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(clock_correction_requests::table)
+            .values(&request)
+            .get_result(&mut conn)
+            .await
+            .map_err(|e| match e {
+                diesel::result::Error::DatabaseError(
+                    diesel::result::DatabaseErrorKind::UniqueViolation,
+                    _,
+                ) => AppError::Conflict(
+                    "A correction request is already pending for this clock entry".to_string(),
+                ),
+                _ => AppError::DatabaseError(e),
+            })
+    }
+
+    /// Get correction request by ID
+    pub async fn find_by_id(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+    ) -> Result<ClockCorrectionRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_correction_requests::table
+            .filter(clock_correction_requests::organization_id.eq(org_id))
+            .find(request_id)
+            .first::<ClockCorrectionRequest>(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound("Correction request not found".to_string()))
+    }
+
+    /// Find the pending correction request for a clock entry, if any
+    pub async fn find_pending_for_entry(
+        &self,
+        org_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<Option<ClockCorrectionRequest>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_correction_requests::table
+            .filter(clock_correction_requests::organization_id.eq(org_id))
+            .filter(clock_correction_requests::clock_entry_id.eq(entry_id))
+            .filter(clock_correction_requests::status.eq(ClockCorrectionStatus::Pending))
+            .first::<ClockCorrectionRequest>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List all correction requests for a clock entry (history, newest first)
+    pub async fn list_by_entry(
+        &self,
+        org_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<Vec<ClockCorrectionRequest>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_correction_requests::table
+            .filter(clock_correction_requests::organization_id.eq(org_id))
+            .filter(clock_correction_requests::clock_entry_id.eq(entry_id))
+            .order(clock_correction_requests::created_at.desc())
+            .load::<ClockCorrectionRequest>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List pending correction requests for organization
+    ///
+    /// `user_ids` restricts the list to some users (None for the whole organization).
+    pub async fn list_pending(
+        &self,
+        org_id: Uuid,
+        user_ids: Option<&[Uuid]>,
+        pagination: &Pagination,
+    ) -> Result<(Vec<ClockCorrectionRequest>, i64), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let total: i64 = pending(org_id, user_ids)
+            .count()
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        let offset = (pagination.page - 1) * pagination.per_page;
+        let requests = pending(org_id, user_ids)
+            .order(clock_correction_requests::created_at.desc())
+            .limit(pagination.per_page)
+            .offset(offset)
+            .load::<ClockCorrectionRequest>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok((requests, total))
+    }
+
+    /// Update a correction request (for review)
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        update: ClockCorrectionRequestUpdate,
+    ) -> Result<ClockCorrectionRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            clock_correction_requests::table
+                .filter(clock_correction_requests::organization_id.eq(org_id))
+                .filter(clock_correction_requests::id.eq(request_id)),
+        )
+        .set(&update)
+        .get_result(&mut conn)
+        .await
+        .map_err(|e| match e {
+            diesel::result::Error::NotFound => {
+                AppError::NotFound("Correction request not found".to_string())
+            }
+            _ => AppError::DatabaseError(e),
+        })
+    }
+
+    /// Mark a correction request as reviewed with the given status
+    pub async fn review(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        status: ClockCorrectionStatus,
+        review_notes: Option<String>,
+    ) -> Result<ClockCorrectionRequest, AppError> {
+        let update = ClockCorrectionRequestUpdate {
+            status: Some(status),
+            reviewed_by: Some(Some(reviewer_id)),
+            reviewed_at: Some(Some(Utc::now())),
+            review_notes: Some(review_notes),
+        };
+
+        self.update(org_id, request_id, update).await
+    }
+}
+
+/// Pending correction requests of an organization, optionally restricted to some users
+fn pending<'a>(
+    org_id: Uuid,
+    user_ids: Option<&'a [Uuid]>,
+) -> clock_correction_requests::BoxedQuery<'a, Pg> {
+    let mut query = clock_correction_requests::table
+        .filter(clock_correction_requests::organization_id.eq(org_id))
+        .filter(clock_correction_requests::status.eq(ClockCorrectionStatus::Pending))
+        .into_boxed();
+
+    if let Some(ids) = user_ids {
+        query = query.filter(clock_correction_requests::user_id.eq_any(ids));
+    }
+
+    query
+}
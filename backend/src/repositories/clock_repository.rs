@@ -1,14 +1,19 @@
 use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
 use crate::domain::enums::ClockEntryStatus;
 use crate::error::AppError;
-use crate::models::{ClockEntry, ClockEntryUpdate, ClockFilter, NewClockEntry, Pagination};
+use crate::models::{
+    ClockContext, ClockEntry, ClockEntryUpdate, ClockFilter, NewClockEntry, Pagination,
+};
+use crate::repositories::pay_period_repository::ensure_instants_open;
 use crate::schema::{clock_entries, team_members, users};
-use crate::utils::{end_of_day, start_of_day};
+use crate::utils::timezone::local_day_bounds;
 
 /// Clock repository for database operations
 pub struct ClockRepository {
@@ -25,12 +30,18 @@ impl ClockRepository {
         &self.pool
     }
 
-    /// Create a new clock entry (clock in)
+    /// Create a new clock entry (clock in) with the position and device it was captured from
+    ///
+    /// Events recorded offline are stored at the time they happened.
+    /// Fails when that time falls in a closed pay period.
     pub async fn clock_in(
         &self,
         org_id: Uuid,
         user_id: Uuid,
         notes: Option<String>,
+        context: ClockContext,
+        work_site_id: Option<Uuid>,
+        outside_geofence: bool,
     ) -> Result<ClockEntry, AppError> {
         let mut conn = self
             .pool
@@ -41,23 +52,75 @@ impl ClockRepository {
         let new_entry = NewClockEntry {
             organization_id: org_id,
             user_id,
-            clock_in: Utc::now(),
+            clock_in: context.recorded_at.unwrap_or_else(Utc::now),
+            clock_out: None,
+            status: ClockEntryStatus::Pending,
+            approved_by: None,
+            approved_at: None,
             notes,
+            source: context.source(),
+            created_by: None,
+            clock_in_latitude: context.position.map(|p| p.latitude),
+            clock_in_longitude: context.position.map(|p| p.longitude),
+            clock_in_accuracy: context.position.and_then(|p| p.accuracy),
+            work_site_id,
+            outside_geofence,
+            kiosk_device_id: context.kiosk_device_id,
+            clock_drift_seconds: context.clock_drift_seconds,
+            clock_in_ip: context.client_ip.map(|ip| ip.to_string()),
         };
 
-        diesel::insert_into(clock_entries::table)
-            .values(&new_entry)
-            .get_result(&mut conn)
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_instants_open(conn, org_id, &[new_entry.clock_in]).await?;
+
+                diesel::insert_into(clock_entries::table)
+                    .values(&new_entry)
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Insert a fully specified clock entry (e.g. manual entries recorded by a manager)
+    /// Fails when the entry falls in a closed pay period
+    pub async fn create(&self, new_entry: NewClockEntry) -> Result<ClockEntry, AppError> {
+        let mut conn = self
+            .pool
+            .get()
             .await
-            .map_err(AppError::DatabaseError)
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut instants = vec![new_entry.clock_in];
+        instants.extend(new_entry.clock_out);
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_instants_open(conn, new_entry.organization_id, &instants).await?;
+
+                diesel::insert_into(clock_entries::table)
+                    .values(&new_entry)
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
     }
 
-    /// Clock out an entry with optional notes
+    /// Clock out an entry with optional notes and the context it was captured in
+    /// Fails when the entry or the clock-out time falls in a closed pay period
     pub async fn clock_out(
         &self,
         org_id: Uuid,
         entry_id: Uuid,
         notes: Option<String>,
+        context: ClockContext,
+        outside_geofence: bool,
     ) -> Result<ClockEntry, AppError> {
         let mut conn = self
             .pool
@@ -65,24 +128,40 @@ impl ClockRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::update(
-            clock_entries::table
-                .filter(clock_entries::organization_id.eq(org_id))
-                .filter(clock_entries::id.eq(entry_id)),
-        )
-        .set((
-            clock_entries::clock_out.eq(Some(Utc::now())),
-            clock_entries::notes.eq(notes),
-            clock_entries::updated_at.eq(Utc::now()),
-        ))
-        .get_result(&mut conn)
-        .await
-        .map_err(|e| match e {
-            diesel::result::Error::NotFound => {
-                AppError::NotFound("Clock entry not found".to_string())
+        let position = context.position;
+        let clock_out = context.recorded_at.unwrap_or_else(Utc::now);
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &[clock_out]).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id)),
+                )
+                .set((
+                    clock_entries::clock_out.eq(Some(clock_out)),
+                    clock_entries::notes.eq(notes),
+                    clock_entries::clock_out_latitude.eq(position.map(|p| p.latitude)),
+                    clock_entries::clock_out_longitude.eq(position.map(|p| p.longitude)),
+                    clock_entries::clock_out_accuracy.eq(position.and_then(|p| p.accuracy)),
+                    clock_entries::outside_geofence.eq(outside_geofence),
+                    clock_entries::clock_drift_seconds.eq(context.clock_drift_seconds),
+                    clock_entries::updated_at.eq(Utc::now()),
+                ))
+                .get_result(conn)
+                .await
+                .map_err(|e| match e {
+                    diesel::result::Error::NotFound => {
+                        AppError::NotFound("Clock entry not found".to_string())
+                    }
+                    _ => AppError::DatabaseError(e),
+                })
             }
-            _ => AppError::DatabaseError(e),
+            .scope_boxed()
         })
+        .await
     }
 
     /// Find open clock entry for user
@@ -109,6 +188,69 @@ impl ClockRepository {
         Ok(entry)
     }
 
+    /// List open clock entries (all organizations) started before the given instant
+    pub async fn list_open_entries_started_before(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<Vec<ClockEntry>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_entries::table
+            .filter(clock_entries::clock_out.is_null())
+            .filter(clock_entries::clock_in.lt(before))
+            .order(clock_entries::clock_in.asc())
+            .load::<ClockEntry>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Close a forgotten open entry on behalf of the system
+    ///
+    /// The entry is reset to pending and flagged for review. Returns `None` when the
+    /// entry was closed in the meantime (e.g. the user clocked out concurrently).
+    /// Fails when the entry or the clock-out time falls in a closed pay period.
+    pub async fn auto_close_entry(
+        &self,
+        org_id: Uuid,
+        entry_id: Uuid,
+        clock_out: DateTime<Utc>,
+    ) -> Result<Option<ClockEntry>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &[clock_out]).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id))
+                        .filter(clock_entries::clock_out.is_null()),
+                )
+                .set((
+                    clock_entries::clock_out.eq(Some(clock_out)),
+                    clock_entries::status.eq(ClockEntryStatus::Pending),
+                    clock_entries::auto_closed.eq(true),
+                    clock_entries::updated_at.eq(Utc::now()),
+                ))
+                .get_result::<ClockEntry>(conn)
+                .await
+                .optional()
+                .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
     /// Find clock entry by ID
     pub async fn find_by_id(&self, org_id: Uuid, entry_id: Uuid) -> Result<ClockEntry, AppError> {
         let mut conn = self
@@ -305,7 +447,49 @@ impl ClockRepository {
         Ok((entries, total))
     }
 
+    /// IDs of pending, closed entries matching a bulk review selection
+    /// Oldest first, at most `limit`
+    pub async fn list_pending_ids(
+        &self,
+        org_id: Uuid,
+        user_ids: Option<&[Uuid]>,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = clock_entries::table
+            .filter(clock_entries::organization_id.eq(org_id))
+            .filter(clock_entries::status.eq(ClockEntryStatus::Pending))
+            .filter(clock_entries::clock_out.is_not_null())
+            .into_boxed();
+
+        if let Some(ids) = user_ids {
+            query = query.filter(clock_entries::user_id.eq_any(ids.to_vec()));
+        }
+        if let Some(start) = start {
+            query = query.filter(clock_entries::clock_in.ge(start));
+        }
+        if let Some(end) = end {
+            query = query.filter(clock_entries::clock_in.lt(end));
+        }
+
+        query
+            .select(clock_entries::id)
+            .order(clock_entries::clock_in.asc())
+            .limit(limit)
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
     /// Approve a clock entry
+    /// Fails when the entry falls in a closed pay period
     pub async fn approve(
         &self,
         org_id: Uuid,
@@ -318,28 +502,77 @@ impl ClockRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::update(
-            clock_entries::table
-                .filter(clock_entries::organization_id.eq(org_id))
-                .filter(clock_entries::id.eq(entry_id)),
-        )
-        .set((
-            clock_entries::status.eq(ClockEntryStatus::Approved),
-            clock_entries::approved_by.eq(Some(approver_id)),
-            clock_entries::approved_at.eq(Some(Utc::now())),
-            clock_entries::updated_at.eq(Utc::now()),
-        ))
-        .get_result(&mut conn)
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &[]).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id)),
+                )
+                .set((
+                    clock_entries::status.eq(ClockEntryStatus::Approved),
+                    clock_entries::approved_by.eq(Some(approver_id)),
+                    clock_entries::approved_at.eq(Some(Utc::now())),
+                    clock_entries::updated_at.eq(Utc::now()),
+                ))
+                .get_result(conn)
+                .await
+                .map_err(|e| match e {
+                    diesel::result::Error::NotFound => {
+                        AppError::NotFound("Clock entry not found".to_string())
+                    }
+                    _ => AppError::DatabaseError(e),
+                })
+            }
+            .scope_boxed()
+        })
         .await
-        .map_err(|e| match e {
-            diesel::result::Error::NotFound => {
-                AppError::NotFound("Clock entry not found".to_string())
+    }
+
+    /// Approve a clock entry through an approval rule (no approver)
+    /// Fails when the entry falls in a closed pay period
+    pub async fn auto_approve(
+        &self,
+        org_id: Uuid,
+        entry_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<ClockEntry, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &[]).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id)),
+                )
+                .set((
+                    clock_entries::status.eq(ClockEntryStatus::Approved),
+                    clock_entries::approved_at.eq(Some(Utc::now())),
+                    clock_entries::auto_approval_rule_id.eq(Some(rule_id)),
+                    clock_entries::updated_at.eq(Utc::now()),
+                ))
+                .get_result(conn)
+                .await
+                .optional()
+                .map_err(AppError::DatabaseError)?
+                .ok_or_else(|| AppError::NotFound("Clock entry not found".to_string()))
             }
-            _ => AppError::DatabaseError(e),
+            .scope_boxed()
         })
+        .await
     }
 
     /// Reject a clock entry
+    /// Fails when the entry falls in a closed pay period
     pub async fn reject(
         &self,
         org_id: Uuid,
@@ -353,26 +586,34 @@ impl ClockRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::update(
-            clock_entries::table
-                .filter(clock_entries::organization_id.eq(org_id))
-                .filter(clock_entries::id.eq(entry_id)),
-        )
-        .set((
-            clock_entries::status.eq(ClockEntryStatus::Rejected),
-            clock_entries::approved_by.eq(Some(approver_id)),
-            clock_entries::approved_at.eq(Some(Utc::now())),
-            clock_entries::notes.eq(reason),
-            clock_entries::updated_at.eq(Utc::now()),
-        ))
-        .get_result(&mut conn)
-        .await
-        .map_err(|e| match e {
-            diesel::result::Error::NotFound => {
-                AppError::NotFound("Clock entry not found".to_string())
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &[]).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id)),
+                )
+                .set((
+                    clock_entries::status.eq(ClockEntryStatus::Rejected),
+                    clock_entries::approved_by.eq(Some(approver_id)),
+                    clock_entries::approved_at.eq(Some(Utc::now())),
+                    clock_entries::notes.eq(reason),
+                    clock_entries::updated_at.eq(Utc::now()),
+                ))
+                .get_result(conn)
+                .await
+                .map_err(|e| match e {
+                    diesel::result::Error::NotFound => {
+                        AppError::NotFound("Clock entry not found".to_string())
+                    }
+                    _ => AppError::DatabaseError(e),
+                })
             }
-            _ => AppError::DatabaseError(e),
+            .scope_boxed()
         })
+        .await
     }
 
     /// Get entries for a period (for KPI calculations)
@@ -425,6 +666,7 @@ impl ClockRepository {
     }
 
     /// Update a clock entry
+    /// Fails when the entry, before or after the update, falls in a closed pay period
     pub async fn update(
         &self,
         org_id: Uuid,
@@ -437,20 +679,72 @@ impl ClockRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        diesel::update(
-            clock_entries::table
-                .filter(clock_entries::organization_id.eq(org_id))
-                .filter(clock_entries::id.eq(entry_id)),
-        )
-        .set(&update)
-        .get_result(&mut conn)
-        .await
-        .map_err(|e| match e {
-            diesel::result::Error::NotFound => {
-                AppError::NotFound("Clock entry not found".to_string())
+        let mut instants: Vec<DateTime<Utc>> = update.clock_in.into_iter().collect();
+        instants.extend(update.clock_out.flatten());
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                ensure_entry_open(conn, org_id, entry_id, &instants).await?;
+
+                diesel::update(
+                    clock_entries::table
+                        .filter(clock_entries::organization_id.eq(org_id))
+                        .filter(clock_entries::id.eq(entry_id)),
+                )
+                .set(&update)
+                .get_result(conn)
+                .await
+                .map_err(|e| match e {
+                    diesel::result::Error::NotFound => {
+                        AppError::NotFound("Clock entry not found".to_string())
+                    }
+                    _ => AppError::DatabaseError(e),
+                })
             }
-            _ => AppError::DatabaseError(e),
+            .scope_boxed()
         })
+        .await
+    }
+
+    /// Check whether a user has an entry overlapping the given period
+    /// Open entries are treated as still running. `exclude_id` skips the entry being edited.
+    pub async fn has_overlapping_entry(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        exclude_id: Option<Uuid>,
+    ) -> Result<bool, AppError> {
+        use diesel::dsl::count;
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = clock_entries::table
+            .filter(clock_entries::organization_id.eq(org_id))
+            .filter(clock_entries::user_id.eq(user_id))
+            .filter(clock_entries::clock_in.lt(end))
+            .filter(
+                clock_entries::clock_out
+                    .is_null()
+                    .or(clock_entries::clock_out.gt(start)),
+            )
+            .into_boxed();
+
+        if let Some(id) = exclude_id {
+            query = query.filter(clock_entries::id.ne(id));
+        }
+
+        let overlapping: i64 = query
+            .select(count(clock_entries::id))
+            .first(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok(overlapping > 0)
     }
 
     /// Get user info for clock entries
@@ -547,6 +841,7 @@ impl ClockRepository {
         org_id: Uuid,
         user_id: Uuid,
         date: NaiveDate,
+        tz: Tz,
     ) -> Result<i64, AppError> {
         use diesel::dsl::count;
 
@@ -556,15 +851,14 @@ impl ClockRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        // Calculate start and end of day in UTC
-        let day_start = start_of_day(date);
-        let day_end = end_of_day(date);
+        // Calculate start and end of the local day in UTC
+        let (day_start, day_end) = local_day_bounds(date, tz);
 
         let count_result: i64 = clock_entries::table
             .filter(clock_entries::organization_id.eq(org_id))
             .filter(clock_entries::user_id.eq(user_id))
             .filter(clock_entries::clock_in.ge(day_start))
-            .filter(clock_entries::clock_in.le(day_end))
+            .filter(clock_entries::clock_in.lt(day_end))
             .select(count(clock_entries::id))
             .first(&mut conn)
             .await
@@ -572,4 +866,107 @@ impl ClockRepository {
 
         Ok(count_result)
     }
+
+    /// Count clock-ins of a user within `[start, end]`
+    pub async fn count_clock_ins_between(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<i64, AppError> {
+        use diesel::dsl::count;
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_entries::table
+            .filter(clock_entries::organization_id.eq(org_id))
+            .filter(clock_entries::user_id.eq(user_id))
+            .filter(clock_entries::clock_in.ge(start))
+            .filter(clock_entries::clock_in.le(end))
+            .select(count(clock_entries::id))
+            .first(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Distinct addresses a user clocked in from before an instant
+    pub async fn list_known_clock_in_ips(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        before: DateTime<Utc>,
+    ) -> Result<Vec<String>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_entries::table
+            .filter(clock_entries::organization_id.eq(org_id))
+            .filter(clock_entries::user_id.eq(user_id))
+            .filter(clock_entries::clock_in.lt(before))
+            .filter(clock_entries::clock_in_ip.is_not_null())
+            .select(clock_entries::clock_in_ip.assume_not_null())
+            .distinct()
+            .load::<String>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List closed, non-rejected entries (all organizations) clocked out or
+    /// modified since the given instant
+    pub async fn list_closed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<ClockEntry>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_entries::table
+            .filter(clock_entries::clock_out.is_not_null())
+            .filter(clock_entries::status.ne(ClockEntryStatus::Rejected))
+            .filter(
+                clock_entries::clock_out
+                    .ge(since)
+                    .or(clock_entries::updated_at.ge(since)),
+            )
+            .order(clock_entries::clock_in.asc())
+            .load::<ClockEntry>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+}
+
+/// Fail with a conflict when an existing entry, or any of the new times it is
+/// being moved to, falls in a closed pay period
+async fn ensure_entry_open(
+    conn: &mut AsyncPgConnection,
+    org_id: Uuid,
+    entry_id: Uuid,
+    new_times: &[DateTime<Utc>],
+) -> Result<(), AppError> {
+    let current: Option<(DateTime<Utc>, Option<DateTime<Utc>>)> = clock_entries::table
+        .filter(clock_entries::organization_id.eq(org_id))
+        .filter(clock_entries::id.eq(entry_id))
+        .select((clock_entries::clock_in, clock_entries::clock_out))
+        .first(conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?;
+
+    let mut instants = new_times.to_vec();
+    if let Some((clock_in, clock_out)) = current {
+        instants.push(clock_in);
+        instants.extend(clock_out);
+    }
+    ensure_instants_open(conn, org_id, &instants).await
 }
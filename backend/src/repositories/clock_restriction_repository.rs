@@ -4,7 +4,7 @@ use diesel_async::RunQueryDsl;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
-use crate::domain::enums::{ClockOverrideStatus, ClockRestrictionMode};
+use crate::domain::enums::{ClockOverrideStatus, ClockRestrictionMode, GeofenceMode};
 use crate::error::AppError;
 use crate::models::{
     ClockOverrideFilter, ClockOverrideRequest, ClockOverrideRequestUpdate, ClockRestriction,
@@ -645,6 +645,10 @@ impl ClockRestrictionRepository {
             require_manager_approval: false,
             is_active: true,
             max_daily_clock_events: None,
+            geofence_mode: GeofenceMode::Off,
+            allowed_networks: Vec::new(),
+            schedule_early_minutes: 15,
+            schedule_late_minutes: 15,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -0,0 +1,154 @@
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::ClockSyncStatus;
+use crate::error::AppError;
+use crate::models::{ClockSyncEvent, NewClockSyncEvent};
+use crate::schema::clock_sync_events;
+
+/// Time after which a pending reservation is considered abandoned
+const RESERVATION_TIMEOUT_SECS: i64 = 300;
+
+/// Repository for processed offline clock events
+pub struct ClockSyncRepository {
+    pool: DbPool,
+}
+
+impl ClockSyncRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Find an already processed event by its idempotency key
+    pub async fn find_by_key(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        idempotency_key: &str,
+    ) -> Result<Option<ClockSyncEvent>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        clock_sync_events::table
+            .filter(clock_sync_events::organization_id.eq(org_id))
+            .filter(clock_sync_events::user_id.eq(user_id))
+            .filter(clock_sync_events::idempotency_key.eq(idempotency_key))
+            .first::<ClockSyncEvent>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Reserve an event before replaying it
+    ///
+    /// Returns `None` when the idempotency key is already taken, by a processed event
+    /// or by a concurrent upload replaying it. A pending reservation left for longer
+    /// than the timeout was abandoned and is taken over.
+    pub async fn reserve(
+        &self,
+        new_event: NewClockSyncEvent,
+    ) -> Result<Option<ClockSyncEvent>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let reserved = diesel::insert_into(clock_sync_events::table)
+            .values(&new_event)
+            .on_conflict((
+                clock_sync_events::user_id,
+                clock_sync_events::idempotency_key,
+            ))
+            .do_nothing()
+            .get_result(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?;
+        if reserved.is_some() {
+            return Ok(reserved);
+        }
+
+        // Concurrent takeovers are serialized on the row: the later one no longer matches
+        let cutoff = Utc::now() - Duration::seconds(RESERVATION_TIMEOUT_SECS);
+        diesel::update(
+            clock_sync_events::table
+                .filter(clock_sync_events::user_id.eq(new_event.user_id))
+                .filter(clock_sync_events::idempotency_key.eq(&new_event.idempotency_key))
+                .filter(clock_sync_events::status.eq(ClockSyncStatus::Pending))
+                .filter(clock_sync_events::reserved_at.lt(cutoff)),
+        )
+        .set((
+            clock_sync_events::device_id.eq(&new_event.device_id),
+            clock_sync_events::action.eq(&new_event.action),
+            clock_sync_events::client_timestamp.eq(new_event.client_timestamp),
+            clock_sync_events::clock_drift_seconds.eq(new_event.clock_drift_seconds),
+            clock_sync_events::reserved_at.eq(Utc::now()),
+        ))
+        .get_result(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)
+    }
+
+    /// Store the outcome of a reserved event
+    /// Fails when the reservation was taken over in the meantime
+    pub async fn complete(
+        &self,
+        reservation: &ClockSyncEvent,
+        status: ClockSyncStatus,
+        clock_entry_id: Option<Uuid>,
+        message: Option<String>,
+    ) -> Result<ClockSyncEvent, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            clock_sync_events::table
+                .filter(clock_sync_events::id.eq(reservation.id))
+                .filter(clock_sync_events::reserved_at.eq(reservation.reserved_at)),
+        )
+        .set((
+            clock_sync_events::status.eq(status),
+            clock_sync_events::clock_entry_id.eq(clock_entry_id),
+            clock_sync_events::message.eq(message),
+        ))
+        .get_result(&mut conn)
+        .await
+        .map_err(|e| match e {
+            diesel::result::Error::NotFound => AppError::Conflict(
+                "The event was synchronized by another upload in the meantime".to_string(),
+            ),
+            _ => AppError::DatabaseError(e),
+        })
+    }
+
+    /// Drop a reservation whose replay failed, so a retry can replay the event
+    pub async fn release(&self, event_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::delete(
+            clock_sync_events::table
+                .filter(clock_sync_events::id.eq(event_id))
+                .filter(clock_sync_events::status.eq(ClockSyncStatus::Pending)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        Ok(())
+    }
+}
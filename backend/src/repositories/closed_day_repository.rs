@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::config::database::DbPool;
 use crate::error::AppError;
-use crate::models::{ClosedDay, ClosedDayFilter, ClosedDayUpdate, NewClosedDay};
+use crate::models::{closed_dates, ClosedDay, ClosedDayFilter, ClosedDayUpdate, NewClosedDay};
 use crate::schema::closed_days;
 use crate::utils::{end_of_year, start_of_year};
 
@@ -137,48 +137,19 @@ impl ClosedDayRepository {
             .await
             .map_err(|e| AppError::PoolError(e.to_string()))?;
 
-        // Get non-recurring closed days in range
-        let non_recurring: Vec<NaiveDate> = closed_days::table
+        // Non-recurring closed days in range, and recurring ones of any year
+        let rows: Vec<ClosedDay> = closed_days::table
             .filter(closed_days::organization_id.eq(org_id))
-            .filter(closed_days::is_recurring.eq(false))
-            .filter(closed_days::date.ge(start_date))
-            .filter(closed_days::date.le(end_date))
-            .select(closed_days::date)
-            .load(&mut conn)
-            .await
-            .map_err(AppError::DatabaseError)?;
-
-        // Get recurring closed days
-        let recurring: Vec<ClosedDay> = closed_days::table
-            .filter(closed_days::organization_id.eq(org_id))
-            .filter(closed_days::is_recurring.eq(true))
+            .filter(
+                closed_days::is_recurring.eq(true).or(closed_days::date
+                    .ge(start_date)
+                    .and(closed_days::date.le(end_date))),
+            )
             .load::<ClosedDay>(&mut conn)
             .await
             .map_err(AppError::DatabaseError)?;
 
-        // Calculate recurring dates for each year in range
-        let mut all_dates: Vec<NaiveDate> = non_recurring;
-
-        for closed_day in recurring {
-            let mut current_year = start_date.year();
-            while current_year <= end_date.year() {
-                if let Some(date) = NaiveDate::from_ymd_opt(
-                    current_year,
-                    closed_day.date.month(),
-                    closed_day.date.day(),
-                ) {
-                    if date >= start_date && date <= end_date {
-                        all_dates.push(date);
-                    }
-                }
-                current_year += 1;
-            }
-        }
-
-        all_dates.sort();
-        all_dates.dedup();
-
-        Ok(all_dates)
+        Ok(closed_dates(&rows, start_date, end_date))
     }
 
     /// Check if a date is a closed day
@@ -0,0 +1,254 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    KioskDevice, KioskDeviceUpdate, KioskEmployee, KioskPin, NewKioskDevice, NewKioskPin,
+};
+use crate::schema::{kiosk_devices, kiosk_pins, users};
+
+/// Kiosk repository for devices and employee PINs
+pub struct KioskRepository {
+    pool: DbPool,
+}
+
+impl KioskRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Register a new kiosk device
+    pub async fn create_device(&self, new_device: NewKioskDevice) -> Result<KioskDevice, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(kiosk_devices::table)
+            .values(&new_device)
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find kiosk device by ID within organization
+    pub async fn find_device(
+        &self,
+        org_id: Uuid,
+        device_id: Uuid,
+    ) -> Result<KioskDevice, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        kiosk_devices::table
+            .filter(kiosk_devices::organization_id.eq(org_id))
+            .find(device_id)
+            .first::<KioskDevice>(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound("Kiosk device not found".to_string()))
+    }
+
+    /// Find an active kiosk device by its credential hash
+    pub async fn find_active_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<KioskDevice>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        kiosk_devices::table
+            .filter(kiosk_devices::token_hash.eq(token_hash))
+            .filter(kiosk_devices::is_active.eq(true))
+            .first::<KioskDevice>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List kiosk devices of an organization
+    pub async fn list_devices(&self, org_id: Uuid) -> Result<Vec<KioskDevice>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        kiosk_devices::table
+            .filter(kiosk_devices::organization_id.eq(org_id))
+            .order(kiosk_devices::name.asc())
+            .load::<KioskDevice>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a kiosk device
+    pub async fn update_device(
+        &self,
+        org_id: Uuid,
+        device_id: Uuid,
+        update: KioskDeviceUpdate,
+    ) -> Result<KioskDevice, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            kiosk_devices::table
+                .filter(kiosk_devices::organization_id.eq(org_id))
+                .filter(kiosk_devices::id.eq(device_id)),
+        )
+        .set((&update, kiosk_devices::updated_at.eq(Utc::now())))
+        .get_result::<KioskDevice>(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?
+        .ok_or_else(|| AppError::NotFound("Kiosk device not found".to_string()))
+    }
+
+    /// Record that a device has just been used
+    pub async fn touch_device(&self, device_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(kiosk_devices::table.find(device_id))
+            .set(kiosk_devices::last_used_at.eq(Utc::now()))
+            .execute(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    /// Delete a kiosk device
+    pub async fn delete_device(&self, org_id: Uuid, device_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            kiosk_devices::table
+                .filter(kiosk_devices::organization_id.eq(org_id))
+                .filter(kiosk_devices::id.eq(device_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Kiosk device not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Set (or replace) an employee's kiosk PIN
+    pub async fn upsert_pin(&self, new_pin: NewKioskPin) -> Result<KioskPin, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(kiosk_pins::table)
+            .values(&new_pin)
+            .on_conflict(kiosk_pins::user_id)
+            .do_update()
+            .set((
+                kiosk_pins::pin_hash.eq(&new_pin.pin_hash),
+                kiosk_pins::updated_at.eq(Utc::now()),
+            ))
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find an employee's kiosk PIN within organization
+    pub async fn find_pin(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<KioskPin>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        kiosk_pins::table
+            .filter(kiosk_pins::organization_id.eq(org_id))
+            .filter(kiosk_pins::user_id.eq(user_id))
+            .first::<KioskPin>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Remove an employee's kiosk PIN
+    pub async fn delete_pin(&self, org_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            kiosk_pins::table
+                .filter(kiosk_pins::organization_id.eq(org_id))
+                .filter(kiosk_pins::user_id.eq(user_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Kiosk PIN not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// List employees who can use the kiosk (active users with a PIN)
+    pub async fn list_employees(&self, org_id: Uuid) -> Result<Vec<KioskEmployee>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let rows: Vec<(Uuid, String, String)> = kiosk_pins::table
+            .inner_join(users::table)
+            .filter(kiosk_pins::organization_id.eq(org_id))
+            .filter(users::deleted_at.is_null())
+            .select((users::id, users::first_name, users::last_name))
+            .order((users::last_name.asc(), users::first_name.asc()))
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, first_name, last_name)| KioskEmployee {
+                id,
+                first_name,
+                last_name,
+            })
+            .collect())
+    }
+}
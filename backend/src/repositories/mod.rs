@@ -5,39 +5,61 @@ pub mod absence_repository;
 pub mod absence_type_repository;
 pub mod audit_repository;
 pub mod break_repository;
+pub mod clock_anomaly_repository;
+pub mod clock_approval_rule_repository;
+pub mod clock_correction_repository;
 pub mod clock_repository;
 pub mod clock_restriction_repository;
+pub mod clock_sync_repository;
 pub mod closed_day_repository;
 pub mod invite_token_repository;
+pub mod kiosk_repository;
 pub mod leave_balance_repository;
 pub mod login_attempt_repository;
 pub mod notification_repository;
 pub mod organization_repository;
 pub mod password_history_repository;
 pub mod password_reset_repository;
+pub mod pay_period_repository;
+pub mod project_repository;
 pub mod refresh_token_repository;
+pub mod roster_repository;
+pub mod shift_swap_repository;
 pub mod team_repository;
+pub mod time_allocation_repository;
 pub mod user_repository;
 pub mod user_session_repository;
 pub mod work_schedule_repository;
+pub mod work_site_repository;
 
 // Re-export repository types for convenience
 pub use absence_repository::AbsenceRepository;
 pub use absence_type_repository::AbsenceTypeRepository;
 pub use audit_repository::AuditRepository;
 pub use break_repository::BreakRepository;
+pub use clock_anomaly_repository::ClockAnomalyRepository;
+pub use clock_approval_rule_repository::ClockApprovalRuleRepository;
+pub use clock_correction_repository::ClockCorrectionRepository;
 pub use clock_repository::ClockRepository;
 pub use clock_restriction_repository::ClockRestrictionRepository;
+pub use clock_sync_repository::ClockSyncRepository;
 pub use closed_day_repository::ClosedDayRepository;
 pub use invite_token_repository::InviteTokenRepository;
+pub use kiosk_repository::KioskRepository;
 pub use leave_balance_repository::LeaveBalanceRepository;
 pub use login_attempt_repository::LoginAttemptRepository;
 pub use notification_repository::NotificationRepository;
 pub use organization_repository::OrganizationRepository;
 pub use password_history_repository::PasswordHistoryRepository;
 pub use password_reset_repository::PasswordResetRepository;
+pub use pay_period_repository::PayPeriodRepository;
+pub use project_repository::ProjectRepository;
 pub use refresh_token_repository::RefreshTokenRepository;
+pub use roster_repository::RosterRepository;
+pub use shift_swap_repository::ShiftSwapRepository;
 pub use team_repository::TeamRepository;
+pub use time_allocation_repository::TimeAllocationRepository;
 pub use user_repository::{User, UserRepository};
 pub use user_session_repository::UserSessionRepository;
 pub use work_schedule_repository::WorkScheduleRepository;
+pub use work_site_repository::WorkSiteRepository;
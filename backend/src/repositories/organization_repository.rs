@@ -1,3 +1,4 @@
+use chrono_tz::Tz;
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use uuid::Uuid;
@@ -9,6 +10,7 @@ use crate::models::{
     OrganizationResponse, OrganizationUpdate, PaginatedOrganizations,
 };
 use crate::schema::{organizations, users};
+use crate::utils::timezone::parse_timezone;
 
 pub struct OrganizationRepository {
     pool: DbPool,
@@ -34,6 +36,24 @@ impl OrganizationRepository {
             .map_err(|_| AppError::NotFound(format!("Organization {} not found", id)))
     }
 
+    /// Get the timezone of an organization
+    pub async fn get_timezone(&self, id: Uuid) -> Result<Tz, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let timezone: String = organizations::table
+            .find(id)
+            .select(organizations::timezone)
+            .first(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound(format!("Organization {} not found", id)))?;
+
+        Ok(parse_timezone(&timezone))
+    }
+
     /// Find organization by slug
     pub async fn find_by_slug(&self, slug: &str) -> Result<Option<Organization>, AppError> {
         let mut conn = self
@@ -0,0 +1,314 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    NewPayPeriod, NewPayPeriodSettings, PayPeriod, PayPeriodFilter, PayPeriodSettings,
+};
+use crate::schema::{clock_entries, organizations, pay_period_settings, pay_periods};
+use crate::utils::timezone::{local_day_bounds, parse_timezone};
+
+/// Pay period repository for database operations
+pub struct PayPeriodRepository {
+    pool: DbPool,
+}
+
+/// Fail with a conflict when the local dates `[start, end]` touch a closed pay period
+///
+/// Shared by the repositories guarding time data so the check can run on their connection.
+/// Inside a transaction, the organization row stays share-locked until the write commits,
+/// so a concurrent close waits for it instead of missing it.
+pub async fn ensure_dates_open(
+    conn: &mut AsyncPgConnection,
+    org_id: Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<(), AppError> {
+    organizations::table
+        .find(org_id)
+        .select(organizations::id)
+        .for_share()
+        .first::<Uuid>(conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?;
+
+    let closed = pay_periods::table
+        .filter(pay_periods::organization_id.eq(org_id))
+        .filter(pay_periods::is_closed.eq(true))
+        .filter(pay_periods::start_date.le(end))
+        .filter(pay_periods::end_date.ge(start))
+        .order(pay_periods::start_date.asc())
+        .first::<PayPeriod>(conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?;
+
+    match closed {
+        Some(period) => Err(closed_period_error(&period)),
+        None => Ok(()),
+    }
+}
+
+/// Fail with a conflict when any of the instants falls in a closed pay period
+///
+/// Instants are resolved to dates in the organization's timezone.
+pub async fn ensure_instants_open(
+    conn: &mut AsyncPgConnection,
+    org_id: Uuid,
+    instants: &[DateTime<Utc>],
+) -> Result<(), AppError> {
+    let (Some(first), Some(last)) = (instants.iter().min(), instants.iter().max()) else {
+        return Ok(());
+    };
+
+    let timezone: String = organizations::table
+        .find(org_id)
+        .select(organizations::timezone)
+        .first(conn)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Organization {} not found", org_id)))?;
+    let tz = parse_timezone(&timezone);
+
+    ensure_dates_open(
+        conn,
+        org_id,
+        first.with_timezone(&tz).date_naive(),
+        last.with_timezone(&tz).date_naive(),
+    )
+    .await
+}
+
+/// Conflict returned for mutations inside a closed pay period
+fn closed_period_error(period: &PayPeriod) -> AppError {
+    AppError::Conflict(format!(
+        "The pay period from {} to {} is closed; its time data can no longer be modified",
+        period.start_date, period.end_date
+    ))
+}
+
+impl PayPeriodRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Get pay period settings of an organization, if any
+    pub async fn find_settings(&self, org_id: Uuid) -> Result<Option<PayPeriodSettings>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        pay_period_settings::table
+            .find(org_id)
+            .first::<PayPeriodSettings>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Create or replace pay period settings of an organization
+    pub async fn upsert_settings(
+        &self,
+        settings: NewPayPeriodSettings,
+    ) -> Result<PayPeriodSettings, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(pay_period_settings::table)
+            .values(&settings)
+            .on_conflict(pay_period_settings::organization_id)
+            .do_update()
+            .set((&settings, pay_period_settings::updated_at.eq(Utc::now())))
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find pay period by ID within organization
+    pub async fn find_by_id(&self, org_id: Uuid, period_id: Uuid) -> Result<PayPeriod, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        pay_periods::table
+            .filter(pay_periods::organization_id.eq(org_id))
+            .find(period_id)
+            .first::<PayPeriod>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Pay period not found".to_string()))
+    }
+
+    /// Find the recorded period starting on a date
+    pub async fn find_by_start_date(
+        &self,
+        org_id: Uuid,
+        start_date: NaiveDate,
+    ) -> Result<Option<PayPeriod>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        pay_periods::table
+            .filter(pay_periods::organization_id.eq(org_id))
+            .filter(pay_periods::start_date.eq(start_date))
+            .first::<PayPeriod>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List recorded pay periods, most recent first
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &PayPeriodFilter,
+    ) -> Result<Vec<PayPeriod>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = pay_periods::table
+            .filter(pay_periods::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(is_closed) = filter.is_closed {
+            query = query.filter(pay_periods::is_closed.eq(is_closed));
+        }
+        if let Some(start) = filter.start_date {
+            query = query.filter(pay_periods::end_date.ge(start));
+        }
+        if let Some(end) = filter.end_date {
+            query = query.filter(pay_periods::start_date.le(end));
+        }
+
+        query
+            .order(pay_periods::start_date.desc())
+            .load::<PayPeriod>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Check that local dates `[start, end]` do not touch a closed pay period
+    pub async fn ensure_open(
+        &self,
+        org_id: Uuid,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        ensure_dates_open(&mut conn, org_id, start, end).await
+    }
+
+    /// Close a period, reusing the row of a previously reopened period starting on the same day
+    ///
+    /// Waits for time data writes checked against the periods (see [`ensure_dates_open`]).
+    /// Fails with a conflict while clock entries started before the period end are still open.
+    pub async fn close(&self, new_period: NewPayPeriod) -> Result<PayPeriod, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let now = Utc::now();
+        conn.transaction::<_, AppError, _>(|conn| {
+            async move {
+                let timezone: String = organizations::table
+                    .find(new_period.organization_id)
+                    .select(organizations::timezone)
+                    .for_no_key_update()
+                    .first(conn)
+                    .await?;
+
+                let (_, period_end) =
+                    local_day_bounds(new_period.end_date, parse_timezone(&timezone));
+                let open_entries: i64 = clock_entries::table
+                    .filter(clock_entries::organization_id.eq(new_period.organization_id))
+                    .filter(clock_entries::clock_out.is_null())
+                    .filter(clock_entries::clock_in.lt(period_end))
+                    .count()
+                    .get_result(conn)
+                    .await?;
+                if open_entries > 0 {
+                    return Err(AppError::Conflict(format!(
+                        "{} clock entries of this pay period are still open; close them before closing the period",
+                        open_entries
+                    )));
+                }
+
+                diesel::insert_into(pay_periods::table)
+                    .values(&new_period)
+                    .on_conflict((pay_periods::organization_id, pay_periods::start_date))
+                    .do_update()
+                    .set((
+                        pay_periods::end_date.eq(new_period.end_date),
+                        pay_periods::is_closed.eq(true),
+                        pay_periods::closed_by.eq(new_period.closed_by),
+                        pay_periods::closed_at.eq(now),
+                        pay_periods::reopened_by.eq(None::<Uuid>),
+                        pay_periods::reopened_at.eq(None::<DateTime<Utc>>),
+                        pay_periods::updated_at.eq(now),
+                    ))
+                    .get_result(conn)
+                    .await
+                    .map_err(AppError::DatabaseError)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Reopen a closed period
+    pub async fn reopen(
+        &self,
+        org_id: Uuid,
+        period_id: Uuid,
+        reopened_by: Uuid,
+    ) -> Result<PayPeriod, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let now = Utc::now();
+        diesel::update(
+            pay_periods::table
+                .filter(pay_periods::organization_id.eq(org_id))
+                .filter(pay_periods::id.eq(period_id)),
+        )
+        .set((
+            pay_periods::is_closed.eq(false),
+            pay_periods::reopened_by.eq(Some(reopened_by)),
+            pay_periods::reopened_at.eq(Some(now)),
+            pay_periods::updated_at.eq(now),
+        ))
+        .get_result::<PayPeriod>(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?
+        .ok_or_else(|| AppError::NotFound("Pay period not found".to_string()))
+    }
+}
@@ -0,0 +1,263 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    NewProject, NewProjectTask, Project, ProjectFilter, ProjectTask, ProjectTaskUpdate,
+    ProjectUpdate,
+};
+use crate::schema::{project_tasks, projects};
+
+/// Project repository for projects and their tasks
+pub struct ProjectRepository {
+    pool: DbPool,
+}
+
+/// Map write errors on projects and tasks
+fn map_write_error(e: diesel::result::Error, duplicate: &str, in_use: &str) -> AppError {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => AppError::Conflict(duplicate.to_string()),
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::ForeignKeyViolation,
+            _,
+        ) => AppError::Conflict(in_use.to_string()),
+        _ => AppError::DatabaseError(e),
+    }
+}
+
+const DUPLICATE_PROJECT: &str = "A project with this name already exists";
+const PROJECT_IN_USE: &str = "Time is allocated to this project, deactivate it instead";
+const DUPLICATE_TASK: &str = "A task with this name already exists in the project";
+const TASK_IN_USE: &str = "Time is allocated to this task, deactivate it instead";
+
+impl ProjectRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new project
+    pub async fn create(&self, new_project: NewProject) -> Result<Project, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(projects::table)
+            .values(&new_project)
+            .get_result(&mut conn)
+            .await
+            .map_err(|e| map_write_error(e, DUPLICATE_PROJECT, PROJECT_IN_USE))
+    }
+
+    /// Find project by ID within organization
+    pub async fn find_by_id(&self, org_id: Uuid, project_id: Uuid) -> Result<Project, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        projects::table
+            .filter(projects::organization_id.eq(org_id))
+            .find(project_id)
+            .first::<Project>(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound("Project not found".to_string()))
+    }
+
+    /// List projects with filters
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &ProjectFilter,
+    ) -> Result<Vec<Project>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = projects::table
+            .filter(projects::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(is_active) = filter.is_active {
+            query = query.filter(projects::is_active.eq(is_active));
+        }
+
+        query
+            .order(projects::name.asc())
+            .load::<Project>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a project
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        update: ProjectUpdate,
+    ) -> Result<Project, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            projects::table
+                .filter(projects::organization_id.eq(org_id))
+                .filter(projects::id.eq(project_id)),
+        )
+        .set((&update, projects::updated_at.eq(Utc::now())))
+        .get_result::<Project>(&mut conn)
+        .await
+        .optional()
+        .map_err(|e| map_write_error(e, DUPLICATE_PROJECT, PROJECT_IN_USE))?
+        .ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    }
+
+    /// Delete a project and its tasks
+    /// Fails with a conflict when time has been allocated to it
+    pub async fn delete(&self, org_id: Uuid, project_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            projects::table
+                .filter(projects::organization_id.eq(org_id))
+                .filter(projects::id.eq(project_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(|e| map_write_error(e, DUPLICATE_PROJECT, PROJECT_IN_USE))?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Project not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Create a task in a project
+    pub async fn create_task(&self, new_task: NewProjectTask) -> Result<ProjectTask, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(project_tasks::table)
+            .values(&new_task)
+            .get_result(&mut conn)
+            .await
+            .map_err(|e| map_write_error(e, DUPLICATE_TASK, TASK_IN_USE))
+    }
+
+    /// Find task by ID within organization
+    pub async fn find_task(&self, org_id: Uuid, task_id: Uuid) -> Result<ProjectTask, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        project_tasks::table
+            .filter(project_tasks::organization_id.eq(org_id))
+            .find(task_id)
+            .first::<ProjectTask>(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound("Task not found".to_string()))
+    }
+
+    /// List tasks of the given projects
+    pub async fn list_tasks(
+        &self,
+        org_id: Uuid,
+        project_ids: &[Uuid],
+    ) -> Result<Vec<ProjectTask>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        project_tasks::table
+            .filter(project_tasks::organization_id.eq(org_id))
+            .filter(project_tasks::project_id.eq_any(project_ids))
+            .order(project_tasks::name.asc())
+            .load::<ProjectTask>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a task of a project
+    pub async fn update_task(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        task_id: Uuid,
+        update: ProjectTaskUpdate,
+    ) -> Result<ProjectTask, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            project_tasks::table
+                .filter(project_tasks::organization_id.eq(org_id))
+                .filter(project_tasks::project_id.eq(project_id))
+                .filter(project_tasks::id.eq(task_id)),
+        )
+        .set((&update, project_tasks::updated_at.eq(Utc::now())))
+        .get_result::<ProjectTask>(&mut conn)
+        .await
+        .optional()
+        .map_err(|e| map_write_error(e, DUPLICATE_TASK, TASK_IN_USE))?
+        .ok_or_else(|| AppError::NotFound("Task not found".to_string()))
+    }
+
+    /// Delete a task of a project
+    /// Fails with a conflict when time has been allocated to it
+    pub async fn delete_task(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            project_tasks::table
+                .filter(project_tasks::organization_id.eq(org_id))
+                .filter(project_tasks::project_id.eq(project_id))
+                .filter(project_tasks::id.eq(task_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(|e| map_write_error(e, DUPLICATE_TASK, TASK_IN_USE))?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Task not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
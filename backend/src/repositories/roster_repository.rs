@@ -0,0 +1,296 @@
+use chrono::{NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::RosterShiftStatus;
+use crate::error::AppError;
+use crate::models::{
+    NewRosterSettings, NewRosterShift, RosterSettings, RosterShift, RosterShiftFilter,
+    RosterShiftUpdate,
+};
+use crate::schema::{roster_settings, roster_shifts, users};
+
+/// Roster shift repository for database operations
+pub struct RosterRepository {
+    pool: DbPool,
+}
+
+impl RosterRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Find the roster settings of an organization
+    pub async fn find_settings(&self, org_id: Uuid) -> Result<Option<RosterSettings>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        roster_settings::table
+            .find(org_id)
+            .first::<RosterSettings>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Create or replace the roster settings of an organization
+    pub async fn upsert_settings(
+        &self,
+        settings: NewRosterSettings,
+    ) -> Result<RosterSettings, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(roster_settings::table)
+            .values(&settings)
+            .on_conflict(roster_settings::organization_id)
+            .do_update()
+            .set((&settings, roster_settings::updated_at.eq(Utc::now())))
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Create a new draft shift
+    pub async fn create(&self, new_shift: NewRosterShift) -> Result<RosterShift, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(roster_shifts::table)
+            .values(&new_shift)
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find shift by ID within organization
+    pub async fn find_by_id(&self, org_id: Uuid, shift_id: Uuid) -> Result<RosterShift, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        roster_shifts::table
+            .filter(roster_shifts::organization_id.eq(org_id))
+            .find(shift_id)
+            .first::<RosterShift>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Roster shift not found".to_string()))
+    }
+
+    /// List shifts with filters, along with the user's name
+    /// `user_ids` narrows the result to a set of users (None for no restriction);
+    /// the filter's team is applied by the caller through it
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &RosterShiftFilter,
+        user_ids: Option<&[Uuid]>,
+    ) -> Result<Vec<(RosterShift, String)>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = roster_shifts::table
+            .inner_join(users::table.on(users::id.eq(roster_shifts::user_id)))
+            .filter(roster_shifts::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(start_date) = filter.start_date {
+            query = query.filter(roster_shifts::shift_date.ge(start_date));
+        }
+        if let Some(end_date) = filter.end_date {
+            query = query.filter(roster_shifts::shift_date.le(end_date));
+        }
+        if let Some(user_id) = filter.user_id {
+            query = query.filter(roster_shifts::user_id.eq(user_id));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(roster_shifts::status.eq(status));
+        }
+        if let Some(user_ids) = user_ids {
+            query = query.filter(roster_shifts::user_id.eq_any(user_ids.to_vec()));
+        }
+
+        let rows: Vec<(RosterShift, String, String)> = query
+            .select((
+                RosterShift::as_select(),
+                users::first_name,
+                users::last_name,
+            ))
+            .order((
+                roster_shifts::shift_date.asc(),
+                roster_shifts::start_time.asc(),
+            ))
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(shift, first_name, last_name)| (shift, format!("{} {}", first_name, last_name)))
+            .collect())
+    }
+
+    /// Shifts of a user between two dates (inclusive), drafts included
+    pub async fn list_for_user(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<RosterShift>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        roster_shifts::table
+            .filter(roster_shifts::organization_id.eq(org_id))
+            .filter(roster_shifts::user_id.eq(user_id))
+            .filter(roster_shifts::shift_date.between(start_date, end_date))
+            .order((
+                roster_shifts::shift_date.asc(),
+                roster_shifts::start_time.asc(),
+            ))
+            .load::<RosterShift>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Published shifts of a user between two dates (inclusive)
+    pub async fn list_published_for_user(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<RosterShift>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        roster_shifts::table
+            .filter(roster_shifts::organization_id.eq(org_id))
+            .filter(roster_shifts::user_id.eq(user_id))
+            .filter(roster_shifts::status.eq(RosterShiftStatus::Published))
+            .filter(roster_shifts::shift_date.between(start_date, end_date))
+            .order((
+                roster_shifts::shift_date.asc(),
+                roster_shifts::start_time.asc(),
+            ))
+            .load::<RosterShift>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a shift
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        shift_id: Uuid,
+        update: RosterShiftUpdate,
+    ) -> Result<RosterShift, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            roster_shifts::table
+                .filter(roster_shifts::organization_id.eq(org_id))
+                .filter(roster_shifts::id.eq(shift_id)),
+        )
+        .set((&update, roster_shifts::updated_at.eq(Utc::now())))
+        .get_result::<RosterShift>(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?
+        .ok_or_else(|| AppError::NotFound("Roster shift not found".to_string()))
+    }
+
+    /// Delete a shift
+    pub async fn delete(&self, org_id: Uuid, shift_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            roster_shifts::table
+                .filter(roster_shifts::organization_id.eq(org_id))
+                .filter(roster_shifts::id.eq(shift_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Roster shift not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Publish the draft shifts between two dates (inclusive)
+    /// `user_ids` narrows publication to a set of users (None for the whole organization)
+    pub async fn publish(
+        &self,
+        org_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        user_ids: Option<&[Uuid]>,
+    ) -> Result<Vec<RosterShift>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let now = Utc::now();
+        let mut target = roster_shifts::table
+            .filter(roster_shifts::organization_id.eq(org_id))
+            .filter(roster_shifts::status.eq(RosterShiftStatus::Draft))
+            .filter(roster_shifts::shift_date.between(start_date, end_date))
+            .into_boxed();
+        if let Some(user_ids) = user_ids {
+            target = target.filter(roster_shifts::user_id.eq_any(user_ids.to_vec()));
+        }
+        let ids: Vec<Uuid> = target
+            .select(roster_shifts::id)
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        diesel::update(roster_shifts::table.filter(roster_shifts::id.eq_any(ids)))
+            .set((
+                roster_shifts::status.eq(RosterShiftStatus::Published),
+                roster_shifts::published_at.eq(Some(now)),
+                roster_shifts::updated_at.eq(now),
+            ))
+            .get_results::<RosterShift>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::{NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::ShiftSwapStatus;
+use crate::error::AppError;
+use crate::models::{
+    NewShiftSwapRequest, ShiftSwapFilter, ShiftSwapRequest, ShiftSwapRequestUpdate,
+};
+use crate::schema::{roster_shifts, shift_swap_requests};
+
+/// Shift swap repository for database operations
+pub struct ShiftSwapRepository {
+    pool: DbPool,
+}
+
+/// Map the one-active-request-per-shift violation to a conflict
+fn map_write_error(e: diesel::result::Error) -> AppError {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => AppError::Conflict("This shift is already on offer".to_string()),
+        _ => AppError::DatabaseError(e),
+    }
+}
+
+impl ShiftSwapRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new swap request
+    pub async fn create(
+        &self,
+        new_request: NewShiftSwapRequest,
+    ) -> Result<ShiftSwapRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(shift_swap_requests::table)
+            .values(&new_request)
+            .get_result(&mut conn)
+            .await
+            .map_err(map_write_error)
+    }
+
+    /// Find swap request by ID within organization
+    pub async fn find_by_id(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+    ) -> Result<ShiftSwapRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        shift_swap_requests::table
+            .filter(shift_swap_requests::organization_id.eq(org_id))
+            .find(request_id)
+            .first::<ShiftSwapRequest>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Shift swap request not found".to_string()))
+    }
+
+    /// List swap requests, newest first
+    /// `participant` keeps requests the user offered, was offered or claimed;
+    /// `requester_ids` keeps requests offered by a set of users
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &ShiftSwapFilter,
+        participant: Option<Uuid>,
+        requester_ids: Option<&[Uuid]>,
+    ) -> Result<Vec<ShiftSwapRequest>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = shift_swap_requests::table
+            .filter(shift_swap_requests::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(status) = filter.status {
+            query = query.filter(shift_swap_requests::status.eq(status));
+        }
+        if let Some(user_id) = participant {
+            query = query.filter(
+                shift_swap_requests::requester_id
+                    .eq(user_id)
+                    .or(shift_swap_requests::target_user_id.eq(user_id))
+                    .or(shift_swap_requests::claimed_by.eq(user_id)),
+            );
+        }
+        if let Some(requester_ids) = requester_ids {
+            query = query.filter(shift_swap_requests::requester_id.eq_any(requester_ids.to_vec()));
+        }
+
+        query
+            .order(shift_swap_requests::created_at.desc())
+            .load::<ShiftSwapRequest>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Open requests a user may claim: released to the pool or offered to them,
+    /// for shifts on or after a date, soonest shift first
+    pub async fn list_claimable(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        from_date: NaiveDate,
+    ) -> Result<Vec<ShiftSwapRequest>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        shift_swap_requests::table
+            .inner_join(roster_shifts::table)
+            .filter(shift_swap_requests::organization_id.eq(org_id))
+            .filter(shift_swap_requests::status.eq(ShiftSwapStatus::Open))
+            .filter(shift_swap_requests::requester_id.ne(user_id))
+            .filter(
+                shift_swap_requests::target_user_id
+                    .is_null()
+                    .or(shift_swap_requests::target_user_id.eq(user_id)),
+            )
+            .filter(roster_shifts::shift_date.ge(from_date))
+            .order((
+                roster_shifts::shift_date.asc(),
+                roster_shifts::start_time.asc(),
+            ))
+            .select(ShiftSwapRequest::as_select())
+            .load::<ShiftSwapRequest>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a request still in the expected status
+    /// Fails with a conflict when another change got there first
+    pub async fn transition(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        from_status: ShiftSwapStatus,
+        update: ShiftSwapRequestUpdate,
+    ) -> Result<ShiftSwapRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            shift_swap_requests::table
+                .filter(shift_swap_requests::organization_id.eq(org_id))
+                .filter(shift_swap_requests::id.eq(request_id))
+                .filter(shift_swap_requests::status.eq(from_status)),
+        )
+        .set((&update, shift_swap_requests::updated_at.eq(Utc::now())))
+        .get_result::<ShiftSwapRequest>(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?
+        .ok_or_else(|| {
+            AppError::Conflict("This swap request has changed, please reload it".to_string())
+        })
+    }
+
+    /// Approve a request and hand its shift over to the claimant in one transaction
+    /// Fails with a conflict when the request or the shift changed in the meantime
+    pub async fn apply(
+        &self,
+        org_id: Uuid,
+        request: &ShiftSwapRequest,
+        claimant_id: Uuid,
+        update: ShiftSwapRequestUpdate,
+    ) -> Result<ShiftSwapRequest, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let request_id = request.id;
+        let from_status = request.status;
+        let shift_id = request.shift_id;
+        let requester_id = request.requester_id;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            async move {
+                let now = Utc::now();
+                let approved = diesel::update(
+                    shift_swap_requests::table
+                        .filter(shift_swap_requests::organization_id.eq(org_id))
+                        .filter(shift_swap_requests::id.eq(request_id))
+                        .filter(shift_swap_requests::status.eq(from_status)),
+                )
+                .set((&update, shift_swap_requests::updated_at.eq(now)))
+                .get_result::<ShiftSwapRequest>(conn)
+                .await?;
+
+                let moved = diesel::update(
+                    roster_shifts::table
+                        .filter(roster_shifts::organization_id.eq(org_id))
+                        .filter(roster_shifts::id.eq(shift_id))
+                        .filter(roster_shifts::user_id.eq(requester_id)),
+                )
+                .set((
+                    roster_shifts::user_id.eq(claimant_id),
+                    roster_shifts::updated_at.eq(now),
+                ))
+                .execute(conn)
+                .await?;
+                if moved == 0 {
+                    return Err(diesel::result::Error::NotFound);
+                }
+
+                Ok(approved)
+            }
+            .scope_boxed()
+        })
+        .await
+        .map_err(|e| match e {
+            diesel::result::Error::NotFound => AppError::Conflict(
+                "This swap request or its shift has changed, please reload it".to_string(),
+            ),
+            e => AppError::DatabaseError(e),
+        })
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{NewTimeAllocation, TimeAllocation};
+use crate::schema::{clock_entries, project_tasks, projects, time_allocations, users};
+
+/// Allocation with the names of its project and task
+pub type NamedTimeAllocation = (TimeAllocation, String, Option<String>);
+
+/// Allocation row used by project reports
+#[derive(Debug, Clone, Queryable)]
+pub struct AllocationReportRow {
+    pub user_id: Uuid,
+    pub project_id: Uuid,
+    pub minutes: i32,
+    pub segment_start: Option<DateTime<Utc>>,
+    pub clock_in: DateTime<Utc>,
+    pub project_name: String,
+    pub project_code: Option<String>,
+    pub user_email: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+/// Repository for project time allocations
+pub struct TimeAllocationRepository {
+    pool: DbPool,
+}
+
+impl TimeAllocationRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// List allocations of a clock entry with project and task names
+    pub async fn list_for_entry(
+        &self,
+        org_id: Uuid,
+        clock_entry_id: Uuid,
+    ) -> Result<Vec<NamedTimeAllocation>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        time_allocations::table
+            .inner_join(projects::table)
+            .left_join(project_tasks::table)
+            .filter(time_allocations::organization_id.eq(org_id))
+            .filter(time_allocations::clock_entry_id.eq(clock_entry_id))
+            .order((
+                time_allocations::segment_start.asc(),
+                time_allocations::created_at.asc(),
+            ))
+            .select((
+                TimeAllocation::as_select(),
+                projects::name,
+                project_tasks::name.nullable(),
+            ))
+            .load::<NamedTimeAllocation>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Replace all allocations of a clock entry in one transaction
+    pub async fn replace_for_entry(
+        &self,
+        org_id: Uuid,
+        clock_entry_id: Uuid,
+        allocations: Vec<NewTimeAllocation>,
+    ) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            async move {
+                diesel::delete(
+                    time_allocations::table
+                        .filter(time_allocations::organization_id.eq(org_id))
+                        .filter(time_allocations::clock_entry_id.eq(clock_entry_id)),
+                )
+                .execute(conn)
+                .await?;
+
+                if !allocations.is_empty() {
+                    diesel::insert_into(time_allocations::table)
+                        .values(&allocations)
+                        .execute(conn)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .map_err(AppError::DatabaseError)
+    }
+
+    /// Allocations of entries clocked in within a period, for reports
+    pub async fn list_for_report(
+        &self,
+        org_id: Uuid,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<AllocationReportRow>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = time_allocations::table
+            .inner_join(clock_entries::table)
+            .inner_join(projects::table)
+            .inner_join(users::table)
+            .filter(time_allocations::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(start) = start {
+            query = query.filter(clock_entries::clock_in.ge(start));
+        }
+        if let Some(end) = end {
+            query = query.filter(clock_entries::clock_in.le(end));
+        }
+        if let Some(user_id) = user_id {
+            query = query.filter(time_allocations::user_id.eq(user_id));
+        }
+
+        query
+            .select((
+                time_allocations::user_id,
+                time_allocations::project_id,
+                time_allocations::minutes,
+                time_allocations::segment_start,
+                clock_entries::clock_in,
+                projects::name,
+                projects::code,
+                users::email,
+                users::first_name,
+                users::last_name,
+            ))
+            .order(clock_entries::clock_in.asc())
+            .load::<AllocationReportRow>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+}
@@ -1,24 +1,48 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
+use crate::domain::enums::AbsenceStatus;
 use crate::error::AppError;
 use crate::models::{
-    NewWorkSchedule, NewWorkScheduleDay, WorkSchedule, WorkScheduleDay, WorkScheduleDayUpdate,
-    WorkScheduleUpdate,
+    closed_dates, planned_shifts_on, Absence, ClosedDay, ExpectedHours, NewScheduleException,
+    NewWorkSchedule, NewWorkScheduleDay, ScheduleException, ScheduleExceptionFilter,
+    ScheduleExceptionUpdate, WorkSchedule, WorkScheduleDay, WorkScheduleDayUpdate,
+    WorkScheduleUpdate, WorkScheduleWithDays,
 };
-use crate::schema::{users, work_schedule_days, work_schedules};
+use crate::repositories::RosterRepository;
+use crate::schema::{
+    absences, closed_days, schedule_exceptions, team_members, teams, users, work_schedule_days,
+    work_schedules,
+};
+use crate::utils::timezone::to_local;
 
 /// Work schedule repository for database operations
 pub struct WorkScheduleRepository {
     pool: DbPool,
+    roster_repo: RosterRepository,
+}
+
+/// Map the one-exception-per-date violation to a conflict
+fn map_exception_write_error(e: diesel::result::Error) -> AppError {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => AppError::Conflict("User already has a schedule exception on this date".to_string()),
+        _ => AppError::DatabaseError(e),
+    }
 }
 
 impl WorkScheduleRepository {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self {
+            roster_repo: RosterRepository::new(pool.clone()),
+            pool,
+        }
     }
 
     /// Create a new work schedule
@@ -303,7 +327,10 @@ impl WorkScheduleRepository {
 
         let days = work_schedule_days::table
             .filter(work_schedule_days::work_schedule_id.eq(schedule_id))
-            .order(work_schedule_days::day_of_week.asc())
+            .order((
+                work_schedule_days::week_index.asc(),
+                work_schedule_days::day_of_week.asc(),
+            ))
             .load::<WorkScheduleDay>(&mut conn)
             .await
             .map_err(AppError::DatabaseError)?;
@@ -311,6 +338,130 @@ impl WorkScheduleRepository {
         Ok(days)
     }
 
+    /// Create a schedule exception
+    pub async fn create_exception(
+        &self,
+        new_exception: NewScheduleException,
+    ) -> Result<ScheduleException, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(schedule_exceptions::table)
+            .values(&new_exception)
+            .get_result(&mut conn)
+            .await
+            .map_err(map_exception_write_error)
+    }
+
+    /// Find schedule exception by ID within organization
+    pub async fn find_exception(
+        &self,
+        org_id: Uuid,
+        exception_id: Uuid,
+    ) -> Result<ScheduleException, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        schedule_exceptions::table
+            .filter(schedule_exceptions::organization_id.eq(org_id))
+            .find(exception_id)
+            .first::<ScheduleException>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .ok_or_else(|| AppError::NotFound("Schedule exception not found".to_string()))
+    }
+
+    /// List a user's schedule exceptions by date
+    pub async fn list_exceptions(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        filter: &ScheduleExceptionFilter,
+    ) -> Result<Vec<ScheduleException>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = schedule_exceptions::table
+            .filter(schedule_exceptions::organization_id.eq(org_id))
+            .filter(schedule_exceptions::user_id.eq(user_id))
+            .into_boxed();
+
+        if let Some(start_date) = filter.start_date {
+            query = query.filter(schedule_exceptions::exception_date.ge(start_date));
+        }
+        if let Some(end_date) = filter.end_date {
+            query = query.filter(schedule_exceptions::exception_date.le(end_date));
+        }
+
+        query
+            .order(schedule_exceptions::exception_date.asc())
+            .load::<ScheduleException>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Update a schedule exception
+    pub async fn update_exception(
+        &self,
+        org_id: Uuid,
+        exception_id: Uuid,
+        update: ScheduleExceptionUpdate,
+    ) -> Result<ScheduleException, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            schedule_exceptions::table
+                .filter(schedule_exceptions::organization_id.eq(org_id))
+                .filter(schedule_exceptions::id.eq(exception_id)),
+        )
+        .set((&update, schedule_exceptions::updated_at.eq(Utc::now())))
+        .get_result::<ScheduleException>(&mut conn)
+        .await
+        .optional()
+        .map_err(map_exception_write_error)?
+        .ok_or_else(|| AppError::NotFound("Schedule exception not found".to_string()))
+    }
+
+    /// Delete a schedule exception
+    pub async fn delete_exception(&self, org_id: Uuid, exception_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            schedule_exceptions::table
+                .filter(schedule_exceptions::organization_id.eq(org_id))
+                .filter(schedule_exceptions::id.eq(exception_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound(
+                "Schedule exception not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Assign schedule to user
     pub async fn assign_to_user(
         &self,
@@ -394,45 +545,188 @@ impl WorkScheduleRepository {
         }
     }
 
-    /// Calculate theoretical hours for a user in a date range
-    pub async fn get_theoretical_hours(
+    /// Get the schedule of the first team a user joined that has one
+    pub async fn get_team_schedule(
         &self,
         org_id: Uuid,
         user_id: Uuid,
-        start: DateTime<Utc>,
-        end: DateTime<Utc>,
-    ) -> Result<f64, AppError> {
-        // Get user's schedule (or default)
-        let schedule = match self.get_user_schedule(org_id, user_id).await? {
-            Some(s) => s,
-            None => match self.get_default(org_id).await? {
-                Some(s) => s,
-                None => return Ok(0.0),
-            },
+    ) -> Result<Option<WorkSchedule>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let schedule_id: Option<Uuid> = team_members::table
+            .inner_join(teams::table)
+            .filter(team_members::user_id.eq(user_id))
+            .filter(teams::organization_id.eq(org_id))
+            .filter(teams::work_schedule_id.is_not_null())
+            .order(team_members::joined_at.asc())
+            .select(teams::work_schedule_id)
+            .first::<Option<Uuid>>(&mut conn)
+            .await
+            .optional()
+            .map_err(AppError::DatabaseError)?
+            .flatten();
+
+        match schedule_id {
+            Some(id) => Ok(Some(self.find_by_id(org_id, id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve the schedule that applies to a user: their own, then their team's,
+    /// then the organization default
+    pub async fn resolve_schedule(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<WorkSchedule>, AppError> {
+        if let Some(schedule) = self.get_user_schedule(org_id, user_id).await? {
+            return Ok(Some(schedule));
+        }
+        if let Some(schedule) = self.get_team_schedule(org_id, user_id).await? {
+            return Ok(Some(schedule));
+        }
+        self.get_default(org_id).await
+    }
+
+    /// Get the schedule that applies to a user, with its days and the user's
+    /// schedule exceptions between two dates (inclusive)
+    /// Resolved from the user, their team, then the organization default; None
+    /// when none of them has a schedule
+    pub async fn get_effective_schedule(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Option<WorkScheduleWithDays>, AppError> {
+        let Some(schedule) = self.resolve_schedule(org_id, user_id).await? else {
+            return Ok(None);
         };
 
         let days = self.get_days(schedule.id).await?;
-        if days.is_empty() {
-            return Ok(0.0);
-        }
+        let filter = ScheduleExceptionFilter {
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+        };
+        let exceptions = self.list_exceptions(org_id, user_id, &filter).await?;
+        Ok(Some(
+            WorkScheduleWithDays::new(schedule, days).with_exceptions(&exceptions),
+        ))
+    }
+
+    /// Rotation week of the schedule that applies to a user on a date
+    /// Always 0 for weekly schedules and users without schedule
+    pub async fn get_rotation_week(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<i16, AppError> {
+        let schedule = self.resolve_schedule(org_id, user_id).await?;
 
-        // Calculate total hours
-        let mut total_minutes: i64 = 0;
-        let mut current = start.date_naive();
-        let end_date = end.date_naive();
+        Ok(schedule.map_or(0, |s| s.rotation_week(date)))
+    }
+
+    /// Local bounds of the shifts planned for a user between two dates (inclusive)
+    /// Published roster shifts replace the schedule on their dates. None when the
+    /// user has neither a schedule nor roster shifts in the range.
+    pub async fn get_planned_shifts(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Option<Vec<(NaiveDateTime, NaiveDateTime)>>, AppError> {
+        let schedule = self
+            .get_effective_schedule(org_id, user_id, start_date, end_date)
+            .await?;
+        let shifts = self
+            .roster_repo
+            .list_published_for_user(org_id, user_id, start_date, end_date)
+            .await?;
+        if schedule.is_none() && shifts.is_empty() {
+            return Ok(None);
+        }
 
+        let mut planned = Vec::new();
+        let mut current = start_date;
         while current <= end_date {
-            let weekday = current.weekday().num_days_from_monday() as i16;
-            if let Some(day) = days.iter().find(|d| d.day_of_week == weekday) {
-                let work_minutes =
-                    (day.end_time - day.start_time).num_minutes() - day.break_minutes as i64;
-                if work_minutes > 0 {
-                    total_minutes += work_minutes;
-                }
-            }
+            planned.extend(planned_shifts_on(schedule.as_ref(), &shifts, current));
             current = current.succ_opt().unwrap_or(current);
         }
+        Ok(Some(planned))
+    }
 
-        Ok(total_minutes as f64 / 60.0)
+    /// Calculate theoretical hours for a user in a date range
+    /// Days are counted in the organization's local calendar (`tz`), following
+    /// the rotation of the schedule, the user's schedule exceptions and published
+    /// roster shifts. Closed days and approved absences are deducted.
+    pub async fn get_theoretical_hours(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        tz: Tz,
+    ) -> Result<ExpectedHours, AppError> {
+        let start_date = to_local(start, tz).date();
+        let end_date = to_local(end, tz).date();
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let user_absences: Vec<Absence> = absences::table
+            .filter(absences::organization_id.eq(org_id))
+            .filter(absences::user_id.eq(user_id))
+            .filter(absences::status.eq(AbsenceStatus::Approved))
+            .filter(absences::start_date.le(end_date))
+            .filter(absences::end_date.ge(start_date))
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+
+        // Shifts and closed days over the whole absences too, so every date an
+        // absence covers is planned the same way
+        let first_date = user_absences
+            .iter()
+            .map(|a| a.start_date)
+            .fold(start_date, NaiveDate::min);
+        let last_date = user_absences
+            .iter()
+            .map(|a| a.end_date)
+            .fold(end_date, NaiveDate::max);
+        let org_closed_days: Vec<ClosedDay> = closed_days::table
+            .filter(closed_days::organization_id.eq(org_id))
+            .filter(
+                closed_days::is_recurring.eq(true).or(closed_days::date
+                    .ge(first_date)
+                    .and(closed_days::date.le(last_date))),
+            )
+            .load(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)?;
+        let schedule = self
+            .get_effective_schedule(org_id, user_id, first_date, last_date)
+            .await?;
+        let shifts = self
+            .roster_repo
+            .list_published_for_user(org_id, user_id, first_date, last_date)
+            .await?;
+
+        Ok(ExpectedHours::compute(
+            schedule.as_ref(),
+            &shifts,
+            &closed_dates(&org_closed_days, first_date, last_date),
+            &user_absences,
+            start_date,
+            end_date,
+        ))
     }
 }
@@ -0,0 +1,139 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{NewWorkSite, WorkSite, WorkSiteFilter, WorkSiteUpdate};
+use crate::schema::work_sites;
+
+/// WorkSite repository for database operations
+pub struct WorkSiteRepository {
+    pool: DbPool,
+}
+
+impl WorkSiteRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new work site
+    pub async fn create(&self, new_site: NewWorkSite) -> Result<WorkSite, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::insert_into(work_sites::table)
+            .values(&new_site)
+            .get_result(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// Find work site by ID within organization
+    pub async fn find_by_id(&self, org_id: Uuid, site_id: Uuid) -> Result<WorkSite, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        work_sites::table
+            .filter(work_sites::organization_id.eq(org_id))
+            .find(site_id)
+            .first::<WorkSite>(&mut conn)
+            .await
+            .map_err(|_| AppError::NotFound("Work site not found".to_string()))
+    }
+
+    /// List work sites with filters
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: &WorkSiteFilter,
+    ) -> Result<Vec<WorkSite>, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let mut query = work_sites::table
+            .filter(work_sites::organization_id.eq(org_id))
+            .into_boxed();
+
+        if let Some(is_active) = filter.is_active {
+            query = query.filter(work_sites::is_active.eq(is_active));
+        }
+
+        query
+            .order(work_sites::name.asc())
+            .load::<WorkSite>(&mut conn)
+            .await
+            .map_err(AppError::DatabaseError)
+    }
+
+    /// List active work sites (for geofence checks)
+    pub async fn list_active(&self, org_id: Uuid) -> Result<Vec<WorkSite>, AppError> {
+        self.list(
+            org_id,
+            &WorkSiteFilter {
+                is_active: Some(true),
+            },
+        )
+        .await
+    }
+
+    /// Update a work site
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        site_id: Uuid,
+        update: WorkSiteUpdate,
+    ) -> Result<WorkSite, AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        diesel::update(
+            work_sites::table
+                .filter(work_sites::organization_id.eq(org_id))
+                .filter(work_sites::id.eq(site_id)),
+        )
+        .set((&update, work_sites::updated_at.eq(Utc::now())))
+        .get_result::<WorkSite>(&mut conn)
+        .await
+        .optional()
+        .map_err(AppError::DatabaseError)?
+        .ok_or_else(|| AppError::NotFound("Work site not found".to_string()))
+    }
+
+    /// Delete a work site
+    pub async fn delete(&self, org_id: Uuid, site_id: Uuid) -> Result<(), AppError> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| AppError::PoolError(e.to_string()))?;
+
+        let deleted = diesel::delete(
+            work_sites::table
+                .filter(work_sites::organization_id.eq(org_id))
+                .filter(work_sites::id.eq(site_id)),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(AppError::DatabaseError)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Work site not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,30 @@
 // @generated automatically by Diesel CLI.
 
 pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "anomaly_kind"))]
+    pub struct AnomalyKind;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "anomaly_severity"))]
+    pub struct AnomalySeverity;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "anomaly_status"))]
+    pub struct AnomalyStatus;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "absence_half_day"))]
+    pub struct AbsenceHalfDay;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "absence_status"))]
     pub struct AbsenceStatus;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "absence_unit"))]
+    pub struct AbsenceUnit;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "audit_action"))]
     pub struct AuditAction;
@@ -13,6 +33,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "break_tracking_mode"))]
     pub struct BreakTrackingMode;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "clock_correction_status"))]
+    pub struct ClockCorrectionStatus;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "clock_entry_source"))]
+    pub struct ClockEntrySource;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "clock_entry_status"))]
     pub struct ClockEntryStatus;
@@ -25,16 +53,75 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "clock_restriction_mode"))]
     pub struct ClockRestrictionMode;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "clock_sync_status"))]
+    pub struct ClockSyncStatus;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "geofence_mode"))]
+    pub struct GeofenceMode;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "notification_type"))]
     pub struct NotificationType;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "pay_period_frequency"))]
+    pub struct PayPeriodFrequency;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "roster_shift_status"))]
+    pub struct RosterShiftStatus;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "shift_swap_status"))]
+    pub struct ShiftSwapStatus;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "user_role"))]
     pub struct UserRole;
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::UserRole;
+
+    absence_approval_steps (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        absence_type_id -> Uuid,
+        step_order -> Int2,
+        approver_role -> UserRole,
+        min_days -> Nullable<Numeric>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    absence_approvals (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        absence_id -> Uuid,
+        step_order -> Int2,
+        approver_id -> Nullable<Uuid>,
+        comment -> Nullable<Text>,
+        approved_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    absence_settings (organization_id) {
+        organization_id -> Uuid,
+        working_days -> Array<Int2>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AbsenceUnit;
+
     absence_types (id) {
         id -> Uuid,
         organization_id -> Uuid,
@@ -49,12 +136,14 @@ diesel::table! {
         is_paid -> Bool,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        unit -> AbsenceUnit,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::AbsenceStatus;
+    use super::sql_types::AbsenceHalfDay;
 
     absences (id) {
         id -> Uuid,
@@ -71,6 +160,22 @@ diesel::table! {
         approved_at -> Nullable<Timestamptz>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        start_half -> AbsenceHalfDay,
+        end_half -> AbsenceHalfDay,
+        start_time -> Nullable<Time>,
+        end_time -> Nullable<Time>,
+        hours -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    anomaly_settings (organization_id) {
+        organization_id -> Uuid,
+        max_shift_hours -> Int4,
+        rapid_clock_max_entries -> Int4,
+        rapid_clock_window_minutes -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -141,12 +246,77 @@ diesel::table! {
         max_duration_minutes -> Int4,
         is_mandatory -> Bool,
         created_at -> Timestamptz,
+        week_index -> Nullable<Int2>,
+    }
+}
+
+diesel::table! {
+    clock_approval_rules (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        team_id -> Nullable<Uuid>,
+        #[max_length = 100]
+        name -> Varchar,
+        max_schedule_deviation_minutes -> Nullable<Int4>,
+        max_duration_minutes -> Nullable<Int4>,
+        allow_override -> Bool,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AnomalyKind;
+    use super::sql_types::AnomalySeverity;
+    use super::sql_types::AnomalyStatus;
+
+    clock_anomalies (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        user_id -> Uuid,
+        clock_entry_id -> Uuid,
+        break_entry_id -> Nullable<Uuid>,
+        kind -> AnomalyKind,
+        severity -> AnomalySeverity,
+        message -> Text,
+        status -> AnomalyStatus,
+        reviewed_by -> Nullable<Uuid>,
+        reviewed_at -> Nullable<Timestamptz>,
+        review_note -> Nullable<Text>,
+        detected_at -> Timestamptz,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ClockCorrectionStatus;
+
+    clock_correction_requests (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        user_id -> Uuid,
+        clock_entry_id -> Uuid,
+        original_clock_in -> Timestamptz,
+        original_clock_out -> Nullable<Timestamptz>,
+        requested_clock_in -> Timestamptz,
+        requested_clock_out -> Timestamptz,
+        reason -> Text,
+        status -> ClockCorrectionStatus,
+        reviewed_by -> Nullable<Uuid>,
+        reviewed_at -> Nullable<Timestamptz>,
+        review_notes -> Nullable<Text>,
+        created_at -> Timestamptz,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ClockEntryStatus;
+    use super::sql_types::ClockEntrySource;
 
     clock_entries (id) {
         id -> Uuid,
@@ -160,6 +330,22 @@ diesel::table! {
         notes -> Nullable<Text>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        source -> ClockEntrySource,
+        created_by -> Nullable<Uuid>,
+        auto_closed -> Bool,
+        clock_in_latitude -> Nullable<Float8>,
+        clock_in_longitude -> Nullable<Float8>,
+        clock_in_accuracy -> Nullable<Float8>,
+        clock_out_latitude -> Nullable<Float8>,
+        clock_out_longitude -> Nullable<Float8>,
+        clock_out_accuracy -> Nullable<Float8>,
+        work_site_id -> Nullable<Uuid>,
+        outside_geofence -> Bool,
+        kiosk_device_id -> Nullable<Uuid>,
+        clock_drift_seconds -> Nullable<Int4>,
+        auto_approval_rule_id -> Nullable<Uuid>,
+        #[max_length = 45]
+        clock_in_ip -> Nullable<Varchar>,
     }
 }
 
@@ -187,6 +373,7 @@ diesel::table! {
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ClockRestrictionMode;
+    use super::sql_types::GeofenceMode;
 
     clock_restrictions (id) {
         id -> Uuid,
@@ -204,6 +391,34 @@ diesel::table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         max_daily_clock_events -> Nullable<Int4>,
+        geofence_mode -> GeofenceMode,
+        allowed_networks -> Array<Text>,
+        schedule_early_minutes -> Int4,
+        schedule_late_minutes -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ClockSyncStatus;
+
+    clock_sync_events (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        device_id -> Varchar,
+        #[max_length = 100]
+        idempotency_key -> Varchar,
+        #[max_length = 10]
+        action -> Varchar,
+        client_timestamp -> Timestamptz,
+        clock_drift_seconds -> Int4,
+        status -> ClockSyncStatus,
+        clock_entry_id -> Nullable<Uuid>,
+        message -> Nullable<Text>,
+        created_at -> Timestamptz,
+        reserved_at -> Timestamptz,
     }
 }
 
@@ -243,6 +458,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    kiosk_devices (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        work_site_id -> Nullable<Uuid>,
+        is_active -> Bool,
+        last_used_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    kiosk_pins (user_id) {
+        user_id -> Uuid,
+        organization_id -> Uuid,
+        #[max_length = 255]
+        pin_hash -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     leave_balances (id) {
         id -> Uuid,
@@ -325,6 +568,64 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PayPeriodFrequency;
+
+    pay_period_settings (organization_id) {
+        organization_id -> Uuid,
+        frequency -> PayPeriodFrequency,
+        anchor_date -> Date,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    pay_periods (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        start_date -> Date,
+        end_date -> Date,
+        is_closed -> Bool,
+        closed_by -> Nullable<Uuid>,
+        closed_at -> Timestamptz,
+        reopened_by -> Nullable<Uuid>,
+        reopened_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    project_tasks (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        project_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    projects (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 20]
+        code -> Nullable<Varchar>,
+        #[max_length = 100]
+        client_name -> Nullable<Varchar>,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     refresh_tokens (id) {
         id -> Uuid,
@@ -340,6 +641,80 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    roster_settings (organization_id) {
+        organization_id -> Uuid,
+        swap_requires_approval -> Bool,
+        min_rest_hours -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RosterShiftStatus;
+
+    roster_shifts (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        user_id -> Uuid,
+        team_id -> Nullable<Uuid>,
+        shift_date -> Date,
+        start_time -> Time,
+        end_time -> Time,
+        break_minutes -> Int4,
+        #[max_length = 100]
+        position -> Nullable<Varchar>,
+        notes -> Nullable<Text>,
+        status -> RosterShiftStatus,
+        published_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    schedule_exceptions (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        user_id -> Uuid,
+        exception_date -> Date,
+        is_day_off -> Bool,
+        start_time -> Nullable<Time>,
+        end_time -> Nullable<Time>,
+        break_minutes -> Int4,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ShiftSwapStatus;
+
+    shift_swap_requests (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        shift_id -> Uuid,
+        requester_id -> Uuid,
+        target_user_id -> Nullable<Uuid>,
+        claimed_by -> Nullable<Uuid>,
+        claimed_at -> Nullable<Timestamptz>,
+        status -> ShiftSwapStatus,
+        note -> Nullable<Text>,
+        reviewed_by -> Nullable<Uuid>,
+        reviewed_at -> Nullable<Timestamptz>,
+        review_note -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     team_members (id) {
         id -> Uuid,
@@ -363,6 +738,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    time_allocations (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        clock_entry_id -> Uuid,
+        user_id -> Uuid,
+        project_id -> Uuid,
+        task_id -> Nullable<Uuid>,
+        segment_start -> Nullable<Timestamptz>,
+        segment_end -> Nullable<Timestamptz>,
+        minutes -> Int4,
+        notes -> Nullable<Text>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     user_sessions (id) {
         id -> Uuid,
@@ -413,6 +804,7 @@ diesel::table! {
         start_time -> Time,
         end_time -> Time,
         break_minutes -> Int4,
+        week_index -> Int2,
     }
 }
 
@@ -426,12 +818,36 @@ diesel::table! {
         is_default -> Bool,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        cycle_weeks -> Int2,
+        cycle_anchor_date -> Date,
+    }
+}
+
+diesel::table! {
+    work_sites (id) {
+        id -> Uuid,
+        organization_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        latitude -> Float8,
+        longitude -> Float8,
+        radius_meters -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
 diesel::joinable!(absence_types -> organizations (organization_id));
+diesel::joinable!(absence_approval_steps -> absence_types (absence_type_id));
+diesel::joinable!(absence_approval_steps -> organizations (organization_id));
+diesel::joinable!(absence_approvals -> absences (absence_id));
+diesel::joinable!(absence_approvals -> organizations (organization_id));
+diesel::joinable!(absence_approvals -> users (approver_id));
+diesel::joinable!(absence_settings -> organizations (organization_id));
 diesel::joinable!(absences -> absence_types (type_id));
 diesel::joinable!(absences -> organizations (organization_id));
+diesel::joinable!(anomaly_settings -> organizations (organization_id));
 diesel::joinable!(audit_logs -> organizations (organization_id));
 diesel::joinable!(audit_logs -> users (user_id));
 diesel::joinable!(break_entries -> clock_entries (clock_entry_id));
@@ -441,15 +857,34 @@ diesel::joinable!(break_policies -> organizations (organization_id));
 diesel::joinable!(break_policies -> teams (team_id));
 diesel::joinable!(break_policies -> users (user_id));
 diesel::joinable!(break_windows -> break_policies (break_policy_id));
+diesel::joinable!(clock_anomalies -> break_entries (break_entry_id));
+diesel::joinable!(clock_anomalies -> clock_entries (clock_entry_id));
+diesel::joinable!(clock_anomalies -> organizations (organization_id));
+diesel::joinable!(clock_anomalies -> users (user_id));
+diesel::joinable!(clock_approval_rules -> organizations (organization_id));
+diesel::joinable!(clock_approval_rules -> teams (team_id));
+diesel::joinable!(clock_correction_requests -> clock_entries (clock_entry_id));
+diesel::joinable!(clock_correction_requests -> organizations (organization_id));
+diesel::joinable!(clock_entries -> clock_approval_rules (auto_approval_rule_id));
 diesel::joinable!(clock_entries -> organizations (organization_id));
+diesel::joinable!(clock_entries -> kiosk_devices (kiosk_device_id));
+diesel::joinable!(clock_entries -> work_sites (work_site_id));
 diesel::joinable!(clock_override_requests -> clock_entries (clock_entry_id));
 diesel::joinable!(clock_override_requests -> organizations (organization_id));
 diesel::joinable!(clock_restrictions -> organizations (organization_id));
 diesel::joinable!(clock_restrictions -> teams (team_id));
 diesel::joinable!(clock_restrictions -> users (user_id));
+diesel::joinable!(clock_sync_events -> clock_entries (clock_entry_id));
+diesel::joinable!(clock_sync_events -> organizations (organization_id));
+diesel::joinable!(clock_sync_events -> users (user_id));
 diesel::joinable!(closed_days -> organizations (organization_id));
 diesel::joinable!(holidays -> organizations (organization_id));
 diesel::joinable!(invite_tokens -> users (user_id));
+diesel::joinable!(kiosk_devices -> organizations (organization_id));
+diesel::joinable!(kiosk_devices -> users (created_by));
+diesel::joinable!(kiosk_devices -> work_sites (work_site_id));
+diesel::joinable!(kiosk_pins -> organizations (organization_id));
+diesel::joinable!(kiosk_pins -> users (user_id));
 diesel::joinable!(leave_balances -> absence_types (absence_type_id));
 diesel::joinable!(leave_balances -> organizations (organization_id));
 diesel::joinable!(leave_balances -> users (user_id));
@@ -457,43 +892,81 @@ diesel::joinable!(notifications -> organizations (organization_id));
 diesel::joinable!(notifications -> users (user_id));
 diesel::joinable!(password_history -> users (user_id));
 diesel::joinable!(password_reset_tokens -> users (user_id));
+diesel::joinable!(pay_period_settings -> organizations (organization_id));
+diesel::joinable!(pay_periods -> organizations (organization_id));
+diesel::joinable!(project_tasks -> organizations (organization_id));
+diesel::joinable!(project_tasks -> projects (project_id));
+diesel::joinable!(projects -> organizations (organization_id));
 diesel::joinable!(refresh_tokens -> users (user_id));
+diesel::joinable!(roster_settings -> organizations (organization_id));
+diesel::joinable!(roster_shifts -> organizations (organization_id));
+diesel::joinable!(roster_shifts -> teams (team_id));
+diesel::joinable!(roster_shifts -> users (user_id));
+diesel::joinable!(schedule_exceptions -> organizations (organization_id));
+diesel::joinable!(shift_swap_requests -> organizations (organization_id));
+diesel::joinable!(shift_swap_requests -> roster_shifts (shift_id));
 diesel::joinable!(team_members -> teams (team_id));
 diesel::joinable!(team_members -> users (user_id));
 diesel::joinable!(teams -> organizations (organization_id));
 diesel::joinable!(teams -> users (manager_id));
 diesel::joinable!(teams -> work_schedules (work_schedule_id));
+diesel::joinable!(time_allocations -> clock_entries (clock_entry_id));
+diesel::joinable!(time_allocations -> organizations (organization_id));
+diesel::joinable!(time_allocations -> project_tasks (task_id));
+diesel::joinable!(time_allocations -> projects (project_id));
+diesel::joinable!(time_allocations -> users (user_id));
 diesel::joinable!(user_sessions -> refresh_tokens (refresh_token_id));
 diesel::joinable!(user_sessions -> users (user_id));
 diesel::joinable!(users -> organizations (organization_id));
 diesel::joinable!(users -> work_schedules (work_schedule_id));
 diesel::joinable!(work_schedule_days -> work_schedules (work_schedule_id));
 diesel::joinable!(work_schedules -> organizations (organization_id));
+diesel::joinable!(work_sites -> organizations (organization_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    absence_approval_steps,
+    absence_approvals,
+    absence_settings,
     absence_types,
     absences,
+    anomaly_settings,
     audit_logs,
     break_entries,
     break_policies,
     break_windows,
+    clock_anomalies,
+    clock_approval_rules,
+    clock_correction_requests,
     clock_entries,
     clock_override_requests,
     clock_restrictions,
+    clock_sync_events,
     closed_days,
     holidays,
     invite_tokens,
+    kiosk_devices,
+    kiosk_pins,
     leave_balances,
     login_attempts,
     notifications,
     organizations,
     password_history,
     password_reset_tokens,
+    pay_period_settings,
+    pay_periods,
+    project_tasks,
+    projects,
     refresh_tokens,
+    roster_settings,
+    roster_shifts,
+    schedule_exceptions,
+    shift_swap_requests,
     team_members,
     teams,
+    time_allocations,
     user_sessions,
     users,
     work_schedule_days,
     work_schedules,
+    work_sites,
 );
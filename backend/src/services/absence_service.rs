@@ -1,18 +1,24 @@
 use bigdecimal::{BigDecimal, ToPrimitive};
-use chrono::{Datelike, NaiveDate, Utc, Weekday};
+use chrono::{Datelike, NaiveDate, NaiveTime, Utc};
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
-use crate::domain::enums::{AbsenceStatus, NotificationType, UserRole};
+use crate::domain::enums::{
+    AbsenceHalfDay, AbsenceStatus, AbsenceUnit, NotificationType, UserRole,
+};
 use crate::error::AppError;
+use crate::models::absence_approval::approval_chain;
 use crate::models::{
-    Absence, AbsenceFilter, AbsenceResponse, AbsenceUpdate, NewAbsence, PaginatedAbsences,
-    Pagination, PendingAbsenceFilter,
+    Absence, AbsenceApprovalResponse, AbsenceFilter, AbsencePeriod, AbsencePreview,
+    AbsenceResponse, AbsenceSettingsResponse, AbsenceType, AbsenceUpdate, NewAbsence,
+    NewAbsenceApproval, NewAbsenceSettings, PaginatedAbsences, Pagination, PendingAbsenceFilter,
+    UserFilter, WorkScheduleWithDays,
 };
 use crate::repositories::{
     AbsenceRepository, AbsenceTypeRepository, ClosedDayRepository, LeaveBalanceRepository,
-    OrganizationRepository, TeamRepository,
+    OrganizationRepository, PayPeriodRepository, TeamRepository, UserRepository,
+    WorkScheduleRepository,
 };
 use crate::services::NotificationService;
 
@@ -22,9 +28,23 @@ pub struct CreateAbsenceRequest {
     pub type_id: Uuid,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    /// Part of the start date taken (full day when omitted)
+    pub start_half: Option<AbsenceHalfDay>,
+    /// Part of the end date taken (full day when omitted)
+    pub end_half: Option<AbsenceHalfDay>,
+    /// Time range on a single date, required for hourly absence types
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
     pub reason: Option<String>,
 }
 
+/// Request to update absence settings
+#[derive(Debug, Deserialize)]
+pub struct UpdateAbsenceSettingsRequest {
+    /// Working week of users without a work schedule (0 = Monday, 6 = Sunday)
+    pub working_days: Vec<i16>,
+}
+
 /// Service for absence operations
 pub struct AbsenceService {
     absence_repo: AbsenceRepository,
@@ -33,6 +53,9 @@ pub struct AbsenceService {
     closed_day_repo: ClosedDayRepository,
     team_repo: TeamRepository,
     org_repo: OrganizationRepository,
+    pay_period_repo: PayPeriodRepository,
+    work_schedule_repo: WorkScheduleRepository,
+    user_repo: UserRepository,
 }
 
 impl AbsenceService {
@@ -43,7 +66,10 @@ impl AbsenceService {
             leave_balance_repo: LeaveBalanceRepository::new(pool.clone()),
             closed_day_repo: ClosedDayRepository::new(pool.clone()),
             team_repo: TeamRepository::new(pool.clone()),
-            org_repo: OrganizationRepository::new(pool),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            pay_period_repo: PayPeriodRepository::new(pool.clone()),
+            work_schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            user_repo: UserRepository::new(pool),
         }
     }
 
@@ -54,13 +80,6 @@ impl AbsenceService {
         user_id: Uuid,
         request: CreateAbsenceRequest,
     ) -> Result<AbsenceResponse, AppError> {
-        // Validate dates
-        if request.end_date < request.start_date {
-            return Err(AppError::ValidationError(
-                "End date must be on or after start date".to_string(),
-            ));
-        }
-
         // Don't allow requests in the past (except for same-day)
         let today = Utc::now().date_naive();
         if request.start_date < today {
@@ -69,16 +88,18 @@ impl AbsenceService {
             ));
         }
 
-        // Get absence type
-        let absence_type = self
-            .absence_type_repo
-            .find_by_id(org_id, request.type_id)
+        // Closed pay periods are locked
+        self.pay_period_repo
+            .ensure_open(org_id, request.start_date, request.end_date)
             .await?;
 
+        let (absence_type, period, preview) =
+            self.assess_request(org_id, user_id, &request).await?;
+
         // Check for overlapping absences
         let has_overlap = self
             .absence_repo
-            .check_overlap(org_id, user_id, request.start_date, request.end_date, None)
+            .check_overlap(org_id, user_id, &period, None)
             .await?;
 
         if has_overlap {
@@ -87,41 +108,28 @@ impl AbsenceService {
             ));
         }
 
-        // Calculate working days
-        let days_count = self
-            .calculate_working_days(org_id, request.start_date, request.end_date)
-            .await?;
-
+        let days_count = preview.days_count;
+        let hours = preview.hours;
         if days_count <= 0.0 {
             return Err(AppError::ValidationError(
                 "No working days in the selected period".to_string(),
             ));
         }
 
-        // Check balance if affects_balance
+        // Check balance if affects_balance, held in hours for hourly types
         if absence_type.affects_balance {
-            let year = request.start_date.year();
-            let balance = self
-                .leave_balance_repo
-                .find_by_user_type_year(org_id, user_id, request.type_id, year)
-                .await?;
-
-            if let Some(b) = balance {
-                let initial = b.initial_balance.to_f64().unwrap_or(0.0);
-                let used = b.used.to_f64().unwrap_or(0.0);
-                let adj = b.adjustment.to_f64().unwrap_or(0.0);
-                let remaining = initial - used + adj;
-
-                if days_count > remaining {
-                    return Err(AppError::ValidationError(format!(
-                        "Insufficient balance. Remaining: {} days, Requested: {} days",
-                        remaining, days_count
-                    )));
-                }
-            } else {
+            let Some(remaining) = preview.remaining_balance else {
                 return Err(AppError::ValidationError(
                     "No leave balance set for this absence type".to_string(),
                 ));
+            };
+            let requested = hours.unwrap_or(days_count);
+            if requested > remaining {
+                let unit_name = unit_name(absence_type.unit);
+                return Err(AppError::ValidationError(format!(
+                    "Insufficient balance. Remaining: {} {}, Requested: {} {}",
+                    remaining, unit_name, requested, unit_name
+                )));
             }
         }
 
@@ -139,9 +147,16 @@ impl AbsenceService {
             type_id: request.type_id,
             start_date: request.start_date,
             end_date: request.end_date,
-            days_count: BigDecimal::try_from(days_count).unwrap_or_default(),
+            days_count: BigDecimal::try_from(days_count)
+                .unwrap_or_default()
+                .round(2),
             status,
             reason: request.reason,
+            start_half: period.start_half,
+            end_half: period.end_half,
+            start_time: period.times.map(|(start, _)| start),
+            end_time: period.times.map(|(_, end)| end),
+            hours: hours.map(|h| BigDecimal::try_from(h).unwrap_or_default().round(2)),
         };
 
         let absence = self.absence_repo.create(new_absence).await?;
@@ -155,7 +170,7 @@ impl AbsenceService {
                     user_id,
                     request.type_id,
                     year,
-                    BigDecimal::try_from(days_count).unwrap_or_default(),
+                    absence.balance_amount(),
                 )
                 .await?;
         }
@@ -163,13 +178,171 @@ impl AbsenceService {
         self.build_response(&absence).await
     }
 
+    /// Preview the days (or hours) and balance an absence request would take
+    pub async fn preview_request(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        request: CreateAbsenceRequest,
+    ) -> Result<AbsencePreview, AppError> {
+        let (_, _, preview) = self.assess_request(org_id, user_id, &request).await?;
+        Ok(preview)
+    }
+
+    /// Validate a request and compute what it takes from the working time and balance
+    async fn assess_request(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        request: &CreateAbsenceRequest,
+    ) -> Result<(AbsenceType, AbsencePeriod, AbsencePreview), AppError> {
+        // Validate dates
+        if request.end_date < request.start_date {
+            return Err(AppError::ValidationError(
+                "End date must be on or after start date".to_string(),
+            ));
+        }
+
+        // Get absence type
+        let absence_type = self
+            .absence_type_repo
+            .find_by_id(org_id, request.type_id)
+            .await?;
+
+        // Hourly types take a time range on a single date, other types whole or half days
+        let period = match absence_type.unit {
+            AbsenceUnit::Hours => AbsencePeriod {
+                times: Some(validate_hourly_times(
+                    request.start_date,
+                    request.end_date,
+                    request.start_time,
+                    request.end_time,
+                )?),
+                ..AbsencePeriod::days(request.start_date, request.end_date)
+            },
+            AbsenceUnit::Days => {
+                if request.start_time.is_some() || request.end_time.is_some() {
+                    return Err(AppError::ValidationError(
+                        "Start and end times are only allowed for hourly absence types".to_string(),
+                    ));
+                }
+                let (start_half, end_half) = normalize_halves(
+                    request.start_date,
+                    request.end_date,
+                    request.start_half.unwrap_or_default(),
+                    request.end_half.unwrap_or_default(),
+                )?;
+                AbsencePeriod {
+                    start_half,
+                    end_half,
+                    ..AbsencePeriod::days(request.start_date, request.end_date)
+                }
+            }
+        };
+
+        // Days charged on each date, and the requested hours of hourly absences
+        let schedule = self
+            .work_schedule_repo
+            .get_effective_schedule(org_id, user_id, request.start_date, request.end_date)
+            .await?;
+        let (charged, hours) = match period.times {
+            Some((start_time, end_time)) => {
+                // Without a schedule there is no working day to take the hours from
+                if schedule.is_none() {
+                    return Err(AppError::ValidationError(
+                        "Hourly absences need a work schedule to convert the hours into days"
+                            .to_string(),
+                    ));
+                }
+                let minutes = (end_time - start_time).num_minutes();
+                let scheduled = self
+                    .scheduled_minutes(org_id, schedule.as_ref(), request.start_date)
+                    .await?;
+                (
+                    vec![(request.start_date, hourly_days(minutes, scheduled))],
+                    Some(minutes as f64 / 60.0),
+                )
+            }
+            None => (
+                self.calculate_working_days(org_id, schedule.as_ref(), &period)
+                    .await?,
+                None,
+            ),
+        };
+        let days_count: f64 = charged.iter().map(|(_, days)| days).sum();
+
+        // Remaining balance of the year the absence starts in
+        let remaining_balance = if absence_type.affects_balance {
+            self.leave_balance_repo
+                .find_by_user_type_year(org_id, user_id, request.type_id, request.start_date.year())
+                .await?
+                .map(|b| {
+                    b.initial_balance.to_f64().unwrap_or(0.0) - b.used.to_f64().unwrap_or(0.0)
+                        + b.adjustment.to_f64().unwrap_or(0.0)
+                })
+        } else {
+            None
+        };
+
+        let preview = AbsencePreview {
+            type_id: absence_type.id,
+            unit: absence_type.unit,
+            start_date: request.start_date,
+            end_date: request.end_date,
+            working_dates: charged
+                .iter()
+                .filter(|(_, days)| *days > 0.0)
+                .map(|(date, _)| *date)
+                .collect(),
+            days_count,
+            hours,
+            remaining_balance,
+            remaining_after: remaining_balance.map(|r| r - hours.unwrap_or(days_count)),
+        };
+        Ok((absence_type, period, preview))
+    }
+
+    /// Get absence settings (defaults when none are set)
+    pub async fn get_settings(&self, org_id: Uuid) -> Result<AbsenceSettingsResponse, AppError> {
+        Ok(self
+            .absence_repo
+            .find_settings(org_id)
+            .await?
+            .map(AbsenceSettingsResponse::from)
+            .unwrap_or_default())
+    }
+
+    /// Create or replace absence settings
+    pub async fn update_settings(
+        &self,
+        org_id: Uuid,
+        request: UpdateAbsenceSettingsRequest,
+    ) -> Result<AbsenceSettingsResponse, AppError> {
+        let working_days = normalize_working_days(request.working_days)?;
+
+        let settings = self
+            .absence_repo
+            .upsert_settings(NewAbsenceSettings {
+                organization_id: org_id,
+                working_days,
+            })
+            .await?;
+
+        Ok(AbsenceSettingsResponse::from(settings))
+    }
+
     /// Approve an absence request
+    ///
+    /// Records the next step of the type's approval chain. The absence stays
+    /// pending and the next approvers are notified until the last step is
+    /// approved, which consumes the balance.
     pub async fn approve(
         &self,
         org_id: Uuid,
         absence_id: Uuid,
         approver_id: Uuid,
         approver_role: UserRole,
+        comment: Option<String>,
     ) -> Result<AbsenceResponse, AppError> {
         // Check permission
         if approver_role == UserRole::Employee {
@@ -187,6 +360,11 @@ impl AbsenceService {
             ));
         }
 
+        // Closed pay periods are locked
+        self.pay_period_repo
+            .ensure_open(org_id, absence.start_date, absence.end_date)
+            .await?;
+
         // For managers, verify they manage a team the user belongs to
         if approver_role == UserRole::Manager {
             self.verify_manager_permission(org_id, approver_id, absence.user_id)
@@ -199,6 +377,43 @@ impl AbsenceService {
             .find_by_id(org_id, absence.type_id)
             .await?;
 
+        // Find the step awaiting approval
+        let chain = self.approval_chain_for(&absence).await?;
+        let approvals = self.absence_repo.list_approvals(org_id, absence_id).await?;
+        if approvals
+            .iter()
+            .any(|approval| approval.approver_id == Some(approver_id))
+        {
+            return Err(AppError::Forbidden(
+                "You have already approved this absence".to_string(),
+            ));
+        }
+        let step = approvals.len();
+        let required_role = chain[step.min(chain.len() - 1)];
+        if approver_role < required_role {
+            return Err(AppError::Forbidden(format!(
+                "This approval step requires the {:?} role",
+                required_role
+            )));
+        }
+
+        self.absence_repo
+            .record_approval(NewAbsenceApproval {
+                organization_id: org_id,
+                absence_id,
+                step_order: step as i16 + 1,
+                approver_id: Some(approver_id),
+                comment,
+            })
+            .await?;
+
+        // Partial approval: hand over to the next step
+        if step + 1 < chain.len() {
+            self.notify_next_approvers(&absence, &absence_type, chain[step + 1], approver_id)
+                .await;
+            return self.build_response(&absence).await;
+        }
+
         // Update status
         let update = AbsenceUpdate {
             status: Some(AbsenceStatus::Approved),
@@ -218,7 +433,7 @@ impl AbsenceService {
                     absence.user_id,
                     absence.type_id,
                     year,
-                    absence.days_count,
+                    absence.balance_amount(),
                 )
                 .await?;
         }
@@ -277,6 +492,11 @@ impl AbsenceService {
             ));
         }
 
+        // Closed pay periods are locked
+        self.pay_period_repo
+            .ensure_open(org_id, absence.start_date, absence.end_date)
+            .await?;
+
         // For managers, verify they manage a team the user belongs to
         if approver_role == UserRole::Manager {
             self.verify_manager_permission(org_id, approver_id, absence.user_id)
@@ -366,6 +586,11 @@ impl AbsenceService {
             }
         }
 
+        // Closed pay periods are locked
+        self.pay_period_repo
+            .ensure_open(org_id, absence.start_date, absence.end_date)
+            .await?;
+
         // Get absence type to check if balance should be restored
         let absence_type = self
             .absence_type_repo
@@ -389,7 +614,7 @@ impl AbsenceService {
                     absence.user_id,
                     absence.type_id,
                     year,
-                    absence.days_count,
+                    absence.balance_amount(),
                 )
                 .await?;
         }
@@ -529,37 +754,127 @@ impl AbsenceService {
         Ok(responses)
     }
 
-    /// Calculate working days between two dates (excluding weekends and closed days)
+    /// Days charged on each date of an absence, excluding closed days
+    ///
+    /// Working days follow the user's effective schedule, or the organization's
+    /// working week for users without one.
     async fn calculate_working_days(
         &self,
         org_id: Uuid,
-        start_date: NaiveDate,
-        end_date: NaiveDate,
-    ) -> Result<f64, AppError> {
+        schedule: Option<&WorkScheduleWithDays>,
+        period: &AbsencePeriod,
+    ) -> Result<Vec<(NaiveDate, f64)>, AppError> {
         // Get closed days in range
         let closed_days = self
             .closed_day_repo
-            .list_range(org_id, start_date, end_date)
+            .list_range(org_id, period.start_date, period.end_date)
             .await?;
 
-        let mut working_days = 0.0;
-        let mut current = start_date;
+        let working_days = match schedule {
+            Some(_) => Vec::new(),
+            None => self.get_settings(org_id).await?.working_days,
+        };
 
-        while current <= end_date {
-            let weekday = current.weekday();
+        Ok(charged_days(period, &closed_days, |date| {
+            is_working_day(schedule, &working_days, date)
+        }))
+    }
+
+    /// Minutes the user is scheduled to work on a date, 0 on closed days
+    async fn scheduled_minutes(
+        &self,
+        org_id: Uuid,
+        schedule: Option<&WorkScheduleWithDays>,
+        date: NaiveDate,
+    ) -> Result<i64, AppError> {
+        let closed_days = self.closed_day_repo.list_range(org_id, date, date).await?;
+        if closed_days.contains(&date) {
+            return Ok(0);
+        }
 
-            // Skip weekends
-            if weekday != Weekday::Sat && weekday != Weekday::Sun {
-                // Skip closed days
-                if !closed_days.contains(&current) {
-                    working_days += 1.0;
+        Ok(schedule
+            .and_then(|s| s.day_for(date))
+            .map_or(0, |day| day.work_minutes()))
+    }
+
+    /// Roles approving an absence, one per step of its type's chain
+    async fn approval_chain_for(&self, absence: &Absence) -> Result<Vec<UserRole>, AppError> {
+        let steps = self
+            .absence_type_repo
+            .list_approval_steps(absence.organization_id, absence.type_id)
+            .await?;
+        Ok(approval_chain(
+            &steps,
+            absence.days_count.to_f64().unwrap_or(0.0),
+        ))
+    }
+
+    /// Notify the approvers of the next step of a partially approved absence
+    async fn notify_next_approvers(
+        &self,
+        absence: &Absence,
+        absence_type: &AbsenceType,
+        role: UserRole,
+        approver_id: Uuid,
+    ) {
+        let org_id = absence.organization_id;
+        let mut recipients = Vec::new();
+        if role == UserRole::Manager {
+            let teams = self
+                .team_repo
+                .get_user_teams(org_id, absence.user_id)
+                .await
+                .unwrap_or_default();
+            recipients.extend(teams.into_iter().filter_map(|t| t.manager_id));
+        } else {
+            let filter = UserFilter {
+                role: Some(role),
+                search: None,
+                team_id: None,
+            };
+            let pagination = Pagination {
+                page: 1,
+                per_page: 100,
+            };
+            match self.user_repo.list(org_id, &filter, &pagination).await {
+                Ok((users, _)) => recipients.extend(users.into_iter().map(|u| u.id)),
+                Err(e) => {
+                    tracing::warn!(absence_id = %absence.id, error = %e, "Failed to load approvers");
                 }
             }
-
-            current = current.succ_opt().unwrap_or(current);
         }
+        recipients.sort();
+        recipients.dedup();
 
-        Ok(working_days)
+        let notification_service = NotificationService::new(self.absence_repo.pool().clone());
+        for recipient in recipients {
+            if recipient == absence.user_id || recipient == approver_id {
+                continue;
+            }
+            if let Err(e) = notification_service
+                .create_notification(
+                    org_id,
+                    recipient,
+                    NotificationType::AbsencePending,
+                    "Absence Awaiting Approval".to_string(),
+                    format!(
+                        "A {} request from {} to {} awaits your approval.",
+                        absence_type.name,
+                        absence.start_date.format("%Y-%m-%d"),
+                        absence.end_date.format("%Y-%m-%d")
+                    ),
+                    None,
+                )
+                .await
+            {
+                tracing::warn!(
+                    user_id = %recipient,
+                    absence_id = %absence.id,
+                    error = %e,
+                    "Failed to create pending approval notification"
+                );
+            }
+        }
     }
 
     /// Verify manager can manage this user
@@ -613,6 +928,27 @@ impl AbsenceService {
             None
         };
 
+        // Get the approval chain and the steps approved so far
+        let required_approvals = self.approval_chain_for(absence).await?;
+        let mut approvals = Vec::new();
+        for approval in self
+            .absence_repo
+            .list_approvals(absence.organization_id, absence.id)
+            .await?
+        {
+            let approver_name = match approval.approver_id {
+                Some(id) => Some(self.get_user_info(id).await?.0),
+                None => None,
+            };
+            approvals.push(AbsenceApprovalResponse {
+                step_order: approval.step_order,
+                approver_id: approval.approver_id,
+                approver_name,
+                comment: approval.comment,
+                approved_at: approval.approved_at,
+            });
+        }
+
         Ok(AbsenceResponse {
             id: absence.id,
             organization_id: absence.organization_id,
@@ -628,6 +964,11 @@ impl AbsenceService {
             type_color: absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
             start_date: absence.start_date,
             end_date: absence.end_date,
+            start_half: absence.start_half,
+            end_half: absence.end_half,
+            start_time: absence.start_time,
+            end_time: absence.end_time,
+            hours: absence.hours.as_ref().and_then(|h| h.to_f64()),
             days_count: absence.days_count.to_f64().unwrap_or(0.0),
             status: absence.status,
             reason: absence.reason.clone(),
@@ -635,6 +976,8 @@ impl AbsenceService {
             approved_by: absence.approved_by,
             approver_name,
             approved_at: absence.approved_at,
+            required_approvals,
+            approvals,
             created_at: absence.created_at,
         })
     }
@@ -661,3 +1004,213 @@ impl AbsenceService {
         Ok((format!("{} {}", first_name, last_name), email))
     }
 }
+
+/// Check the halves taken on the start and end dates
+///
+/// An absence spanning several days can only start in the afternoon and end in the
+/// morning. A single-day absence takes the half given on either side, both sides
+/// then hold the same value.
+fn normalize_halves(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    start_half: AbsenceHalfDay,
+    end_half: AbsenceHalfDay,
+) -> Result<(AbsenceHalfDay, AbsenceHalfDay), AppError> {
+    if start_date == end_date {
+        let half = match (start_half, end_half) {
+            (AbsenceHalfDay::Full, half) | (half, AbsenceHalfDay::Full) => half,
+            (a, b) if a == b => a,
+            _ => {
+                return Err(AppError::ValidationError(
+                    "A single-day absence takes either the morning or the afternoon".to_string(),
+                ))
+            }
+        };
+        return Ok((half, half));
+    }
+    if start_half == AbsenceHalfDay::Morning {
+        return Err(AppError::ValidationError(
+            "An absence spanning several days can only start in the afternoon".to_string(),
+        ));
+    }
+    if end_half == AbsenceHalfDay::Afternoon {
+        return Err(AppError::ValidationError(
+            "An absence spanning several days can only end in the morning".to_string(),
+        ));
+    }
+    Ok((start_half, end_half))
+}
+
+/// Display name of an absence unit in messages
+fn unit_name(unit: AbsenceUnit) -> &'static str {
+    match unit {
+        AbsenceUnit::Days => "days",
+        AbsenceUnit::Hours => "hours",
+    }
+}
+
+/// Whether work is expected on a date: scheduled work for users with a schedule,
+/// otherwise the organization's working week
+fn is_working_day(
+    schedule: Option<&WorkScheduleWithDays>,
+    working_days: &[i16],
+    date: NaiveDate,
+) -> bool {
+    match schedule {
+        Some(schedule) => schedule
+            .day_for(date)
+            .is_some_and(|day| day.work_minutes() > 0),
+        None => working_days.contains(&(date.weekday().num_days_from_monday() as i16)),
+    }
+}
+
+/// Days charged on each date of an absence
+/// Working dates that are not closed count for 1, or 0.5 when only half is taken
+fn charged_days(
+    period: &AbsencePeriod,
+    closed_days: &[NaiveDate],
+    is_working_day: impl Fn(NaiveDate) -> bool,
+) -> Vec<(NaiveDate, f64)> {
+    let mut charged = Vec::new();
+    let mut current = period.start_date;
+    while current <= period.end_date {
+        let days = if is_working_day(current) && !closed_days.contains(&current) {
+            let (morning, afternoon) = period.parts_on(current);
+            (morning as u8 + afternoon as u8) as f64 / 2.0
+        } else {
+            0.0
+        };
+        charged.push((current, days));
+        current = current.succ_opt().unwrap_or(current);
+    }
+    charged
+}
+
+/// Check a working week, returning its days sorted and deduplicated
+fn normalize_working_days(mut working_days: Vec<i16>) -> Result<Vec<i16>, AppError> {
+    if working_days.iter().any(|day| !(0..=6).contains(day)) {
+        return Err(AppError::ValidationError(
+            "Working days must be between 0 (Monday) and 6 (Sunday)".to_string(),
+        ));
+    }
+    working_days.sort_unstable();
+    working_days.dedup();
+    if working_days.is_empty() {
+        return Err(AppError::ValidationError(
+            "At least one working day is required".to_string(),
+        ));
+    }
+    Ok(working_days)
+}
+
+/// Check the time range of an hourly absence
+fn validate_hourly_times(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    start_time: Option<NaiveTime>,
+    end_time: Option<NaiveTime>,
+) -> Result<(NaiveTime, NaiveTime), AppError> {
+    let (Some(start_time), Some(end_time)) = (start_time, end_time) else {
+        return Err(AppError::ValidationError(
+            "Start and end times are required for hourly absence types".to_string(),
+        ));
+    };
+    if start_date != end_date {
+        return Err(AppError::ValidationError(
+            "An hourly absence must start and end on the same date".to_string(),
+        ));
+    }
+    if end_time <= start_time {
+        return Err(AppError::ValidationError(
+            "End time must be after start time".to_string(),
+        ));
+    }
+    Ok((start_time, end_time))
+}
+
+/// Days taken by an hourly absence: its share of the minutes scheduled that date
+/// Nothing is taken on a date without scheduled work
+fn hourly_days(minutes: i64, scheduled_minutes: i64) -> f64 {
+    if scheduled_minutes <= 0 {
+        return 0.0;
+    }
+    (minutes as f64 / scheduled_minutes as f64).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_halves() {
+        use AbsenceHalfDay::*;
+
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let next = NaiveDate::from_ymd_opt(2026, 3, 3).unwrap();
+
+        assert_eq!(
+            normalize_halves(day, day, Full, Morning).unwrap(),
+            (Morning, Morning)
+        );
+        assert_eq!(
+            normalize_halves(day, day, Afternoon, Afternoon).unwrap(),
+            (Afternoon, Afternoon)
+        );
+        assert!(normalize_halves(day, day, Morning, Afternoon).is_err());
+
+        assert_eq!(
+            normalize_halves(day, next, Afternoon, Morning).unwrap(),
+            (Afternoon, Morning)
+        );
+        assert!(normalize_halves(day, next, Morning, Full).is_err());
+        assert!(normalize_halves(day, next, Full, Afternoon).is_err());
+    }
+
+    #[test]
+    fn test_hourly_absences() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let next = NaiveDate::from_ymd_opt(2026, 3, 3).unwrap();
+        let at = |h: u32| NaiveTime::from_hms_opt(h, 0, 0);
+
+        assert_eq!(
+            validate_hourly_times(day, day, at(9), at(11)).unwrap(),
+            (at(9).unwrap(), at(11).unwrap())
+        );
+        assert!(validate_hourly_times(day, day, at(9), None).is_err());
+        assert!(validate_hourly_times(day, next, at(9), at(11)).is_err());
+        assert!(validate_hourly_times(day, day, at(11), at(9)).is_err());
+
+        // Two hours of an eight-hour day
+        assert_eq!(hourly_days(120, 480), 0.25);
+        assert_eq!(hourly_days(600, 480), 1.0);
+        assert_eq!(hourly_days(120, 0), 0.0);
+    }
+
+    #[test]
+    fn test_charged_days_follow_the_working_week() {
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        // Monday 2nd to Sunday 8th, half of Monday taken
+        let period = AbsencePeriod {
+            start_half: AbsenceHalfDay::Afternoon,
+            ..AbsencePeriod::days(date(2), date(8))
+        };
+        let total = |working_days: &[i16], closed: &[NaiveDate]| -> f64 {
+            charged_days(&period, closed, |d| is_working_day(None, working_days, d))
+                .iter()
+                .map(|(_, days)| days)
+                .sum()
+        };
+
+        // Full-timer, with the Wednesday closed
+        assert_eq!(total(&[0, 1, 2, 3, 4], &[]), 4.5);
+        assert_eq!(total(&[0, 1, 2, 3, 4], &[date(4)]), 3.5);
+        // Part-timer working Monday to Wednesday
+        assert_eq!(total(&[0, 1, 2], &[]), 2.5);
+        // Weekend worker
+        assert_eq!(total(&[5, 6], &[]), 2.0);
+
+        assert_eq!(normalize_working_days(vec![4, 0, 4]).unwrap(), vec![0, 4]);
+        assert!(normalize_working_days(vec![]).is_err());
+        assert!(normalize_working_days(vec![7]).is_err());
+    }
+}
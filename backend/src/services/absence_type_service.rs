@@ -1,9 +1,14 @@
+use bigdecimal::BigDecimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
+use crate::domain::enums::{AbsenceUnit, UserRole};
 use crate::error::AppError;
-use crate::models::{AbsenceType, AbsenceTypeResponse, AbsenceTypeUpdate, NewAbsenceType};
+use crate::models::{
+    AbsenceApprovalStepResponse, AbsenceType, AbsenceTypeResponse, AbsenceTypeUpdate,
+    NewAbsenceApprovalStep, NewAbsenceType,
+};
 use crate::repositories::AbsenceTypeRepository;
 
 /// Request to create an absence type
@@ -15,6 +20,8 @@ pub struct CreateAbsenceTypeRequest {
     pub requires_approval: Option<bool>,
     pub affects_balance: Option<bool>,
     pub is_paid: Option<bool>,
+    /// Unit of requests and balances (days when omitted)
+    pub unit: Option<AbsenceUnit>,
 }
 
 /// Request to update an absence type
@@ -26,8 +33,27 @@ pub struct UpdateAbsenceTypeRequest {
     pub requires_approval: Option<bool>,
     pub affects_balance: Option<bool>,
     pub is_paid: Option<bool>,
+    pub unit: Option<AbsenceUnit>,
 }
 
+/// One step of an approval chain, in request order
+#[derive(Debug, Deserialize)]
+pub struct ApprovalStepRequest {
+    /// Lowest role allowed to approve the step (manager or admin)
+    pub approver_role: UserRole,
+    /// Only require the step for absences of more than this many days
+    pub min_days: Option<f64>,
+}
+
+/// Request to replace the approval chain of an absence type
+#[derive(Debug, Deserialize)]
+pub struct UpdateApprovalStepsRequest {
+    pub steps: Vec<ApprovalStepRequest>,
+}
+
+/// Longest approval chain an absence type can have
+const MAX_APPROVAL_STEPS: usize = 5;
+
 /// Service for absence type operations
 pub struct AbsenceTypeService {
     absence_type_repo: AbsenceTypeRepository,
@@ -74,6 +100,7 @@ impl AbsenceTypeService {
             requires_approval: request.requires_approval.unwrap_or(true),
             affects_balance: request.affects_balance.unwrap_or(true),
             is_paid: request.is_paid.unwrap_or(true),
+            unit: request.unit.unwrap_or_default(),
         };
 
         let absence_type = self.absence_type_repo.create(new_type).await?;
@@ -144,6 +171,7 @@ impl AbsenceTypeService {
             requires_approval: request.requires_approval,
             affects_balance: request.affects_balance,
             is_paid: request.is_paid,
+            unit: request.unit,
             updated_at: None,
         };
 
@@ -163,4 +191,113 @@ impl AbsenceTypeService {
     pub async fn get_type(&self, org_id: Uuid, type_id: Uuid) -> Result<AbsenceType, AppError> {
         self.absence_type_repo.find_by_id(org_id, type_id).await
     }
+
+    /// Get the approval chain of an absence type
+    pub async fn get_approval_steps(
+        &self,
+        org_id: Uuid,
+        type_id: Uuid,
+    ) -> Result<Vec<AbsenceApprovalStepResponse>, AppError> {
+        self.absence_type_repo.find_by_id(org_id, type_id).await?;
+
+        let steps = self
+            .absence_type_repo
+            .list_approval_steps(org_id, type_id)
+            .await?;
+        Ok(steps.into_iter().map(Into::into).collect())
+    }
+
+    /// Replace the approval chain of an absence type
+    ///
+    /// An empty chain falls back to a single manager approval.
+    pub async fn update_approval_steps(
+        &self,
+        org_id: Uuid,
+        type_id: Uuid,
+        request: UpdateApprovalStepsRequest,
+    ) -> Result<Vec<AbsenceApprovalStepResponse>, AppError> {
+        validate_approval_steps(&request.steps)?;
+        self.absence_type_repo.find_by_id(org_id, type_id).await?;
+
+        let steps = request
+            .steps
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| NewAbsenceApprovalStep {
+                organization_id: org_id,
+                absence_type_id: type_id,
+                step_order: index as i16 + 1,
+                approver_role: step.approver_role,
+                min_days: step
+                    .min_days
+                    .map(|d| BigDecimal::try_from(d).unwrap_or_default().round(2)),
+            })
+            .collect();
+
+        let steps = self
+            .absence_type_repo
+            .replace_approval_steps(org_id, type_id, steps)
+            .await?;
+        Ok(steps.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Check the steps of an approval chain
+fn validate_approval_steps(steps: &[ApprovalStepRequest]) -> Result<(), AppError> {
+    if steps.len() > MAX_APPROVAL_STEPS {
+        return Err(AppError::ValidationError(format!(
+            "An approval chain can have at most {} steps",
+            MAX_APPROVAL_STEPS
+        )));
+    }
+
+    for step in steps {
+        if !matches!(step.approver_role, UserRole::Manager | UserRole::Admin) {
+            return Err(AppError::ValidationError(
+                "Approval steps must be assigned to managers or admins".to_string(),
+            ));
+        }
+        if step
+            .min_days
+            .is_some_and(|d| !d.is_finite() || !(0.0..=366.0).contains(&d))
+        {
+            return Err(AppError::ValidationError(
+                "Minimum days must be between 0 and 366".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(approver_role: UserRole, min_days: Option<f64>) -> ApprovalStepRequest {
+        ApprovalStepRequest {
+            approver_role,
+            min_days,
+        }
+    }
+
+    #[test]
+    fn test_validate_approval_steps() {
+        assert!(validate_approval_steps(&[]).is_ok());
+        assert!(validate_approval_steps(&[
+            step(UserRole::Manager, None),
+            step(UserRole::Admin, Some(5.0)),
+        ])
+        .is_ok());
+
+        assert!(validate_approval_steps(&[step(UserRole::Employee, None)]).is_err());
+        assert!(validate_approval_steps(&[step(UserRole::SuperAdmin, None)]).is_err());
+        assert!(validate_approval_steps(&[step(UserRole::Admin, Some(-1.0))]).is_err());
+        assert!(validate_approval_steps(&[step(UserRole::Admin, Some(f64::NAN))]).is_err());
+
+        let too_long: Vec<_> = (0..=MAX_APPROVAL_STEPS)
+            .map(|_| step(UserRole::Manager, None))
+            .collect();
+        assert!(validate_approval_steps(&too_long).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc};
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
@@ -12,8 +12,11 @@ use crate::models::{
     PaginatedBreakPolicies, Pagination, StartBreakRequest, UpdateBreakPolicyRequest,
 };
 use crate::repositories::{
-    BreakRepository, OrganizationRepository, TeamRepository, UserRepository,
+    BreakRepository, OrganizationRepository, TeamRepository, UserRepository, WorkScheduleRepository,
 };
+use crate::services::{PresenceEventKind, PresenceService};
+use crate::utils::datetime::time_range_on;
+use crate::utils::timezone::to_local;
 
 /// Service for break policies, windows, and entries
 pub struct BreakService {
@@ -21,6 +24,7 @@ pub struct BreakService {
     team_repo: TeamRepository,
     org_repo: OrganizationRepository,
     user_repo: UserRepository,
+    schedule_repo: WorkScheduleRepository,
 }
 
 impl BreakService {
@@ -29,7 +33,8 @@ impl BreakService {
             break_repo: BreakRepository::new(pool.clone()),
             team_repo: TeamRepository::new(pool.clone()),
             org_repo: OrganizationRepository::new(pool.clone()),
-            user_repo: UserRepository::new(pool),
+            user_repo: UserRepository::new(pool.clone()),
+            schedule_repo: WorkScheduleRepository::new(pool),
         }
     }
 
@@ -100,6 +105,7 @@ impl BreakService {
                     min_duration_minutes: window_req.min_duration_minutes,
                     max_duration_minutes: window_req.max_duration_minutes,
                     is_mandatory: window_req.is_mandatory,
+                    week_index: window_req.week_index,
                 };
                 self.break_repo.create_window(new_window).await?;
             }
@@ -217,6 +223,12 @@ impl BreakService {
             ));
         }
 
+        if request.week_index.is_some_and(|week| week < 0) {
+            return Err(AppError::ValidationError(
+                "Week index cannot be negative".to_string(),
+            ));
+        }
+
         // Validate durations
         if request.min_duration_minutes <= 0 {
             return Err(AppError::ValidationError(
@@ -237,6 +249,7 @@ impl BreakService {
             min_duration_minutes: request.min_duration_minutes,
             max_duration_minutes: request.max_duration_minutes,
             is_mandatory: request.is_mandatory,
+            week_index: request.week_index,
         };
 
         let window = self.break_repo.create_window(new_window).await?;
@@ -249,6 +262,7 @@ impl BreakService {
             min_duration_minutes: window.min_duration_minutes,
             max_duration_minutes: window.max_duration_minutes,
             is_mandatory: window.is_mandatory,
+            week_index: window.week_index,
         })
     }
 
@@ -301,6 +315,7 @@ impl BreakService {
                 min_duration_minutes: w.min_duration_minutes,
                 max_duration_minutes: w.max_duration_minutes,
                 is_mandatory: w.is_mandatory,
+                week_index: w.week_index,
             })
             .collect())
     }
@@ -360,6 +375,17 @@ impl BreakService {
         };
 
         let entry = self.break_repo.create_entry(new_entry).await?;
+
+        PresenceService::new(self.pool().clone())
+            .publish(
+                PresenceEventKind::BreakStarted,
+                org_id,
+                user_id,
+                clock_entry_id,
+                entry.break_start,
+            )
+            .await;
+
         self.build_entry_response(&entry).await
     }
 
@@ -395,6 +421,16 @@ impl BreakService {
             .update_entry(org_id, active_break.id, update)
             .await?;
 
+        PresenceService::new(self.pool().clone())
+            .publish(
+                PresenceEventKind::BreakEnded,
+                org_id,
+                user_id,
+                entry.clock_entry_id,
+                now,
+            )
+            .await;
+
         self.build_entry_response(&entry).await
     }
 
@@ -488,21 +524,39 @@ impl BreakService {
 
         match policy.tracking_mode {
             BreakTrackingMode::AutoDeduct => {
-                // Get the break window for the day
-                let day_of_week = clock_in.weekday().num_days_from_sunday() as i16;
+                // Break windows are expressed in the organization's local time
+                let tz = self.org_repo.get_timezone(org_id).await?;
+                let local_clock_in = to_local(clock_in, tz);
+
+                // Get the break window for the day, in the user's rotation week
+                let day_of_week = local_clock_in.weekday().num_days_from_sunday() as i16;
+                let week = self
+                    .schedule_repo
+                    .get_rotation_week(org_id, user_id, local_clock_in.date())
+                    .await?;
                 let window = self
                     .break_repo
-                    .get_window_for_day(policy.id, day_of_week)
+                    .get_window_for_day(policy.id, day_of_week, week)
                     .await?;
 
                 let total_minutes = if let Some(w) = window {
                     // Check if clock period overlaps with break window
                     let clock_out_time = clock_out.unwrap_or_else(Utc::now);
-                    let clock_in_time = clock_in.time();
-                    let clock_out_naive = clock_out_time.time();
+                    let local_clock_out = to_local(clock_out_time, tz);
+
+                    // The window belongs to the clock-in day but may fall after midnight
+                    // (overnight shifts), so check it on both calendar days
+                    let clock_in_date = local_clock_in.date();
+                    let overlaps = [clock_in_date, clock_in_date + Duration::days(1)]
+                        .into_iter()
+                        .any(|date| {
+                            let (window_start, window_end) =
+                                time_range_on(date, w.window_start, w.window_end);
+                            local_clock_in <= window_end && local_clock_out >= window_start
+                        });
 
                     // Simple overlap check: if clocked during break window, deduct mandatory minimum
-                    if clock_in_time <= w.window_end && clock_out_naive >= w.window_start {
+                    if overlaps {
                         w.min_duration_minutes
                     } else {
                         0
@@ -607,6 +661,7 @@ impl BreakService {
                 min_duration_minutes: w.min_duration_minutes,
                 max_duration_minutes: w.max_duration_minutes,
                 is_mandatory: w.is_mandatory,
+                week_index: w.week_index,
             })
             .collect();
 
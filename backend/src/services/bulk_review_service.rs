@@ -0,0 +1,449 @@
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::models::{
+    BulkReviewFilter, BulkReviewItemResult, BulkReviewRequest, BulkReviewResponse,
+};
+use crate::repositories::{
+    AbsenceRepository, ClockRepository, OrganizationRepository, TeamRepository,
+};
+use crate::services::{AbsenceService, ClockService};
+use crate::utils::timezone::local_day_bounds;
+
+/// Largest number of items reviewed in one request
+const MAX_BULK_ITEMS: usize = 200;
+
+/// Service approving or rejecting clock entries and absences in bulk
+///
+/// Each item goes through the same path as a single approval or rejection,
+/// so permission checks and notifications are identical. A failing item is
+/// reported in the results and does not stop the others.
+pub struct BulkReviewService {
+    clock_service: ClockService,
+    absence_service: AbsenceService,
+    clock_repo: ClockRepository,
+    absence_repo: AbsenceRepository,
+    team_repo: TeamRepository,
+    org_repo: OrganizationRepository,
+}
+
+impl BulkReviewService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            clock_service: ClockService::new(pool.clone()),
+            absence_service: AbsenceService::new(pool.clone()),
+            clock_repo: ClockRepository::new(pool.clone()),
+            absence_repo: AbsenceRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool),
+        }
+    }
+
+    /// Approve several pending clock entries
+    pub async fn approve_entries(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        request: BulkReviewRequest,
+    ) -> Result<BulkReviewResponse, AppError> {
+        let ids = self
+            .select_entries(
+                org_id,
+                approver_id,
+                approver_role,
+                request.ids,
+                request.filter,
+            )
+            .await?;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            let outcome = self
+                .clock_service
+                .approve_entry(org_id, id, approver_id, approver_role)
+                .await
+                .map(|_| ());
+            results.push(item_result(id, outcome));
+        }
+
+        Ok(BulkReviewResponse::from_results(results))
+    }
+
+    /// Reject several pending clock entries with the same reason
+    pub async fn reject_entries(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        request: BulkReviewRequest,
+    ) -> Result<BulkReviewResponse, AppError> {
+        let ids = self
+            .select_entries(
+                org_id,
+                approver_id,
+                approver_role,
+                request.ids,
+                request.filter,
+            )
+            .await?;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            let outcome = self
+                .clock_service
+                .reject_entry(
+                    org_id,
+                    id,
+                    approver_id,
+                    approver_role,
+                    request.reason.clone(),
+                )
+                .await
+                .map(|_| ());
+            results.push(item_result(id, outcome));
+        }
+
+        Ok(BulkReviewResponse::from_results(results))
+    }
+
+    /// Approve several pending absences
+    pub async fn approve_absences(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        request: BulkReviewRequest,
+    ) -> Result<BulkReviewResponse, AppError> {
+        let ids = self
+            .select_absences(
+                org_id,
+                approver_id,
+                approver_role,
+                request.ids,
+                request.filter,
+            )
+            .await?;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            let outcome = self
+                .absence_service
+                .approve(org_id, id, approver_id, approver_role, None)
+                .await
+                .map(|_| ());
+            results.push(item_result(id, outcome));
+        }
+
+        Ok(BulkReviewResponse::from_results(results))
+    }
+
+    /// Reject several pending absences with the same reason
+    pub async fn reject_absences(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        request: BulkReviewRequest,
+    ) -> Result<BulkReviewResponse, AppError> {
+        let ids = self
+            .select_absences(
+                org_id,
+                approver_id,
+                approver_role,
+                request.ids,
+                request.filter,
+            )
+            .await?;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            let outcome = self
+                .absence_service
+                .reject(
+                    org_id,
+                    id,
+                    approver_id,
+                    approver_role,
+                    request.reason.clone(),
+                )
+                .await
+                .map(|_| ());
+            results.push(item_result(id, outcome));
+        }
+
+        Ok(BulkReviewResponse::from_results(results))
+    }
+
+    /// Clock entries targeted by a request
+    async fn select_entries(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        ids: Option<Vec<Uuid>>,
+        filter: Option<BulkReviewFilter>,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let filter = match parse_selection(approver_role, ids, filter)? {
+            Selection::Ids(ids) => return Ok(ids),
+            Selection::Filter(filter) => filter,
+        };
+
+        let user_ids = self
+            .filter_users(org_id, approver_id, approver_role, &filter)
+            .await?;
+        let tz = self.org_repo.get_timezone(org_id).await?;
+
+        self.clock_repo
+            .list_pending_ids(
+                org_id,
+                user_ids.as_deref(),
+                filter.start_date.map(|d| local_day_bounds(d, tz).0),
+                filter.end_date.map(|d| local_day_bounds(d, tz).1),
+                MAX_BULK_ITEMS as i64,
+            )
+            .await
+    }
+
+    /// Absences targeted by a request
+    async fn select_absences(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        ids: Option<Vec<Uuid>>,
+        filter: Option<BulkReviewFilter>,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let filter = match parse_selection(approver_role, ids, filter)? {
+            Selection::Ids(ids) => return Ok(ids),
+            Selection::Filter(filter) => filter,
+        };
+
+        let user_ids = self
+            .filter_users(org_id, approver_id, approver_role, &filter)
+            .await?;
+
+        self.absence_repo
+            .list_pending_ids(
+                org_id,
+                user_ids.as_deref(),
+                filter.start_date,
+                filter.end_date,
+                MAX_BULK_ITEMS as i64,
+            )
+            .await
+    }
+
+    /// Users whose items a filter selects (`None` for the whole organization)
+    ///
+    /// Managers are limited to members of the teams they manage.
+    async fn filter_users(
+        &self,
+        org_id: Uuid,
+        approver_id: Uuid,
+        approver_role: UserRole,
+        filter: &BulkReviewFilter,
+    ) -> Result<Option<Vec<Uuid>>, AppError> {
+        let mut team_ids = Vec::new();
+        if approver_role == UserRole::Manager {
+            let managed_teams = self
+                .team_repo
+                .get_managed_teams(org_id, approver_id)
+                .await?;
+            team_ids = managed_teams.into_iter().map(|t| t.id).collect();
+
+            if let Some(team_id) = filter.team_id {
+                if !team_ids.contains(&team_id) {
+                    return Err(AppError::Forbidden(
+                        "You can only review items of the teams you manage".to_string(),
+                    ));
+                }
+                team_ids = vec![team_id];
+            }
+        } else if let Some(team_id) = filter.team_id {
+            self.team_repo.find_by_id(org_id, team_id).await?;
+            team_ids = vec![team_id];
+        }
+
+        let mut user_ids = None;
+        if approver_role == UserRole::Manager || filter.team_id.is_some() {
+            let mut member_ids = Vec::new();
+            for team_id in team_ids {
+                for member in self.team_repo.list_members(team_id).await? {
+                    if !member_ids.contains(&member.id) {
+                        member_ids.push(member.id);
+                    }
+                }
+            }
+            user_ids = Some(member_ids);
+        }
+
+        Ok(match (user_ids, filter.user_id) {
+            (Some(members), Some(user_id)) => {
+                Some(members.into_iter().filter(|id| *id == user_id).collect())
+            }
+            (None, Some(user_id)) => Some(vec![user_id]),
+            (user_ids, None) => user_ids,
+        })
+    }
+}
+
+/// Items targeted by a bulk review
+#[derive(Debug)]
+enum Selection {
+    Ids(Vec<Uuid>),
+    Filter(BulkReviewFilter),
+}
+
+/// Check the shape of a bulk review request
+fn parse_selection(
+    approver_role: UserRole,
+    ids: Option<Vec<Uuid>>,
+    filter: Option<BulkReviewFilter>,
+) -> Result<Selection, AppError> {
+    if approver_role == UserRole::Employee {
+        return Err(AppError::Forbidden(
+            "Only managers and admins can review in bulk".to_string(),
+        ));
+    }
+
+    match (ids, filter) {
+        (Some(ids), None) => {
+            if ids.is_empty() {
+                return Err(AppError::ValidationError("No ids were given".to_string()));
+            }
+            let mut unique = Vec::with_capacity(ids.len());
+            for id in ids {
+                if !unique.contains(&id) {
+                    unique.push(id);
+                }
+            }
+            if unique.len() > MAX_BULK_ITEMS {
+                return Err(AppError::ValidationError(format!(
+                    "At most {} items can be reviewed at once",
+                    MAX_BULK_ITEMS
+                )));
+            }
+            Ok(Selection::Ids(unique))
+        }
+        (None, Some(filter)) => {
+            if let (Some(start), Some(end)) = (filter.start_date, filter.end_date) {
+                if end < start {
+                    return Err(AppError::ValidationError(
+                        "End date must be on or after start date".to_string(),
+                    ));
+                }
+            }
+            Ok(Selection::Filter(filter))
+        }
+        _ => Err(AppError::ValidationError(
+            "Provide either ids or a filter".to_string(),
+        )),
+    }
+}
+
+/// Result of one item; unexpected errors are logged and reported generically
+fn item_result(id: Uuid, outcome: Result<(), AppError>) -> BulkReviewItemResult {
+    let error = match outcome {
+        Ok(()) => None,
+        Err(AppError::ValidationError(message))
+        | Err(AppError::NotFound(message))
+        | Err(AppError::Forbidden(message))
+        | Err(AppError::Conflict(message)) => Some(message),
+        Err(e) => {
+            tracing::warn!(item_id = %id, error = %e, "Bulk review item failed");
+            Some("An unexpected error occurred".to_string())
+        }
+    };
+
+    BulkReviewItemResult {
+        id,
+        success: error.is_none(),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selection_deduplicates_ids() {
+        let id = Uuid::new_v4();
+        let selection = parse_selection(UserRole::Manager, Some(vec![id, id]), None).unwrap();
+
+        assert!(matches!(selection, Selection::Ids(ids) if ids == vec![id]));
+    }
+
+    #[test]
+    fn test_parse_selection_requires_ids_or_filter() {
+        let ids = Some(vec![Uuid::new_v4()]);
+        let filter = Some(BulkReviewFilter::default());
+
+        assert!(parse_selection(UserRole::Admin, None, None).is_err());
+        assert!(parse_selection(UserRole::Admin, ids, filter).is_err());
+        assert!(parse_selection(UserRole::Admin, Some(vec![]), None).is_err());
+    }
+
+    #[test]
+    fn test_parse_selection_limits() {
+        let ids = (0..=MAX_BULK_ITEMS).map(|_| Uuid::new_v4()).collect();
+        assert!(parse_selection(UserRole::Admin, Some(ids), None).is_err());
+
+        let filter = BulkReviewFilter {
+            start_date: chrono::NaiveDate::from_ymd_opt(2024, 6, 10),
+            end_date: chrono::NaiveDate::from_ymd_opt(2024, 6, 1),
+            ..Default::default()
+        };
+        assert!(parse_selection(UserRole::Admin, None, Some(filter)).is_err());
+    }
+
+    #[test]
+    fn test_parse_selection_employee_forbidden() {
+        let result = parse_selection(UserRole::Employee, Some(vec![Uuid::new_v4()]), None);
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_item_result() {
+        let id = Uuid::new_v4();
+
+        let ok = item_result(id, Ok(()));
+        assert!(ok.success);
+        assert!(ok.error.is_none());
+
+        let failed = item_result(
+            id,
+            Err(AppError::ValidationError(
+                "Only pending absences can be approved".to_string(),
+            )),
+        );
+        assert!(!failed.success);
+        assert_eq!(
+            failed.error.as_deref(),
+            Some("Only pending absences can be approved")
+        );
+
+        let internal = item_result(id, Err(AppError::InternalError));
+        assert_eq!(
+            internal.error.as_deref(),
+            Some("An unexpected error occurred")
+        );
+    }
+
+    #[test]
+    fn test_response_counts() {
+        let response = BulkReviewResponse::from_results(vec![
+            item_result(Uuid::new_v4(), Ok(())),
+            item_result(Uuid::new_v4(), Err(AppError::InternalError)),
+            item_result(Uuid::new_v4(), Ok(())),
+        ]);
+
+        assert_eq!(response.processed, 3);
+        assert_eq!(response.succeeded, 2);
+        assert_eq!(response.failed, 1);
+    }
+}
@@ -0,0 +1,793 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::{
+    AnomalyKind, AnomalySeverity, AnomalyStatus, ClockEntrySource, UserRole,
+};
+use crate::error::AppError;
+use crate::models::{
+    AnomalySettingsResponse, ClockAnomaly, ClockAnomalyFilter, ClockAnomalyResponse, ClockEntry,
+    NewAnomalySettings, NewClockAnomaly, PaginatedClockAnomalies, Pagination,
+};
+use crate::repositories::{
+    AbsenceRepository, BreakRepository, ClockAnomalyRepository, ClockRepository,
+    ClosedDayRepository, OrganizationRepository, TeamRepository, WorkScheduleRepository,
+};
+use crate::services::ClockService;
+use crate::utils::timezone::to_local;
+
+/// Longest shift threshold an organization can configure
+const MAX_SHIFT_HOURS: i32 = 48;
+
+/// Largest number of clock-ins tolerated within the rapid clocking window
+const MAX_RAPID_CLOCK_ENTRIES: i32 = 50;
+
+/// Longest rapid clocking window
+const MAX_RAPID_CLOCK_WINDOW_MINUTES: i32 = 24 * 60;
+
+/// Request to update anomaly settings
+#[derive(Debug, Deserialize)]
+pub struct UpdateAnomalySettingsRequest {
+    pub max_shift_hours: i32,
+    pub rapid_clock_max_entries: i32,
+    pub rapid_clock_window_minutes: i32,
+}
+
+/// Request to dismiss an anomaly as legitimate
+#[derive(Debug, Deserialize)]
+pub struct DismissClockAnomalyRequest {
+    pub note: Option<String>,
+}
+
+/// Request to resolve an anomaly after acting on it
+#[derive(Debug, Deserialize)]
+pub struct ResolveClockAnomalyRequest {
+    pub note: Option<String>,
+    /// Also reject the clock entry; resolves every open anomaly of the entry
+    #[serde(default)]
+    pub reject_entry: bool,
+}
+
+/// Service detecting suspicious time data and managing the review queue
+///
+/// Closed entries are evaluated at clock out and again by the nightly job, which
+/// catches entries closed by the system or changed afterwards. Each entry gets at
+/// most one finding per kind, so re-evaluation never reopens a reviewed finding.
+pub struct ClockAnomalyService {
+    pool: DbPool,
+    anomaly_repo: ClockAnomalyRepository,
+    clock_repo: ClockRepository,
+    break_repo: BreakRepository,
+    absence_repo: AbsenceRepository,
+    closed_day_repo: ClosedDayRepository,
+    org_repo: OrganizationRepository,
+    team_repo: TeamRepository,
+    schedule_repo: WorkScheduleRepository,
+}
+
+impl ClockAnomalyService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            anomaly_repo: ClockAnomalyRepository::new(pool.clone()),
+            clock_repo: ClockRepository::new(pool.clone()),
+            break_repo: BreakRepository::new(pool.clone()),
+            absence_repo: AbsenceRepository::new(pool.clone()),
+            closed_day_repo: ClosedDayRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            pool,
+        }
+    }
+
+    // =====================
+    // Settings
+    // =====================
+
+    /// Get anomaly settings (defaults when none are set)
+    pub async fn get_settings(&self, org_id: Uuid) -> Result<AnomalySettingsResponse, AppError> {
+        Ok(self
+            .anomaly_repo
+            .find_settings(org_id)
+            .await?
+            .map(AnomalySettingsResponse::from)
+            .unwrap_or_default())
+    }
+
+    /// Create or replace anomaly settings
+    pub async fn update_settings(
+        &self,
+        org_id: Uuid,
+        request: UpdateAnomalySettingsRequest,
+    ) -> Result<AnomalySettingsResponse, AppError> {
+        validate_settings(&request)?;
+
+        let settings = self
+            .anomaly_repo
+            .upsert_settings(NewAnomalySettings {
+                organization_id: org_id,
+                max_shift_hours: request.max_shift_hours,
+                rapid_clock_max_entries: request.rapid_clock_max_entries,
+                rapid_clock_window_minutes: request.rapid_clock_window_minutes,
+            })
+            .await?;
+
+        Ok(AnomalySettingsResponse::from(settings))
+    }
+
+    // =====================
+    // Detection
+    // =====================
+
+    /// Evaluate a closed entry and record new findings
+    /// Returns the findings recorded by this evaluation
+    pub async fn evaluate_entry(&self, entry: &ClockEntry) -> Result<Vec<ClockAnomaly>, AppError> {
+        let Some(clock_out) = entry.clock_out else {
+            return Ok(Vec::new());
+        };
+
+        let org_id = entry.organization_id;
+        let settings = self.get_settings(org_id).await?;
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let local_in = to_local(entry.clock_in, tz);
+        let first_day = local_in.date();
+        let last_day = to_local(clock_out, tz).date();
+
+        let absences = self
+            .absence_repo
+            .get_for_date_range(org_id, first_day, last_day, Some(vec![entry.user_id]))
+            .await?
+            .into_iter()
+            .map(|a| (a.start_date, a.end_date))
+            .collect();
+        let closed_days = self
+            .closed_day_repo
+            .list_range(org_id, first_day, last_day)
+            .await?;
+
+        let window = Duration::minutes(settings.rapid_clock_window_minutes as i64);
+        let clock_ins_in_window = self
+            .clock_repo
+            .count_clock_ins_between(
+                org_id,
+                entry.user_id,
+                entry.clock_in - window,
+                entry.clock_in,
+            )
+            .await?;
+
+        let new_ip = match (&entry.clock_in_ip, entry.source) {
+            (Some(ip), ClockEntrySource::Clock) => {
+                let known = self
+                    .clock_repo
+                    .list_known_clock_in_ips(org_id, entry.user_id, entry.clock_in)
+                    .await?;
+                // A first recorded address is not suspicious
+                (!known.is_empty() && !known.contains(ip)).then(|| ip.clone())
+            }
+            _ => None,
+        };
+
+        // Break limits come from the policy window of the clock-in day
+        let max_break_minutes = match self
+            .break_repo
+            .get_effective_policy(org_id, entry.user_id)
+            .await?
+        {
+            Some((policy, _)) => {
+                let day_of_week = local_in.weekday().num_days_from_sunday() as i16;
+                let week = self
+                    .schedule_repo
+                    .get_rotation_week(org_id, entry.user_id, local_in.date())
+                    .await?;
+                self.break_repo
+                    .get_window_for_day(policy.id, day_of_week, week)
+                    .await?
+                    .map(|w| w.max_duration_minutes)
+            }
+            None => None,
+        };
+        let breaks = if max_break_minutes.is_some() {
+            self.break_repo
+                .get_entries_for_clock_entry(entry.id)
+                .await?
+                .into_iter()
+                .filter_map(|b| {
+                    let minutes = match b.duration_minutes {
+                        Some(minutes) => minutes as i64,
+                        None => (b.break_end? - b.break_start).num_minutes(),
+                    };
+                    Some((b.id, minutes))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let facts = EntryFacts {
+            duration_minutes: (clock_out - entry.clock_in).num_minutes(),
+            absences,
+            closed_days,
+            clock_ins_in_window,
+            new_ip,
+            breaks,
+            max_break_minutes,
+        };
+
+        let findings = detect_anomalies(&facts, &settings)
+            .into_iter()
+            .map(|finding| NewClockAnomaly {
+                organization_id: org_id,
+                user_id: entry.user_id,
+                clock_entry_id: entry.id,
+                break_entry_id: finding.break_entry_id,
+                kind: finding.kind,
+                severity: finding.severity,
+                message: finding.message,
+            })
+            .collect();
+
+        self.anomaly_repo.insert_new(findings).await
+    }
+
+    /// Evaluate entries (all organizations) closed or changed since an instant (background job)
+    /// Returns the number of new findings
+    pub async fn evaluate_since(&self, since: DateTime<Utc>) -> Result<usize, AppError> {
+        let entries = self.clock_repo.list_closed_since(since).await?;
+
+        let mut recorded = 0;
+        for entry in &entries {
+            match self.evaluate_entry(entry).await {
+                Ok(findings) => recorded += findings.len(),
+                Err(e) => {
+                    tracing::warn!(
+                        entry_id = %entry.id,
+                        error = %e,
+                        "Failed to evaluate clock anomalies"
+                    );
+                }
+            }
+        }
+
+        Ok(recorded)
+    }
+
+    // =====================
+    // Review queue
+    // =====================
+
+    /// List anomalies for review (Manager+ only)
+    /// Managers only see anomalies of members of the teams they manage
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        filter: ClockAnomalyFilter,
+        pagination: Pagination,
+    ) -> Result<PaginatedClockAnomalies, AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can review clock anomalies".to_string(),
+            ));
+        }
+
+        let user_ids = self
+            .scope_users(org_id, reviewer_id, reviewer_role, filter.team_id)
+            .await?;
+        let (anomalies, total) = self
+            .anomaly_repo
+            .list(org_id, &filter, user_ids.as_deref(), &pagination)
+            .await?;
+
+        let mut responses = Vec::with_capacity(anomalies.len());
+        for anomaly in &anomalies {
+            responses.push(self.build_response(anomaly).await?);
+        }
+
+        let total_pages = (total as f64 / pagination.per_page as f64).ceil() as i64;
+
+        Ok(PaginatedClockAnomalies {
+            data: responses,
+            total,
+            page: pagination.page,
+            per_page: pagination.per_page,
+            total_pages,
+        })
+    }
+
+    /// Get a single anomaly (Manager+ only)
+    pub async fn get(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<ClockAnomalyResponse, AppError> {
+        let anomaly = self
+            .find_in_scope(org_id, anomaly_id, reviewer_id, reviewer_role)
+            .await?;
+        self.build_response(&anomaly).await
+    }
+
+    /// Dismiss an anomaly as legitimate (Manager+ only)
+    pub async fn dismiss(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        request: DismissClockAnomalyRequest,
+    ) -> Result<ClockAnomalyResponse, AppError> {
+        let anomaly = self
+            .find_open(org_id, anomaly_id, reviewer_id, reviewer_role)
+            .await?;
+
+        let reviewed = self
+            .review(
+                org_id,
+                &[anomaly.id],
+                AnomalyStatus::Dismissed,
+                reviewer_id,
+                request.note,
+            )
+            .await?;
+        self.build_response(&reviewed).await
+    }
+
+    /// Resolve an anomaly after acting on it (Manager+ only)
+    ///
+    /// When `reject_entry` is set the clock entry is rejected and every open anomaly
+    /// of the entry is resolved along with this one.
+    pub async fn resolve(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        request: ResolveClockAnomalyRequest,
+    ) -> Result<ClockAnomalyResponse, AppError> {
+        let anomaly = self
+            .find_open(org_id, anomaly_id, reviewer_id, reviewer_role)
+            .await?;
+
+        let anomaly_ids = if request.reject_entry {
+            ClockService::new(self.pool.clone())
+                .reject_entry(
+                    org_id,
+                    anomaly.clock_entry_id,
+                    reviewer_id,
+                    reviewer_role,
+                    request.note.clone(),
+                )
+                .await?;
+            self.anomaly_repo
+                .list_open_ids_for_entry(org_id, anomaly.clock_entry_id)
+                .await?
+        } else {
+            vec![anomaly.id]
+        };
+
+        let reviewed = self
+            .review(
+                org_id,
+                &anomaly_ids,
+                AnomalyStatus::Resolved,
+                reviewer_id,
+                request.note,
+            )
+            .await?;
+        self.build_response(&reviewed).await
+    }
+
+    // =====================
+    // Helper Methods
+    // =====================
+
+    /// Users a reviewer may see, optionally narrowed to a team (`None` for the whole organization)
+    async fn scope_users(
+        &self,
+        org_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        team_id: Option<Uuid>,
+    ) -> Result<Option<Vec<Uuid>>, AppError> {
+        let team_ids = if reviewer_role == UserRole::Manager {
+            let managed: Vec<Uuid> = self
+                .team_repo
+                .get_managed_teams(org_id, reviewer_id)
+                .await?
+                .into_iter()
+                .map(|team| team.id)
+                .collect();
+            match team_id {
+                Some(team_id) if !managed.contains(&team_id) => {
+                    return Err(AppError::Forbidden(
+                        "You can only review anomalies of the teams you manage".to_string(),
+                    ));
+                }
+                Some(team_id) => vec![team_id],
+                None => managed,
+            }
+        } else {
+            match team_id {
+                Some(team_id) => {
+                    self.team_repo.find_by_id(org_id, team_id).await?;
+                    vec![team_id]
+                }
+                None => return Ok(None),
+            }
+        };
+
+        let mut user_ids = Vec::new();
+        for team_id in team_ids {
+            for member in self.team_repo.list_members(team_id).await? {
+                if !user_ids.contains(&member.id) {
+                    user_ids.push(member.id);
+                }
+            }
+        }
+        Ok(Some(user_ids))
+    }
+
+    /// Find an anomaly the reviewer may see
+    async fn find_in_scope(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<ClockAnomaly, AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can review clock anomalies".to_string(),
+            ));
+        }
+
+        let anomaly = self.anomaly_repo.find_by_id(org_id, anomaly_id).await?;
+        if let Some(user_ids) = self
+            .scope_users(org_id, reviewer_id, reviewer_role, None)
+            .await?
+        {
+            if !user_ids.contains(&anomaly.user_id) {
+                return Err(AppError::Forbidden(
+                    "You can only review anomalies of members of your team".to_string(),
+                ));
+            }
+        }
+        Ok(anomaly)
+    }
+
+    /// Find an anomaly still awaiting review
+    async fn find_open(
+        &self,
+        org_id: Uuid,
+        anomaly_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<ClockAnomaly, AppError> {
+        let anomaly = self
+            .find_in_scope(org_id, anomaly_id, reviewer_id, reviewer_role)
+            .await?;
+        if anomaly.status != AnomalyStatus::Open {
+            return Err(AppError::ValidationError(
+                "This anomaly has already been reviewed".to_string(),
+            ));
+        }
+        Ok(anomaly)
+    }
+
+    /// Close anomalies with a review outcome, returning the first one
+    async fn review(
+        &self,
+        org_id: Uuid,
+        anomaly_ids: &[Uuid],
+        status: AnomalyStatus,
+        reviewer_id: Uuid,
+        note: Option<String>,
+    ) -> Result<ClockAnomaly, AppError> {
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        self.anomaly_repo
+            .review(org_id, anomaly_ids, status, reviewer_id, note)
+            .await?
+            .into_iter()
+            .find(|a| a.id == anomaly_ids[0])
+            // Reviewed concurrently by someone else
+            .ok_or_else(|| {
+                AppError::ValidationError("This anomaly has already been reviewed".to_string())
+            })
+    }
+
+    async fn build_response(
+        &self,
+        anomaly: &ClockAnomaly,
+    ) -> Result<ClockAnomalyResponse, AppError> {
+        let (user_name, user_email) = self.clock_repo.get_user_info(anomaly.user_id).await?;
+        let reviewer_name = if let Some(reviewer_id) = anomaly.reviewed_by {
+            let (name, _) = self.clock_repo.get_user_info(reviewer_id).await?;
+            Some(name)
+        } else {
+            None
+        };
+
+        Ok(ClockAnomalyResponse::from_anomaly(
+            anomaly,
+            user_name,
+            user_email,
+            reviewer_name,
+        ))
+    }
+}
+
+/// What the anomaly engine checks about a closed entry
+#[derive(Debug, Clone, Default)]
+struct EntryFacts {
+    duration_minutes: i64,
+    /// Approved absences of the user covering the entry's local days
+    absences: Vec<(NaiveDate, NaiveDate)>,
+    /// Organization closed days among the entry's local days
+    closed_days: Vec<NaiveDate>,
+    /// Clock-ins of the user within the rapid clocking window ending at this one
+    clock_ins_in_window: i64,
+    /// Clock-in address never used by the user before
+    new_ip: Option<String>,
+    /// Ended breaks of the entry with their length in minutes
+    breaks: Vec<(Uuid, i64)>,
+    /// Longest break allowed by the user's break policy that day
+    max_break_minutes: Option<i32>,
+}
+
+/// Anomaly found in an entry
+#[derive(Debug, Clone, PartialEq)]
+struct Finding {
+    kind: AnomalyKind,
+    severity: AnomalySeverity,
+    message: String,
+    break_entry_id: Option<Uuid>,
+}
+
+impl Finding {
+    fn new(kind: AnomalyKind, severity: AnomalySeverity, message: String) -> Self {
+        Self {
+            kind,
+            severity,
+            message,
+            break_entry_id: None,
+        }
+    }
+}
+
+/// Anomalies of an entry, at most one per kind
+fn detect_anomalies(facts: &EntryFacts, settings: &AnomalySettingsResponse) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // Shifts half again as long as the limit are highly suspicious
+    let max_shift_minutes = settings.max_shift_hours as i64 * 60;
+    if facts.duration_minutes > max_shift_minutes {
+        let severity = if facts.duration_minutes * 2 >= max_shift_minutes * 3 {
+            AnomalySeverity::High
+        } else {
+            AnomalySeverity::Medium
+        };
+        findings.push(Finding::new(
+            AnomalyKind::LongShift,
+            severity,
+            format!(
+                "Shift lasted {}h{:02}, longer than the {}h limit",
+                facts.duration_minutes / 60,
+                facts.duration_minutes % 60,
+                settings.max_shift_hours
+            ),
+        ));
+    }
+
+    if let Some((start, end)) = facts.absences.first() {
+        findings.push(Finding::new(
+            AnomalyKind::AbsenceOverlap,
+            AnomalySeverity::High,
+            format!(
+                "Time recorded during an approved absence from {} to {}",
+                start, end
+            ),
+        ));
+    }
+
+    if let Some(day) = facts.closed_days.first() {
+        findings.push(Finding::new(
+            AnomalyKind::ClosedDayOverlap,
+            AnomalySeverity::Medium,
+            format!("Time recorded on {}, a closed day", day),
+        ));
+    }
+
+    if facts.clock_ins_in_window > settings.rapid_clock_max_entries as i64 {
+        findings.push(Finding::new(
+            AnomalyKind::RapidClocking,
+            AnomalySeverity::Medium,
+            format!(
+                "{} clock-ins within {} minutes",
+                facts.clock_ins_in_window, settings.rapid_clock_window_minutes
+            ),
+        ));
+    }
+
+    if let Some(ref ip) = facts.new_ip {
+        findings.push(Finding::new(
+            AnomalyKind::NewIp,
+            AnomalySeverity::Low,
+            format!("Clocked in from a new address ({})", ip),
+        ));
+    }
+
+    // Only the longest break is reported
+    if let Some(max) = facts.max_break_minutes {
+        let max = max as i64;
+        if let Some(&(break_id, minutes)) = facts
+            .breaks
+            .iter()
+            .filter(|(_, minutes)| *minutes > max)
+            .max_by_key(|(_, minutes)| *minutes)
+        {
+            let severity = if minutes > max * 2 {
+                AnomalySeverity::Medium
+            } else {
+                AnomalySeverity::Low
+            };
+            findings.push(Finding {
+                kind: AnomalyKind::LongBreak,
+                severity,
+                message: format!(
+                    "Break lasted {} minutes, longer than the {} minute maximum",
+                    minutes, max
+                ),
+                break_entry_id: Some(break_id),
+            });
+        }
+    }
+
+    findings
+}
+
+fn validate_settings(request: &UpdateAnomalySettingsRequest) -> Result<(), AppError> {
+    if !(1..=MAX_SHIFT_HOURS).contains(&request.max_shift_hours) {
+        return Err(AppError::ValidationError(format!(
+            "Maximum shift length must be between 1 and {} hours",
+            MAX_SHIFT_HOURS
+        )));
+    }
+    if !(1..=MAX_RAPID_CLOCK_ENTRIES).contains(&request.rapid_clock_max_entries) {
+        return Err(AppError::ValidationError(format!(
+            "Rapid clocking limit must be between 1 and {} clock-ins",
+            MAX_RAPID_CLOCK_ENTRIES
+        )));
+    }
+    if !(1..=MAX_RAPID_CLOCK_WINDOW_MINUTES).contains(&request.rapid_clock_window_minutes) {
+        return Err(AppError::ValidationError(format!(
+            "Rapid clocking window must be between 1 and {} minutes",
+            MAX_RAPID_CLOCK_WINDOW_MINUTES
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn facts(duration_minutes: i64) -> EntryFacts {
+        EntryFacts {
+            duration_minutes,
+            clock_ins_in_window: 1,
+            ..Default::default()
+        }
+    }
+
+    fn kinds(findings: &[Finding]) -> Vec<AnomalyKind> {
+        findings.iter().map(|f| f.kind).collect()
+    }
+
+    #[test]
+    fn test_detect_nothing_on_regular_entry() {
+        let settings = AnomalySettingsResponse::default();
+        assert!(detect_anomalies(&facts(8 * 60), &settings).is_empty());
+    }
+
+    #[test]
+    fn test_detect_long_shift_severity() {
+        let settings = AnomalySettingsResponse::default();
+
+        let findings = detect_anomalies(&facts(13 * 60), &settings);
+        assert_eq!(kinds(&findings), vec![AnomalyKind::LongShift]);
+        assert_eq!(findings[0].severity, AnomalySeverity::Medium);
+
+        // 1.5 times the 12h limit
+        let findings = detect_anomalies(&facts(18 * 60), &settings);
+        assert_eq!(findings[0].severity, AnomalySeverity::High);
+    }
+
+    #[test]
+    fn test_detect_absence_closed_day_rapid_and_new_ip() {
+        let settings = AnomalySettingsResponse::default();
+        let facts = EntryFacts {
+            absences: vec![(date(2024, 6, 3), date(2024, 6, 7))],
+            closed_days: vec![date(2024, 6, 3)],
+            clock_ins_in_window: 4,
+            new_ip: Some("203.0.113.7".to_string()),
+            ..facts(8 * 60)
+        };
+
+        let findings = detect_anomalies(&facts, &settings);
+        assert_eq!(
+            kinds(&findings),
+            vec![
+                AnomalyKind::AbsenceOverlap,
+                AnomalyKind::ClosedDayOverlap,
+                AnomalyKind::RapidClocking,
+                AnomalyKind::NewIp,
+            ]
+        );
+        assert_eq!(findings[0].severity, AnomalySeverity::High);
+        assert_eq!(findings[3].severity, AnomalySeverity::Low);
+    }
+
+    #[test]
+    fn test_detect_rapid_clocking_at_limit() {
+        let settings = AnomalySettingsResponse::default();
+        let facts = EntryFacts {
+            clock_ins_in_window: 3,
+            ..facts(30)
+        };
+        assert!(detect_anomalies(&facts, &settings).is_empty());
+    }
+
+    #[test]
+    fn test_detect_longest_break_only() {
+        let settings = AnomalySettingsResponse::default();
+        let short = Uuid::new_v4();
+        let long = Uuid::new_v4();
+        let longest = Uuid::new_v4();
+        let facts = EntryFacts {
+            breaks: vec![(short, 20), (long, 45), (longest, 70)],
+            max_break_minutes: Some(30),
+            ..facts(8 * 60)
+        };
+
+        let findings = detect_anomalies(&facts, &settings);
+        assert_eq!(kinds(&findings), vec![AnomalyKind::LongBreak]);
+        assert_eq!(findings[0].break_entry_id, Some(longest));
+        assert_eq!(findings[0].severity, AnomalySeverity::Medium);
+
+        // Without a policy window breaks are never flagged
+        let unlimited = EntryFacts {
+            max_break_minutes: None,
+            ..facts
+        };
+        assert!(detect_anomalies(&unlimited, &settings).is_empty());
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        let valid = UpdateAnomalySettingsRequest {
+            max_shift_hours: 12,
+            rapid_clock_max_entries: 3,
+            rapid_clock_window_minutes: 60,
+        };
+        assert!(validate_settings(&valid).is_ok());
+        assert!(validate_settings(&UpdateAnomalySettingsRequest {
+            max_shift_hours: 0,
+            ..valid
+        })
+        .is_err());
+        assert!(validate_settings(&UpdateAnomalySettingsRequest {
+            rapid_clock_window_minutes: 2000,
+            ..valid
+        })
+        .is_err());
+    }
+}
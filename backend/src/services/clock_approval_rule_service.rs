@@ -0,0 +1,445 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::ClockEntrySource;
+use crate::error::AppError;
+use crate::models::{
+    resolve_shift_day, ClockApprovalRule, ClockApprovalRuleFilter, ClockApprovalRuleResponse,
+    ClockApprovalRuleUpdate, ClockEntry, NewClockApprovalRule, WorkScheduleWithDays,
+};
+use crate::repositories::{
+    ClockApprovalRuleRepository, OrganizationRepository, TeamRepository, WorkScheduleRepository,
+};
+use crate::utils::timezone::to_local;
+
+/// Largest tolerance around the scheduled shift
+const MAX_SCHEDULE_DEVIATION_MINUTES: i32 = 720;
+
+/// Longest duration a rule can accept
+const MAX_DURATION_MINUTES: i32 = 24 * 60;
+
+/// Request to create an approval rule
+#[derive(Debug, Deserialize)]
+pub struct CreateClockApprovalRuleRequest {
+    pub name: String,
+    pub team_id: Option<Uuid>,
+    pub max_schedule_deviation_minutes: Option<i32>,
+    pub max_duration_minutes: Option<i32>,
+    pub allow_override: Option<bool>,
+    pub is_active: Option<bool>,
+}
+
+/// Request to update an approval rule
+#[derive(Debug, Deserialize)]
+pub struct UpdateClockApprovalRuleRequest {
+    pub name: Option<String>,
+    pub team_id: Option<Option<Uuid>>,
+    pub max_schedule_deviation_minutes: Option<Option<i32>>,
+    pub max_duration_minutes: Option<Option<i32>>,
+    pub allow_override: Option<bool>,
+    pub is_active: Option<bool>,
+}
+
+/// Service for automatic approval of clock entries
+///
+/// Rules only look at entries clocked in and out live by the employee. Kiosk,
+/// offline-synced, manual and auto-closed entries, as well as entries flagged for
+/// clock drift or outside the geofence, always go to the pending queue.
+pub struct ClockApprovalRuleService {
+    rule_repo: ClockApprovalRuleRepository,
+    team_repo: TeamRepository,
+    org_repo: OrganizationRepository,
+    work_schedule_repo: WorkScheduleRepository,
+}
+
+impl ClockApprovalRuleService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            rule_repo: ClockApprovalRuleRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            work_schedule_repo: WorkScheduleRepository::new(pool),
+        }
+    }
+
+    /// Create a new approval rule
+    pub async fn create(
+        &self,
+        org_id: Uuid,
+        request: CreateClockApprovalRuleRequest,
+    ) -> Result<ClockApprovalRuleResponse, AppError> {
+        validate_name(&request.name)?;
+        validate_conditions(
+            request.max_schedule_deviation_minutes,
+            request.max_duration_minutes,
+        )?;
+        if let Some(team_id) = request.team_id {
+            self.team_repo.find_by_id(org_id, team_id).await?;
+        }
+
+        let new_rule = NewClockApprovalRule {
+            organization_id: org_id,
+            team_id: request.team_id,
+            name: request.name.trim().to_string(),
+            max_schedule_deviation_minutes: request.max_schedule_deviation_minutes,
+            max_duration_minutes: request.max_duration_minutes,
+            allow_override: request.allow_override.unwrap_or(false),
+            is_active: request.is_active.unwrap_or(true),
+        };
+
+        let rule = self.rule_repo.create(new_rule).await?;
+        Ok(ClockApprovalRuleResponse::from(rule))
+    }
+
+    /// Get an approval rule by ID
+    pub async fn get(
+        &self,
+        org_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<ClockApprovalRuleResponse, AppError> {
+        let rule = self.rule_repo.find_by_id(org_id, rule_id).await?;
+        Ok(ClockApprovalRuleResponse::from(rule))
+    }
+
+    /// List approval rules
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: ClockApprovalRuleFilter,
+    ) -> Result<Vec<ClockApprovalRuleResponse>, AppError> {
+        let rules = self.rule_repo.list(org_id, &filter).await?;
+        Ok(rules
+            .into_iter()
+            .map(ClockApprovalRuleResponse::from)
+            .collect())
+    }
+
+    /// Update an approval rule
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        rule_id: Uuid,
+        request: UpdateClockApprovalRuleRequest,
+    ) -> Result<ClockApprovalRuleResponse, AppError> {
+        let existing = self.rule_repo.find_by_id(org_id, rule_id).await?;
+
+        if let Some(ref name) = request.name {
+            validate_name(name)?;
+        }
+        validate_conditions(
+            request
+                .max_schedule_deviation_minutes
+                .unwrap_or(existing.max_schedule_deviation_minutes),
+            request
+                .max_duration_minutes
+                .unwrap_or(existing.max_duration_minutes),
+        )?;
+        if let Some(Some(team_id)) = request.team_id {
+            self.team_repo.find_by_id(org_id, team_id).await?;
+        }
+
+        let update = ClockApprovalRuleUpdate {
+            team_id: request.team_id,
+            name: request.name.map(|n| n.trim().to_string()),
+            max_schedule_deviation_minutes: request.max_schedule_deviation_minutes,
+            max_duration_minutes: request.max_duration_minutes,
+            allow_override: request.allow_override,
+            is_active: request.is_active,
+        };
+
+        let rule = self.rule_repo.update(org_id, rule_id, update).await?;
+        Ok(ClockApprovalRuleResponse::from(rule))
+    }
+
+    /// Delete an approval rule
+    pub async fn delete(&self, org_id: Uuid, rule_id: Uuid) -> Result<(), AppError> {
+        self.rule_repo.delete(org_id, rule_id).await
+    }
+
+    /// First active rule approving a closed entry, if any
+    /// Team rules are tried before organization-wide rules
+    pub async fn find_matching_rule(
+        &self,
+        entry: &ClockEntry,
+        override_used: bool,
+    ) -> Result<Option<ClockApprovalRule>, AppError> {
+        let Some(clock_out) = entry.clock_out else {
+            return Ok(None);
+        };
+        if !is_eligible(entry) {
+            return Ok(None);
+        }
+
+        let org_id = entry.organization_id;
+        let rules = self
+            .rule_repo
+            .list_applicable(org_id, entry.user_id)
+            .await?;
+        if rules.is_empty() {
+            return Ok(None);
+        }
+
+        // The schedule is only loaded when a rule needs it
+        let schedule_deviation = if rules
+            .iter()
+            .any(|r| r.max_schedule_deviation_minutes.is_some())
+        {
+            let tz = self.org_repo.get_timezone(org_id).await?;
+            let shift_date = to_local(entry.clock_in, tz).date();
+            let schedule = self
+                .work_schedule_repo
+                .get_effective_schedule(
+                    org_id,
+                    entry.user_id,
+                    shift_date - Duration::days(1),
+                    shift_date,
+                )
+                .await?;
+            schedule_deviation_minutes(schedule.as_ref(), entry.clock_in, clock_out, tz)
+        } else {
+            None
+        };
+
+        let facts = EntryFacts {
+            duration_minutes: (clock_out - entry.clock_in).num_minutes(),
+            override_used,
+            schedule_deviation_minutes: schedule_deviation,
+        };
+
+        Ok(rules.into_iter().find(|rule| rule_matches(rule, &facts)))
+    }
+}
+
+/// What approval rules check about an entry
+#[derive(Debug, Clone, Copy)]
+struct EntryFacts {
+    duration_minutes: i64,
+    override_used: bool,
+    /// Largest gap between the entry and its scheduled shift (None when unscheduled)
+    schedule_deviation_minutes: Option<i64>,
+}
+
+/// Entries rules may approve: captured live and not flagged
+fn is_eligible(entry: &ClockEntry) -> bool {
+    entry.source == ClockEntrySource::Clock
+        && entry.clock_drift_seconds.is_none()
+        && !entry.outside_geofence
+        && !entry.auto_closed
+}
+
+fn rule_matches(rule: &ClockApprovalRule, facts: &EntryFacts) -> bool {
+    if facts.override_used && !rule.allow_override {
+        return false;
+    }
+    if let Some(max) = rule.max_duration_minutes {
+        if facts.duration_minutes > max as i64 {
+            return false;
+        }
+    }
+    if let Some(max) = rule.max_schedule_deviation_minutes {
+        match facts.schedule_deviation_minutes {
+            Some(deviation) if deviation <= max as i64 => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Largest gap in minutes between clock in/out and the scheduled shift start/end
+/// None when the schedule has no shift for the entry's day
+fn schedule_deviation_minutes(
+    schedule: Option<&WorkScheduleWithDays>,
+    clock_in: DateTime<Utc>,
+    clock_out: DateTime<Utc>,
+    tz: Tz,
+) -> Option<i64> {
+    let local_in = to_local(clock_in, tz);
+    let local_out = to_local(clock_out, tz);
+
+    let (shift_date, day) = resolve_shift_day(schedule, local_in);
+    let (shift_start, shift_end) = day?.shift_bounds(shift_date);
+
+    let start_gap = (local_in - shift_start).num_minutes().abs();
+    let end_gap = (local_out - shift_end).num_minutes().abs();
+    Some(start_gap.max(end_gap))
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() || name.len() > 100 {
+        return Err(AppError::ValidationError(
+            "Name must be between 1 and 100 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_conditions(
+    max_schedule_deviation_minutes: Option<i32>,
+    max_duration_minutes: Option<i32>,
+) -> Result<(), AppError> {
+    if max_schedule_deviation_minutes.is_none() && max_duration_minutes.is_none() {
+        return Err(AppError::ValidationError(
+            "A rule needs a schedule tolerance or a maximum duration".to_string(),
+        ));
+    }
+    if let Some(minutes) = max_schedule_deviation_minutes {
+        if !(0..=MAX_SCHEDULE_DEVIATION_MINUTES).contains(&minutes) {
+            return Err(AppError::ValidationError(format!(
+                "Schedule tolerance must be between 0 and {} minutes",
+                MAX_SCHEDULE_DEVIATION_MINUTES
+            )));
+        }
+    }
+    if let Some(minutes) = max_duration_minutes {
+        if !(1..=MAX_DURATION_MINUTES).contains(&minutes) {
+            return Err(AppError::ValidationError(format!(
+                "Maximum duration must be between 1 and {} minutes",
+                MAX_DURATION_MINUTES
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{WorkSchedule, WorkScheduleDay};
+    use chrono::{NaiveDate, NaiveTime, TimeZone};
+
+    fn rule(deviation: Option<i32>, duration: Option<i32>) -> ClockApprovalRule {
+        ClockApprovalRule {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            team_id: None,
+            name: "On schedule".to_string(),
+            max_schedule_deviation_minutes: deviation,
+            max_duration_minutes: duration,
+            allow_override: false,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn facts(duration: i64, deviation: Option<i64>) -> EntryFacts {
+        EntryFacts {
+            duration_minutes: duration,
+            override_used: false,
+            schedule_deviation_minutes: deviation,
+        }
+    }
+
+    fn day(weekday: i16, start: (u32, u32), end: (u32, u32)) -> WorkScheduleDay {
+        WorkScheduleDay {
+            id: Uuid::new_v4(),
+            work_schedule_id: Uuid::new_v4(),
+            day_of_week: weekday,
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+            break_minutes: 60,
+            week_index: 0,
+        }
+    }
+
+    fn weekly(days: Vec<WorkScheduleDay>) -> WorkScheduleWithDays {
+        WorkScheduleWithDays {
+            schedule: WorkSchedule {
+                id: Uuid::new_v4(),
+                organization_id: Uuid::new_v4(),
+                name: "Office".to_string(),
+                description: None,
+                is_default: true,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                cycle_weeks: 1,
+                cycle_anchor_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            },
+            days,
+            exceptions: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_rule_matches_duration() {
+        let rule = rule(None, Some(600));
+
+        assert!(rule_matches(&rule, &facts(480, None)));
+        assert!(!rule_matches(&rule, &facts(660, None)));
+    }
+
+    #[test]
+    fn test_rule_matches_schedule_deviation() {
+        let rule = rule(Some(15), None);
+
+        assert!(rule_matches(&rule, &facts(480, Some(10))));
+        assert!(!rule_matches(&rule, &facts(480, Some(20))));
+        // Unscheduled days never match a schedule rule
+        assert!(!rule_matches(&rule, &facts(480, None)));
+    }
+
+    #[test]
+    fn test_rule_matches_override() {
+        let mut rule = rule(None, Some(600));
+        let used = EntryFacts {
+            override_used: true,
+            ..facts(480, None)
+        };
+
+        assert!(!rule_matches(&rule, &used));
+        rule.allow_override = true;
+        assert!(rule_matches(&rule, &used));
+    }
+
+    #[test]
+    fn test_schedule_deviation_minutes() {
+        // Monday 2024-06-03, scheduled 09:00 to 17:00
+        let schedule = weekly(vec![day(0, (9, 0), (17, 0))]);
+        let clock_in = Utc.with_ymd_and_hms(2024, 6, 3, 9, 5, 0).unwrap();
+        let clock_out = Utc.with_ymd_and_hms(2024, 6, 3, 16, 48, 0).unwrap();
+
+        assert_eq!(
+            schedule_deviation_minutes(Some(&schedule), clock_in, clock_out, chrono_tz::UTC),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn test_schedule_deviation_overnight_shift() {
+        // Monday 22:00 to Tuesday 06:00
+        let schedule = weekly(vec![day(0, (22, 0), (6, 0))]);
+        let clock_in = Utc.with_ymd_and_hms(2024, 6, 3, 21, 55, 0).unwrap();
+        let clock_out = Utc.with_ymd_and_hms(2024, 6, 4, 6, 3, 0).unwrap();
+
+        assert_eq!(
+            schedule_deviation_minutes(Some(&schedule), clock_in, clock_out, chrono_tz::UTC),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_schedule_deviation_unscheduled_day() {
+        // Schedule only on Monday, entry on Saturday
+        let schedule = weekly(vec![day(0, (9, 0), (17, 0))]);
+        let clock_in = Utc.with_ymd_and_hms(2024, 6, 8, 9, 0, 0).unwrap();
+        let clock_out = Utc.with_ymd_and_hms(2024, 6, 8, 17, 0, 0).unwrap();
+
+        assert_eq!(
+            schedule_deviation_minutes(Some(&schedule), clock_in, clock_out, chrono_tz::UTC),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_conditions() {
+        assert!(validate_conditions(Some(15), None).is_ok());
+        assert!(validate_conditions(None, Some(600)).is_ok());
+        assert!(validate_conditions(None, None).is_err());
+        assert!(validate_conditions(Some(-1), None).is_err());
+        assert!(validate_conditions(None, Some(0)).is_err());
+    }
+}
@@ -0,0 +1,498 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::{ClockCorrectionStatus, ClockEntryStatus, NotificationType, UserRole};
+use crate::error::AppError;
+use crate::models::{
+    ClockCorrectionRequest, ClockCorrectionResponse, ClockEntryUpdate,
+    CreateClockCorrectionRequest, NewClockCorrectionRequest, PaginatedClockCorrections, Pagination,
+};
+use crate::repositories::{ClockCorrectionRepository, ClockRepository, TeamRepository};
+use crate::services::NotificationService;
+
+/// Service for employee-initiated clock entry corrections
+pub struct ClockCorrectionService {
+    correction_repo: ClockCorrectionRepository,
+    clock_repo: ClockRepository,
+    team_repo: TeamRepository,
+}
+
+impl ClockCorrectionService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            correction_repo: ClockCorrectionRepository::new(pool.clone()),
+            clock_repo: ClockRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool),
+        }
+    }
+
+    /// Request a correction of one of the user's own clock entries
+    pub async fn request_correction(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+        request: CreateClockCorrectionRequest,
+    ) -> Result<ClockCorrectionResponse, AppError> {
+        let entry = self.clock_repo.find_by_id(org_id, entry_id).await?;
+        if entry.user_id != user_id {
+            return Err(AppError::Forbidden(
+                "You can only request corrections for your own clock entries".to_string(),
+            ));
+        }
+
+        let reason = request.reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(AppError::ValidationError(
+                "A reason is required for a correction request".to_string(),
+            ));
+        }
+
+        if request.clock_in.is_none() && request.clock_out.is_none() {
+            return Err(AppError::ValidationError(
+                "Provide a corrected clock in or clock out time".to_string(),
+            ));
+        }
+
+        let requested_clock_in = request.clock_in.unwrap_or(entry.clock_in);
+        let requested_clock_out = request.clock_out.or(entry.clock_out).ok_or_else(|| {
+            AppError::ValidationError(
+                "A clock out time is required to correct an open entry".to_string(),
+            )
+        })?;
+
+        if requested_clock_out <= requested_clock_in {
+            return Err(AppError::ValidationError(
+                "Clock out must be after clock in".to_string(),
+            ));
+        }
+        if requested_clock_out > Utc::now() {
+            return Err(AppError::ValidationError(
+                "Corrected times cannot be in the future".to_string(),
+            ));
+        }
+        if requested_clock_in == entry.clock_in && Some(requested_clock_out) == entry.clock_out {
+            return Err(AppError::ValidationError(
+                "The requested times are identical to the current entry".to_string(),
+            ));
+        }
+
+        if self
+            .clock_repo
+            .has_overlapping_entry(
+                org_id,
+                user_id,
+                requested_clock_in,
+                requested_clock_out,
+                Some(entry.id),
+            )
+            .await?
+        {
+            return Err(AppError::ValidationError(
+                "The corrected times overlap another clock entry".to_string(),
+            ));
+        }
+
+        if self
+            .correction_repo
+            .find_pending_for_entry(org_id, entry.id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::Conflict(
+                "A correction request is already pending for this clock entry".to_string(),
+            ));
+        }
+
+        let correction = self
+            .correction_repo
+            .create(NewClockCorrectionRequest {
+                organization_id: org_id,
+                user_id,
+                clock_entry_id: entry.id,
+                original_clock_in: entry.clock_in,
+                original_clock_out: entry.clock_out,
+                requested_clock_in,
+                requested_clock_out,
+                reason,
+                status: ClockCorrectionStatus::Pending,
+            })
+            .await?;
+
+        self.notify_managers_of_correction(org_id, &correction)
+            .await;
+
+        self.build_response(&correction).await
+    }
+
+    /// List the correction history of a clock entry
+    /// Visible to the entry owner and to managers of the owner's teams
+    pub async fn list_for_entry(
+        &self,
+        org_id: Uuid,
+        entry_id: Uuid,
+        requester_id: Uuid,
+        requester_role: UserRole,
+    ) -> Result<Vec<ClockCorrectionResponse>, AppError> {
+        let entry = self.clock_repo.find_by_id(org_id, entry_id).await?;
+
+        if entry.user_id != requester_id {
+            if requester_role == UserRole::Employee {
+                return Err(AppError::Forbidden(
+                    "You can only view corrections of your own clock entries".to_string(),
+                ));
+            }
+            self.verify_team_scope(
+                org_id,
+                requester_id,
+                requester_role,
+                entry.user_id,
+                "You can only view corrections for members of your team",
+            )
+            .await?;
+        }
+
+        let corrections = self.correction_repo.list_by_entry(org_id, entry.id).await?;
+
+        let mut responses = Vec::with_capacity(corrections.len());
+        for correction in &corrections {
+            responses.push(self.build_response(correction).await?);
+        }
+
+        Ok(responses)
+    }
+
+    /// List pending correction requests (Manager+ only)
+    pub async fn list_pending(
+        &self,
+        org_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        pagination: Pagination,
+    ) -> Result<PaginatedClockCorrections, AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can view pending corrections".to_string(),
+            ));
+        }
+
+        // Managers only see requests of members of the teams they manage
+        let user_ids = if reviewer_role == UserRole::Manager {
+            let mut user_ids = Vec::new();
+            for team in self
+                .team_repo
+                .get_managed_teams(org_id, reviewer_id)
+                .await?
+            {
+                for member in self.team_repo.list_members(team.id).await? {
+                    if !user_ids.contains(&member.id) {
+                        user_ids.push(member.id);
+                    }
+                }
+            }
+            Some(user_ids)
+        } else {
+            None
+        };
+
+        let (corrections, total) = self
+            .correction_repo
+            .list_pending(org_id, user_ids.as_deref(), &pagination)
+            .await?;
+
+        let mut responses = Vec::with_capacity(corrections.len());
+        for correction in &corrections {
+            responses.push(self.build_response(correction).await?);
+        }
+
+        let total_pages = (total as f64 / pagination.per_page as f64).ceil() as i64;
+
+        Ok(PaginatedClockCorrections {
+            data: responses,
+            total,
+            page: pagination.page,
+            per_page: pagination.per_page,
+            total_pages,
+        })
+    }
+
+    /// Approve a correction request (Manager+ only)
+    /// Applies the requested values to the clock entry and approves it
+    pub async fn approve_correction(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<ClockCorrectionResponse, AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can approve clock corrections".to_string(),
+            ));
+        }
+
+        let correction = self.find_pending(org_id, request_id).await?;
+
+        self.verify_team_scope(
+            org_id,
+            reviewer_id,
+            reviewer_role,
+            correction.user_id,
+            "You can only approve corrections for members of your team",
+        )
+        .await?;
+
+        // Entries may have changed since the request was made
+        if self
+            .clock_repo
+            .has_overlapping_entry(
+                org_id,
+                correction.user_id,
+                correction.requested_clock_in,
+                correction.requested_clock_out,
+                Some(correction.clock_entry_id),
+            )
+            .await?
+        {
+            return Err(AppError::ValidationError(
+                "The corrected times overlap another clock entry".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        self.clock_repo
+            .update(
+                org_id,
+                correction.clock_entry_id,
+                ClockEntryUpdate {
+                    clock_in: Some(correction.requested_clock_in),
+                    clock_out: Some(Some(correction.requested_clock_out)),
+                    status: Some(ClockEntryStatus::Approved),
+                    approved_by: Some(Some(reviewer_id)),
+                    approved_at: Some(Some(now)),
+                    updated_at: Some(now),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let approved = self
+            .correction_repo
+            .review(
+                org_id,
+                request_id,
+                reviewer_id,
+                ClockCorrectionStatus::Approved,
+                None,
+            )
+            .await?;
+
+        self.notify_user_of_review(org_id, &approved).await;
+
+        self.build_response(&approved).await
+    }
+
+    /// Reject a correction request (Manager+ only)
+    /// The clock entry keeps its current values
+    pub async fn reject_correction(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        reason: Option<String>,
+    ) -> Result<ClockCorrectionResponse, AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can reject clock corrections".to_string(),
+            ));
+        }
+
+        let correction = self.find_pending(org_id, request_id).await?;
+
+        self.verify_team_scope(
+            org_id,
+            reviewer_id,
+            reviewer_role,
+            correction.user_id,
+            "You can only reject corrections for members of your team",
+        )
+        .await?;
+
+        let rejected = self
+            .correction_repo
+            .review(
+                org_id,
+                request_id,
+                reviewer_id,
+                ClockCorrectionStatus::Rejected,
+                reason,
+            )
+            .await?;
+
+        self.notify_user_of_review(org_id, &rejected).await;
+
+        self.build_response(&rejected).await
+    }
+
+    // =====================
+    // Helper Methods
+    // =====================
+
+    async fn find_pending(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+    ) -> Result<ClockCorrectionRequest, AppError> {
+        let correction = self.correction_repo.find_by_id(org_id, request_id).await?;
+        if correction.status != ClockCorrectionStatus::Pending {
+            return Err(AppError::ValidationError(
+                "This correction request has already been reviewed".to_string(),
+            ));
+        }
+        Ok(correction)
+    }
+
+    /// For managers, verify they manage a team the user belongs to
+    async fn verify_team_scope(
+        &self,
+        org_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        user_id: Uuid,
+        message: &str,
+    ) -> Result<(), AppError> {
+        if reviewer_role != UserRole::Manager {
+            return Ok(());
+        }
+
+        let managed_teams = self
+            .team_repo
+            .get_managed_teams(org_id, reviewer_id)
+            .await?;
+
+        for team in managed_teams {
+            if self.team_repo.is_member(team.id, user_id).await? {
+                return Ok(());
+            }
+        }
+
+        Err(AppError::Forbidden(message.to_string()))
+    }
+
+    async fn build_response(
+        &self,
+        correction: &ClockCorrectionRequest,
+    ) -> Result<ClockCorrectionResponse, AppError> {
+        let (user_name, user_email) = self.clock_repo.get_user_info(correction.user_id).await?;
+        let reviewer_name = if let Some(reviewer_id) = correction.reviewed_by {
+            let (name, _) = self.clock_repo.get_user_info(reviewer_id).await?;
+            Some(name)
+        } else {
+            None
+        };
+
+        Ok(ClockCorrectionResponse::from_request(
+            correction,
+            user_name,
+            user_email,
+            reviewer_name,
+        ))
+    }
+
+    async fn notify_managers_of_correction(
+        &self,
+        org_id: Uuid,
+        correction: &ClockCorrectionRequest,
+    ) {
+        let teams = self
+            .team_repo
+            .get_user_teams(org_id, correction.user_id)
+            .await
+            .unwrap_or_default();
+
+        let notification_service = NotificationService::new(self.clock_repo.pool().clone());
+        let clock_in_str = correction
+            .original_clock_in
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+
+        for team in teams {
+            if let Some(manager_id) = team.manager_id {
+                if let Err(e) = notification_service
+                    .create_notification(
+                        org_id,
+                        manager_id,
+                        NotificationType::ClockCorrection,
+                        "Clock Correction Pending".to_string(),
+                        format!(
+                            "A team member has requested a correction of their clock entry from {}.",
+                            clock_in_str
+                        ),
+                        None,
+                    )
+                    .await
+                {
+                    tracing::warn!(
+                        manager_id = %manager_id,
+                        user_id = %correction.user_id,
+                        error = %e,
+                        "Failed to create clock correction notification for manager"
+                    );
+                }
+            }
+        }
+    }
+
+    async fn notify_user_of_review(&self, org_id: Uuid, correction: &ClockCorrectionRequest) {
+        let notification_service = NotificationService::new(self.clock_repo.pool().clone());
+        let clock_in_str = correction
+            .original_clock_in
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+
+        let (notification_type, title, message) = match correction.status {
+            ClockCorrectionStatus::Approved => (
+                NotificationType::ClockApproved,
+                "Clock Correction Approved".to_string(),
+                format!(
+                    "Your correction of the clock entry from {} has been approved.",
+                    clock_in_str
+                ),
+            ),
+            ClockCorrectionStatus::Rejected => (
+                NotificationType::ClockRejected,
+                "Clock Correction Rejected".to_string(),
+                format!(
+                    "Your correction of the clock entry from {} has been rejected. Reason: {}",
+                    clock_in_str,
+                    correction
+                        .review_notes
+                        .as_deref()
+                        .unwrap_or("Not specified")
+                ),
+            ),
+            ClockCorrectionStatus::Pending => return,
+        };
+
+        if let Err(e) = notification_service
+            .create_notification(
+                org_id,
+                correction.user_id,
+                notification_type,
+                title,
+                message,
+                None,
+            )
+            .await
+        {
+            tracing::warn!(
+                user_id = %correction.user_id,
+                request_id = %correction.id,
+                error = %e,
+                "Failed to create clock correction review notification"
+            );
+        }
+    }
+}
@@ -1,24 +1,234 @@
-use chrono::{NaiveTime, Timelike, Utc};
+use std::net::IpAddr;
+
+use chrono::{Duration, NaiveDateTime, NaiveTime, Timelike, Utc};
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
-use crate::domain::enums::{ClockOverrideStatus, ClockRestrictionMode, NotificationType, UserRole};
+use crate::domain::enums::{
+    ClockOverrideStatus, ClockRestrictionMode, GeofenceMode, NotificationType, UserRole,
+};
 use crate::error::AppError;
 use crate::models::{
     ClockOverrideFilter, ClockOverrideRequestResponse, ClockRestriction, ClockRestrictionFilter,
     ClockRestrictionResponse, ClockRestrictionUpdate, ClockValidationResult,
-    CreateClockRestrictionRequest, CreateOverrideRequest, EffectiveRestriction,
+    CreateClockRestrictionRequest, CreateOverrideRequest, EffectiveRestriction, GeoPosition,
     NewClockOverrideRequest, NewClockRestriction, PaginatedClockOverrideRequests, Pagination,
     ReviewOverrideRequest, UpdateClockRestrictionRequest,
 };
-use crate::repositories::{ClockRestrictionRepository, OrganizationRepository, TeamRepository};
+use crate::repositories::{
+    ClockRestrictionRepository, OrganizationRepository, TeamRepository, WorkScheduleRepository,
+    WorkSiteRepository,
+};
+use crate::services::work_site_service::{check_geofence, geofence_message};
 use crate::services::NotificationService;
+use crate::utils::datetime::is_within_time_window;
+use crate::utils::network::{is_in_networks, parse_network};
+use crate::utils::timezone::to_local;
+
+/// Maximum number of networks in an allowlist
+const MAX_ALLOWED_NETWORKS: usize = 50;
+
+/// Default tolerance around planned shift starts and ends
+const DEFAULT_SCHEDULE_TOLERANCE_MINUTES: i32 = 15;
+
+/// Largest tolerance around planned shift starts and ends
+const MAX_SCHEDULE_TOLERANCE_MINUTES: i32 = 720;
+
+/// Message shown when a clock action falls outside the windows of the planned shifts
+/// Clocking in is allowed around a shift start and clocking out around a shift end,
+/// within the restriction's early and late tolerances. `planned` holds the local
+/// bounds of the shifts planned around the action. None when the action is allowed.
+pub fn schedule_window_message(
+    restriction: &ClockRestriction,
+    action: &str,
+    local: NaiveDateTime,
+    planned: &[(NaiveDateTime, NaiveDateTime)],
+) -> Option<String> {
+    let early = Duration::minutes(restriction.schedule_early_minutes as i64);
+    let late = Duration::minutes(restriction.schedule_late_minutes as i64);
+    let anchors: Vec<NaiveDateTime> = planned
+        .iter()
+        .map(|(start, end)| if action == "clock_in" { *start } else { *end })
+        .collect();
+    if anchors
+        .iter()
+        .any(|anchor| *anchor - early <= local && local <= *anchor + late)
+    {
+        return None;
+    }
+
+    let action_name = if action == "clock_in" {
+        "Clock in"
+    } else {
+        "Clock out"
+    };
+    let nearest = anchors
+        .iter()
+        .filter(|anchor| anchor.date() == local.date())
+        .min_by_key(|anchor| (**anchor - local).num_minutes().abs());
+    Some(match nearest {
+        Some(anchor) => format!(
+            "{} is only allowed between {} and {} according to your schedule",
+            action_name,
+            (*anchor - early).format("%H:%M"),
+            (*anchor + late).format("%H:%M")
+        ),
+        None => format!("{} is not allowed: no work is scheduled today", action_name),
+    })
+}
+
+/// Message shown when a clock action at `local` falls outside its time window
+/// With `enforce_schedule` the windows follow the shifts planned around the action;
+/// users without any schedule keep the restriction's fixed windows. Windows where
+/// earliest is after latest span midnight (e.g. night shifts). None when allowed.
+pub async fn time_window_message(
+    work_schedule_repo: &WorkScheduleRepository,
+    restriction: &ClockRestriction,
+    user_id: Uuid,
+    action: &str,
+    local: NaiveDateTime,
+) -> Result<Option<String>, AppError> {
+    let planned = if restriction.enforce_schedule {
+        let date = local.date();
+        work_schedule_repo
+            .get_planned_shifts(
+                restriction.organization_id,
+                user_id,
+                date - Duration::days(1),
+                date + Duration::days(1),
+            )
+            .await?
+    } else {
+        None
+    };
+    if let Some(planned) = planned {
+        return Ok(schedule_window_message(
+            restriction,
+            action,
+            local,
+            &planned,
+        ));
+    }
+
+    let (earliest, latest) = if action == "clock_in" {
+        (restriction.clock_in_earliest, restriction.clock_in_latest)
+    } else {
+        (restriction.clock_out_earliest, restriction.clock_out_latest)
+    };
+    let current_time =
+        NaiveTime::from_hms_opt(local.hour(), local.minute(), local.second()).unwrap_or_default();
+    Ok((!is_within_time_window(current_time, earliest, latest))
+        .then(|| fixed_window_message(action, earliest, latest)))
+}
+
+/// Message shown when a clock action falls outside the restriction's fixed window
+fn fixed_window_message(
+    action: &str,
+    earliest: Option<NaiveTime>,
+    latest: Option<NaiveTime>,
+) -> String {
+    let action_name = if action == "clock_in" {
+        "Clock in"
+    } else {
+        "Clock out"
+    };
+
+    match (earliest, latest) {
+        (Some(e), Some(l)) => {
+            format!(
+                "{} is only allowed between {} and {}",
+                action_name,
+                e.format("%H:%M"),
+                l.format("%H:%M")
+            )
+        }
+        (Some(e), None) => {
+            format!(
+                "{} is not allowed before {}",
+                action_name,
+                e.format("%H:%M")
+            )
+        }
+        (None, Some(l)) => {
+            format!("{} is not allowed after {}", action_name, l.format("%H:%M"))
+        }
+        (None, None) => format!("{} is currently restricted", action_name),
+    }
+}
+
+/// Check the tolerances around planned shift starts and ends
+fn validate_schedule_tolerance(minutes: i32) -> Result<i32, AppError> {
+    if !(0..=MAX_SCHEDULE_TOLERANCE_MINUTES).contains(&minutes) {
+        return Err(AppError::ValidationError(format!(
+            "Schedule tolerances must be between 0 and {} minutes",
+            MAX_SCHEDULE_TOLERANCE_MINUTES
+        )));
+    }
+    Ok(minutes)
+}
+
+/// Message shown when a clock action comes from outside the allowed networks
+/// None when the restriction has no allowlist or the address is allowed
+pub fn network_message(
+    restriction: &ClockRestriction,
+    action: &str,
+    client_ip: Option<IpAddr>,
+) -> Option<String> {
+    if restriction.allowed_networks.is_empty() {
+        return None;
+    }
+
+    let networks: Vec<_> = restriction
+        .allowed_networks
+        .iter()
+        .filter_map(|network| parse_network(network))
+        .collect();
+    if client_ip.is_some_and(|ip| is_in_networks(ip, &networks)) {
+        return None;
+    }
+
+    let action_name = if action == "clock_in" {
+        "Clock in"
+    } else {
+        "Clock out"
+    };
+    Some(format!(
+        "{} is only allowed from an authorized network",
+        action_name
+    ))
+}
+
+/// Validate an allowlist, storing each range in canonical form
+/// Bare addresses are accepted as single-host ranges
+fn normalize_networks(networks: Vec<String>) -> Result<Vec<String>, AppError> {
+    if networks.len() > MAX_ALLOWED_NETWORKS {
+        return Err(AppError::ValidationError(format!(
+            "At most {} allowed networks can be configured",
+            MAX_ALLOWED_NETWORKS
+        )));
+    }
+
+    let mut normalized: Vec<String> = Vec::with_capacity(networks.len());
+    for network in &networks {
+        let parsed = parse_network(network).ok_or_else(|| {
+            AppError::ValidationError(format!("Invalid network: {}", network.trim()))
+        })?;
+        let parsed = parsed.to_string();
+        if !normalized.contains(&parsed) {
+            normalized.push(parsed);
+        }
+    }
+
+    Ok(normalized)
+}
 
 /// Service for clock restrictions and override requests
 pub struct ClockRestrictionService {
     restriction_repo: ClockRestrictionRepository,
     team_repo: TeamRepository,
     org_repo: OrganizationRepository,
+    work_schedule_repo: WorkScheduleRepository,
+    work_site_repo: WorkSiteRepository,
 }
 
 impl ClockRestrictionService {
@@ -26,7 +236,9 @@ impl ClockRestrictionService {
         Self {
             restriction_repo: ClockRestrictionRepository::new(pool.clone()),
             team_repo: TeamRepository::new(pool.clone()),
-            org_repo: OrganizationRepository::new(pool),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            work_schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            work_site_repo: WorkSiteRepository::new(pool),
         }
     }
 
@@ -63,6 +275,18 @@ impl ClockRestrictionService {
             }
         }
 
+        let allowed_networks = normalize_networks(request.allowed_networks.unwrap_or_default())?;
+        let schedule_early_minutes = validate_schedule_tolerance(
+            request
+                .schedule_early_minutes
+                .unwrap_or(DEFAULT_SCHEDULE_TOLERANCE_MINUTES),
+        )?;
+        let schedule_late_minutes = validate_schedule_tolerance(
+            request
+                .schedule_late_minutes
+                .unwrap_or(DEFAULT_SCHEDULE_TOLERANCE_MINUTES),
+        )?;
+
         let new_restriction = NewClockRestriction {
             organization_id: org_id,
             team_id: request.team_id,
@@ -76,6 +300,10 @@ impl ClockRestrictionService {
             require_manager_approval: request.require_manager_approval.unwrap_or(false),
             is_active: request.is_active.unwrap_or(true),
             max_daily_clock_events: request.max_daily_clock_events,
+            geofence_mode: request.geofence_mode.unwrap_or_default(),
+            allowed_networks,
+            schedule_early_minutes,
+            schedule_late_minutes,
         };
 
         let restriction = self
@@ -143,6 +371,19 @@ impl ClockRestrictionService {
             require_manager_approval: request.require_manager_approval,
             is_active: request.is_active,
             max_daily_clock_events: request.max_daily_clock_events,
+            geofence_mode: request.geofence_mode,
+            allowed_networks: request
+                .allowed_networks
+                .map(normalize_networks)
+                .transpose()?,
+            schedule_early_minutes: request
+                .schedule_early_minutes
+                .map(validate_schedule_tolerance)
+                .transpose()?,
+            schedule_late_minutes: request
+                .schedule_late_minutes
+                .map(validate_schedule_tolerance)
+                .transpose()?,
             updated_at: None, // Will be set by repository
         };
 
@@ -182,6 +423,8 @@ impl ClockRestrictionService {
         org_id: Uuid,
         user_id: Uuid,
         action: &str, // "clock_in" or "clock_out"
+        position: Option<&GeoPosition>,
+        client_ip: Option<IpAddr>,
     ) -> Result<ClockValidationResult, AppError> {
         // Get effective restriction for the user
         let effective = self
@@ -196,11 +439,46 @@ impl ClockRestrictionService {
                 message: None,
                 can_request_override: false,
                 effective_restriction: None,
+                geofence: None,
             });
         };
 
         let restriction = &effective_restriction.restriction;
 
+        // Geofencing applies on top of the time window mode
+        let geofence = if restriction.geofence_mode == GeofenceMode::Off {
+            None
+        } else {
+            let sites = self.work_site_repo.list_active(org_id).await?;
+            Some(check_geofence(&sites, position))
+        };
+
+        if let Some(check) = geofence
+            .as_ref()
+            .filter(|check| restriction.geofence_mode == GeofenceMode::Enforce && !check.within)
+        {
+            let message = geofence_message(action, position, check);
+            return Ok(ClockValidationResult {
+                allowed: false,
+                message: Some(message),
+                can_request_override: false,
+                effective_restriction: Some(effective_restriction),
+                geofence,
+            });
+        }
+
+        // The network allowlist applies in every mode, only flexible mode can be overridden
+        if let Some(message) = network_message(restriction, action, client_ip) {
+            let can_request_override = restriction.mode == ClockRestrictionMode::Flexible;
+            return Ok(ClockValidationResult {
+                allowed: false,
+                message: Some(message),
+                can_request_override,
+                effective_restriction: Some(effective_restriction),
+                geofence,
+            });
+        }
+
         // Unrestricted mode - always allowed
         if restriction.mode == ClockRestrictionMode::Unrestricted {
             return Ok(ClockValidationResult {
@@ -208,34 +486,27 @@ impl ClockRestrictionService {
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             });
         }
 
-        // Get current time
-        let now = Utc::now();
-        let current_time =
-            NaiveTime::from_hms_opt(now.hour(), now.minute(), now.second()).unwrap_or_default();
-
-        // Check time window based on action
-        let (earliest, latest) = if action == "clock_in" {
-            (restriction.clock_in_earliest, restriction.clock_in_latest)
-        } else {
-            (restriction.clock_out_earliest, restriction.clock_out_latest)
-        };
-
-        let within_window = self.check_time_window(current_time, earliest, latest);
+        // Get current time in the organization's timezone
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let now = to_local(Utc::now(), tz);
+        let message =
+            time_window_message(&self.work_schedule_repo, restriction, user_id, action, now)
+                .await?;
 
-        if within_window {
+        // Outside allowed window
+        let Some(message) = message else {
             return Ok(ClockValidationResult {
                 allowed: true,
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             });
-        }
-
-        // Outside allowed window
-        let message = self.build_restriction_message(action, earliest, latest);
+        };
 
         match restriction.mode {
             ClockRestrictionMode::Strict => {
@@ -245,6 +516,7 @@ impl ClockRestrictionService {
                     message: Some(message),
                     can_request_override: false,
                     effective_restriction: Some(effective_restriction),
+                    geofence,
                 })
             }
             ClockRestrictionMode::Flexible => {
@@ -254,6 +526,7 @@ impl ClockRestrictionService {
                     message: Some(message),
                     can_request_override: true,
                     effective_restriction: Some(effective_restriction),
+                    geofence,
                 })
             }
             ClockRestrictionMode::Unrestricted => {
@@ -263,6 +536,7 @@ impl ClockRestrictionService {
                     message: None,
                     can_request_override: false,
                     effective_restriction: Some(effective_restriction),
+                    geofence,
                 })
             }
         }
@@ -528,52 +802,6 @@ impl ClockRestrictionService {
     // Helper Methods
     // =====================
 
-    fn check_time_window(
-        &self,
-        current: NaiveTime,
-        earliest: Option<NaiveTime>,
-        latest: Option<NaiveTime>,
-    ) -> bool {
-        let after_earliest = earliest.is_none_or(|e| current >= e);
-        let before_latest = latest.is_none_or(|l| current <= l);
-        after_earliest && before_latest
-    }
-
-    fn build_restriction_message(
-        &self,
-        action: &str,
-        earliest: Option<NaiveTime>,
-        latest: Option<NaiveTime>,
-    ) -> String {
-        let action_name = if action == "clock_in" {
-            "Clock in"
-        } else {
-            "Clock out"
-        };
-
-        match (earliest, latest) {
-            (Some(e), Some(l)) => {
-                format!(
-                    "{} is only allowed between {} and {}",
-                    action_name,
-                    e.format("%H:%M"),
-                    l.format("%H:%M")
-                )
-            }
-            (Some(e), None) => {
-                format!(
-                    "{} is not allowed before {}",
-                    action_name,
-                    e.format("%H:%M")
-                )
-            }
-            (None, Some(l)) => {
-                format!("{} is not allowed after {}", action_name, l.format("%H:%M"))
-            }
-            (None, None) => format!("{} is currently restricted", action_name),
-        }
-    }
-
     async fn build_restriction_response(
         &self,
         restriction: &ClockRestriction,
@@ -720,3 +948,106 @@ impl ClockRestrictionService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enums::GeofenceMode;
+
+    fn restriction_with_networks(networks: &[&str]) -> ClockRestriction {
+        ClockRestriction {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            team_id: None,
+            user_id: None,
+            mode: ClockRestrictionMode::Strict,
+            clock_in_earliest: None,
+            clock_in_latest: None,
+            clock_out_earliest: None,
+            clock_out_latest: None,
+            enforce_schedule: false,
+            require_manager_approval: false,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            max_daily_clock_events: None,
+            geofence_mode: GeofenceMode::Off,
+            allowed_networks: networks.iter().map(|n| n.to_string()).collect(),
+            schedule_early_minutes: 15,
+            schedule_late_minutes: 15,
+        }
+    }
+
+    #[test]
+    fn test_network_message() {
+        let open = restriction_with_networks(&[]);
+        assert!(network_message(&open, "clock_in", None).is_none());
+
+        let office = restriction_with_networks(&["203.0.113.0/24", "2001:db8::/32"]);
+        assert!(network_message(&office, "clock_in", "203.0.113.9".parse().ok()).is_none());
+        assert!(network_message(&office, "clock_out", "2001:db8::1".parse().ok()).is_none());
+        assert_eq!(
+            network_message(&office, "clock_out", "198.51.100.1".parse().ok()).as_deref(),
+            Some("Clock out is only allowed from an authorized network")
+        );
+        // Unknown addresses cannot be checked against the allowlist
+        assert!(network_message(&office, "clock_in", None).is_some());
+    }
+
+    #[test]
+    fn test_normalize_networks() {
+        let networks = normalize_networks(vec![
+            "10.1.2.3/8".to_string(),
+            "10.0.0.0/8".to_string(),
+            "192.0.2.7".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(networks, vec!["10.0.0.0/8", "192.0.2.7/32"]);
+
+        assert!(normalize_networks(vec!["office".to_string()]).is_err());
+        assert!(normalize_networks(vec!["10.0.0.1".to_string(); 51]).is_err());
+    }
+
+    #[test]
+    fn test_schedule_window_message() {
+        let mut restriction = restriction_with_networks(&[]);
+        restriction.schedule_early_minutes = 15;
+        restriction.schedule_late_minutes = 30;
+        let at = |d: u32, h: u32, m: u32| {
+            chrono::NaiveDate::from_ymd_opt(2026, 3, d)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+        // Night shift from the 2nd 22:00 to the 3rd 06:00
+        let planned = vec![(at(2, 22, 0), at(3, 6, 0))];
+
+        assert!(
+            schedule_window_message(&restriction, "clock_in", at(2, 21, 45), &planned).is_none()
+        );
+        assert!(
+            schedule_window_message(&restriction, "clock_in", at(2, 22, 30), &planned).is_none()
+        );
+        assert_eq!(
+            schedule_window_message(&restriction, "clock_in", at(2, 21, 40), &planned).as_deref(),
+            Some("Clock in is only allowed between 21:45 and 22:30 according to your schedule")
+        );
+        // Clocking out after midnight follows the shift end
+        assert!(
+            schedule_window_message(&restriction, "clock_out", at(3, 6, 20), &planned).is_none()
+        );
+        assert!(
+            schedule_window_message(&restriction, "clock_out", at(3, 4, 0), &planned).is_some()
+        );
+        assert_eq!(
+            schedule_window_message(&restriction, "clock_in", at(4, 9, 0), &planned).as_deref(),
+            Some("Clock in is not allowed: no work is scheduled today")
+        );
+        assert!(schedule_window_message(&restriction, "clock_in", at(4, 9, 0), &[]).is_some());
+
+        assert!(validate_schedule_tolerance(0).is_ok());
+        assert!(validate_schedule_tolerance(720).is_ok());
+        assert!(validate_schedule_tolerance(-1).is_err());
+        assert!(validate_schedule_tolerance(721).is_err());
+    }
+}
@@ -1,18 +1,32 @@
-use chrono::Utc;
+use std::net::IpAddr;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use chrono_tz::Tz;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
-use crate::domain::enums::{ClockRestrictionMode, NotificationType, UserRole};
+use crate::config::AutoCloseConfig;
+use crate::domain::enums::{
+    ClockEntrySource, ClockEntryStatus, ClockRestrictionMode, GeofenceMode, NotificationType,
+    UserRole,
+};
 use crate::error::AppError;
 use crate::models::{
-    ClockEntry, ClockEntryResponse, ClockFilter, ClockStatus, ClockValidationResult,
+    resolve_shift_day, ClockContext, ClockEntry, ClockEntryResponse, ClockFilter, ClockStatus,
+    ClockValidationResult, CreateManualClockEntryRequest, GeoPosition, NewClockEntry,
     PaginatedClockEntries, Pagination, PendingClockFilter,
 };
 use crate::repositories::{
     ClockRepository, ClockRestrictionRepository, OrganizationRepository, TeamRepository,
-    WorkScheduleRepository,
+    UserRepository, WorkScheduleRepository, WorkSiteRepository,
 };
-use crate::services::NotificationService;
+use crate::services::clock_restriction_service::{network_message, time_window_message};
+use crate::services::work_site_service::{check_geofence, geofence_message};
+use crate::services::{
+    ClockAnomalyService, ClockApprovalRuleService, NotificationService, PresenceEventKind,
+    PresenceService,
+};
+use crate::utils::timezone::{local_day_bounds, to_local, to_utc, DEFAULT_TIMEZONE};
 
 /// Service for clock in/out operations
 pub struct ClockService {
@@ -20,9 +34,14 @@ pub struct ClockService {
     restriction_repo: ClockRestrictionRepository,
     team_repo: TeamRepository,
     org_repo: OrganizationRepository,
+    user_repo: UserRepository,
     work_schedule_repo: WorkScheduleRepository,
+    work_site_repo: WorkSiteRepository,
 }
 
+/// Longest manual entry a manager can record in one go
+const MAX_MANUAL_ENTRY_HOURS: i64 = 24;
+
 impl ClockService {
     pub fn new(pool: DbPool) -> Self {
         Self {
@@ -30,17 +49,22 @@ impl ClockService {
             restriction_repo: ClockRestrictionRepository::new(pool.clone()),
             team_repo: TeamRepository::new(pool.clone()),
             org_repo: OrganizationRepository::new(pool.clone()),
-            work_schedule_repo: WorkScheduleRepository::new(pool),
+            user_repo: UserRepository::new(pool.clone()),
+            work_schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            work_site_repo: WorkSiteRepository::new(pool),
         }
     }
 
     /// Clock in - creates a new clock entry
     /// Returns validation result if restrictions block the action
+    ///
+    /// Events recorded offline (`context.recorded_at`) are validated at the time they happened.
     pub async fn clock_in(
         &self,
         org_id: Uuid,
         user_id: Uuid,
         notes: Option<String>,
+        context: ClockContext,
     ) -> Result<ClockEntry, AppError> {
         // Check if user already has an open clock entry
         if self
@@ -54,6 +78,18 @@ impl ClockService {
             ));
         }
 
+        let at = context.recorded_at.unwrap_or_else(Utc::now);
+        if context.recorded_at.is_some()
+            && self
+                .clock_repo
+                .has_overlapping_entry(org_id, user_id, at, Utc::now(), None)
+                .await?
+        {
+            return Err(AppError::ValidationError(
+                "Clock in overlaps an existing clock entry".to_string(),
+            ));
+        }
+
         // Check daily clock limit if configured
         let effective = self
             .restriction_repo
@@ -61,10 +97,12 @@ impl ClockService {
             .await?;
         if let Some(ref eff) = effective {
             if let Some(max_daily) = eff.restriction.max_daily_clock_events {
-                let today = Utc::now().date_naive();
+                // "Today" is the organization's local day
+                let tz = self.org_repo.get_timezone(org_id).await?;
+                let today = to_local(at, tz).date();
                 let daily_count = self
                     .clock_repo
-                    .count_daily_entries(org_id, user_id, today)
+                    .count_daily_entries(org_id, user_id, today, tz)
                     .await?;
                 if daily_count >= max_daily as i64 {
                     return Err(AppError::ValidationError(format!(
@@ -83,7 +121,14 @@ impl ClockService {
 
         // Validate clock restrictions
         let validation = self
-            .validate_clock_action(org_id, user_id, "clock_in")
+            .validate_clock_action_at(
+                org_id,
+                user_id,
+                "clock_in",
+                context.position.as_ref(),
+                context.client_ip,
+                at,
+            )
             .await?;
         if !validation.allowed {
             let message = validation
@@ -98,7 +143,21 @@ impl ClockService {
             return Err(AppError::ValidationError(message));
         }
 
-        let entry = self.clock_repo.clock_in(org_id, user_id, notes).await?;
+        let (work_site_id, outside_geofence) = match validation.geofence {
+            Some(check) => (check.work_site_id, !check.within),
+            None => (None, false),
+        };
+        let entry = self
+            .clock_repo
+            .clock_in(
+                org_id,
+                user_id,
+                notes,
+                context,
+                work_site_id,
+                outside_geofence,
+            )
+            .await?;
 
         // Mark the override as used if one was found
         if let Some(override_req) = valid_override {
@@ -108,6 +167,16 @@ impl ClockService {
                 .await;
         }
 
+        PresenceService::new(self.clock_repo.pool().clone())
+            .publish(
+                PresenceEventKind::ClockedIn,
+                org_id,
+                user_id,
+                entry.id,
+                entry.clock_in,
+            )
+            .await;
+
         Ok(entry)
     }
 
@@ -117,6 +186,7 @@ impl ClockService {
         org_id: Uuid,
         user_id: Uuid,
         notes: Option<String>,
+        context: ClockContext,
     ) -> Result<ClockEntry, AppError> {
         // Find the open entry
         let entry = self
@@ -125,6 +195,23 @@ impl ClockService {
             .await?
             .ok_or_else(|| AppError::ValidationError("You are not clocked in".to_string()))?;
 
+        let at = context.recorded_at.unwrap_or_else(Utc::now);
+        if at <= entry.clock_in {
+            return Err(AppError::ValidationError(
+                "Clock out must be after clock in".to_string(),
+            ));
+        }
+        if context.recorded_at.is_some()
+            && self
+                .clock_repo
+                .has_overlapping_entry(org_id, user_id, entry.clock_in, at, Some(entry.id))
+                .await?
+        {
+            return Err(AppError::ValidationError(
+                "Clock out overlaps an existing clock entry".to_string(),
+            ));
+        }
+
         // Check for valid approved override before validation
         let valid_override = self
             .restriction_repo
@@ -133,7 +220,14 @@ impl ClockService {
 
         // Validate clock restrictions
         let validation = self
-            .validate_clock_action(org_id, user_id, "clock_out")
+            .validate_clock_action_at(
+                org_id,
+                user_id,
+                "clock_out",
+                context.position.as_ref(),
+                context.client_ip,
+                at,
+            )
             .await?;
         if !validation.allowed {
             let message = validation
@@ -148,7 +242,18 @@ impl ClockService {
             return Err(AppError::ValidationError(message));
         }
 
-        let result = self.clock_repo.clock_out(org_id, entry.id, notes).await?;
+        // Keep the flag raised at clock in
+        let outside_geofence =
+            entry.outside_geofence || validation.geofence.is_some_and(|check| !check.within);
+        // Keep the drift flag raised at clock in as well
+        let context = ClockContext {
+            clock_drift_seconds: context.clock_drift_seconds.or(entry.clock_drift_seconds),
+            ..context
+        };
+        let result = self
+            .clock_repo
+            .clock_out(org_id, entry.id, notes, context, outside_geofence)
+            .await?;
 
         // Mark the override as used if one was found
         if let Some(override_req) = valid_override {
@@ -158,6 +263,69 @@ impl ClockService {
                 .await;
         }
 
+        PresenceService::new(self.clock_repo.pool().clone())
+            .publish(
+                PresenceEventKind::ClockedOut,
+                org_id,
+                user_id,
+                result.id,
+                result.clock_out.unwrap_or(at),
+            )
+            .await;
+
+        // Entries with anomalies wait for a manager
+        let anomaly_service = ClockAnomalyService::new(self.clock_repo.pool().clone());
+        match anomaly_service.evaluate_entry(&result).await {
+            Ok(findings) if !findings.is_empty() => return Ok(result),
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(
+                    entry_id = %result.id,
+                    error = %e,
+                    "Failed to evaluate clock anomalies"
+                );
+            }
+        }
+
+        // Approve automatically when an approval rule matches
+        // Overrides used at clock in or clock out are linked to the entry
+        let override_used = !self
+            .restriction_repo
+            .find_overrides_by_clock_entry_ids(org_id, &[result.id])
+            .await?
+            .is_empty();
+        let rule_service = ClockApprovalRuleService::new(self.clock_repo.pool().clone());
+        match rule_service
+            .find_matching_rule(&result, override_used)
+            .await
+        {
+            Ok(Some(rule)) => {
+                // The entry stays pending when it falls in a closed pay period
+                match self
+                    .clock_repo
+                    .auto_approve(org_id, result.id, rule.id)
+                    .await
+                {
+                    Ok(approved) => return Ok(approved),
+                    Err(e) => {
+                        tracing::warn!(
+                            entry_id = %result.id,
+                            error = %e,
+                            "Failed to auto-approve clock entry"
+                        );
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(
+                    entry_id = %result.id,
+                    error = %e,
+                    "Failed to evaluate clock approval rules"
+                );
+            }
+        }
+
         Ok(result)
     }
 
@@ -434,6 +602,294 @@ impl ClockService {
         Ok(rejected)
     }
 
+    /// Create a closed clock entry on behalf of a team member (Manager+ only)
+    /// Used for missed punches; the entry is tagged as manual and approved by its creator
+    pub async fn create_manual_entry(
+        &self,
+        org_id: Uuid,
+        creator_id: Uuid,
+        creator_role: UserRole,
+        request: CreateManualClockEntryRequest,
+    ) -> Result<ClockEntry, AppError> {
+        // Verify creator has permission
+        if creator_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can create clock entries for other users".to_string(),
+            ));
+        }
+
+        let user = self.user_repo.find_by_id(request.user_id).await?;
+        if user.organization_id != org_id {
+            return Err(AppError::NotFound("User not found".to_string()));
+        }
+
+        if request.clock_out <= request.clock_in {
+            return Err(AppError::ValidationError(
+                "Clock out must be after clock in".to_string(),
+            ));
+        }
+        if request.clock_out > Utc::now() {
+            return Err(AppError::ValidationError(
+                "Manual entries can only be created for the past".to_string(),
+            ));
+        }
+        if request.clock_out - request.clock_in > Duration::hours(MAX_MANUAL_ENTRY_HOURS) {
+            return Err(AppError::ValidationError(format!(
+                "A manual entry cannot exceed {} hours",
+                MAX_MANUAL_ENTRY_HOURS
+            )));
+        }
+
+        // For managers, verify they manage a team the user belongs to
+        if creator_role == UserRole::Manager {
+            let managed_teams = self.team_repo.get_managed_teams(org_id, creator_id).await?;
+            let mut can_create = false;
+
+            for team in managed_teams {
+                if self.team_repo.is_member(team.id, request.user_id).await? {
+                    can_create = true;
+                    break;
+                }
+            }
+
+            if !can_create {
+                return Err(AppError::Forbidden(
+                    "You can only create entries for members of your team".to_string(),
+                ));
+            }
+        }
+
+        if self
+            .clock_repo
+            .has_overlapping_entry(
+                org_id,
+                request.user_id,
+                request.clock_in,
+                request.clock_out,
+                None,
+            )
+            .await?
+        {
+            return Err(AppError::ValidationError(
+                "The entry overlaps an existing clock entry for this user".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        self.clock_repo
+            .create(NewClockEntry {
+                organization_id: org_id,
+                user_id: request.user_id,
+                clock_in: request.clock_in,
+                clock_out: Some(request.clock_out),
+                status: ClockEntryStatus::Approved,
+                approved_by: Some(creator_id),
+                approved_at: Some(now),
+                notes: request.notes,
+                source: ClockEntrySource::Manual,
+                created_by: Some(creator_id),
+                clock_in_latitude: None,
+                clock_in_longitude: None,
+                clock_in_accuracy: None,
+                work_site_id: None,
+                outside_geofence: false,
+                kiosk_device_id: None,
+                clock_drift_seconds: None,
+                clock_in_ip: None,
+            })
+            .await
+    }
+
+    /// Close open entries that were forgotten by their owner (background job)
+    ///
+    /// An entry is closed at the end of the user's scheduled day, or after
+    /// `max_shift_hours` when no schedule applies, once the grace period has elapsed.
+    /// Closed entries are flagged for review and the user and their managers are notified.
+    /// Returns the number of entries closed.
+    pub async fn auto_close_stale_entries(
+        &self,
+        config: &AutoCloseConfig,
+    ) -> Result<usize, AppError> {
+        let now = Utc::now();
+        let grace = Duration::minutes(config.grace_minutes);
+
+        // Any entry that could be due is at least as old as the shortest cutoff
+        let candidates = self
+            .clock_repo
+            .list_open_entries_started_before(now - grace)
+            .await?;
+
+        let mut closed = 0;
+        for entry in candidates {
+            let tz = match self.org_repo.get_timezone(entry.organization_id).await {
+                Ok(tz) => tz,
+                Err(e) => {
+                    tracing::warn!(entry_id = %entry.id, error = %e, "Failed to resolve timezone");
+                    DEFAULT_TIMEZONE
+                }
+            };
+            let scheduled_end = match self.scheduled_end_for(&entry, tz).await {
+                Ok(end) => end,
+                Err(e) => {
+                    tracing::warn!(
+                        entry_id = %entry.id,
+                        error = %e,
+                        "Failed to resolve schedule for open clock entry"
+                    );
+                    None
+                }
+            };
+
+            let close_at =
+                auto_close_time(entry.clock_in, scheduled_end, config.max_shift_hours, tz);
+            if now < close_at + grace {
+                continue;
+            }
+
+            let closed_entry = match self
+                .clock_repo
+                .auto_close_entry(entry.organization_id, entry.id, close_at)
+                .await
+            {
+                Ok(Some(closed_entry)) => closed_entry,
+                // User clocked out in the meantime
+                Ok(None) => continue,
+                // Left open in a closed pay period: later entries must still be closed
+                Err(AppError::Conflict(message)) => {
+                    tracing::warn!(
+                        entry_id = %entry.id,
+                        reason = %message,
+                        "Cannot auto-close clock entry"
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            tracing::info!(
+                entry_id = %closed_entry.id,
+                user_id = %closed_entry.user_id,
+                clock_out = %close_at,
+                "Auto-closed forgotten clock entry"
+            );
+            PresenceService::new(self.clock_repo.pool().clone())
+                .publish(
+                    PresenceEventKind::ClockedOut,
+                    closed_entry.organization_id,
+                    closed_entry.user_id,
+                    closed_entry.id,
+                    close_at,
+                )
+                .await;
+            self.notify_auto_close(&closed_entry).await;
+            closed += 1;
+        }
+
+        Ok(closed)
+    }
+
+    /// Local scheduled end of the shift the entry belongs to, if any
+    async fn scheduled_end_for(
+        &self,
+        entry: &ClockEntry,
+        tz: Tz,
+    ) -> Result<Option<NaiveDateTime>, AppError> {
+        let local_clock_in = to_local(entry.clock_in, tz);
+        let schedule = self
+            .work_schedule_repo
+            .get_effective_schedule(
+                entry.organization_id,
+                entry.user_id,
+                local_clock_in.date() - Duration::days(1),
+                local_clock_in.date(),
+            )
+            .await?;
+
+        let (shift_date, day) = resolve_shift_day(schedule.as_ref(), local_clock_in);
+        Ok(day.map(|d| d.shift_bounds(shift_date).1))
+    }
+
+    /// Notify the employee and their team managers that an entry was auto-closed
+    async fn notify_auto_close(&self, entry: &ClockEntry) {
+        let notification_service = NotificationService::new(self.clock_repo.pool().clone());
+        let clock_in_str = entry.clock_in.format("%Y-%m-%d %H:%M").to_string();
+        let clock_out_str = entry
+            .clock_out
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        if let Err(e) = notification_service
+            .create_notification(
+                entry.organization_id,
+                entry.user_id,
+                NotificationType::ClockAutoClosed,
+                "Clock Entry Closed Automatically".to_string(),
+                format!(
+                    "You did not clock out after clocking in at {}. The entry was closed at {} and is pending review. Submit a correction if this time is wrong.",
+                    clock_in_str, clock_out_str
+                ),
+                None,
+            )
+            .await
+        {
+            tracing::warn!(
+                user_id = %entry.user_id,
+                entry_id = %entry.id,
+                error = %e,
+                "Failed to create auto-close notification"
+            );
+        }
+
+        let teams = match self
+            .team_repo
+            .get_user_teams(entry.organization_id, entry.user_id)
+            .await
+        {
+            Ok(teams) => teams,
+            Err(e) => {
+                tracing::warn!(entry_id = %entry.id, error = %e, "Failed to load user teams");
+                return;
+            }
+        };
+
+        let user_name = self
+            .clock_repo
+            .get_user_info(entry.user_id)
+            .await
+            .map(|(name, _)| name)
+            .unwrap_or_else(|_| "An employee".to_string());
+
+        let mut notified = Vec::new();
+        for manager_id in teams.into_iter().filter_map(|t| t.manager_id) {
+            if manager_id == entry.user_id || notified.contains(&manager_id) {
+                continue;
+            }
+            notified.push(manager_id);
+
+            if let Err(e) = notification_service
+                .create_notification(
+                    entry.organization_id,
+                    manager_id,
+                    NotificationType::ClockAutoClosed,
+                    "Clock Entry Closed Automatically".to_string(),
+                    format!(
+                        "{}'s clock entry from {} was left open and closed automatically at {}. Please review it.",
+                        user_name, clock_in_str, clock_out_str
+                    ),
+                    None,
+                )
+                .await
+            {
+                tracing::warn!(
+                    manager_id = %manager_id,
+                    entry_id = %entry.id,
+                    error = %e,
+                    "Failed to create auto-close notification"
+                );
+            }
+        }
+    }
+
     /// List pending entries (for approval)
     ///
     /// - SuperAdmin: Can filter by organization_id (defaults to their org), can filter by team_id
@@ -509,8 +965,9 @@ impl ClockService {
             }
         };
 
-        // Fetch organization name
+        // Fetch organization name and timezone
         let organization = self.org_repo.find_by_id(org_id).await?;
+        let tz = organization.tz();
         let org_name = organization.name;
 
         // Batch fetch override info for all entries
@@ -536,27 +993,20 @@ impl ClockService {
                 .get(&entry.id)
                 .map(|o| (o.id, o.reason.clone(), o.status));
 
-            // Calculate theoretical hours for this entry's day from user's schedule
+            // Calculate theoretical hours for this entry's local day from user's schedule
+            let (day_start, day_end) = local_day_bounds(to_local(entry.clock_in, tz).date(), tz);
             let theoretical_hours = self
                 .work_schedule_repo
                 .get_theoretical_hours(
                     org_id,
                     entry.user_id,
-                    entry
-                        .clock_in
-                        .date_naive()
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_utc(),
-                    entry
-                        .clock_in
-                        .date_naive()
-                        .and_hms_opt(23, 59, 59)
-                        .unwrap()
-                        .and_utc(),
+                    day_start,
+                    day_end - Duration::seconds(1),
+                    tz,
                 )
                 .await
                 .ok()
+                .map(|h| h.expected_hours)
                 .filter(|h| *h > 0.0);
 
             responses.push(ClockEntryResponse::from_entry(
@@ -589,14 +1039,32 @@ impl ClockService {
 
     /// Validate if a clock action is allowed based on restrictions
     /// Checks for approved overrides before blocking
+    ///
+    /// The reported position is checked against the work sites when the
+    /// restriction has geofencing on. An enforced geofence cannot be overridden.
+    /// The client address is checked against the allowed networks of the restriction.
     pub async fn validate_clock_action(
         &self,
         org_id: Uuid,
         user_id: Uuid,
         action: &str,
+        position: Option<&GeoPosition>,
+        client_ip: Option<IpAddr>,
     ) -> Result<ClockValidationResult, AppError> {
-        use chrono::{NaiveTime, Timelike};
+        self.validate_clock_action_at(org_id, user_id, action, position, client_ip, Utc::now())
+            .await
+    }
 
+    /// Validate a clock action as if it happened at `at` (offline events)
+    pub async fn validate_clock_action_at(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        action: &str,
+        position: Option<&GeoPosition>,
+        client_ip: Option<IpAddr>,
+        at: DateTime<Utc>,
+    ) -> Result<ClockValidationResult, AppError> {
         // Get effective restriction for the user
         let effective = self
             .restriction_repo
@@ -610,11 +1078,54 @@ impl ClockService {
                 message: None,
                 can_request_override: false,
                 effective_restriction: None,
+                geofence: None,
             });
         };
 
         let restriction = &effective_restriction.restriction;
 
+        // Geofencing applies on top of the time window mode
+        let geofence = if restriction.geofence_mode == GeofenceMode::Off {
+            None
+        } else {
+            let sites = self.work_site_repo.list_active(org_id).await?;
+            Some(check_geofence(&sites, position))
+        };
+
+        if let Some(check) = geofence
+            .as_ref()
+            .filter(|check| restriction.geofence_mode == GeofenceMode::Enforce && !check.within)
+        {
+            let message = geofence_message(action, position, check);
+            return Ok(ClockValidationResult {
+                allowed: false,
+                message: Some(message),
+                can_request_override: false,
+                effective_restriction: Some(effective_restriction),
+                geofence,
+            });
+        }
+
+        // The network allowlist applies in every mode, only flexible mode can be overridden
+        if let Some(message) = network_message(restriction, action, client_ip) {
+            let can_request_override = restriction.mode == ClockRestrictionMode::Flexible;
+            let overridden = can_request_override
+                && self
+                    .restriction_repo
+                    .find_valid_approved_override(org_id, user_id, action)
+                    .await?
+                    .is_some();
+            if !overridden {
+                return Ok(ClockValidationResult {
+                    allowed: false,
+                    message: Some(message),
+                    can_request_override,
+                    effective_restriction: Some(effective_restriction),
+                    geofence,
+                });
+            }
+        }
+
         // Unrestricted mode - always allowed
         if restriction.mode == ClockRestrictionMode::Unrestricted {
             return Ok(ClockValidationResult {
@@ -622,31 +1133,26 @@ impl ClockService {
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             });
         }
 
-        // Get current time
-        let now = Utc::now();
-        let current_time =
-            NaiveTime::from_hms_opt(now.hour(), now.minute(), now.second()).unwrap_or_default();
-
-        // Check time window based on action
-        let (earliest, latest) = if action == "clock_in" {
-            (restriction.clock_in_earliest, restriction.clock_in_latest)
-        } else {
-            (restriction.clock_out_earliest, restriction.clock_out_latest)
-        };
-
-        let within_window = self.check_time_window(current_time, earliest, latest);
+        // Get the action time in the organization's timezone
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let now = to_local(at, tz);
+        let message =
+            time_window_message(&self.work_schedule_repo, restriction, user_id, action, now)
+                .await?;
 
-        if within_window {
+        let Some(message) = message else {
             return Ok(ClockValidationResult {
                 allowed: true,
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             });
-        }
+        };
 
         // Outside allowed window - check if there's a valid approved override
         let valid_override = self
@@ -661,30 +1167,32 @@ impl ClockService {
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             });
         }
 
-        // No valid override - build restriction message
-        let message = self.build_restriction_message(action, earliest, latest);
-
+        // No valid override - block according to the mode
         match restriction.mode {
             ClockRestrictionMode::Strict => Ok(ClockValidationResult {
                 allowed: false,
                 message: Some(message),
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             }),
             ClockRestrictionMode::Flexible => Ok(ClockValidationResult {
                 allowed: false,
                 message: Some(message),
                 can_request_override: true,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             }),
             ClockRestrictionMode::Unrestricted => Ok(ClockValidationResult {
                 allowed: true,
                 message: None,
                 can_request_override: false,
                 effective_restriction: Some(effective_restriction),
+                geofence,
             }),
         }
     }
@@ -698,54 +1206,99 @@ impl ClockService {
         // Return both clock_in and clock_out validation status
         // For simplicity, we return the clock_in validation
         let validation = self
-            .validate_clock_action(org_id, user_id, "clock_in")
+            .validate_clock_action(org_id, user_id, "clock_in", None, None)
             .await?;
         Ok(Some(validation))
     }
+}
 
-    fn check_time_window(
-        &self,
-        current: chrono::NaiveTime,
-        earliest: Option<chrono::NaiveTime>,
-        latest: Option<chrono::NaiveTime>,
-    ) -> bool {
-        let after_earliest = earliest.is_none_or(|e| current >= e);
-        let before_latest = latest.is_none_or(|l| current <= l);
-        after_earliest && before_latest
+/// Compute when a forgotten entry should be considered closed
+///
+/// Uses the local scheduled end of the entry's shift when it falls after the clock-in,
+/// capped by the maximum shift length.
+fn auto_close_time(
+    clock_in: DateTime<Utc>,
+    scheduled_end: Option<NaiveDateTime>,
+    max_shift_hours: i64,
+    tz: Tz,
+) -> DateTime<Utc> {
+    let max_close = clock_in + Duration::hours(max_shift_hours);
+    scheduled_end
+        .map(|end| to_utc(end, tz))
+        .filter(|end| *end > clock_in)
+        .map_or(max_close, |end| end.min(max_close))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn local(date: (i32, u32, u32), hour: u32) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(date.0, date.1, date.2).and_then(|d| d.and_hms_opt(hour, 0, 0))
     }
 
-    fn build_restriction_message(
-        &self,
-        action: &str,
-        earliest: Option<chrono::NaiveTime>,
-        latest: Option<chrono::NaiveTime>,
-    ) -> String {
-        let action_name = if action == "clock_in" {
-            "Clock in"
-        } else {
-            "Clock out"
-        };
+    #[test]
+    fn test_auto_close_time_uses_scheduled_end() {
+        let clock_in = Utc.with_ymd_and_hms(2026, 1, 12, 8, 0, 0).unwrap();
+        let end = local((2026, 1, 12), 17);
 
-        match (earliest, latest) {
-            (Some(e), Some(l)) => {
-                format!(
-                    "{} is only allowed between {} and {}",
-                    action_name,
-                    e.format("%H:%M"),
-                    l.format("%H:%M")
-                )
-            }
-            (Some(e), None) => {
-                format!(
-                    "{} is not allowed before {}",
-                    action_name,
-                    e.format("%H:%M")
-                )
-            }
-            (None, Some(l)) => {
-                format!("{} is not allowed after {}", action_name, l.format("%H:%M"))
-            }
-            (None, None) => format!("{} is currently restricted", action_name),
-        }
+        let close = auto_close_time(clock_in, end, 12, chrono_tz::UTC);
+
+        assert_eq!(close, Utc.with_ymd_and_hms(2026, 1, 12, 17, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_auto_close_time_falls_back_to_max_shift() {
+        let clock_in = Utc.with_ymd_and_hms(2026, 1, 12, 8, 0, 0).unwrap();
+
+        assert_eq!(
+            auto_close_time(clock_in, None, 12, chrono_tz::UTC),
+            Utc.with_ymd_and_hms(2026, 1, 12, 20, 0, 0).unwrap()
+        );
+
+        // Clocked in after the scheduled end of day
+        let late = Utc.with_ymd_and_hms(2026, 1, 12, 18, 0, 0).unwrap();
+        assert_eq!(
+            auto_close_time(late, local((2026, 1, 12), 17), 12, chrono_tz::UTC),
+            Utc.with_ymd_and_hms(2026, 1, 13, 6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_auto_close_time_uses_local_schedule() {
+        // 17:00 in Paris is 16:00 UTC in winter and 15:00 UTC in summer
+        let tz = chrono_tz::Europe::Paris;
+
+        let winter = Utc.with_ymd_and_hms(2026, 1, 12, 7, 0, 0).unwrap();
+        assert_eq!(
+            auto_close_time(winter, local((2026, 1, 12), 17), 12, tz),
+            Utc.with_ymd_and_hms(2026, 1, 12, 16, 0, 0).unwrap()
+        );
+
+        let summer = Utc.with_ymd_and_hms(2026, 7, 13, 6, 0, 0).unwrap();
+        assert_eq!(
+            auto_close_time(summer, local((2026, 7, 13), 17), 12, tz),
+            Utc.with_ymd_and_hms(2026, 7, 13, 15, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_auto_close_time_overnight_shift() {
+        // 22:00 to 06:00 shift closes the next morning
+        let clock_in = Utc.with_ymd_and_hms(2026, 1, 12, 22, 0, 0).unwrap();
+
+        let close = auto_close_time(clock_in, local((2026, 1, 13), 6), 12, chrono_tz::UTC);
+
+        assert_eq!(close, Utc.with_ymd_and_hms(2026, 1, 13, 6, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_auto_close_time_capped_by_max_shift() {
+        let clock_in = Utc.with_ymd_and_hms(2026, 1, 12, 6, 0, 0).unwrap();
+
+        let close = auto_close_time(clock_in, local((2026, 1, 12), 23), 8, chrono_tz::UTC);
+
+        assert_eq!(close, Utc.with_ymd_and_hms(2026, 1, 12, 14, 0, 0).unwrap());
     }
 }
@@ -0,0 +1,388 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::ClockSyncStatus;
+use crate::error::AppError;
+use crate::models::{
+    ClockContext, ClockSyncAction, ClockSyncBatchRequest, ClockSyncBatchResponse,
+    ClockSyncEventRequest, ClockSyncEventResult, NewClockSyncEvent,
+};
+use crate::repositories::ClockSyncRepository;
+use crate::services::work_site_service::parse_position;
+use crate::services::ClockService;
+
+/// Largest number of events accepted in one upload
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Oldest offline event that can still be replayed
+const MAX_EVENT_AGE_DAYS: i64 = 7;
+
+/// Device clock drift tolerated before entries need manager approval
+const MAX_CLOCK_DRIFT_SECONDS: i64 = 300;
+
+/// Longest idempotency key or device id
+const MAX_ID_LENGTH: usize = 100;
+
+/// Service replaying clock events recorded offline
+///
+/// Events are replayed in chronological order through the regular clock in/out
+/// logic, so the same sequence and restriction checks apply. Every event is
+/// reserved by its idempotency key before it is replayed and stored with its
+/// outcome: uploading the same event again returns the stored result instead of
+/// replaying it.
+pub struct ClockSyncService {
+    clock_service: ClockService,
+    sync_repo: ClockSyncRepository,
+}
+
+impl ClockSyncService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            clock_service: ClockService::new(pool.clone()),
+            sync_repo: ClockSyncRepository::new(pool),
+        }
+    }
+
+    /// Replay a batch of offline events for a user
+    ///
+    /// A rejected event does not stop the batch. Unexpected errors (database, pool)
+    /// abort it; events processed so far are kept and a retry skips them.
+    pub async fn sync_batch(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        request: ClockSyncBatchRequest,
+    ) -> Result<ClockSyncBatchResponse, AppError> {
+        if request.events.is_empty() {
+            return Err(AppError::ValidationError(
+                "The batch contains no events".to_string(),
+            ));
+        }
+        if request.events.len() > MAX_BATCH_SIZE {
+            return Err(AppError::ValidationError(format!(
+                "A batch cannot contain more than {} events",
+                MAX_BATCH_SIZE
+            )));
+        }
+
+        // Malformed events reject the whole upload before anything is replayed
+        for event in &request.events {
+            validate_id("Idempotency key", event.idempotency_key.trim())?;
+            validate_id("Device id", event.device_id.trim())?;
+        }
+
+        let clock = UploadClock::new(request.sent_at, Utc::now());
+
+        let mut events = request.events;
+        // Stable: events with the same timestamp keep their upload order
+        events.sort_by_key(|e| e.client_timestamp);
+
+        let mut results = Vec::with_capacity(events.len());
+        for event in events {
+            let result = self.replay_event(org_id, user_id, event, &clock).await?;
+            results.push(result);
+        }
+
+        let count = |status: ClockSyncStatus| {
+            results
+                .iter()
+                .filter(|r| !r.duplicate && r.status == status)
+                .count()
+        };
+
+        Ok(ClockSyncBatchResponse {
+            received_at: clock.received_at,
+            clock_drift_seconds: clock.drift_seconds(),
+            applied: count(ClockSyncStatus::Applied),
+            flagged: count(ClockSyncStatus::Flagged),
+            rejected: count(ClockSyncStatus::Rejected),
+            duplicates: results.iter().filter(|r| r.duplicate).count(),
+            results,
+        })
+    }
+
+    /// Replay one event, once per idempotency key
+    ///
+    /// The key is reserved before the event is replayed, so concurrent uploads of the
+    /// same event cannot both apply it: the later one fails with a conflict while the
+    /// event is being replayed, and gets the outcome back as a duplicate afterwards.
+    async fn replay_event(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        event: ClockSyncEventRequest,
+        clock: &UploadClock,
+    ) -> Result<ClockSyncEventResult, AppError> {
+        let key = event.idempotency_key.trim().to_string();
+        let device_id = event.device_id.trim().to_string();
+
+        let reserved = self
+            .sync_repo
+            .reserve(NewClockSyncEvent {
+                organization_id: org_id,
+                user_id,
+                device_id,
+                idempotency_key: key.clone(),
+                action: event.action.as_str().to_string(),
+                client_timestamp: event.client_timestamp,
+                clock_drift_seconds: clock.drift_seconds(),
+                status: ClockSyncStatus::Pending,
+                clock_entry_id: None,
+                message: None,
+            })
+            .await?;
+        let Some(reserved) = reserved else {
+            let existing = self
+                .sync_repo
+                .find_by_key(org_id, user_id, &key)
+                .await?
+                .filter(|existing| existing.status != ClockSyncStatus::Pending)
+                .ok_or_else(|| {
+                    AppError::Conflict(
+                        "This event is already being synchronized, retry the upload later"
+                            .to_string(),
+                    )
+                })?;
+            return Ok(ClockSyncEventResult::from_event(existing, true));
+        };
+
+        let outcome = match clock.check_event(&event) {
+            Ok(recorded_at) => match self
+                .apply_event(org_id, user_id, &event, recorded_at, clock.drift_flag())
+                .await
+            {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    if let Err(release_error) = self.sync_repo.release(reserved.id).await {
+                        tracing::warn!(
+                            event_id = %reserved.id,
+                            error = %release_error,
+                            "Failed to release clock sync reservation"
+                        );
+                    }
+                    return Err(e);
+                }
+            },
+            Err(message) => Err(message),
+        };
+
+        let (status, clock_entry_id, message) = match outcome {
+            Ok(entry_id) if clock.drift_flag().is_some() => (
+                ClockSyncStatus::Flagged,
+                Some(entry_id),
+                Some(format!(
+                    "Device clock is off by {} seconds, the entry needs manager approval",
+                    clock.drift_seconds()
+                )),
+            ),
+            Ok(entry_id) => (ClockSyncStatus::Applied, Some(entry_id), None),
+            Err(message) => (ClockSyncStatus::Rejected, None, Some(message)),
+        };
+
+        let stored = self
+            .sync_repo
+            .complete(&reserved, status, clock_entry_id, message)
+            .await?;
+
+        Ok(ClockSyncEventResult::from_event(stored, false))
+    }
+
+    /// Replay one event through the clock service
+    /// Business rule violations become a rejection message, other errors are returned
+    async fn apply_event(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        event: &ClockSyncEventRequest,
+        recorded_at: DateTime<Utc>,
+        drift_flag: Option<i32>,
+    ) -> Result<Result<Uuid, String>, AppError> {
+        let position = match parse_position(event.latitude, event.longitude, event.accuracy) {
+            Ok(position) => position,
+            Err(e) => return Ok(Err(rejection_message(e)?)),
+        };
+        let context = ClockContext {
+            position,
+            kiosk_device_id: None,
+            recorded_at: Some(recorded_at),
+            clock_drift_seconds: drift_flag,
+            // The upload address says nothing about where the event happened
+            client_ip: None,
+        };
+
+        let result = match event.action {
+            ClockSyncAction::ClockIn => {
+                self.clock_service
+                    .clock_in(org_id, user_id, event.notes.clone(), context)
+                    .await
+            }
+            ClockSyncAction::ClockOut => {
+                self.clock_service
+                    .clock_out(org_id, user_id, event.notes.clone(), context)
+                    .await
+            }
+        };
+
+        match result {
+            Ok(entry) => Ok(Ok(entry.id)),
+            Err(e) => Ok(Err(rejection_message(e)?)),
+        }
+    }
+}
+
+/// Device and server clocks of an upload
+struct UploadClock {
+    /// Device clock when the batch was sent
+    sent_at: DateTime<Utc>,
+    /// Server clock when the batch arrived
+    received_at: DateTime<Utc>,
+}
+
+impl UploadClock {
+    fn new(sent_at: DateTime<Utc>, received_at: DateTime<Utc>) -> Self {
+        Self {
+            sent_at,
+            received_at,
+        }
+    }
+
+    /// Device clock minus server clock
+    fn drift(&self) -> Duration {
+        self.sent_at - self.received_at
+    }
+
+    fn drift_seconds(&self) -> i32 {
+        self.drift()
+            .num_seconds()
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Drift recorded on entries when it exceeds the tolerance
+    fn drift_flag(&self) -> Option<i32> {
+        (self.drift().num_seconds().abs() > MAX_CLOCK_DRIFT_SECONDS).then(|| self.drift_seconds())
+    }
+
+    /// Check that an event can have happened and return the server time it is recorded at
+    ///
+    /// The device time is corrected by the measured drift and capped at the upload time.
+    fn check_event(&self, event: &ClockSyncEventRequest) -> Result<DateTime<Utc>, String> {
+        if event.client_timestamp > self.sent_at {
+            return Err("Event is dated after the batch was sent".to_string());
+        }
+
+        let recorded_at = (event.client_timestamp - self.drift()).min(self.received_at);
+        if self.received_at - recorded_at > Duration::days(MAX_EVENT_AGE_DAYS) {
+            return Err(format!(
+                "Event is older than {} days and cannot be synchronized",
+                MAX_EVENT_AGE_DAYS
+            ));
+        }
+
+        Ok(recorded_at)
+    }
+}
+
+/// Message returned for events refused by the clock rules
+fn rejection_message(error: AppError) -> Result<String, AppError> {
+    match error {
+        AppError::ValidationError(message)
+        | AppError::Conflict(message)
+        | AppError::Forbidden(message)
+        | AppError::NotFound(message) => Ok(message),
+        other => Err(other),
+    }
+}
+
+fn validate_id(field: &str, value: &str) -> Result<(), AppError> {
+    if value.is_empty() || value.len() > MAX_ID_LENGTH {
+        return Err(AppError::ValidationError(format!(
+            "{} must be between 1 and {} characters",
+            field, MAX_ID_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 3, h, m, 0).unwrap()
+    }
+
+    fn event(client_timestamp: DateTime<Utc>) -> ClockSyncEventRequest {
+        ClockSyncEventRequest {
+            idempotency_key: "key-1".to_string(),
+            device_id: "phone-1".to_string(),
+            action: ClockSyncAction::ClockIn,
+            client_timestamp,
+            notes: None,
+            latitude: None,
+            longitude: None,
+            accuracy: None,
+        }
+    }
+
+    #[test]
+    fn test_drift_flag() {
+        // Device 10 minutes ahead
+        let clock = UploadClock::new(at(12, 10), at(12, 0));
+        assert_eq!(clock.drift_seconds(), 600);
+        assert_eq!(clock.drift_flag(), Some(600));
+
+        // Device 5 minutes behind, within tolerance
+        let clock = UploadClock::new(at(11, 55), at(12, 0));
+        assert_eq!(clock.drift_seconds(), -300);
+        assert_eq!(clock.drift_flag(), None);
+    }
+
+    #[test]
+    fn test_check_event_corrects_drift() {
+        let clock = UploadClock::new(at(12, 10), at(12, 0));
+
+        assert_eq!(clock.check_event(&event(at(9, 10))).unwrap(), at(9, 0));
+    }
+
+    #[test]
+    fn test_check_event_caps_at_upload_time() {
+        // Device slightly ahead, within tolerance
+        let clock = UploadClock::new(at(12, 1), at(12, 0));
+
+        assert_eq!(clock.check_event(&event(at(12, 1))).unwrap(), at(12, 0));
+    }
+
+    #[test]
+    fn test_check_event_rejects_event_after_sending() {
+        let clock = UploadClock::new(at(12, 0), at(12, 0));
+
+        assert!(clock.check_event(&event(at(12, 5))).is_err());
+    }
+
+    #[test]
+    fn test_check_event_rejects_old_event() {
+        let clock = UploadClock::new(at(12, 0), at(12, 0));
+        let old = at(12, 0) - Duration::days(MAX_EVENT_AGE_DAYS + 1);
+
+        assert!(clock.check_event(&event(old)).is_err());
+    }
+
+    #[test]
+    fn test_rejection_message() {
+        let message = rejection_message(AppError::ValidationError(
+            "You are not clocked in".to_string(),
+        ));
+        assert_eq!(message.unwrap(), "You are not clocked in");
+
+        assert!(rejection_message(AppError::InternalError).is_err());
+    }
+
+    #[test]
+    fn test_validate_id() {
+        assert!(validate_id("Device id", "phone-1").is_ok());
+        assert!(validate_id("Device id", "").is_err());
+        assert!(validate_id("Device id", &"x".repeat(MAX_ID_LENGTH + 1)).is_err());
+    }
+}
@@ -61,6 +61,9 @@ impl EndpointRateLimiter {
         // Verify invite: 10 requests per 5 minutes (less sensitive)
         configs.insert("verify_invite".to_string(), RateLimitConfig::new(10, 300));
 
+        // Kiosk PIN: 5 attempts per 5 minutes, reset by a correct PIN
+        configs.insert("kiosk_pin".to_string(), RateLimitConfig::new(5, 300));
+
         Self {
             configs: Arc::new(configs),
             requests: Arc::new(Mutex::new(HashMap::new())),
@@ -115,6 +118,15 @@ impl EndpointRateLimiter {
         Ok(config.max_requests - entry.timestamps.len())
     }
 
+    /// Forget the requests counted for the given endpoint and key (after a success)
+    pub fn reset(&self, endpoint: &str, key: &str) -> Result<(), AppError> {
+        let key = format!("{}:{}", endpoint, key);
+        let mut requests = self.requests.lock().map_err(|_| AppError::InternalError)?;
+        requests.remove(&key);
+
+        Ok(())
+    }
+
     /// Get the configuration for an endpoint
     pub fn get_config(&self, endpoint: &str) -> Option<&RateLimitConfig> {
         self.configs.get(endpoint)
@@ -236,6 +248,27 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_reset() {
+        let limiter = EndpointRateLimiter::new();
+        let endpoint = "kiosk_pin"; // max 5
+        let key = "device:user";
+
+        for _ in 0..4 {
+            limiter.check_rate_limit(endpoint, key).unwrap();
+        }
+        limiter.reset(endpoint, key).unwrap();
+
+        // The budget is whole again after a reset
+        for _ in 0..5 {
+            assert!(limiter.check_rate_limit(endpoint, key).is_ok());
+        }
+        assert!(limiter.check_rate_limit(endpoint, key).is_err());
+
+        // Other keys are not affected
+        assert!(limiter.check_rate_limit(endpoint, "device:other").is_ok());
+    }
+
     #[test]
     fn test_remaining_requests() {
         let limiter = EndpointRateLimiter::new();
@@ -0,0 +1,291 @@
+use rand::Rng;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    GeoPosition, KioskDevice, KioskDeviceCreated, KioskDeviceResponse, KioskDeviceUpdate,
+    KioskEmployee, NewKioskDevice, NewKioskPin,
+};
+use crate::repositories::{KioskRepository, UserRepository, WorkSiteRepository};
+use crate::utils::PasswordService;
+
+/// Prefix of kiosk device credentials, makes them recognizable in logs and configs
+const KIOSK_TOKEN_PREFIX: &str = "kiosk_";
+
+/// Request to register a kiosk device
+#[derive(Debug, Deserialize)]
+pub struct CreateKioskDeviceRequest {
+    pub name: String,
+    pub work_site_id: Option<Uuid>,
+}
+
+/// Request to update a kiosk device
+#[derive(Debug, Deserialize)]
+pub struct UpdateKioskDeviceRequest {
+    pub name: Option<String>,
+    pub work_site_id: Option<Option<Uuid>>,
+    pub is_active: Option<bool>,
+}
+
+/// Request to set an employee's kiosk PIN
+#[derive(Debug, Deserialize)]
+pub struct SetKioskPinRequest {
+    pub pin: String,
+}
+
+/// Service for kiosk devices and employee PINs
+pub struct KioskService {
+    kiosk_repo: KioskRepository,
+    user_repo: UserRepository,
+    work_site_repo: WorkSiteRepository,
+    password_service: PasswordService,
+}
+
+impl KioskService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            kiosk_repo: KioskRepository::new(pool.clone()),
+            user_repo: UserRepository::new(pool.clone()),
+            work_site_repo: WorkSiteRepository::new(pool),
+            password_service: PasswordService::new(),
+        }
+    }
+
+    /// Register a kiosk device
+    /// Returns the device credential, which is only shown once
+    pub async fn create_device(
+        &self,
+        org_id: Uuid,
+        created_by: Uuid,
+        request: CreateKioskDeviceRequest,
+    ) -> Result<KioskDeviceCreated, AppError> {
+        validate_name(&request.name)?;
+        if let Some(site_id) = request.work_site_id {
+            self.work_site_repo.find_by_id(org_id, site_id).await?;
+        }
+
+        let token = generate_kiosk_token();
+        let new_device = NewKioskDevice {
+            organization_id: org_id,
+            name: request.name.trim().to_string(),
+            token_hash: hash_token(&token),
+            work_site_id: request.work_site_id,
+            is_active: true,
+            created_by: Some(created_by),
+        };
+
+        let device = self.kiosk_repo.create_device(new_device).await?;
+        Ok(KioskDeviceCreated {
+            device: KioskDeviceResponse::from(device),
+            token,
+        })
+    }
+
+    /// Get a kiosk device by ID
+    pub async fn get_device(
+        &self,
+        org_id: Uuid,
+        device_id: Uuid,
+    ) -> Result<KioskDeviceResponse, AppError> {
+        let device = self.kiosk_repo.find_device(org_id, device_id).await?;
+        Ok(KioskDeviceResponse::from(device))
+    }
+
+    /// List kiosk devices
+    pub async fn list_devices(&self, org_id: Uuid) -> Result<Vec<KioskDeviceResponse>, AppError> {
+        let devices = self.kiosk_repo.list_devices(org_id).await?;
+        Ok(devices.into_iter().map(KioskDeviceResponse::from).collect())
+    }
+
+    /// Update a kiosk device
+    pub async fn update_device(
+        &self,
+        org_id: Uuid,
+        device_id: Uuid,
+        request: UpdateKioskDeviceRequest,
+    ) -> Result<KioskDeviceResponse, AppError> {
+        if let Some(ref name) = request.name {
+            validate_name(name)?;
+        }
+        if let Some(Some(site_id)) = request.work_site_id {
+            self.work_site_repo.find_by_id(org_id, site_id).await?;
+        }
+
+        let update = KioskDeviceUpdate {
+            name: request.name.map(|n| n.trim().to_string()),
+            work_site_id: request.work_site_id,
+            is_active: request.is_active,
+        };
+
+        let device = self
+            .kiosk_repo
+            .update_device(org_id, device_id, update)
+            .await?;
+        Ok(KioskDeviceResponse::from(device))
+    }
+
+    /// Delete a kiosk device (revokes its credential)
+    pub async fn delete_device(&self, org_id: Uuid, device_id: Uuid) -> Result<(), AppError> {
+        self.kiosk_repo.delete_device(org_id, device_id).await
+    }
+
+    /// Authenticate a device by its credential
+    /// Returns `None` for unknown or deactivated devices
+    pub async fn authenticate(&self, token: &str) -> Result<Option<KioskDevice>, AppError> {
+        if !token.starts_with(KIOSK_TOKEN_PREFIX) {
+            return Ok(None);
+        }
+
+        let device = self
+            .kiosk_repo
+            .find_active_by_token_hash(&hash_token(token))
+            .await?;
+
+        if let Some(ref device) = device {
+            self.kiosk_repo.touch_device(device.id).await?;
+        }
+
+        Ok(device)
+    }
+
+    /// Position recorded for clock actions made on a device
+    ///
+    /// Kiosks are fixed terminals, so the center of their work site is used.
+    pub async fn device_position(
+        &self,
+        device: &KioskDevice,
+    ) -> Result<Option<GeoPosition>, AppError> {
+        let Some(site_id) = device.work_site_id else {
+            return Ok(None);
+        };
+
+        let site = self
+            .work_site_repo
+            .find_by_id(device.organization_id, site_id)
+            .await?;
+
+        Ok(site.is_active.then_some(GeoPosition {
+            latitude: site.latitude,
+            longitude: site.longitude,
+            accuracy: None,
+        }))
+    }
+
+    /// Set (or replace) an employee's kiosk PIN
+    pub async fn set_pin(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        request: SetKioskPinRequest,
+    ) -> Result<(), AppError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+        if user.organization_id != org_id {
+            return Err(AppError::NotFound("User not found".to_string()));
+        }
+
+        let pin_hash = self.password_service.hash_pin(&request.pin)?;
+        self.kiosk_repo
+            .upsert_pin(NewKioskPin {
+                user_id,
+                organization_id: org_id,
+                pin_hash,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove an employee's kiosk PIN
+    pub async fn clear_pin(&self, org_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        self.kiosk_repo.delete_pin(org_id, user_id).await
+    }
+
+    /// Verify an employee's PIN
+    /// Returns false when the employee has no PIN or is no longer active
+    pub async fn verify_pin(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        pin: &str,
+    ) -> Result<bool, AppError> {
+        let Some(stored) = self.kiosk_repo.find_pin(org_id, user_id).await? else {
+            return Ok(false);
+        };
+
+        if self.user_repo.find_by_id(user_id).await.is_err() {
+            return Ok(false);
+        }
+
+        self.password_service.verify_password(pin, &stored.pin_hash)
+    }
+
+    /// List employees selectable on a kiosk
+    pub async fn list_employees(&self, org_id: Uuid) -> Result<Vec<KioskEmployee>, AppError> {
+        self.kiosk_repo.list_employees(org_id).await
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() || name.len() > 100 {
+        return Err(AppError::ValidationError(
+            "Name must be between 1 and 100 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Generate a device credential (prefix + 32 random bytes as hex)
+fn generate_kiosk_token() -> String {
+    let mut rng = rand::thread_rng();
+    let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+    format!("{}{}", KIOSK_TOKEN_PREFIX, hex::encode(bytes))
+}
+
+/// Hash a token using SHA-256
+fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_kiosk_token() {
+        let token1 = generate_kiosk_token();
+        let token2 = generate_kiosk_token();
+
+        assert_ne!(token1, token2);
+        assert!(token1.starts_with(KIOSK_TOKEN_PREFIX));
+        assert_eq!(token1.len(), KIOSK_TOKEN_PREFIX.len() + 64);
+    }
+
+    #[test]
+    fn test_hash_token_fits_column() {
+        let hash = hash_token(&generate_kiosk_token());
+
+        // Stored in VARCHAR(64)
+        assert_eq!(hash.len(), 64);
+    }
+
+    #[test]
+    fn test_hash_token_consistency() {
+        let token = "kiosk_test_token";
+
+        assert_eq!(hash_token(token), hash_token(token));
+        assert_ne!(hash_token(token), hash_token("kiosk_other_token"));
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("Front desk").is_ok());
+        assert!(validate_name("   ").is_err());
+        assert!(validate_name(&"a".repeat(101)).is_err());
+    }
+}
@@ -1,14 +1,17 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
 use serde::Serialize;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
 use crate::domain::enums::ClockEntryStatus;
 use crate::error::AppError;
+use crate::models::{resolve_planned_shift, ExpectedHours};
 use crate::repositories::{
-    ClockRepository, TeamRepository, UserRepository, WorkScheduleRepository,
+    AbsenceRepository, ClockRepository, ClosedDayRepository, OrganizationRepository,
+    RosterRepository, TeamRepository, UserRepository, WorkScheduleRepository,
 };
-use crate::utils::start_of_day;
+use crate::utils::timezone::{local_day_bounds, to_local};
 
 /// Individual user KPIs
 #[derive(Debug, Serialize)]
@@ -17,6 +20,8 @@ pub struct UserKPIs {
     pub user_name: String,
     pub total_hours_worked: f64,
     pub theoretical_hours: f64,
+    /// How theoretical hours were derived from the planned hours
+    pub theoretical_breakdown: ExpectedHours,
     pub hours_variance: f64,
     pub punctuality_rate: f64,
     pub days_worked: i32,
@@ -103,12 +108,29 @@ pub enum Granularity {
     Month,
 }
 
+impl Granularity {
+    /// First day of the period containing `date` (weeks start on Monday)
+    pub fn period_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Granularity::Day => date,
+            Granularity::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            Granularity::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+}
+
 /// Service for KPI calculations
 pub struct KPIService {
     clock_repo: ClockRepository,
     team_repo: TeamRepository,
     user_repo: UserRepository,
+    org_repo: OrganizationRepository,
     schedule_repo: WorkScheduleRepository,
+    roster_repo: RosterRepository,
+    closed_day_repo: ClosedDayRepository,
+    absence_repo: AbsenceRepository,
 }
 
 impl KPIService {
@@ -117,7 +139,11 @@ impl KPIService {
             clock_repo: ClockRepository::new(pool.clone()),
             team_repo: TeamRepository::new(pool.clone()),
             user_repo: UserRepository::new(pool.clone()),
-            schedule_repo: WorkScheduleRepository::new(pool),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            roster_repo: RosterRepository::new(pool.clone()),
+            closed_day_repo: ClosedDayRepository::new(pool.clone()),
+            absence_repo: AbsenceRepository::new(pool),
         }
     }
 
@@ -130,6 +156,7 @@ impl KPIService {
     ) -> Result<UserKPIs, AppError> {
         let user = self.user_repo.find_by_id(user_id).await?;
         let user_name = format!("{} {}", user.first_name, user.last_name);
+        let tz = self.org_repo.get_timezone(org_id).await?;
 
         // Get clock entries for the period
         let entries = self
@@ -145,15 +172,17 @@ impl KPIService {
             .sum();
         let total_hours_worked = total_minutes as f64 / 60.0;
 
-        // Calculate theoretical hours
-        let theoretical_hours = self
+        // Calculate theoretical hours (published roster shifts override the schedule,
+        // closed days and approved absences are not expected)
+        let theoretical_breakdown = self
             .schedule_repo
-            .get_theoretical_hours(org_id, user_id, period.start, period.end)
+            .get_theoretical_hours(org_id, user_id, period.start, period.end, tz)
             .await?;
+        let theoretical_hours = theoretical_breakdown.expected_hours;
 
         // Calculate punctuality
         let (days_worked, days_late) = self
-            .calculate_punctuality(org_id, user_id, &entries)
+            .calculate_punctuality(org_id, user_id, &entries, tz)
             .await?;
 
         let punctuality_rate = if days_worked > 0 {
@@ -173,6 +202,7 @@ impl KPIService {
             user_name,
             total_hours_worked,
             theoretical_hours,
+            theoretical_breakdown,
             hours_variance: total_hours_worked - theoretical_hours,
             punctuality_rate,
             days_worked,
@@ -190,6 +220,7 @@ impl KPIService {
     ) -> Result<TeamKPIs, AppError> {
         let team = self.team_repo.find_by_id(org_id, team_id).await?;
         let members = self.team_repo.list_members(team_id).await?;
+        let tz = self.org_repo.get_timezone(org_id).await?;
 
         let mut member_summaries = Vec::with_capacity(members.len());
         let mut total_hours = 0.0;
@@ -210,7 +241,7 @@ impl KPIService {
                 / 60.0;
 
             let (days_worked, days_late) = self
-                .calculate_punctuality(org_id, member.id, &entries)
+                .calculate_punctuality(org_id, member.id, &entries, tz)
                 .await?;
 
             let punctuality_rate = if days_worked > 0 {
@@ -269,6 +300,7 @@ impl KPIService {
         };
 
         let (users, total_employees) = self.user_repo.list(org_id, &filter, &pagination).await?;
+        let tz = self.org_repo.get_timezone(org_id).await?;
 
         let mut total_hours = 0.0;
         let mut total_punctuality = 0.0;
@@ -291,7 +323,7 @@ impl KPIService {
                     / 60.0;
 
                 let (days_worked, days_late) = self
-                    .calculate_punctuality(org_id, user.id, &entries)
+                    .calculate_punctuality(org_id, user.id, &entries, tz)
                     .await?;
 
                 let punctuality = if days_worked > 0 {
@@ -375,56 +407,110 @@ impl KPIService {
         // Simplified - generates data points based on granularity
         let mut data = Vec::new();
 
+        // Buckets follow the organization's local calendar days
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let start_date = to_local(period.start, tz).date();
+
+        // Schedule and published roster used to attribute cross-midnight entries to
+        // their shift day and to compute theoretical hours, along with the closed
+        // days and approved absences deducted from them
+        let (schedule, shifts, closed_dates, absences) = match user_id {
+            Some(uid) => {
+                let end_date = to_local(period.end, tz).date();
+                let (first_day, last_day) =
+                    (start_date - Duration::days(1), end_date + Duration::days(1));
+                let schedule = self
+                    .schedule_repo
+                    .get_effective_schedule(org_id, uid, first_day, last_day)
+                    .await?;
+                let shifts = self
+                    .roster_repo
+                    .list_published_for_user(org_id, uid, first_day, last_day)
+                    .await?;
+                let absences = self
+                    .absence_repo
+                    .get_for_date_range(org_id, start_date, end_date, Some(vec![uid]))
+                    .await?;
+                // Cover the whole absences to weigh partial ones
+                let first_date = absences
+                    .iter()
+                    .map(|a| a.start_date)
+                    .fold(start_date, NaiveDate::min);
+                let last_date = absences
+                    .iter()
+                    .map(|a| a.end_date)
+                    .fold(end_date, NaiveDate::max);
+                let closed_dates = self
+                    .closed_day_repo
+                    .list_range(org_id, first_date, last_date)
+                    .await?;
+                (schedule, shifts, closed_dates, absences)
+            }
+            None => (None, Vec::new(), Vec::new(), Vec::new()),
+        };
+
         // For Week granularity, align to ISO week boundaries (Monday)
         let mut current = if granularity == Granularity::Week {
             // Find the Monday of the week containing period.start
-            let days_since_monday = period.start.weekday().num_days_from_monday() as i64;
-            period.start - chrono::Duration::days(days_since_monday)
+            let days_since_monday = start_date.weekday().num_days_from_monday() as i64;
+            start_date - Duration::days(days_since_monday)
         } else {
-            period.start
+            start_date
         };
 
-        while current < period.end {
-            let (point_end, date_str) = match granularity {
-                Granularity::Day => {
-                    let next = current + chrono::Duration::days(1);
-                    // Return ISO date format for frontend parsing
-                    (next, current.format("%Y-%m-%d").to_string())
-                }
-                Granularity::Week => {
-                    // Week runs Monday to Sunday
-                    let week_end = current + chrono::Duration::weeks(1);
-                    // Return ISO date of week's Monday - frontend will display week info
-                    (week_end, current.format("%Y-%m-%d").to_string())
-                }
+        loop {
+            let (point_start, _) = local_day_bounds(current, tz);
+            if point_start >= period.end {
+                break;
+            }
+
+            let next = match granularity {
+                Granularity::Day => current + Duration::days(1),
+                // Week runs Monday to Sunday
+                Granularity::Week => current + Duration::weeks(1),
                 Granularity::Month => {
                     // Move to first day of next month for accurate month handling
                     let next_month = if current.month() == 12 {
-                        chrono::NaiveDate::from_ymd_opt(current.year() + 1, 1, 1)
+                        NaiveDate::from_ymd_opt(current.year() + 1, 1, 1)
                     } else {
-                        chrono::NaiveDate::from_ymd_opt(current.year(), current.month() + 1, 1)
+                        NaiveDate::from_ymd_opt(current.year(), current.month() + 1, 1)
                     };
-                    let next = next_month
-                        .map(start_of_day)
-                        .unwrap_or_else(|| current + chrono::Duration::days(30));
-                    // Return ISO date format for frontend parsing
-                    (next, current.format("%Y-%m-%d").to_string())
+                    next_month.unwrap_or_else(|| current + Duration::days(30))
                 }
             };
+            let (point_end, _) = local_day_bounds(next, tz);
+
+            // Return ISO date format for frontend parsing (week's Monday for weeks)
+            let date_str = current.format("%Y-%m-%d").to_string();
 
             // Clip data range to period boundaries (important for week mode to stay within month)
-            let actual_start = current.max(period.start);
+            let actual_start = point_start.max(period.start);
             let actual_end = point_end.min(period.end);
 
             let hours_worked = if let Some(uid) = user_id {
+                // Entries clocked in after midnight may still belong to the bucket's
+                // last shift day, so look one day past the bucket end
+                let fetch_end = if point_end <= period.end {
+                    point_end + Duration::days(1)
+                } else {
+                    period.end
+                };
                 let entries = self
                     .clock_repo
-                    .get_entries_for_period(org_id, uid, actual_start, actual_end)
+                    .get_entries_for_period(org_id, uid, actual_start, fetch_end)
                     .await?;
 
                 entries
                     .iter()
                     .filter(|e| e.status == ClockEntryStatus::Approved)
+                    .filter(|e| {
+                        let (shift_date, _) = resolve_planned_shift(
+                            schedule.as_ref(),
+                            &shifts,
+                            to_local(e.clock_in, tz),
+                        );
+                        shift_date >= current && shift_date < next
+                    })
                     .filter_map(|e| e.clock_out.map(|out| (out - e.clock_in).num_minutes()))
                     .sum::<i64>() as f64
                     / 60.0
@@ -433,10 +519,16 @@ impl KPIService {
                 0.0
             };
 
-            let theoretical_hours = if let Some(uid) = user_id {
-                self.schedule_repo
-                    .get_theoretical_hours(org_id, uid, actual_start, actual_end)
-                    .await?
+            let theoretical_hours = if user_id.is_some() {
+                ExpectedHours::compute(
+                    schedule.as_ref(),
+                    &shifts,
+                    &closed_dates,
+                    &absences,
+                    to_local(actual_start, tz).date(),
+                    to_local(actual_end, tz).date(),
+                )
+                .expected_hours
             } else {
                 0.0
             };
@@ -447,7 +539,7 @@ impl KPIService {
                 theoretical_hours,
             });
 
-            current = point_end;
+            current = next;
         }
 
         Ok(ChartData {
@@ -461,30 +553,31 @@ impl KPIService {
     }
 
     /// Calculate punctuality (days on time vs late)
+    /// Clock-in times are compared to the schedule in the organization's local time,
+    /// against the shift the entry belongs to (which may have started the day before).
+    /// Published roster shifts take precedence over the weekly schedule.
     async fn calculate_punctuality(
         &self,
         org_id: Uuid,
         user_id: Uuid,
         entries: &[crate::models::ClockEntry],
+        tz: Tz,
     ) -> Result<(i32, i32), AppError> {
-        // Get user's schedule
+        let local_dates = entries.iter().map(|e| to_local(e.clock_in, tz).date());
+        let (Some(first), Some(last)) = (local_dates.clone().min(), local_dates.max()) else {
+            return Ok((0, 0));
+        };
+
+        // Get user's schedule (or default)
         let schedule = self
             .schedule_repo
-            .get_user_schedule(org_id, user_id)
+            .get_effective_schedule(org_id, user_id, first - Duration::days(1), last)
             .await?;
-        let schedule = match schedule {
-            Some(s) => s,
-            None => {
-                // Try default schedule
-                match self.schedule_repo.get_default(org_id).await? {
-                    Some(s) => s,
-                    None => return Ok((entries.len() as i32, 0)), // No schedule, consider all on time
-                }
-            }
-        };
-
-        let days = self.schedule_repo.get_days(schedule.id).await?;
-        if days.is_empty() {
+        let shifts = self
+            .roster_repo
+            .list_published_for_user(org_id, user_id, first - Duration::days(1), last)
+            .await?;
+        if shifts.is_empty() && schedule.as_ref().is_none_or(|s| s.days.is_empty()) {
             return Ok((entries.len() as i32, 0));
         }
 
@@ -499,13 +592,12 @@ impl KPIService {
 
             days_worked += 1;
 
-            let weekday = entry.clock_in.weekday().num_days_from_monday() as i16;
-            if let Some(day_schedule) = days.iter().find(|d| d.day_of_week == weekday) {
-                let clock_in_time = entry.clock_in.time();
-                let expected_start =
-                    day_schedule.start_time + chrono::Duration::minutes(grace_period_minutes);
+            let local_clock_in = to_local(entry.clock_in, tz);
+            let (_, bounds) = resolve_planned_shift(schedule.as_ref(), &shifts, local_clock_in);
+            if let Some((shift_start, _)) = bounds {
+                let expected_start = shift_start + Duration::minutes(grace_period_minutes);
 
-                if clock_in_time > expected_start {
+                if local_clock_in > expected_start {
                     days_late += 1;
                 }
             }
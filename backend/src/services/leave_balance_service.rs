@@ -73,6 +73,7 @@ impl LeaveBalanceService {
                 absence_type.name,
                 absence_type.code,
                 absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
+                absence_type.unit,
             ));
         }
 
@@ -144,6 +145,7 @@ impl LeaveBalanceService {
             absence_type.name,
             absence_type.code,
             absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
+            absence_type.unit,
         ))
     }
 
@@ -179,6 +181,7 @@ impl LeaveBalanceService {
             absence_type.name,
             absence_type.code,
             absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
+            absence_type.unit,
         ))
     }
 
@@ -200,6 +203,7 @@ impl LeaveBalanceService {
             absence_type.name,
             absence_type.code,
             absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
+            absence_type.unit,
         ))
     }
 
@@ -223,6 +227,7 @@ impl LeaveBalanceService {
                 absence_type.name,
                 absence_type.code,
                 absence_type.color.unwrap_or_else(|| "#3B82F6".to_string()),
+                absence_type.unit,
             ));
         }
 
@@ -7,42 +7,69 @@ pub mod audit_service;
 pub mod auth_service;
 pub mod break_service;
 pub mod brute_force_service;
+pub mod bulk_review_service;
 pub mod cache_service;
+pub mod clock_anomaly_service;
+pub mod clock_approval_rule_service;
+pub mod clock_correction_service;
 pub mod clock_restriction_service;
 pub mod clock_service;
+pub mod clock_sync_service;
 pub mod closed_day_service;
 pub mod email_service;
 pub mod email_templates;
 pub mod endpoint_rate_limiter;
 pub mod hibp_service;
 pub mod invite_service;
+pub mod kiosk_service;
 pub mod kpi_service;
 pub mod leave_balance_service;
 pub mod metrics_service;
 pub mod notification_service;
 pub mod password_expiry_service;
 pub mod password_reset_service;
+pub mod pay_period_service;
+pub mod presence_service;
+pub mod project_service;
+pub mod roster_service;
 pub mod session_service;
+pub mod shift_swap_service;
 pub mod team_service;
+pub mod time_allocation_service;
 pub mod work_schedule_service;
+pub mod work_site_service;
 
 // Re-export commonly used types
-pub use absence_service::{AbsenceService, CreateAbsenceRequest};
+pub use absence_service::{AbsenceService, CreateAbsenceRequest, UpdateAbsenceSettingsRequest};
 pub use absence_type_service::{
-    AbsenceTypeService, CreateAbsenceTypeRequest, UpdateAbsenceTypeRequest,
+    AbsenceTypeService, ApprovalStepRequest, CreateAbsenceTypeRequest, UpdateAbsenceTypeRequest,
+    UpdateApprovalStepsRequest,
 };
 pub use audit_service::AuditService;
 pub use auth_service::AuthService;
 pub use break_service::BreakService;
 pub use brute_force_service::BruteForceService;
+pub use bulk_review_service::BulkReviewService;
 pub use cache_service::CacheService;
+pub use clock_anomaly_service::{
+    ClockAnomalyService, DismissClockAnomalyRequest, ResolveClockAnomalyRequest,
+    UpdateAnomalySettingsRequest,
+};
+pub use clock_approval_rule_service::{
+    ClockApprovalRuleService, CreateClockApprovalRuleRequest, UpdateClockApprovalRuleRequest,
+};
+pub use clock_correction_service::ClockCorrectionService;
 pub use clock_restriction_service::ClockRestrictionService;
 pub use clock_service::ClockService;
+pub use clock_sync_service::ClockSyncService;
 pub use closed_day_service::{ClosedDayService, CreateClosedDayRequest, UpdateClosedDayRequest};
 pub use email_service::EmailService;
 pub use endpoint_rate_limiter::EndpointRateLimiter;
 pub use hibp_service::HibpService;
 pub use invite_service::InviteService;
+pub use kiosk_service::{
+    CreateKioskDeviceRequest, KioskService, SetKioskPinRequest, UpdateKioskDeviceRequest,
+};
 pub use kpi_service::{
     ChartData, DateRange, Granularity, KPIService, MemberKPISummary, OrgKPIs, PresenceOverview,
     TeamKPIs, UserKPIs,
@@ -56,9 +83,24 @@ pub use password_expiry_service::{
     PasswordExpiryPolicy, PasswordExpiryService, PasswordExpiryStatus,
 };
 pub use password_reset_service::PasswordResetService;
+pub use pay_period_service::{
+    ClosePayPeriodRequest, PayPeriodService, PayPeriodStatusQuery, UpdatePayPeriodSettingsRequest,
+};
+pub use presence_service::{PresenceEvent, PresenceEventKind, PresenceScope, PresenceService};
+pub use project_service::{
+    CreateProjectRequest, CreateProjectTaskRequest, ProjectService, UpdateProjectRequest,
+    UpdateProjectTaskRequest,
+};
+pub use roster_service::{
+    CreateRosterShiftRequest, PublishRosterWeekRequest, PublishRosterWeekResponse, RosterService,
+    UpdateRosterSettingsRequest, UpdateRosterShiftRequest,
+};
 pub use session_service::SessionService;
+pub use shift_swap_service::{CreateShiftSwapRequest, ReviewShiftSwapRequest, ShiftSwapService};
 pub use team_service::{CreateTeamRequest, TeamService, UpdateTeamRequest};
+pub use time_allocation_service::TimeAllocationService;
 pub use work_schedule_service::{
-    AddDayRequest, CreateScheduleRequest, UpdateDayRequest, UpdateScheduleRequest,
-    WorkScheduleService,
+    AddDayRequest, CreateScheduleExceptionRequest, CreateScheduleRequest, UpdateDayRequest,
+    UpdateScheduleExceptionRequest, UpdateScheduleRequest, WorkScheduleService,
 };
+pub use work_site_service::{CreateWorkSiteRequest, UpdateWorkSiteRequest, WorkSiteService};
@@ -0,0 +1,300 @@
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::PayPeriodFrequency;
+use crate::error::AppError;
+use crate::models::{
+    NewPayPeriod, NewPayPeriodSettings, PayPeriodFilter, PayPeriodResponse,
+    PayPeriodSettingsResponse, PayPeriodStatusResponse,
+};
+use crate::repositories::{OrganizationRepository, PayPeriodRepository};
+
+/// Anchor used by organizations without settings
+const DEFAULT_ANCHOR_DATE: (i32, u32, u32) = (2024, 1, 1);
+
+/// Request to update pay period settings
+#[derive(Debug, Deserialize)]
+pub struct UpdatePayPeriodSettingsRequest {
+    pub frequency: PayPeriodFrequency,
+    /// First day of any weekly or bi-weekly period; ignored for other frequencies
+    pub anchor_date: Option<NaiveDate>,
+}
+
+/// Request to close the pay period containing a date
+#[derive(Debug, Deserialize)]
+pub struct ClosePayPeriodRequest {
+    pub date: NaiveDate,
+}
+
+/// Query selecting the pay period containing a date (today by default)
+#[derive(Debug, Deserialize)]
+pub struct PayPeriodStatusQuery {
+    pub date: Option<NaiveDate>,
+}
+
+/// Service for pay period definitions and payroll close
+///
+/// Closing a period locks clock entries, breaks and absences dated inside it;
+/// the lock itself is enforced by the repositories and services owning that data.
+pub struct PayPeriodService {
+    pay_period_repo: PayPeriodRepository,
+    org_repo: OrganizationRepository,
+}
+
+impl PayPeriodService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            pay_period_repo: PayPeriodRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool),
+        }
+    }
+
+    /// Get pay period settings (monthly when none are set)
+    pub async fn get_settings(&self, org_id: Uuid) -> Result<PayPeriodSettingsResponse, AppError> {
+        let (frequency, anchor_date) = self.resolve_settings(org_id).await?;
+        Ok(PayPeriodSettingsResponse {
+            frequency,
+            anchor_date,
+        })
+    }
+
+    /// Create or replace pay period settings
+    /// Periods already closed keep their bounds
+    pub async fn update_settings(
+        &self,
+        org_id: Uuid,
+        request: UpdatePayPeriodSettingsRequest,
+    ) -> Result<PayPeriodSettingsResponse, AppError> {
+        let settings = self
+            .pay_period_repo
+            .upsert_settings(NewPayPeriodSettings {
+                organization_id: org_id,
+                frequency: request.frequency,
+                anchor_date: request.anchor_date.unwrap_or_else(default_anchor_date),
+            })
+            .await?;
+
+        Ok(PayPeriodSettingsResponse {
+            frequency: settings.frequency,
+            anchor_date: settings.anchor_date,
+        })
+    }
+
+    /// List recorded (closed or reopened) pay periods
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: PayPeriodFilter,
+    ) -> Result<Vec<PayPeriodResponse>, AppError> {
+        let periods = self.pay_period_repo.list(org_id, &filter).await?;
+        Ok(periods.into_iter().map(PayPeriodResponse::from).collect())
+    }
+
+    /// Bounds and status of the period containing a date (today in the organization by default)
+    pub async fn status(
+        &self,
+        org_id: Uuid,
+        query: PayPeriodStatusQuery,
+    ) -> Result<PayPeriodStatusResponse, AppError> {
+        let (frequency, anchor_date) = self.resolve_settings(org_id).await?;
+        let date = match query.date {
+            Some(date) => date,
+            None => self.local_today(org_id).await?,
+        };
+        let (start_date, end_date) = period_bounds(frequency, anchor_date, date);
+
+        let period = self
+            .pay_period_repo
+            .find_by_start_date(org_id, start_date)
+            .await?
+            .filter(|p| p.end_date == end_date);
+        // A period closed under a previous frequency may cover part of this one
+        let is_closed = match self
+            .pay_period_repo
+            .ensure_open(org_id, start_date, end_date)
+            .await
+        {
+            Ok(()) => false,
+            Err(AppError::Conflict(_)) => true,
+            Err(e) => return Err(e),
+        };
+
+        Ok(PayPeriodStatusResponse {
+            frequency,
+            start_date,
+            end_date,
+            is_closed,
+            period: period.map(PayPeriodResponse::from),
+        })
+    }
+
+    /// Close the pay period containing a date
+    pub async fn close(
+        &self,
+        org_id: Uuid,
+        closed_by: Uuid,
+        request: ClosePayPeriodRequest,
+    ) -> Result<PayPeriodResponse, AppError> {
+        let (frequency, anchor_date) = self.resolve_settings(org_id).await?;
+        let (start_date, end_date) = period_bounds(frequency, anchor_date, request.date);
+
+        if start_date > self.local_today(org_id).await? {
+            return Err(AppError::ValidationError(
+                "Cannot close a pay period that has not started".to_string(),
+            ));
+        }
+
+        // Also rejects periods overlapping one closed under a previous frequency
+        self.pay_period_repo
+            .ensure_open(org_id, start_date, end_date)
+            .await?;
+
+        let period = self
+            .pay_period_repo
+            .close(NewPayPeriod {
+                organization_id: org_id,
+                start_date,
+                end_date,
+                closed_by: Some(closed_by),
+            })
+            .await?;
+
+        Ok(PayPeriodResponse::from(period))
+    }
+
+    /// Reopen a closed pay period
+    pub async fn reopen(
+        &self,
+        org_id: Uuid,
+        period_id: Uuid,
+        reopened_by: Uuid,
+    ) -> Result<PayPeriodResponse, AppError> {
+        let period = self.pay_period_repo.find_by_id(org_id, period_id).await?;
+        if !period.is_closed {
+            return Err(AppError::Conflict(
+                "This pay period is not closed".to_string(),
+            ));
+        }
+
+        let period = self
+            .pay_period_repo
+            .reopen(org_id, period_id, reopened_by)
+            .await?;
+
+        Ok(PayPeriodResponse::from(period))
+    }
+
+    /// Frequency and anchor of an organization
+    async fn resolve_settings(
+        &self,
+        org_id: Uuid,
+    ) -> Result<(PayPeriodFrequency, NaiveDate), AppError> {
+        Ok(match self.pay_period_repo.find_settings(org_id).await? {
+            Some(settings) => (settings.frequency, settings.anchor_date),
+            None => (PayPeriodFrequency::default(), default_anchor_date()),
+        })
+    }
+
+    /// Current date in the organization's timezone
+    async fn local_today(&self, org_id: Uuid) -> Result<NaiveDate, AppError> {
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        Ok(Utc::now().with_timezone(&tz).date_naive())
+    }
+}
+
+fn default_anchor_date() -> NaiveDate {
+    let (year, month, day) = DEFAULT_ANCHOR_DATE;
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid default anchor date")
+}
+
+/// Inclusive bounds of the pay period containing `date`
+///
+/// Weekly and bi-weekly periods repeat every 7 or 14 days from `anchor`, in
+/// both directions. Semi-monthly periods split each month after the 15th.
+pub fn period_bounds(
+    frequency: PayPeriodFrequency,
+    anchor: NaiveDate,
+    date: NaiveDate,
+) -> (NaiveDate, NaiveDate) {
+    match frequency {
+        PayPeriodFrequency::Weekly | PayPeriodFrequency::BiWeekly => {
+            let length = if frequency == PayPeriodFrequency::Weekly {
+                7
+            } else {
+                14
+            };
+            let offset = (date - anchor).num_days().rem_euclid(length);
+            let start = date - Duration::days(offset);
+            (start, start + Duration::days(length - 1))
+        }
+        PayPeriodFrequency::SemiMonthly => {
+            if date.day() <= 15 {
+                (
+                    date.with_day(1).unwrap_or(date),
+                    date.with_day(15).unwrap_or(date),
+                )
+            } else {
+                (date.with_day(16).unwrap_or(date), last_day_of_month(date))
+            }
+        }
+        PayPeriodFrequency::Monthly => (date.with_day(1).unwrap_or(date), last_day_of_month(date)),
+    }
+}
+
+/// Last day of the month containing `date`
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first| first.pred_opt())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_period_bounds_weekly_and_bi_weekly() {
+        let anchor = date(2024, 1, 1);
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::Weekly, anchor, date(2024, 1, 10)),
+            (date(2024, 1, 8), date(2024, 1, 14))
+        );
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::BiWeekly, anchor, date(2024, 1, 15)),
+            (date(2024, 1, 15), date(2024, 1, 28))
+        );
+        // Dates before the anchor
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::BiWeekly, anchor, date(2023, 12, 31)),
+            (date(2023, 12, 18), date(2023, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_period_bounds_semi_monthly_and_monthly() {
+        let anchor = date(2024, 1, 1);
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::SemiMonthly, anchor, date(2024, 2, 15)),
+            (date(2024, 2, 1), date(2024, 2, 15))
+        );
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::SemiMonthly, anchor, date(2024, 2, 20)),
+            (date(2024, 2, 16), date(2024, 2, 29))
+        );
+        assert_eq!(
+            period_bounds(PayPeriodFrequency::Monthly, anchor, date(2024, 12, 31)),
+            (date(2024, 12, 1), date(2024, 12, 31))
+        );
+    }
+}
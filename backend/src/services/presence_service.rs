@@ -0,0 +1,242 @@
+//! Live presence events
+//!
+//! Clock and break actions publish presence deltas on an in-process broadcast
+//! channel. Server-Sent Events subscribers receive the deltas within their scope
+//! (their organization, or the members of a team).
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::repositories::{ClockRepository, TeamRepository};
+use crate::services::{KPIService, PresenceOverview};
+
+/// Events buffered per subscriber before it starts lagging
+const CHANNEL_CAPACITY: usize = 256;
+
+lazy_static::lazy_static! {
+    static ref PRESENCE_CHANNEL: broadcast::Sender<PresenceEvent> =
+        broadcast::channel(CHANNEL_CAPACITY).0;
+}
+
+/// Kind of presence change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceEventKind {
+    ClockedIn,
+    ClockedOut,
+    BreakStarted,
+    BreakEnded,
+}
+
+/// Presence delta pushed to subscribers
+#[derive(Debug, Clone, Serialize)]
+pub struct PresenceEvent {
+    pub kind: PresenceEventKind,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub clock_entry_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Users whose presence a subscriber may follow
+#[derive(Debug, Clone)]
+pub struct PresenceScope {
+    pub organization_id: Uuid,
+    /// Team members in scope (None for the whole organization)
+    pub user_ids: Option<HashSet<Uuid>>,
+}
+
+impl PresenceScope {
+    /// Whether an event belongs to this scope
+    pub fn includes(&self, event: &PresenceEvent) -> bool {
+        event.organization_id == self.organization_id
+            && self
+                .user_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&event.user_id))
+    }
+}
+
+/// Service publishing and scoping live presence events
+pub struct PresenceService {
+    pool: DbPool,
+    clock_repo: ClockRepository,
+    team_repo: TeamRepository,
+}
+
+impl PresenceService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            clock_repo: ClockRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Subscribe to presence events of all organizations
+    pub fn subscribe() -> broadcast::Receiver<PresenceEvent> {
+        PRESENCE_CHANNEL.subscribe()
+    }
+
+    /// Publish a presence change
+    /// Never fails: presence streaming must not break clock or break actions
+    pub async fn publish(
+        &self,
+        kind: PresenceEventKind,
+        org_id: Uuid,
+        user_id: Uuid,
+        clock_entry_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) {
+        // Skip the user lookup when nobody is listening
+        if PRESENCE_CHANNEL.receiver_count() == 0 {
+            return;
+        }
+
+        let user_name = match self.clock_repo.get_user_info(user_id).await {
+            Ok((name, _)) => name,
+            Err(e) => {
+                tracing::warn!(
+                    user_id = %user_id,
+                    error = %e,
+                    "Failed to resolve user for presence event"
+                );
+                return;
+            }
+        };
+
+        // Subscribers may have left in the meantime
+        let _ = PRESENCE_CHANNEL.send(PresenceEvent {
+            kind,
+            organization_id: org_id,
+            user_id,
+            user_name,
+            clock_entry_id,
+            occurred_at,
+        });
+    }
+
+    /// Resolve the users a viewer may follow, optionally narrowed to a team
+    ///
+    /// Admins follow the whole organization; managers follow the members of the
+    /// teams they manage.
+    pub async fn resolve_scope(
+        &self,
+        org_id: Uuid,
+        viewer_id: Uuid,
+        viewer_role: UserRole,
+        team_id: Option<Uuid>,
+    ) -> Result<PresenceScope, AppError> {
+        let team_ids = if viewer_role >= UserRole::Admin {
+            match team_id {
+                Some(team_id) => {
+                    self.team_repo.find_by_id(org_id, team_id).await?;
+                    vec![team_id]
+                }
+                None => {
+                    return Ok(PresenceScope {
+                        organization_id: org_id,
+                        user_ids: None,
+                    })
+                }
+            }
+        } else {
+            let managed: Vec<Uuid> = self
+                .team_repo
+                .get_managed_teams(org_id, viewer_id)
+                .await?
+                .into_iter()
+                .map(|team| team.id)
+                .collect();
+            match team_id {
+                Some(team_id) if !managed.contains(&team_id) => {
+                    return Err(AppError::Forbidden(
+                        "You can only follow the presence of teams you manage".to_string(),
+                    ));
+                }
+                Some(team_id) => vec![team_id],
+                None => managed,
+            }
+        };
+
+        let mut user_ids = HashSet::new();
+        for team_id in team_ids {
+            let members = self.team_repo.list_members(team_id).await?;
+            user_ids.extend(members.into_iter().map(|member| member.id));
+        }
+
+        Ok(PresenceScope {
+            organization_id: org_id,
+            user_ids: Some(user_ids),
+        })
+    }
+
+    /// Current presence restricted to a scope
+    pub async fn snapshot(&self, scope: &PresenceScope) -> Result<PresenceOverview, AppError> {
+        let kpi_service = KPIService::new(self.pool.clone());
+        let mut overview = kpi_service
+            .get_real_time_presence(scope.organization_id)
+            .await?;
+
+        if let Some(ref ids) = scope.user_ids {
+            overview
+                .present_users
+                .retain(|user| ids.contains(&user.user_id));
+            overview.currently_present = overview.present_users.len() as i32;
+            overview.total_employees = ids.len() as i64;
+        }
+
+        Ok(overview)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(org_id: Uuid, user_id: Uuid) -> PresenceEvent {
+        PresenceEvent {
+            kind: PresenceEventKind::ClockedIn,
+            organization_id: org_id,
+            user_id,
+            user_name: "Jane Doe".to_string(),
+            clock_entry_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_scope_includes_organization_and_team_members() {
+        let org_id = Uuid::new_v4();
+        let member = Uuid::new_v4();
+        let outsider = Uuid::new_v4();
+
+        let org_scope = PresenceScope {
+            organization_id: org_id,
+            user_ids: None,
+        };
+        assert!(org_scope.includes(&event(org_id, outsider)));
+        assert!(!org_scope.includes(&event(Uuid::new_v4(), outsider)));
+
+        let team_scope = PresenceScope {
+            organization_id: org_id,
+            user_ids: Some(HashSet::from([member])),
+        };
+        assert!(team_scope.includes(&event(org_id, member)));
+        assert!(!team_scope.includes(&event(org_id, outsider)));
+    }
+
+    #[test]
+    fn test_event_kind_serialization() {
+        let json = serde_json::to_string(&PresenceEventKind::BreakStarted).unwrap();
+        assert_eq!(json, "\"break_started\"");
+    }
+}
@@ -0,0 +1,247 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    NewProject, NewProjectTask, ProjectFilter, ProjectResponse, ProjectTask, ProjectTaskResponse,
+    ProjectTaskUpdate, ProjectUpdate,
+};
+use crate::repositories::ProjectRepository;
+
+/// Request to create a project
+#[derive(Debug, Deserialize)]
+pub struct CreateProjectRequest {
+    pub name: String,
+    pub code: Option<String>,
+    pub client_name: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Request to update a project
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectRequest {
+    pub name: Option<String>,
+    pub code: Option<Option<String>>,
+    pub client_name: Option<Option<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// Request to create a project task
+#[derive(Debug, Deserialize)]
+pub struct CreateProjectTaskRequest {
+    pub name: String,
+}
+
+/// Request to update a project task
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectTaskRequest {
+    pub name: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Service for the projects and tasks catalog
+pub struct ProjectService {
+    project_repo: ProjectRepository,
+}
+
+impl ProjectService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            project_repo: ProjectRepository::new(pool),
+        }
+    }
+
+    /// Create a new project
+    pub async fn create(
+        &self,
+        org_id: Uuid,
+        request: CreateProjectRequest,
+    ) -> Result<ProjectResponse, AppError> {
+        let new_project = NewProject {
+            organization_id: org_id,
+            name: validate_text("Name", &request.name, 100)?,
+            code: optional_text("Code", request.code, 20)?,
+            client_name: optional_text("Client name", request.client_name, 100)?,
+            is_active: request.is_active.unwrap_or(true),
+        };
+
+        let project = self.project_repo.create(new_project).await?;
+        Ok(ProjectResponse::from_project(project, Vec::new()))
+    }
+
+    /// Get a project with its tasks
+    pub async fn get(&self, org_id: Uuid, project_id: Uuid) -> Result<ProjectResponse, AppError> {
+        let project = self.project_repo.find_by_id(org_id, project_id).await?;
+        let tasks = self.project_repo.list_tasks(org_id, &[project_id]).await?;
+        Ok(ProjectResponse::from_project(project, tasks))
+    }
+
+    /// List projects with their tasks
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: ProjectFilter,
+    ) -> Result<Vec<ProjectResponse>, AppError> {
+        let projects = self.project_repo.list(org_id, &filter).await?;
+        let ids: Vec<Uuid> = projects.iter().map(|p| p.id).collect();
+        let mut tasks_by_project: HashMap<Uuid, Vec<ProjectTask>> = HashMap::new();
+        for task in self.project_repo.list_tasks(org_id, &ids).await? {
+            tasks_by_project
+                .entry(task.project_id)
+                .or_default()
+                .push(task);
+        }
+
+        Ok(projects
+            .into_iter()
+            .map(|project| {
+                let tasks = tasks_by_project.remove(&project.id).unwrap_or_default();
+                ProjectResponse::from_project(project, tasks)
+            })
+            .collect())
+    }
+
+    /// Update a project
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        request: UpdateProjectRequest,
+    ) -> Result<ProjectResponse, AppError> {
+        let update = ProjectUpdate {
+            name: request
+                .name
+                .map(|name| validate_text("Name", &name, 100))
+                .transpose()?,
+            code: request
+                .code
+                .map(|code| optional_text("Code", code, 20))
+                .transpose()?,
+            client_name: request
+                .client_name
+                .map(|client| optional_text("Client name", client, 100))
+                .transpose()?,
+            is_active: request.is_active,
+        };
+
+        let project = self.project_repo.update(org_id, project_id, update).await?;
+        let tasks = self.project_repo.list_tasks(org_id, &[project_id]).await?;
+        Ok(ProjectResponse::from_project(project, tasks))
+    }
+
+    /// Delete a project (only when no time is allocated to it)
+    pub async fn delete(&self, org_id: Uuid, project_id: Uuid) -> Result<(), AppError> {
+        self.project_repo.delete(org_id, project_id).await
+    }
+
+    /// Add a task to a project
+    pub async fn create_task(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        request: CreateProjectTaskRequest,
+    ) -> Result<ProjectTaskResponse, AppError> {
+        // Ensure the project belongs to the organization
+        self.project_repo.find_by_id(org_id, project_id).await?;
+
+        let task = self
+            .project_repo
+            .create_task(NewProjectTask {
+                organization_id: org_id,
+                project_id,
+                name: validate_text("Name", &request.name, 100)?,
+                is_active: true,
+            })
+            .await?;
+        Ok(ProjectTaskResponse::from(task))
+    }
+
+    /// Update a task of a project
+    pub async fn update_task(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        task_id: Uuid,
+        request: UpdateProjectTaskRequest,
+    ) -> Result<ProjectTaskResponse, AppError> {
+        let update = ProjectTaskUpdate {
+            name: request
+                .name
+                .map(|name| validate_text("Name", &name, 100))
+                .transpose()?,
+            is_active: request.is_active,
+        };
+
+        let task = self
+            .project_repo
+            .update_task(org_id, project_id, task_id, update)
+            .await?;
+        Ok(ProjectTaskResponse::from(task))
+    }
+
+    /// Delete a task (only when no time is allocated to it)
+    pub async fn delete_task(
+        &self,
+        org_id: Uuid,
+        project_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.project_repo
+            .delete_task(org_id, project_id, task_id)
+            .await
+    }
+}
+
+/// Trim a required text field and check its length
+fn validate_text(field: &str, value: &str, max_len: usize) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() || value.chars().count() > max_len {
+        return Err(AppError::ValidationError(format!(
+            "{} must be between 1 and {} characters",
+            field, max_len
+        )));
+    }
+    Ok(value.to_string())
+}
+
+/// Trim an optional text field, blank values are stored as NULL
+fn optional_text(
+    field: &str,
+    value: Option<String>,
+    max_len: usize,
+) -> Result<Option<String>, AppError> {
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(v) => validate_text(field, v, max_len).map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_text() {
+        assert_eq!(
+            validate_text("Name", "  Website  ", 100).unwrap(),
+            "Website"
+        );
+        assert!(validate_text("Name", "   ", 100).is_err());
+        assert!(validate_text("Code", &"x".repeat(21), 20).is_err());
+    }
+
+    #[test]
+    fn test_optional_text_blank_is_none() {
+        assert_eq!(
+            optional_text("Code", Some("  ".to_string()), 20).unwrap(),
+            None
+        );
+        assert_eq!(optional_text("Code", None, 20).unwrap(), None);
+        assert_eq!(
+            optional_text("Code", Some(" WEB ".to_string()), 20).unwrap(),
+            Some("WEB".to_string())
+        );
+    }
+}
@@ -0,0 +1,634 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::UserRole;
+use crate::error::AppError;
+use crate::models::{
+    NewRosterSettings, NewRosterShift, RosterSettingsResponse, RosterShift, RosterShiftFilter,
+    RosterShiftResponse, RosterShiftUpdate,
+};
+use crate::repositories::{
+    OrganizationRepository, RosterRepository, TeamRepository, UserRepository,
+};
+use crate::utils::datetime::{time_range_on, time_span_minutes};
+use crate::utils::timezone::to_local;
+
+/// Longest position label
+const MAX_POSITION_LENGTH: usize = 100;
+
+/// Default number of days shown in an employee's upcoming shifts
+const DEFAULT_UPCOMING_DAYS: i64 = 14;
+
+/// Longest date range returned by a roster query
+const MAX_RANGE_DAYS: i64 = 92;
+
+/// Longest minimum rest between shifts an organization can configure
+const MAX_MIN_REST_HOURS: i32 = 24;
+
+/// Request to update roster settings
+#[derive(Debug, Deserialize)]
+pub struct UpdateRosterSettingsRequest {
+    pub swap_requires_approval: bool,
+    pub min_rest_hours: i32,
+}
+
+/// Request to plan a shift
+#[derive(Debug, Deserialize)]
+pub struct CreateRosterShiftRequest {
+    pub user_id: Uuid,
+    pub team_id: Option<Uuid>,
+    pub shift_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub break_minutes: Option<i32>,
+    pub position: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Request to update a planned shift
+#[derive(Debug, Deserialize)]
+pub struct UpdateRosterShiftRequest {
+    pub team_id: Option<Option<Uuid>>,
+    pub shift_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: Option<i32>,
+    pub position: Option<Option<String>>,
+    pub notes: Option<Option<String>>,
+}
+
+/// Request to publish the draft shifts of a week
+#[derive(Debug, Deserialize)]
+pub struct PublishRosterWeekRequest {
+    /// Monday of the week to publish
+    pub week_start: NaiveDate,
+    /// Only publish shifts of this team's members
+    pub team_id: Option<Uuid>,
+}
+
+/// Result of publishing a week
+#[derive(Debug, Serialize)]
+pub struct PublishRosterWeekResponse {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub published_count: usize,
+}
+
+/// Service for dated shift roster planning
+///
+/// Managers plan shifts as drafts and publish them one week at a time. Only
+/// published shifts are visible to employees and override their weekly work
+/// schedule in KPIs.
+pub struct RosterService {
+    roster_repo: RosterRepository,
+    team_repo: TeamRepository,
+    user_repo: UserRepository,
+    org_repo: OrganizationRepository,
+}
+
+impl RosterService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            roster_repo: RosterRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            user_repo: UserRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool),
+        }
+    }
+
+    // =====================
+    // Settings
+    // =====================
+
+    /// Get roster settings (defaults when none are set)
+    pub async fn get_settings(&self, org_id: Uuid) -> Result<RosterSettingsResponse, AppError> {
+        Ok(self
+            .roster_repo
+            .find_settings(org_id)
+            .await?
+            .map(RosterSettingsResponse::from)
+            .unwrap_or_default())
+    }
+
+    /// Create or replace roster settings
+    pub async fn update_settings(
+        &self,
+        org_id: Uuid,
+        request: UpdateRosterSettingsRequest,
+    ) -> Result<RosterSettingsResponse, AppError> {
+        if !(0..=MAX_MIN_REST_HOURS).contains(&request.min_rest_hours) {
+            return Err(AppError::ValidationError(format!(
+                "Minimum rest must be between 0 and {} hours",
+                MAX_MIN_REST_HOURS
+            )));
+        }
+
+        let settings = self
+            .roster_repo
+            .upsert_settings(NewRosterSettings {
+                organization_id: org_id,
+                swap_requires_approval: request.swap_requires_approval,
+                min_rest_hours: request.min_rest_hours,
+            })
+            .await?;
+
+        Ok(RosterSettingsResponse::from(settings))
+    }
+
+    // =====================
+    // Shifts
+    // =====================
+
+    /// Plan a draft shift (Manager+ only)
+    pub async fn create(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        request: CreateRosterShiftRequest,
+    ) -> Result<RosterShiftResponse, AppError> {
+        ensure_planner(planner_role)?;
+        let user = self.user_repo.find_by_id(request.user_id).await?;
+        if user.organization_id != org_id {
+            return Err(AppError::NotFound("User not found".to_string()));
+        }
+        self.ensure_in_scope(org_id, planner_id, planner_role, request.user_id)
+            .await?;
+        if let Some(team_id) = request.team_id {
+            self.ensure_team(org_id, planner_id, planner_role, team_id)
+                .await?;
+        }
+
+        let break_minutes = request.break_minutes.unwrap_or(0);
+        validate_shift(request.start_time, request.end_time, break_minutes)?;
+        let position = normalize_position(request.position)?;
+        self.ensure_no_overlap(
+            org_id,
+            request.user_id,
+            None,
+            request.shift_date,
+            request.start_time,
+            request.end_time,
+        )
+        .await?;
+
+        let new_shift = NewRosterShift {
+            organization_id: org_id,
+            user_id: request.user_id,
+            team_id: request.team_id,
+            shift_date: request.shift_date,
+            start_time: request.start_time,
+            end_time: request.end_time,
+            break_minutes,
+            position,
+            notes: request.notes,
+            created_by: Some(planner_id),
+        };
+
+        let shift = self.roster_repo.create(new_shift).await?;
+        let user_name = format!("{} {}", user.first_name, user.last_name);
+        Ok(RosterShiftResponse::from_shift(shift, user_name))
+    }
+
+    /// Get a shift by ID (Manager+ only)
+    pub async fn get(
+        &self,
+        org_id: Uuid,
+        shift_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+    ) -> Result<RosterShiftResponse, AppError> {
+        let shift = self
+            .find_in_scope(org_id, shift_id, planner_id, planner_role)
+            .await?;
+        self.build_response(shift).await
+    }
+
+    /// List shifts of a date range, drafts included (Manager+ only)
+    /// Managers only see shifts of members of the teams they manage
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        filter: RosterShiftFilter,
+    ) -> Result<Vec<RosterShiftResponse>, AppError> {
+        ensure_planner(planner_role)?;
+        if let (Some(start), Some(end)) = (filter.start_date, filter.end_date) {
+            validate_range(start, end)?;
+        }
+
+        // A team filter selects the team's members, whichever team the shift is tagged with
+        let user_ids = self
+            .scope_users(org_id, planner_id, planner_role, filter.team_id)
+            .await?;
+        let shifts = self
+            .roster_repo
+            .list(org_id, &filter, user_ids.as_deref())
+            .await?;
+
+        Ok(shifts
+            .into_iter()
+            .map(|(shift, user_name)| RosterShiftResponse::from_shift(shift, user_name))
+            .collect())
+    }
+
+    /// Update a shift (Manager+ only)
+    /// Changes to a published shift are visible to the employee right away
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        shift_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        request: UpdateRosterShiftRequest,
+    ) -> Result<RosterShiftResponse, AppError> {
+        let existing = self
+            .find_in_scope(org_id, shift_id, planner_id, planner_role)
+            .await?;
+        if let Some(Some(team_id)) = request.team_id {
+            self.ensure_team(org_id, planner_id, planner_role, team_id)
+                .await?;
+        }
+
+        let shift_date = request.shift_date.unwrap_or(existing.shift_date);
+        let start_time = request.start_time.unwrap_or(existing.start_time);
+        let end_time = request.end_time.unwrap_or(existing.end_time);
+        let break_minutes = request.break_minutes.unwrap_or(existing.break_minutes);
+        validate_shift(start_time, end_time, break_minutes)?;
+        let position = match request.position {
+            Some(position) => Some(normalize_position(position)?),
+            None => None,
+        };
+        self.ensure_no_overlap(
+            org_id,
+            existing.user_id,
+            Some(existing.id),
+            shift_date,
+            start_time,
+            end_time,
+        )
+        .await?;
+
+        let update = RosterShiftUpdate {
+            team_id: request.team_id,
+            shift_date: request.shift_date,
+            start_time: request.start_time,
+            end_time: request.end_time,
+            break_minutes: request.break_minutes,
+            position,
+            notes: request.notes,
+        };
+
+        let shift = self.roster_repo.update(org_id, shift_id, update).await?;
+        self.build_response(shift).await
+    }
+
+    /// Delete a shift (Manager+ only)
+    pub async fn delete(
+        &self,
+        org_id: Uuid,
+        shift_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+    ) -> Result<(), AppError> {
+        self.find_in_scope(org_id, shift_id, planner_id, planner_role)
+            .await?;
+        self.roster_repo.delete(org_id, shift_id).await
+    }
+
+    /// Publish the draft shifts of a week at once (Manager+ only)
+    /// Managers publish the shifts of members of the teams they manage
+    pub async fn publish_week(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        request: PublishRosterWeekRequest,
+    ) -> Result<PublishRosterWeekResponse, AppError> {
+        ensure_planner(planner_role)?;
+        let week_end = week_end(request.week_start)?;
+
+        let user_ids = self
+            .scope_users(org_id, planner_id, planner_role, request.team_id)
+            .await?;
+        let published = self
+            .roster_repo
+            .publish(org_id, request.week_start, week_end, user_ids.as_deref())
+            .await?;
+
+        Ok(PublishRosterWeekResponse {
+            week_start: request.week_start,
+            week_end,
+            published_count: published.len(),
+        })
+    }
+
+    /// Published upcoming shifts of the current user
+    /// Defaults to the next two weeks, starting today in the organization's time zone
+    pub async fn my_shifts(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> Result<Vec<RosterShiftResponse>, AppError> {
+        let start_date = match start_date {
+            Some(date) => date,
+            None => {
+                let tz = self.org_repo.get_timezone(org_id).await?;
+                to_local(Utc::now(), tz).date()
+            }
+        };
+        let end_date = end_date.unwrap_or(start_date + Duration::days(DEFAULT_UPCOMING_DAYS - 1));
+        validate_range(start_date, end_date)?;
+
+        let user = self.user_repo.find_by_id(user_id).await?;
+        let user_name = format!("{} {}", user.first_name, user.last_name);
+        let shifts = self
+            .roster_repo
+            .list_published_for_user(org_id, user_id, start_date, end_date)
+            .await?;
+
+        Ok(shifts
+            .into_iter()
+            .map(|shift| RosterShiftResponse::from_shift(shift, user_name.clone()))
+            .collect())
+    }
+
+    // =====================
+    // Helpers
+    // =====================
+
+    async fn build_response(&self, shift: RosterShift) -> Result<RosterShiftResponse, AppError> {
+        let user = self
+            .user_repo
+            .find_by_id_including_deleted(shift.user_id)
+            .await?;
+        let user_name = format!("{} {}", user.first_name, user.last_name);
+        Ok(RosterShiftResponse::from_shift(shift, user_name))
+    }
+
+    /// Users a planner may roster, optionally narrowed to a team (`None` for the whole organization)
+    async fn scope_users(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        team_id: Option<Uuid>,
+    ) -> Result<Option<Vec<Uuid>>, AppError> {
+        let team_ids = if planner_role == UserRole::Manager {
+            let managed: Vec<Uuid> = self
+                .team_repo
+                .get_managed_teams(org_id, planner_id)
+                .await?
+                .into_iter()
+                .map(|team| team.id)
+                .collect();
+            match team_id {
+                Some(team_id) if !managed.contains(&team_id) => {
+                    return Err(AppError::Forbidden(
+                        "You can only plan shifts for the teams you manage".to_string(),
+                    ));
+                }
+                Some(team_id) => vec![team_id],
+                None => managed,
+            }
+        } else {
+            match team_id {
+                Some(team_id) => {
+                    self.team_repo.find_by_id(org_id, team_id).await?;
+                    vec![team_id]
+                }
+                None => return Ok(None),
+            }
+        };
+
+        let mut user_ids = Vec::new();
+        for team_id in team_ids {
+            for member in self.team_repo.list_members(team_id).await? {
+                if !user_ids.contains(&member.id) {
+                    user_ids.push(member.id);
+                }
+            }
+        }
+        Ok(Some(user_ids))
+    }
+
+    /// Reject users outside the planner's teams
+    pub async fn ensure_in_scope(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        if let Some(user_ids) = self
+            .scope_users(org_id, planner_id, planner_role, None)
+            .await?
+        {
+            if !user_ids.contains(&user_id) {
+                return Err(AppError::Forbidden(
+                    "You can only plan shifts for members of your team".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a team a shift is tagged with
+    async fn ensure_team(
+        &self,
+        org_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+        team_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.team_repo.find_by_id(org_id, team_id).await?;
+        self.scope_users(org_id, planner_id, planner_role, Some(team_id))
+            .await?;
+        Ok(())
+    }
+
+    /// Find a shift the planner may manage
+    async fn find_in_scope(
+        &self,
+        org_id: Uuid,
+        shift_id: Uuid,
+        planner_id: Uuid,
+        planner_role: UserRole,
+    ) -> Result<RosterShift, AppError> {
+        ensure_planner(planner_role)?;
+        let shift = self.roster_repo.find_by_id(org_id, shift_id).await?;
+        self.ensure_in_scope(org_id, planner_id, planner_role, shift.user_id)
+            .await?;
+        Ok(shift)
+    }
+
+    /// Reject a shift overlapping another shift of the same user
+    async fn ensure_no_overlap(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        exclude_id: Option<Uuid>,
+        shift_date: NaiveDate,
+        start_time: NaiveTime,
+        end_time: NaiveTime,
+    ) -> Result<(), AppError> {
+        // Overnight shifts of the previous day may run into this one
+        let existing = self
+            .roster_repo
+            .list_for_user(
+                org_id,
+                user_id,
+                shift_date - Duration::days(1),
+                shift_date + Duration::days(1),
+            )
+            .await?;
+        let bounds = time_range_on(shift_date, start_time, end_time);
+        let overlapping = existing
+            .iter()
+            .filter(|s| Some(s.id) != exclude_id)
+            .any(|s| ranges_overlap(bounds, s.shift_bounds()));
+        if overlapping {
+            return Err(AppError::Conflict(
+                "This shift overlaps another shift of the employee".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn ensure_planner(role: UserRole) -> Result<(), AppError> {
+    if role == UserRole::Employee {
+        return Err(AppError::Forbidden(
+            "Only managers can plan the roster".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_shift(
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+    break_minutes: i32,
+) -> Result<(), AppError> {
+    if start_time == end_time {
+        return Err(AppError::ValidationError(
+            "Shift start and end times must differ".to_string(),
+        ));
+    }
+    if break_minutes < 0 || break_minutes as i64 >= time_span_minutes(start_time, end_time) {
+        return Err(AppError::ValidationError(
+            "Break must be shorter than the shift".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Trim a position, treating a blank one as unset
+fn normalize_position(position: Option<String>) -> Result<Option<String>, AppError> {
+    let Some(position) = position.map(|p| p.trim().to_string()) else {
+        return Ok(None);
+    };
+    if position.is_empty() {
+        return Ok(None);
+    }
+    if position.chars().count() > MAX_POSITION_LENGTH {
+        return Err(AppError::ValidationError(format!(
+            "Position must be at most {} characters",
+            MAX_POSITION_LENGTH
+        )));
+    }
+    Ok(Some(position))
+}
+
+fn validate_range(start_date: NaiveDate, end_date: NaiveDate) -> Result<(), AppError> {
+    if end_date < start_date {
+        return Err(AppError::ValidationError(
+            "End date must not be before start date".to_string(),
+        ));
+    }
+    if (end_date - start_date).num_days() >= MAX_RANGE_DAYS {
+        return Err(AppError::ValidationError(format!(
+            "Date range must not exceed {} days",
+            MAX_RANGE_DAYS
+        )));
+    }
+    Ok(())
+}
+
+/// Sunday ending the week that starts on `week_start`, which must be a Monday
+fn week_end(week_start: NaiveDate) -> Result<NaiveDate, AppError> {
+    if week_start.weekday() != Weekday::Mon {
+        return Err(AppError::ValidationError(
+            "Week start must be a Monday".to_string(),
+        ));
+    }
+    Ok(week_start + Duration::days(6))
+}
+
+/// Whether two local time ranges overlap (touching ranges do not)
+pub fn ranges_overlap(
+    a: (NaiveDateTime, NaiveDateTime),
+    b: (NaiveDateTime, NaiveDateTime),
+) -> bool {
+    a.0 < b.1 && b.0 < a.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, d).unwrap()
+    }
+
+    #[test]
+    fn test_validate_shift() {
+        assert!(validate_shift(time(9, 0), time(17, 0), 60).is_ok());
+        assert!(validate_shift(time(22, 0), time(6, 0), 30).is_ok());
+        assert!(validate_shift(time(9, 0), time(9, 0), 0).is_err());
+        assert!(validate_shift(time(9, 0), time(10, 0), 60).is_err());
+        assert!(validate_shift(time(9, 0), time(17, 0), -5).is_err());
+    }
+
+    #[test]
+    fn test_normalize_position() {
+        assert_eq!(
+            normalize_position(Some("  Cashier ".to_string())).unwrap(),
+            Some("Cashier".to_string())
+        );
+        assert_eq!(normalize_position(Some("   ".to_string())).unwrap(), None);
+        assert_eq!(normalize_position(None).unwrap(), None);
+        assert!(normalize_position(Some("x".repeat(MAX_POSITION_LENGTH + 1))).is_err());
+    }
+
+    #[test]
+    fn test_week_end_requires_monday() {
+        // June 2nd 2025 is a Monday
+        assert_eq!(week_end(date(2)).unwrap(), date(8));
+        assert!(week_end(date(3)).is_err());
+    }
+
+    #[test]
+    fn test_ranges_overlap_across_midnight() {
+        let night = time_range_on(date(2), time(22, 0), time(6, 0));
+
+        assert!(ranges_overlap(
+            night,
+            time_range_on(date(3), time(5, 0), time(9, 0))
+        ));
+        // Back-to-back shifts do not overlap
+        assert!(!ranges_overlap(
+            night,
+            time_range_on(date(3), time(6, 0), time(14, 0))
+        ));
+    }
+}
@@ -0,0 +1,762 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::{NotificationType, RosterShiftStatus, ShiftSwapStatus, UserRole};
+use crate::error::AppError;
+use crate::models::{
+    AbsencePeriod, NewShiftSwapRequest, RosterShift, RosterShiftResponse, ShiftSwapFilter,
+    ShiftSwapNames, ShiftSwapRequest, ShiftSwapRequestUpdate, ShiftSwapResponse,
+};
+use crate::repositories::{
+    AbsenceRepository, OrganizationRepository, RosterRepository, ShiftSwapRepository,
+    TeamRepository, UserRepository,
+};
+use crate::services::roster_service::ranges_overlap;
+use crate::services::{NotificationService, RosterService};
+use crate::utils::timezone::to_local;
+
+/// Request to offer a shift to a colleague or to the open pool
+#[derive(Debug, Deserialize)]
+pub struct CreateShiftSwapRequest {
+    pub shift_id: Uuid,
+    /// Colleague the shift is offered to (omit to release it to the open pool)
+    pub target_user_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+/// Request to approve or reject a claimed swap
+#[derive(Debug, Deserialize)]
+pub struct ReviewShiftSwapRequest {
+    pub note: Option<String>,
+}
+
+/// Service for shift swaps and the open-shift marketplace
+///
+/// An employee offers one of their upcoming published shifts, either to a
+/// colleague or to anyone. Once claimed, the swap waits for a manager unless the
+/// organization lets swaps apply directly. The claimant is checked for
+/// double-booking, absences and the minimum rest period both when claiming and
+/// when the swap is applied.
+pub struct ShiftSwapService {
+    pool: DbPool,
+    swap_repo: ShiftSwapRepository,
+    roster_repo: RosterRepository,
+    absence_repo: AbsenceRepository,
+    team_repo: TeamRepository,
+    user_repo: UserRepository,
+    org_repo: OrganizationRepository,
+}
+
+impl ShiftSwapService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            swap_repo: ShiftSwapRepository::new(pool.clone()),
+            roster_repo: RosterRepository::new(pool.clone()),
+            absence_repo: AbsenceRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            user_repo: UserRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Offer one of the user's upcoming published shifts
+    pub async fn create(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        request: CreateShiftSwapRequest,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let shift = self
+            .roster_repo
+            .find_by_id(org_id, request.shift_id)
+            .await?;
+        if shift.user_id != user_id {
+            return Err(AppError::Forbidden(
+                "You can only offer your own shifts".to_string(),
+            ));
+        }
+        self.ensure_upcoming(org_id, &shift).await?;
+
+        if let Some(target_user_id) = request.target_user_id {
+            if target_user_id == user_id {
+                return Err(AppError::ValidationError(
+                    "You cannot offer a shift to yourself".to_string(),
+                ));
+            }
+            self.find_colleague(org_id, target_user_id).await?;
+        }
+
+        let swap = self
+            .swap_repo
+            .create(NewShiftSwapRequest {
+                organization_id: org_id,
+                shift_id: shift.id,
+                requester_id: user_id,
+                target_user_id: request.target_user_id,
+                note: request.note,
+            })
+            .await?;
+
+        if let Some(target_user_id) = swap.target_user_id {
+            let requester_name = self.user_name(user_id).await?;
+            self.notify(
+                org_id,
+                target_user_id,
+                NotificationType::ShiftSwapOffered,
+                "Shift Offered To You",
+                format!(
+                    "{} offered you their shift on {}. Claim it to take it over.",
+                    requester_name,
+                    describe_shift(&shift)
+                ),
+            )
+            .await;
+        }
+
+        self.build_response(swap, shift).await
+    }
+
+    /// Get a swap request the user takes part in, or reviews (Manager+)
+    pub async fn get(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        user_id: Uuid,
+        role: UserRole,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let swap = self.swap_repo.find_by_id(org_id, request_id).await?;
+        let is_participant = swap.requester_id == user_id
+            || swap.target_user_id == Some(user_id)
+            || swap.claimed_by == Some(user_id);
+        if !is_participant {
+            self.ensure_reviewer(org_id, &swap, user_id, role).await?;
+        }
+
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        self.build_response(swap, shift).await
+    }
+
+    /// List swap requests
+    /// Employees see the requests they take part in, managers those of their
+    /// team members and admins all of them
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        role: UserRole,
+        filter: ShiftSwapFilter,
+    ) -> Result<Vec<ShiftSwapResponse>, AppError> {
+        let swaps = match role {
+            UserRole::Employee => {
+                self.swap_repo
+                    .list(org_id, &filter, Some(user_id), None)
+                    .await?
+            }
+            UserRole::Manager => {
+                let member_ids = self.managed_members(org_id, user_id).await?;
+                self.swap_repo
+                    .list(org_id, &filter, None, Some(&member_ids))
+                    .await?
+            }
+            _ => self.swap_repo.list(org_id, &filter, None, None).await?,
+        };
+
+        self.build_responses(org_id, swaps).await
+    }
+
+    /// Open shifts the user may claim: the open pool and shifts offered to them
+    pub async fn list_claimable(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<ShiftSwapResponse>, AppError> {
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let today = to_local(Utc::now(), tz).date();
+        let swaps = self
+            .swap_repo
+            .list_claimable(org_id, user_id, today)
+            .await?;
+
+        self.build_responses(org_id, swaps).await
+    }
+
+    /// Claim an open shift
+    /// Applied right away when the organization does not require approval
+    pub async fn claim(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let swap = self.swap_repo.find_by_id(org_id, request_id).await?;
+        if swap.status != ShiftSwapStatus::Open {
+            return Err(AppError::ValidationError(
+                "This shift is no longer available".to_string(),
+            ));
+        }
+        if swap.requester_id == user_id {
+            return Err(AppError::ValidationError(
+                "You cannot claim your own shift".to_string(),
+            ));
+        }
+        if swap.target_user_id.is_some_and(|target| target != user_id) {
+            return Err(AppError::Forbidden(
+                "This shift was offered to another colleague".to_string(),
+            ));
+        }
+
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        self.ensure_upcoming(org_id, &shift).await?;
+        self.find_colleague(org_id, user_id).await?;
+        self.check_assignment(org_id, &shift, user_id).await?;
+
+        let settings = RosterService::new(self.pool.clone())
+            .get_settings(org_id)
+            .await?;
+        let now = Utc::now();
+        let claimed = ShiftSwapRequestUpdate {
+            claimed_by: Some(Some(user_id)),
+            claimed_at: Some(Some(now)),
+            ..Default::default()
+        };
+
+        let requester_name = self.user_name(swap.requester_id).await?;
+        let claimant_name = self.user_name(user_id).await?;
+
+        if !settings.swap_requires_approval {
+            let swap = self
+                .swap_repo
+                .apply(
+                    org_id,
+                    &swap,
+                    user_id,
+                    ShiftSwapRequestUpdate {
+                        status: Some(ShiftSwapStatus::Approved),
+                        ..claimed
+                    },
+                )
+                .await?;
+            self.notify_outcome(&swap, &shift, &claimant_name, true)
+                .await;
+            let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+            return self.build_response(swap, shift).await;
+        }
+
+        let swap = self
+            .swap_repo
+            .transition(
+                org_id,
+                swap.id,
+                ShiftSwapStatus::Open,
+                ShiftSwapRequestUpdate {
+                    status: Some(ShiftSwapStatus::PendingApproval),
+                    ..claimed
+                },
+            )
+            .await?;
+
+        self.notify(
+            org_id,
+            swap.requester_id,
+            NotificationType::ShiftSwapClaimed,
+            "Shift Claimed",
+            format!(
+                "{} claimed your shift on {}. It is awaiting manager approval.",
+                claimant_name,
+                describe_shift(&shift)
+            ),
+        )
+        .await;
+        for manager_id in self.team_managers(org_id, swap.requester_id).await {
+            if manager_id == swap.requester_id || manager_id == user_id {
+                continue;
+            }
+            self.notify(
+                org_id,
+                manager_id,
+                NotificationType::ShiftSwapClaimed,
+                "Shift Swap Awaiting Approval",
+                format!(
+                    "{} wants to take over {}'s shift on {}. Please review the swap.",
+                    claimant_name,
+                    requester_name,
+                    describe_shift(&shift)
+                ),
+            )
+            .await;
+        }
+
+        self.build_response(swap, shift).await
+    }
+
+    /// Approve a claimed swap and hand the shift over (Manager+ only)
+    pub async fn approve(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        request: ReviewShiftSwapRequest,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let swap = self
+            .find_pending(org_id, request_id, reviewer_id, reviewer_role)
+            .await?;
+        let Some(claimant_id) = swap.claimed_by else {
+            return Err(AppError::ValidationError(
+                "This swap has not been claimed".to_string(),
+            ));
+        };
+
+        // The claimant's roster may have changed since the claim
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        self.ensure_upcoming(org_id, &shift).await?;
+        self.check_assignment(org_id, &shift, claimant_id).await?;
+
+        let swap = self
+            .swap_repo
+            .apply(
+                org_id,
+                &swap,
+                claimant_id,
+                ShiftSwapRequestUpdate {
+                    status: Some(ShiftSwapStatus::Approved),
+                    reviewed_by: Some(Some(reviewer_id)),
+                    reviewed_at: Some(Some(Utc::now())),
+                    review_note: Some(request.note),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let claimant_name = self.user_name(claimant_id).await?;
+        self.notify_outcome(&swap, &shift, &claimant_name, true)
+            .await;
+
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        self.build_response(swap, shift).await
+    }
+
+    /// Reject a claimed swap; the shift stays with the requester (Manager+ only)
+    pub async fn reject(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+        request: ReviewShiftSwapRequest,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let swap = self
+            .find_pending(org_id, request_id, reviewer_id, reviewer_role)
+            .await?;
+
+        let swap = self
+            .swap_repo
+            .transition(
+                org_id,
+                swap.id,
+                ShiftSwapStatus::PendingApproval,
+                ShiftSwapRequestUpdate {
+                    status: Some(ShiftSwapStatus::Rejected),
+                    reviewed_by: Some(Some(reviewer_id)),
+                    reviewed_at: Some(Some(Utc::now())),
+                    review_note: Some(request.note),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        let claimant_name = match swap.claimed_by {
+            Some(claimant_id) => self.user_name(claimant_id).await?,
+            None => String::new(),
+        };
+        self.notify_outcome(&swap, &shift, &claimant_name, false)
+            .await;
+
+        self.build_response(swap, shift).await
+    }
+
+    /// Withdraw an offer that has not been applied yet (requester only)
+    pub async fn cancel(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let swap = self.swap_repo.find_by_id(org_id, request_id).await?;
+        if swap.requester_id != user_id {
+            return Err(AppError::Forbidden(
+                "Only the employee who offered the shift can cancel the offer".to_string(),
+            ));
+        }
+        if !matches!(
+            swap.status,
+            ShiftSwapStatus::Open | ShiftSwapStatus::PendingApproval
+        ) {
+            return Err(AppError::ValidationError(
+                "This swap request can no longer be cancelled".to_string(),
+            ));
+        }
+
+        let swap = self
+            .swap_repo
+            .transition(
+                org_id,
+                swap.id,
+                swap.status,
+                ShiftSwapRequestUpdate {
+                    status: Some(ShiftSwapStatus::Cancelled),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+        self.build_response(swap, shift).await
+    }
+
+    // =====================
+    // Helpers
+    // =====================
+
+    /// Reject shifts that are not published or have already started
+    async fn ensure_upcoming(&self, org_id: Uuid, shift: &RosterShift) -> Result<(), AppError> {
+        if shift.status != RosterShiftStatus::Published {
+            return Err(AppError::ValidationError(
+                "Only published shifts can be swapped".to_string(),
+            ));
+        }
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        if shift.shift_bounds().0 <= to_local(Utc::now(), tz) {
+            return Err(AppError::ValidationError(
+                "This shift has already started".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that a colleague can work a shift
+    async fn check_assignment(
+        &self,
+        org_id: Uuid,
+        shift: &RosterShift,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let settings = RosterService::new(self.pool.clone())
+            .get_settings(org_id)
+            .await?;
+        let (start, end) = shift.shift_bounds();
+
+        if self
+            .absence_repo
+            .check_overlap(
+                org_id,
+                user_id,
+                &AbsencePeriod::days(start.date(), end.date()),
+                None,
+            )
+            .await?
+        {
+            return Err(AppError::ValidationError(
+                "The colleague has an absence during this shift".to_string(),
+            ));
+        }
+
+        // Shifts close enough to break the rest period
+        let reach = Duration::days(2);
+        let others: Vec<(NaiveDateTime, NaiveDateTime)> = self
+            .roster_repo
+            .list_for_user(
+                org_id,
+                user_id,
+                shift.shift_date - reach,
+                shift.shift_date + reach,
+            )
+            .await?
+            .iter()
+            .map(RosterShift::shift_bounds)
+            .collect();
+
+        check_rest_and_overlap(
+            (start, end),
+            &others,
+            Duration::hours(settings.min_rest_hours as i64),
+        )
+    }
+
+    /// Find an active user of the organization
+    async fn find_colleague(&self, org_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+        if user.organization_id != org_id {
+            return Err(AppError::NotFound("User not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Find a claimed swap the reviewer may decide on
+    async fn find_pending(
+        &self,
+        org_id: Uuid,
+        request_id: Uuid,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<ShiftSwapRequest, AppError> {
+        let swap = self.swap_repo.find_by_id(org_id, request_id).await?;
+        self.ensure_reviewer(org_id, &swap, reviewer_id, reviewer_role)
+            .await?;
+        if swap.status != ShiftSwapStatus::PendingApproval {
+            return Err(AppError::ValidationError(
+                "This swap is not awaiting approval".to_string(),
+            ));
+        }
+        Ok(swap)
+    }
+
+    /// Managers review swaps of their team members, admins all swaps
+    async fn ensure_reviewer(
+        &self,
+        org_id: Uuid,
+        swap: &ShiftSwapRequest,
+        reviewer_id: Uuid,
+        reviewer_role: UserRole,
+    ) -> Result<(), AppError> {
+        if reviewer_role == UserRole::Employee {
+            return Err(AppError::Forbidden(
+                "Only managers can review shift swaps".to_string(),
+            ));
+        }
+        RosterService::new(self.pool.clone())
+            .ensure_in_scope(org_id, reviewer_id, reviewer_role, swap.requester_id)
+            .await
+    }
+
+    /// Members of the teams a manager manages
+    async fn managed_members(&self, org_id: Uuid, manager_id: Uuid) -> Result<Vec<Uuid>, AppError> {
+        let mut user_ids = Vec::new();
+        for team in self.team_repo.get_managed_teams(org_id, manager_id).await? {
+            for member in self.team_repo.list_members(team.id).await? {
+                if !user_ids.contains(&member.id) {
+                    user_ids.push(member.id);
+                }
+            }
+        }
+        Ok(user_ids)
+    }
+
+    /// Managers of a user's teams
+    async fn team_managers(&self, org_id: Uuid, user_id: Uuid) -> Vec<Uuid> {
+        let teams = match self.team_repo.get_user_teams(org_id, user_id).await {
+            Ok(teams) => teams,
+            Err(e) => {
+                tracing::warn!(user_id = %user_id, error = %e, "Failed to load user teams");
+                return Vec::new();
+            }
+        };
+        let mut manager_ids = Vec::new();
+        for manager_id in teams.into_iter().filter_map(|t| t.manager_id) {
+            if !manager_ids.contains(&manager_id) {
+                manager_ids.push(manager_id);
+            }
+        }
+        manager_ids
+    }
+
+    async fn user_name(&self, user_id: Uuid) -> Result<String, AppError> {
+        let user = self.user_repo.find_by_id_including_deleted(user_id).await?;
+        Ok(format!("{} {}", user.first_name, user.last_name))
+    }
+
+    /// Tell the requester and the claimant whether the swap went through
+    async fn notify_outcome(
+        &self,
+        swap: &ShiftSwapRequest,
+        shift: &RosterShift,
+        claimant_name: &str,
+        approved: bool,
+    ) {
+        let shift_label = describe_shift(shift);
+        let (notification_type, title, requester_message, claimant_message) = if approved {
+            (
+                NotificationType::ShiftSwapApproved,
+                "Shift Swap Approved",
+                format!(
+                    "Your shift on {} now belongs to {}.",
+                    shift_label, claimant_name
+                ),
+                format!("The shift on {} is now yours.", shift_label),
+            )
+        } else {
+            (
+                NotificationType::ShiftSwapRejected,
+                "Shift Swap Rejected",
+                format!(
+                    "The swap of your shift on {} was rejected. The shift stays yours.",
+                    shift_label
+                ),
+                format!("Your claim of the shift on {} was rejected.", shift_label),
+            )
+        };
+
+        self.notify(
+            swap.organization_id,
+            swap.requester_id,
+            notification_type,
+            title,
+            requester_message,
+        )
+        .await;
+        if let Some(claimant_id) = swap.claimed_by {
+            self.notify(
+                swap.organization_id,
+                claimant_id,
+                notification_type,
+                title,
+                claimant_message,
+            )
+            .await;
+        }
+    }
+
+    /// Create a notification, logging failures instead of failing the swap
+    async fn notify(
+        &self,
+        org_id: Uuid,
+        user_id: Uuid,
+        notification_type: NotificationType,
+        title: &str,
+        message: String,
+    ) {
+        let notification_service = NotificationService::new(self.pool.clone());
+        if let Err(e) = notification_service
+            .create_notification(
+                org_id,
+                user_id,
+                notification_type,
+                title.to_string(),
+                message,
+                None,
+            )
+            .await
+        {
+            tracing::warn!(
+                user_id = %user_id,
+                error = %e,
+                "Failed to create shift swap notification"
+            );
+        }
+    }
+
+    async fn build_response(
+        &self,
+        swap: ShiftSwapRequest,
+        shift: RosterShift,
+    ) -> Result<ShiftSwapResponse, AppError> {
+        let names = ShiftSwapNames {
+            requester: self.user_name(swap.requester_id).await?,
+            target_user: match swap.target_user_id {
+                Some(id) => Some(self.user_name(id).await?),
+                None => None,
+            },
+            claimant: match swap.claimed_by {
+                Some(id) => Some(self.user_name(id).await?),
+                None => None,
+            },
+        };
+        let owner_name = self.user_name(shift.user_id).await?;
+        let shift = RosterShiftResponse::from_shift(shift, owner_name);
+        Ok(ShiftSwapResponse::from_request(swap, shift, names))
+    }
+
+    async fn build_responses(
+        &self,
+        org_id: Uuid,
+        swaps: Vec<ShiftSwapRequest>,
+    ) -> Result<Vec<ShiftSwapResponse>, AppError> {
+        let mut responses = Vec::with_capacity(swaps.len());
+        for swap in swaps {
+            let shift = self.roster_repo.find_by_id(org_id, swap.shift_id).await?;
+            responses.push(self.build_response(swap, shift).await?);
+        }
+        Ok(responses)
+    }
+}
+
+/// Short label of a shift for notifications, e.g. "2025-06-02 22:00-06:00"
+fn describe_shift(shift: &RosterShift) -> String {
+    format!(
+        "{} {}-{}",
+        shift.shift_date.format("%Y-%m-%d"),
+        shift.start_time.format("%H:%M"),
+        shift.end_time.format("%H:%M")
+    )
+}
+
+/// Check a shift against the other shifts of the person taking it over
+/// Rejects double-booking and gaps shorter than the minimum rest
+fn check_rest_and_overlap(
+    shift: (NaiveDateTime, NaiveDateTime),
+    others: &[(NaiveDateTime, NaiveDateTime)],
+    min_rest: Duration,
+) -> Result<(), AppError> {
+    for &other in others {
+        if ranges_overlap(shift, other) {
+            return Err(AppError::Conflict(
+                "The colleague already works during this shift".to_string(),
+            ));
+        }
+        let rest = if other.1 <= shift.0 {
+            shift.0 - other.1
+        } else {
+            other.0 - shift.1
+        };
+        if rest < min_rest {
+            return Err(AppError::ValidationError(format!(
+                "The colleague would get less than {} hours of rest between shifts",
+                min_rest.num_hours()
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn at(d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 6, d)
+            .unwrap()
+            .and_time(NaiveTime::from_hms_opt(h, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_check_rest_and_overlap() {
+        let shift = (at(3, 8), at(3, 16));
+        let min_rest = Duration::hours(11);
+
+        assert!(check_rest_and_overlap(shift, &[], min_rest).is_ok());
+        // Previous evening ends 12 hours before
+        assert!(check_rest_and_overlap(shift, &[(at(2, 12), at(2, 20))], min_rest).is_ok());
+        // Night shift ending at 02:00 leaves only 6 hours
+        assert!(matches!(
+            check_rest_and_overlap(shift, &[(at(2, 18), at(3, 2))], min_rest),
+            Err(AppError::ValidationError(_))
+        ));
+        // Next shift starting 4 hours after
+        assert!(matches!(
+            check_rest_and_overlap(shift, &[(at(3, 20), at(4, 4))], min_rest),
+            Err(AppError::ValidationError(_))
+        ));
+        // Double-booking
+        assert!(matches!(
+            check_rest_and_overlap(shift, &[(at(3, 12), at(3, 18))], min_rest),
+            Err(AppError::Conflict(_))
+        ));
+        // No rest rule configured
+        assert!(check_rest_and_overlap(shift, &[(at(3, 16), at(3, 20))], Duration::zero()).is_ok());
+    }
+}
@@ -0,0 +1,496 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::domain::enums::{ClockEntryStatus, UserRole};
+use crate::error::AppError;
+use crate::models::{
+    ClockEntry, ClockEntryAllocations, NewTimeAllocation, ProjectHoursRow,
+    SetTimeAllocationsRequest, TimeAllocationInput, TimeAllocationResponse,
+};
+use crate::repositories::time_allocation_repository::AllocationReportRow;
+use crate::repositories::{
+    BreakRepository, ClockRepository, OrganizationRepository, ProjectRepository, TeamRepository,
+    TimeAllocationRepository,
+};
+use crate::services::Granularity;
+use crate::utils::timezone::to_local;
+
+/// Service allocating worked time of clock entries to projects
+pub struct TimeAllocationService {
+    allocation_repo: TimeAllocationRepository,
+    clock_repo: ClockRepository,
+    break_repo: BreakRepository,
+    project_repo: ProjectRepository,
+    team_repo: TeamRepository,
+    org_repo: OrganizationRepository,
+}
+
+impl TimeAllocationService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            allocation_repo: TimeAllocationRepository::new(pool.clone()),
+            clock_repo: ClockRepository::new(pool.clone()),
+            break_repo: BreakRepository::new(pool.clone()),
+            project_repo: ProjectRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            org_repo: OrganizationRepository::new(pool),
+        }
+    }
+
+    /// Get the allocations of a clock entry
+    /// Employees can see their own entries, managers their team members' entries
+    pub async fn get_for_entry(
+        &self,
+        org_id: Uuid,
+        requester_id: Uuid,
+        requester_role: UserRole,
+        entry_id: Uuid,
+    ) -> Result<ClockEntryAllocations, AppError> {
+        let entry = self.clock_repo.find_by_id(org_id, entry_id).await?;
+        self.check_access(org_id, requester_id, requester_role, &entry)
+            .await?;
+
+        self.build_response(org_id, &entry).await
+    }
+
+    /// Replace the allocations of a clock entry
+    ///
+    /// The entry must be closed and not rejected. Allocations cannot exceed the
+    /// worked time of the entry (duration minus breaks).
+    pub async fn set_for_entry(
+        &self,
+        org_id: Uuid,
+        requester_id: Uuid,
+        requester_role: UserRole,
+        entry_id: Uuid,
+        request: SetTimeAllocationsRequest,
+    ) -> Result<ClockEntryAllocations, AppError> {
+        let entry = self.clock_repo.find_by_id(org_id, entry_id).await?;
+        self.check_access(org_id, requester_id, requester_role, &entry)
+            .await?;
+
+        let Some(clock_out) = entry.clock_out else {
+            return Err(AppError::ValidationError(
+                "Time can only be allocated once the entry is clocked out".to_string(),
+            ));
+        };
+        if entry.status == ClockEntryStatus::Rejected {
+            return Err(AppError::ValidationError(
+                "Time cannot be allocated on a rejected entry".to_string(),
+            ));
+        }
+
+        // Projects and tasks must exist in the organization and be active
+        for input in &request.allocations {
+            let project = self
+                .project_repo
+                .find_by_id(org_id, input.project_id)
+                .await?;
+            if !project.is_active {
+                return Err(AppError::ValidationError(format!(
+                    "Project '{}' is inactive",
+                    project.name
+                )));
+            }
+            if let Some(task_id) = input.task_id {
+                let task = self.project_repo.find_task(org_id, task_id).await?;
+                if task.project_id != project.id {
+                    return Err(AppError::ValidationError(format!(
+                        "Task '{}' does not belong to project '{}'",
+                        task.name, project.name
+                    )));
+                }
+                if !task.is_active {
+                    return Err(AppError::ValidationError(format!(
+                        "Task '{}' is inactive",
+                        task.name
+                    )));
+                }
+            }
+        }
+
+        let worked = self.worked_minutes(&entry, clock_out).await?;
+        let minutes = allocation_minutes(&request.allocations, entry.clock_in, clock_out, worked)?;
+
+        let new_allocations = request
+            .allocations
+            .into_iter()
+            .zip(minutes)
+            .map(|(input, minutes)| NewTimeAllocation {
+                organization_id: org_id,
+                clock_entry_id: entry.id,
+                user_id: entry.user_id,
+                project_id: input.project_id,
+                task_id: input.task_id,
+                segment_start: input.segment_start,
+                segment_end: input.segment_end,
+                minutes,
+                notes: input.notes,
+            })
+            .collect();
+
+        self.allocation_repo
+            .replace_for_entry(org_id, entry.id, new_allocations)
+            .await?;
+
+        self.build_response(org_id, &entry).await
+    }
+
+    /// Hours allocated per period, project and user
+    ///
+    /// Allocations count in the period of their segment start, or of the entry's
+    /// clock in, in the organization's timezone.
+    pub async fn project_hours_report(
+        &self,
+        org_id: Uuid,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        user_id: Option<Uuid>,
+        granularity: Granularity,
+    ) -> Result<Vec<ProjectHoursRow>, AppError> {
+        let tz = self.org_repo.get_timezone(org_id).await?;
+        let rows = self
+            .allocation_repo
+            .list_for_report(org_id, start, end, user_id)
+            .await?;
+
+        Ok(summarize_project_hours(rows, granularity, |at| {
+            to_local(at, tz).date()
+        }))
+    }
+
+    /// Entry owner, admins, and managers of one of the owner's teams
+    async fn check_access(
+        &self,
+        org_id: Uuid,
+        requester_id: Uuid,
+        requester_role: UserRole,
+        entry: &ClockEntry,
+    ) -> Result<(), AppError> {
+        if entry.user_id == requester_id || requester_role >= UserRole::Admin {
+            return Ok(());
+        }
+
+        if requester_role == UserRole::Manager {
+            let managed_teams = self
+                .team_repo
+                .get_managed_teams(org_id, requester_id)
+                .await?;
+            for team in managed_teams {
+                if self.team_repo.is_member(team.id, entry.user_id).await? {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(AppError::Forbidden(
+            "You can only allocate time on your own entries or your team's".to_string(),
+        ))
+    }
+
+    /// Entry duration minus recorded breaks
+    async fn worked_minutes(
+        &self,
+        entry: &ClockEntry,
+        clock_out: DateTime<Utc>,
+    ) -> Result<i64, AppError> {
+        let breaks = self
+            .break_repo
+            .calculate_total_break_minutes(entry.id)
+            .await?;
+        Ok(((clock_out - entry.clock_in).num_minutes() - breaks as i64).max(0))
+    }
+
+    async fn build_response(
+        &self,
+        org_id: Uuid,
+        entry: &ClockEntry,
+    ) -> Result<ClockEntryAllocations, AppError> {
+        let worked_minutes = match entry.clock_out {
+            Some(clock_out) => self.worked_minutes(entry, clock_out).await?,
+            None => 0,
+        };
+
+        let allocations: Vec<TimeAllocationResponse> = self
+            .allocation_repo
+            .list_for_entry(org_id, entry.id)
+            .await?
+            .into_iter()
+            .map(
+                |(allocation, project_name, task_name)| TimeAllocationResponse {
+                    id: allocation.id,
+                    project_id: allocation.project_id,
+                    project_name,
+                    task_id: allocation.task_id,
+                    task_name,
+                    segment_start: allocation.segment_start,
+                    segment_end: allocation.segment_end,
+                    minutes: allocation.minutes,
+                    notes: allocation.notes,
+                },
+            )
+            .collect();
+
+        let allocated_minutes = allocations.iter().map(|a| a.minutes as i64).sum();
+
+        Ok(ClockEntryAllocations {
+            clock_entry_id: entry.id,
+            worked_minutes,
+            allocated_minutes,
+            unallocated_minutes: (worked_minutes - allocated_minutes).max(0),
+            allocations,
+        })
+    }
+}
+
+/// Validate allocations of an entry and return the minutes of each
+///
+/// - An allocation has either minutes, or a segment within the entry; a segment
+///   defaults its minutes to its length and cannot be given more.
+/// - Segments cannot overlap each other.
+/// - The total cannot exceed the worked minutes.
+fn allocation_minutes(
+    inputs: &[TimeAllocationInput],
+    clock_in: DateTime<Utc>,
+    clock_out: DateTime<Utc>,
+    worked_minutes: i64,
+) -> Result<Vec<i32>, AppError> {
+    let mut minutes = Vec::with_capacity(inputs.len());
+    let mut segments = Vec::new();
+
+    for input in inputs {
+        let allocated = match (input.segment_start, input.segment_end) {
+            (Some(start), Some(end)) => {
+                if end <= start {
+                    return Err(AppError::ValidationError(
+                        "Segment end must be after its start".to_string(),
+                    ));
+                }
+                if start < clock_in || end > clock_out {
+                    return Err(AppError::ValidationError(
+                        "Segments must be within the clock entry".to_string(),
+                    ));
+                }
+                let length = (end - start).num_minutes();
+                match input.minutes {
+                    Some(m) if m as i64 > length => {
+                        return Err(AppError::ValidationError(
+                            "Allocated minutes exceed the segment length".to_string(),
+                        ))
+                    }
+                    Some(m) => m as i64,
+                    None => length,
+                }
+            }
+            (None, None) => input.minutes.ok_or_else(|| {
+                AppError::ValidationError("Each allocation needs minutes or a segment".to_string())
+            })? as i64,
+            _ => {
+                return Err(AppError::ValidationError(
+                    "Segment start and end must be provided together".to_string(),
+                ))
+            }
+        };
+
+        if allocated <= 0 {
+            return Err(AppError::ValidationError(
+                "Allocated minutes must be positive".to_string(),
+            ));
+        }
+
+        if let (Some(start), Some(end)) = (input.segment_start, input.segment_end) {
+            segments.push((start, end));
+        }
+        minutes.push(allocated);
+    }
+
+    segments.sort();
+    if segments.windows(2).any(|w| w[1].0 < w[0].1) {
+        return Err(AppError::ValidationError(
+            "Allocation segments cannot overlap".to_string(),
+        ));
+    }
+
+    let total: i64 = minutes.iter().sum();
+    if total > worked_minutes {
+        return Err(AppError::ValidationError(format!(
+            "Allocations total {} minutes but only {} minutes were worked",
+            total, worked_minutes
+        )));
+    }
+
+    Ok(minutes.into_iter().map(|m| m as i32).collect())
+}
+
+/// Group allocation rows by period, project and user
+fn summarize_project_hours(
+    rows: Vec<AllocationReportRow>,
+    granularity: Granularity,
+    local_date: impl Fn(DateTime<Utc>) -> NaiveDate,
+) -> Vec<ProjectHoursRow> {
+    let mut summary: BTreeMap<(NaiveDate, String, Uuid, String, Uuid), ProjectHoursRow> =
+        BTreeMap::new();
+
+    for row in rows {
+        let period_start =
+            granularity.period_start(local_date(row.segment_start.unwrap_or(row.clock_in)));
+        let user_name = format!("{} {}", row.first_name, row.last_name);
+        let key = (
+            period_start,
+            row.project_name.clone(),
+            row.project_id,
+            user_name.clone(),
+            row.user_id,
+        );
+
+        summary
+            .entry(key)
+            .or_insert_with(|| ProjectHoursRow {
+                period_start,
+                project_id: row.project_id,
+                project_name: row.project_name,
+                project_code: row.project_code,
+                user_id: row.user_id,
+                user_email: row.user_email,
+                user_name,
+                minutes: 0,
+            })
+            .minutes += row.minutes as i64;
+    }
+
+    summary.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 5, h, m, 0).unwrap()
+    }
+
+    fn by_minutes(minutes: i32) -> TimeAllocationInput {
+        TimeAllocationInput {
+            project_id: Uuid::new_v4(),
+            task_id: None,
+            minutes: Some(minutes),
+            segment_start: None,
+            segment_end: None,
+            notes: None,
+        }
+    }
+
+    fn by_segment(start: DateTime<Utc>, end: DateTime<Utc>) -> TimeAllocationInput {
+        TimeAllocationInput {
+            minutes: None,
+            segment_start: Some(start),
+            segment_end: Some(end),
+            ..by_minutes(0)
+        }
+    }
+
+    #[test]
+    fn test_allocation_minutes_within_worked_time() {
+        let inputs = [by_minutes(120), by_segment(at(11, 0), at(12, 30))];
+        let minutes = allocation_minutes(&inputs, at(9, 0), at(17, 0), 450).unwrap();
+
+        assert_eq!(minutes, vec![120, 90]);
+    }
+
+    #[test]
+    fn test_allocation_minutes_exceeding_worked_time() {
+        // 8h entry with a 1h break: 7h worked
+        let inputs = [by_minutes(240), by_minutes(200)];
+        let result = allocation_minutes(&inputs, at(9, 0), at(17, 0), 420);
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_allocation_segment_outside_entry() {
+        let inputs = [by_segment(at(8, 30), at(10, 0))];
+
+        assert!(allocation_minutes(&inputs, at(9, 0), at(17, 0), 480).is_err());
+    }
+
+    #[test]
+    fn test_allocation_overlapping_segments() {
+        let inputs = [
+            by_segment(at(9, 0), at(11, 0)),
+            by_segment(at(10, 30), at(12, 0)),
+        ];
+
+        assert!(allocation_minutes(&inputs, at(9, 0), at(17, 0), 480).is_err());
+    }
+
+    #[test]
+    fn test_allocation_segment_minutes_capped() {
+        let mut input = by_segment(at(9, 0), at(10, 0));
+        input.minutes = Some(45);
+        assert_eq!(
+            allocation_minutes(&[input.clone()], at(9, 0), at(17, 0), 480).unwrap(),
+            vec![45]
+        );
+
+        input.minutes = Some(75);
+        assert!(allocation_minutes(&[input], at(9, 0), at(17, 0), 480).is_err());
+    }
+
+    #[test]
+    fn test_allocation_requires_minutes_or_segment() {
+        let mut input = by_minutes(0);
+        input.minutes = None;
+        assert!(allocation_minutes(&[input], at(9, 0), at(17, 0), 480).is_err());
+
+        assert!(allocation_minutes(&[by_minutes(0)], at(9, 0), at(17, 0), 480).is_err());
+    }
+
+    #[test]
+    fn test_summarize_project_hours_by_week() {
+        let project_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let row = |day: u32, minutes: i32| AllocationReportRow {
+            user_id,
+            project_id,
+            minutes,
+            segment_start: None,
+            clock_in: Utc.with_ymd_and_hms(2024, 6, day, 9, 0, 0).unwrap(),
+            project_name: "Website".to_string(),
+            project_code: Some("WEB".to_string()),
+            user_email: "jane@example.com".to_string(),
+            first_name: "Jane".to_string(),
+            last_name: "Doe".to_string(),
+        };
+
+        // Wednesday and Friday of one week, Monday of the next
+        let rows = vec![row(5, 60), row(7, 90), row(10, 30)];
+        let summary = summarize_project_hours(rows, Granularity::Week, |at| at.date_naive());
+
+        assert_eq!(summary.len(), 2);
+        assert_eq!(
+            summary[0].period_start,
+            NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()
+        );
+        assert_eq!(summary[0].minutes, 150);
+        assert_eq!(
+            summary[1].period_start,
+            NaiveDate::from_ymd_opt(2024, 6, 10).unwrap()
+        );
+        assert_eq!(summary[1].minutes, 30);
+        assert_eq!(summary[1].user_name, "Jane Doe");
+    }
+
+    #[test]
+    fn test_granularity_period_start() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 5).unwrap();
+
+        assert_eq!(Granularity::Day.period_start(date), date);
+        assert_eq!(
+            Granularity::Month.period_start(date),
+            NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+        );
+    }
+}
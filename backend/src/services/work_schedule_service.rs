@@ -1,14 +1,24 @@
-use chrono::{DateTime, NaiveTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::config::database::DbPool;
+use crate::domain::enums::UserRole;
 use crate::error::AppError;
 use crate::models::{
-    DayConfig, NewWorkSchedule, NewWorkScheduleDay, WorkScheduleDay, WorkScheduleDayUpdate,
-    WorkScheduleUpdate, WorkScheduleWithDays,
+    DayConfig, ExpectedHours, NewScheduleException, NewWorkSchedule, NewWorkScheduleDay,
+    ScheduleException, ScheduleExceptionFilter, ScheduleExceptionResponse, ScheduleExceptionUpdate,
+    WorkScheduleDay, WorkScheduleDayUpdate, WorkScheduleUpdate, WorkScheduleWithDays,
 };
-use crate::repositories::WorkScheduleRepository;
+use crate::repositories::{TeamRepository, UserRepository, WorkScheduleRepository};
+use crate::utils::datetime::time_span_minutes;
+
+/// Longest supported rotation
+const MAX_CYCLE_WEEKS: i16 = 12;
+
+/// Longest schedule exception reason
+const MAX_REASON_LENGTH: usize = 255;
 
 /// Request to create a work schedule
 #[derive(Debug, Deserialize)]
@@ -16,6 +26,10 @@ pub struct CreateScheduleRequest {
     pub name: String,
     pub description: Option<String>,
     pub is_default: bool,
+    /// Rotation length in weeks (1 when omitted)
+    pub cycle_weeks: Option<i16>,
+    /// A date in rotation week 0 (today when omitted)
+    pub cycle_anchor_date: Option<NaiveDate>,
     pub days: Vec<DayConfig>,
 }
 
@@ -25,11 +39,16 @@ pub struct UpdateScheduleRequest {
     pub name: Option<String>,
     pub description: Option<Option<String>>,
     pub is_default: Option<bool>,
+    pub cycle_weeks: Option<i16>,
+    pub cycle_anchor_date: Option<NaiveDate>,
 }
 
 /// Request to add a day to schedule
 #[derive(Debug, Deserialize)]
 pub struct AddDayRequest {
+    /// Rotation week (0 when omitted)
+    #[serde(default)]
+    pub week_index: i16,
     pub day_of_week: i16,
     pub start_time: NaiveTime,
     pub end_time: NaiveTime,
@@ -44,15 +63,44 @@ pub struct UpdateDayRequest {
     pub break_minutes: Option<i32>,
 }
 
+/// Request to create a schedule exception
+#[derive(Debug, Deserialize)]
+pub struct CreateScheduleExceptionRequest {
+    pub exception_date: NaiveDate,
+    /// Whether the user does not work on the date (false when omitted)
+    #[serde(default)]
+    pub is_day_off: bool,
+    /// Required unless the exception is a day off
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: Option<i32>,
+    pub reason: Option<String>,
+}
+
+/// Request to update a schedule exception
+#[derive(Debug, Deserialize)]
+pub struct UpdateScheduleExceptionRequest {
+    pub exception_date: Option<NaiveDate>,
+    pub is_day_off: Option<bool>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub break_minutes: Option<i32>,
+    pub reason: Option<Option<String>>,
+}
+
 /// Service for work schedule operations
 pub struct WorkScheduleService {
     schedule_repo: WorkScheduleRepository,
+    team_repo: TeamRepository,
+    user_repo: UserRepository,
 }
 
 impl WorkScheduleService {
     pub fn new(pool: DbPool) -> Self {
         Self {
-            schedule_repo: WorkScheduleRepository::new(pool),
+            schedule_repo: WorkScheduleRepository::new(pool.clone()),
+            team_repo: TeamRepository::new(pool.clone()),
+            user_repo: UserRepository::new(pool),
         }
     }
 
@@ -62,16 +110,21 @@ impl WorkScheduleService {
         org_id: Uuid,
         request: CreateScheduleRequest,
     ) -> Result<WorkScheduleWithDays, AppError> {
+        let cycle_weeks = request.cycle_weeks.unwrap_or(1);
+        validate_cycle_weeks(cycle_weeks)?;
+
         // Validate days
         for day in &request.days {
+            validate_week_index(day.week_index, cycle_weeks)?;
             if day.day_of_week < 0 || day.day_of_week > 6 {
                 return Err(AppError::ValidationError(
                     "day_of_week must be between 0 (Monday) and 6 (Sunday)".to_string(),
                 ));
             }
-            if day.start_time >= day.end_time {
+            // end_time before start_time means the shift ends the next day
+            if day.start_time == day.end_time {
                 return Err(AppError::ValidationError(
-                    "start_time must be before end_time".to_string(),
+                    "start_time and end_time must differ".to_string(),
                 ));
             }
             if day.break_minutes < 0 {
@@ -87,6 +140,10 @@ impl WorkScheduleService {
             name: request.name,
             description: request.description,
             is_default: request.is_default,
+            cycle_weeks,
+            cycle_anchor_date: request
+                .cycle_anchor_date
+                .unwrap_or_else(|| Utc::now().date_naive()),
         };
 
         let schedule = self.schedule_repo.create(new_schedule).await?;
@@ -96,6 +153,7 @@ impl WorkScheduleService {
         for day_config in request.days {
             let new_day = NewWorkScheduleDay {
                 work_schedule_id: schedule.id,
+                week_index: day_config.week_index,
                 day_of_week: day_config.day_of_week,
                 start_time: day_config.start_time,
                 end_time: day_config.end_time,
@@ -105,7 +163,7 @@ impl WorkScheduleService {
             days.push(day);
         }
 
-        Ok(WorkScheduleWithDays { schedule, days })
+        Ok(WorkScheduleWithDays::new(schedule, days))
     }
 
     /// Get a schedule by ID with its days
@@ -117,7 +175,7 @@ impl WorkScheduleService {
         let schedule = self.schedule_repo.find_by_id(org_id, schedule_id).await?;
         let days = self.schedule_repo.get_days(schedule_id).await?;
 
-        Ok(WorkScheduleWithDays { schedule, days })
+        Ok(WorkScheduleWithDays::new(schedule, days))
     }
 
     /// List all schedules for organization
@@ -130,7 +188,7 @@ impl WorkScheduleService {
         let mut results = Vec::with_capacity(schedules.len());
         for schedule in schedules {
             let days = self.schedule_repo.get_days(schedule.id).await?;
-            results.push(WorkScheduleWithDays { schedule, days });
+            results.push(WorkScheduleWithDays::new(schedule, days));
         }
 
         Ok(results)
@@ -143,10 +201,24 @@ impl WorkScheduleService {
         schedule_id: Uuid,
         request: UpdateScheduleRequest,
     ) -> Result<WorkScheduleWithDays, AppError> {
+        // Shortening the rotation must not orphan days of the dropped weeks
+        if let Some(cycle_weeks) = request.cycle_weeks {
+            validate_cycle_weeks(cycle_weeks)?;
+            let days = self.schedule_repo.get_days(schedule_id).await?;
+            if days.iter().any(|d| d.week_index >= cycle_weeks) {
+                return Err(AppError::ValidationError(
+                    "Remove the days of the dropped rotation weeks before shortening the cycle"
+                        .to_string(),
+                ));
+            }
+        }
+
         let update = WorkScheduleUpdate {
             name: request.name,
             description: request.description,
             is_default: request.is_default,
+            cycle_weeks: request.cycle_weeks,
+            cycle_anchor_date: request.cycle_anchor_date,
             updated_at: Some(Utc::now()),
         };
 
@@ -156,7 +228,7 @@ impl WorkScheduleService {
             .await?;
         let days = self.schedule_repo.get_days(schedule_id).await?;
 
-        Ok(WorkScheduleWithDays { schedule, days })
+        Ok(WorkScheduleWithDays::new(schedule, days))
     }
 
     /// Delete a schedule
@@ -172,22 +244,25 @@ impl WorkScheduleService {
         request: AddDayRequest,
     ) -> Result<WorkScheduleDay, AppError> {
         // Verify schedule exists
-        let _ = self.schedule_repo.find_by_id(org_id, schedule_id).await?;
+        let schedule = self.schedule_repo.find_by_id(org_id, schedule_id).await?;
 
         // Validate
+        validate_week_index(request.week_index, schedule.cycle_weeks)?;
         if request.day_of_week < 0 || request.day_of_week > 6 {
             return Err(AppError::ValidationError(
                 "day_of_week must be between 0 (Monday) and 6 (Sunday)".to_string(),
             ));
         }
-        if request.start_time >= request.end_time {
+        // end_time before start_time means the shift ends the next day
+        if request.start_time == request.end_time {
             return Err(AppError::ValidationError(
-                "start_time must be before end_time".to_string(),
+                "start_time and end_time must differ".to_string(),
             ));
         }
 
         let new_day = NewWorkScheduleDay {
             work_schedule_id: schedule_id,
+            week_index: request.week_index,
             day_of_week: request.day_of_week,
             start_time: request.start_time,
             end_time: request.end_time,
@@ -248,7 +323,7 @@ impl WorkScheduleService {
         match schedule {
             Some(s) => {
                 let days = self.schedule_repo.get_days(s.id).await?;
-                Ok(Some(WorkScheduleWithDays { schedule: s, days }))
+                Ok(Some(WorkScheduleWithDays::new(s, days)))
             }
             None => Ok(None),
         }
@@ -264,12 +339,140 @@ impl WorkScheduleService {
         match schedule {
             Some(s) => {
                 let days = self.schedule_repo.get_days(s.id).await?;
-                Ok(Some(WorkScheduleWithDays { schedule: s, days }))
+                Ok(Some(WorkScheduleWithDays::new(s, days)))
             }
             None => Ok(None),
         }
     }
 
+    /// List a user's schedule exceptions
+    /// Users can list their own; managers those of their team members
+    pub async fn list_exceptions(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        user_id: Uuid,
+        filter: ScheduleExceptionFilter,
+    ) -> Result<Vec<ScheduleExceptionResponse>, AppError> {
+        if actor_id != user_id {
+            self.ensure_can_manage(org_id, actor_id, actor_role, user_id)
+                .await?;
+        }
+
+        let exceptions = self
+            .schedule_repo
+            .list_exceptions(org_id, user_id, &filter)
+            .await?;
+        Ok(exceptions.into_iter().map(Into::into).collect())
+    }
+
+    /// Create a schedule exception for a user (Manager+ only)
+    pub async fn create_exception(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        user_id: Uuid,
+        request: CreateScheduleExceptionRequest,
+    ) -> Result<ScheduleExceptionResponse, AppError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+        if user.organization_id != org_id {
+            return Err(AppError::NotFound("User not found".to_string()));
+        }
+        self.ensure_can_manage(org_id, actor_id, actor_role, user_id)
+            .await?;
+        // Exceptions replace days of a schedule, they are ignored without one
+        if self
+            .schedule_repo
+            .resolve_schedule(org_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::ValidationError(
+                "The user has no work schedule to make an exception to".to_string(),
+            ));
+        }
+
+        let (start_time, end_time, break_minutes) = validate_exception(
+            request.is_day_off,
+            request.start_time,
+            request.end_time,
+            request.break_minutes.unwrap_or(0),
+        )?;
+
+        let new_exception = NewScheduleException {
+            organization_id: org_id,
+            user_id,
+            exception_date: request.exception_date,
+            is_day_off: request.is_day_off,
+            start_time,
+            end_time,
+            break_minutes,
+            reason: normalize_reason(request.reason)?,
+            created_by: Some(actor_id),
+        };
+
+        let exception = self.schedule_repo.create_exception(new_exception).await?;
+        Ok(exception.into())
+    }
+
+    /// Update a schedule exception (Manager+ only)
+    pub async fn update_exception(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        exception_id: Uuid,
+        request: UpdateScheduleExceptionRequest,
+    ) -> Result<ScheduleExceptionResponse, AppError> {
+        let existing = self
+            .find_exception_in_scope(org_id, actor_id, actor_role, exception_id)
+            .await?;
+
+        let is_day_off = request.is_day_off.unwrap_or(existing.is_day_off);
+        let (start_time, end_time, break_minutes) = validate_exception(
+            is_day_off,
+            request.start_time.or(existing.start_time),
+            request.end_time.or(existing.end_time),
+            request.break_minutes.unwrap_or(existing.break_minutes),
+        )?;
+        let reason = match request.reason {
+            Some(reason) => normalize_reason(reason)?,
+            None => existing.reason,
+        };
+
+        let update = ScheduleExceptionUpdate {
+            exception_date: request.exception_date.unwrap_or(existing.exception_date),
+            is_day_off,
+            start_time,
+            end_time,
+            break_minutes,
+            reason,
+        };
+
+        let exception = self
+            .schedule_repo
+            .update_exception(org_id, exception_id, update)
+            .await?;
+        Ok(exception.into())
+    }
+
+    /// Delete a schedule exception (Manager+ only)
+    pub async fn delete_exception(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        exception_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.find_exception_in_scope(org_id, actor_id, actor_role, exception_id)
+            .await?;
+        self.schedule_repo
+            .delete_exception(org_id, exception_id)
+            .await
+    }
+
     /// Calculate theoretical hours for a user in a period
     pub async fn get_theoretical_hours(
         &self,
@@ -277,9 +480,166 @@ impl WorkScheduleService {
         user_id: Uuid,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-    ) -> Result<f64, AppError> {
+        tz: Tz,
+    ) -> Result<ExpectedHours, AppError> {
         self.schedule_repo
-            .get_theoretical_hours(org_id, user_id, start, end)
+            .get_theoretical_hours(org_id, user_id, start, end, tz)
             .await
     }
+
+    /// Reject actors who may not change a user's schedule exceptions
+    /// Admins manage everyone, managers the members of the teams they manage
+    async fn ensure_can_manage(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        match actor_role {
+            UserRole::Employee => Err(AppError::Forbidden(
+                "Only managers can manage schedule exceptions".to_string(),
+            )),
+            UserRole::Manager => {
+                let manages_user = self
+                    .team_repo
+                    .get_user_teams(org_id, user_id)
+                    .await?
+                    .iter()
+                    .any(|team| team.manager_id == Some(actor_id));
+                if manages_user {
+                    Ok(())
+                } else {
+                    Err(AppError::Forbidden(
+                        "You can only manage schedule exceptions of your team members".to_string(),
+                    ))
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Find a schedule exception the actor may change
+    async fn find_exception_in_scope(
+        &self,
+        org_id: Uuid,
+        actor_id: Uuid,
+        actor_role: UserRole,
+        exception_id: Uuid,
+    ) -> Result<ScheduleException, AppError> {
+        let exception = self
+            .schedule_repo
+            .find_exception(org_id, exception_id)
+            .await?;
+        self.ensure_can_manage(org_id, actor_id, actor_role, exception.user_id)
+            .await?;
+        Ok(exception)
+    }
+}
+
+/// Check the hours of a schedule exception
+/// Returns the times and break to store: none for a day off
+fn validate_exception(
+    is_day_off: bool,
+    start_time: Option<NaiveTime>,
+    end_time: Option<NaiveTime>,
+    break_minutes: i32,
+) -> Result<(Option<NaiveTime>, Option<NaiveTime>, i32), AppError> {
+    if is_day_off {
+        return Ok((None, None, 0));
+    }
+    let (Some(start_time), Some(end_time)) = (start_time, end_time) else {
+        return Err(AppError::ValidationError(
+            "start_time and end_time are required unless the exception is a day off".to_string(),
+        ));
+    };
+    // end_time before start_time means the shift ends the next day
+    if start_time == end_time {
+        return Err(AppError::ValidationError(
+            "start_time and end_time must differ".to_string(),
+        ));
+    }
+    if break_minutes < 0 || break_minutes as i64 >= time_span_minutes(start_time, end_time) {
+        return Err(AppError::ValidationError(
+            "break_minutes must be shorter than the shift".to_string(),
+        ));
+    }
+    Ok((Some(start_time), Some(end_time), break_minutes))
+}
+
+/// Trim a reason, treating a blank one as unset
+fn normalize_reason(reason: Option<String>) -> Result<Option<String>, AppError> {
+    let Some(reason) = reason.map(|r| r.trim().to_string()) else {
+        return Ok(None);
+    };
+    if reason.is_empty() {
+        return Ok(None);
+    }
+    if reason.chars().count() > MAX_REASON_LENGTH {
+        return Err(AppError::ValidationError(format!(
+            "reason cannot exceed {} characters",
+            MAX_REASON_LENGTH
+        )));
+    }
+    Ok(Some(reason))
+}
+
+fn validate_cycle_weeks(cycle_weeks: i16) -> Result<(), AppError> {
+    if !(1..=MAX_CYCLE_WEEKS).contains(&cycle_weeks) {
+        return Err(AppError::ValidationError(format!(
+            "cycle_weeks must be between 1 and {}",
+            MAX_CYCLE_WEEKS
+        )));
+    }
+    Ok(())
+}
+
+fn validate_week_index(week_index: i16, cycle_weeks: i16) -> Result<(), AppError> {
+    if week_index < 0 || week_index >= cycle_weeks {
+        return Err(AppError::ValidationError(format!(
+            "week_index must be between 0 and {} for this rotation",
+            cycle_weeks - 1
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rotation() {
+        assert!(validate_cycle_weeks(1).is_ok());
+        assert!(validate_cycle_weeks(4).is_ok());
+        assert!(validate_cycle_weeks(0).is_err());
+        assert!(validate_cycle_weeks(13).is_err());
+
+        assert!(validate_week_index(0, 1).is_ok());
+        assert!(validate_week_index(1, 2).is_ok());
+        assert!(validate_week_index(2, 2).is_err());
+        assert!(validate_week_index(-1, 2).is_err());
+    }
+
+    #[test]
+    fn test_validate_exception() {
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0);
+
+        // A day off stores no hours, whatever was sent
+        assert_eq!(
+            validate_exception(true, time(9), time(17), 30).unwrap(),
+            (None, None, 0)
+        );
+        assert_eq!(
+            validate_exception(false, time(22), time(6), 30).unwrap(),
+            (time(22), time(6), 30)
+        );
+        assert!(validate_exception(false, time(9), None, 0).is_err());
+        assert!(validate_exception(false, time(9), time(9), 0).is_err());
+        assert!(validate_exception(false, time(9), time(10), 60).is_err());
+        assert!(validate_exception(false, time(9), time(17), -5).is_err());
+
+        assert_eq!(normalize_reason(Some("  ".to_string())).unwrap(), None);
+        assert!(normalize_reason(Some("x".repeat(256))).is_err());
+    }
 }
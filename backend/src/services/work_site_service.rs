@@ -0,0 +1,387 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::config::database::DbPool;
+use crate::error::AppError;
+use crate::models::{
+    GeoPosition, GeofenceCheck, NewWorkSite, WorkSite, WorkSiteFilter, WorkSiteResponse,
+    WorkSiteUpdate,
+};
+use crate::repositories::WorkSiteRepository;
+
+/// Largest radius a work site can cover
+const MAX_RADIUS_METERS: i32 = 10_000;
+
+/// Most a site is widened by the accuracy reported with a position
+const MAX_ACCURACY_TOLERANCE_METERS: f64 = 50.0;
+
+/// Request to create a work site
+#[derive(Debug, Deserialize)]
+pub struct CreateWorkSiteRequest {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: i32,
+    pub is_active: Option<bool>,
+}
+
+/// Request to update a work site
+#[derive(Debug, Deserialize)]
+pub struct UpdateWorkSiteRequest {
+    pub name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius_meters: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+/// Service for work site operations
+pub struct WorkSiteService {
+    work_site_repo: WorkSiteRepository,
+}
+
+impl WorkSiteService {
+    pub fn new(pool: DbPool) -> Self {
+        Self {
+            work_site_repo: WorkSiteRepository::new(pool),
+        }
+    }
+
+    /// Create a new work site
+    pub async fn create(
+        &self,
+        org_id: Uuid,
+        request: CreateWorkSiteRequest,
+    ) -> Result<WorkSiteResponse, AppError> {
+        validate_name(&request.name)?;
+        validate_center(request.latitude, request.longitude)?;
+        validate_radius(request.radius_meters)?;
+
+        let new_site = NewWorkSite {
+            organization_id: org_id,
+            name: request.name.trim().to_string(),
+            latitude: request.latitude,
+            longitude: request.longitude,
+            radius_meters: request.radius_meters,
+            is_active: request.is_active.unwrap_or(true),
+        };
+
+        let site = self.work_site_repo.create(new_site).await?;
+        Ok(WorkSiteResponse::from(site))
+    }
+
+    /// Get a work site by ID
+    pub async fn get(&self, org_id: Uuid, site_id: Uuid) -> Result<WorkSiteResponse, AppError> {
+        let site = self.work_site_repo.find_by_id(org_id, site_id).await?;
+        Ok(WorkSiteResponse::from(site))
+    }
+
+    /// List work sites with filters
+    pub async fn list(
+        &self,
+        org_id: Uuid,
+        filter: WorkSiteFilter,
+    ) -> Result<Vec<WorkSiteResponse>, AppError> {
+        let sites = self.work_site_repo.list(org_id, &filter).await?;
+        Ok(sites.into_iter().map(WorkSiteResponse::from).collect())
+    }
+
+    /// Update a work site
+    pub async fn update(
+        &self,
+        org_id: Uuid,
+        site_id: Uuid,
+        request: UpdateWorkSiteRequest,
+    ) -> Result<WorkSiteResponse, AppError> {
+        if let Some(ref name) = request.name {
+            validate_name(name)?;
+        }
+        if request.latitude.is_some() || request.longitude.is_some() {
+            let current = self.work_site_repo.find_by_id(org_id, site_id).await?;
+            validate_center(
+                request.latitude.unwrap_or(current.latitude),
+                request.longitude.unwrap_or(current.longitude),
+            )?;
+        }
+        if let Some(radius) = request.radius_meters {
+            validate_radius(radius)?;
+        }
+
+        let update = WorkSiteUpdate {
+            name: request.name.map(|n| n.trim().to_string()),
+            latitude: request.latitude,
+            longitude: request.longitude,
+            radius_meters: request.radius_meters,
+            is_active: request.is_active,
+        };
+
+        let site = self.work_site_repo.update(org_id, site_id, update).await?;
+        Ok(WorkSiteResponse::from(site))
+    }
+
+    /// Delete a work site
+    pub async fn delete(&self, org_id: Uuid, site_id: Uuid) -> Result<(), AppError> {
+        self.work_site_repo.delete(org_id, site_id).await
+    }
+
+    /// Check a position against the organization's active work sites
+    pub async fn check_position(
+        &self,
+        org_id: Uuid,
+        position: Option<&GeoPosition>,
+    ) -> Result<GeofenceCheck, AppError> {
+        let sites = self.work_site_repo.list_active(org_id).await?;
+        Ok(check_geofence(&sites, position))
+    }
+}
+
+/// Build a position from optional client-supplied coordinates
+///
+/// Returns `None` when no coordinates were sent. Latitude and longitude must be
+/// provided together and be within range.
+pub fn parse_position(
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    accuracy: Option<f64>,
+) -> Result<Option<GeoPosition>, AppError> {
+    let (latitude, longitude) = match (latitude, longitude) {
+        (Some(latitude), Some(longitude)) => (latitude, longitude),
+        (None, None) => return Ok(None),
+        _ => {
+            return Err(AppError::ValidationError(
+                "Latitude and longitude must be provided together".to_string(),
+            ))
+        }
+    };
+
+    let position = GeoPosition {
+        latitude,
+        longitude,
+        accuracy,
+    };
+    if !position.is_valid() {
+        return Err(AppError::ValidationError(
+            "Invalid position: latitude must be between -90 and 90, longitude between -180 and 180 and accuracy positive".to_string(),
+        ));
+    }
+
+    Ok(Some(position))
+}
+
+/// Message shown when a clock action is refused by an enforced geofence
+pub fn geofence_message(
+    action: &str,
+    position: Option<&GeoPosition>,
+    check: &GeofenceCheck,
+) -> String {
+    let action_name = if action == "clock_in" {
+        "Clock in"
+    } else {
+        "Clock out"
+    };
+
+    if position.is_none() {
+        return format!("{} requires sharing your location", action_name);
+    }
+
+    match (&check.work_site_name, check.distance_meters) {
+        (Some(name), Some(distance)) => format!(
+            "{} is only allowed on a work site. You are {} m away from {}",
+            action_name, distance, name
+        ),
+        _ => format!("{} is only allowed on a work site", action_name),
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::ValidationError(
+            "Work site name cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_center(latitude: f64, longitude: f64) -> Result<(), AppError> {
+    let center = GeoPosition {
+        latitude,
+        longitude,
+        accuracy: None,
+    };
+    if !center.is_valid() {
+        return Err(AppError::ValidationError(
+            "Latitude must be between -90 and 90 and longitude between -180 and 180".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_radius(radius_meters: i32) -> Result<(), AppError> {
+    if radius_meters <= 0 || radius_meters > MAX_RADIUS_METERS {
+        return Err(AppError::ValidationError(format!(
+            "Radius must be between 1 and {} meters",
+            MAX_RADIUS_METERS
+        )));
+    }
+    Ok(())
+}
+
+/// Match a position against work sites
+///
+/// Each site is tested against its own radius. The reported accuracy is given to
+/// the user, up to the site radius and a small fixed cap, so a fuzzy fix at the
+/// edge of a site still counts but a client cannot stretch a site. When several
+/// sites match, the closest one wins; outside, the nearest site is reported.
+/// Without a position the check fails.
+pub fn check_geofence(sites: &[WorkSite], position: Option<&GeoPosition>) -> GeofenceCheck {
+    let Some(position) = position else {
+        return GeofenceCheck {
+            within: false,
+            work_site_id: None,
+            work_site_name: None,
+            distance_meters: None,
+        };
+    };
+
+    let accuracy = position.accuracy.unwrap_or(0.0);
+    let distances: Vec<(&WorkSite, f64)> = sites
+        .iter()
+        .map(|site| (site, site.distance_to(position)))
+        .collect();
+    let contains = |site: &WorkSite, distance: f64| {
+        let radius = site.radius_meters as f64;
+        let tolerance = accuracy.min(radius).min(MAX_ACCURACY_TOLERANCE_METERS);
+        distance <= radius + tolerance
+    };
+
+    let matched = distances
+        .iter()
+        .filter(|(site, distance)| contains(site, *distance))
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    let nearest = distances.iter().min_by(|a, b| a.1.total_cmp(&b.1));
+
+    let Some(&(site, distance)) = matched.or(nearest) else {
+        return GeofenceCheck {
+            within: false,
+            work_site_id: None,
+            work_site_name: None,
+            distance_meters: None,
+        };
+    };
+
+    let within = matched.is_some();
+    GeofenceCheck {
+        within,
+        work_site_id: within.then_some(site.id),
+        work_site_name: Some(site.name.clone()),
+        distance_meters: Some(distance.round()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn site(name: &str, latitude: f64, longitude: f64, radius_meters: i32) -> WorkSite {
+        WorkSite {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            name: name.to_string(),
+            latitude,
+            longitude,
+            radius_meters,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn position(latitude: f64, longitude: f64, accuracy: Option<f64>) -> GeoPosition {
+        GeoPosition {
+            latitude,
+            longitude,
+            accuracy,
+        }
+    }
+
+    #[test]
+    fn test_check_geofence_inside_site() {
+        let hq = site("HQ", 48.8566, 2.3522, 100);
+        let check = check_geofence(
+            std::slice::from_ref(&hq),
+            Some(&position(48.8570, 2.3522, None)),
+        );
+
+        assert!(check.within);
+        assert_eq!(check.work_site_id, Some(hq.id));
+        assert_eq!(check.work_site_name.as_deref(), Some("HQ"));
+    }
+
+    #[test]
+    fn test_check_geofence_outside_reports_nearest() {
+        // About 222 meters north of a 100 meter site
+        let sites = [
+            site("HQ", 48.8566, 2.3522, 100),
+            site("Warehouse", 45.7640, 4.8357, 500),
+        ];
+        let check = check_geofence(&sites, Some(&position(48.8586, 2.3522, None)));
+
+        assert!(!check.within);
+        assert_eq!(check.work_site_id, None);
+        assert_eq!(check.work_site_name.as_deref(), Some("HQ"));
+        assert!(check.distance_meters.unwrap() > 200.0);
+    }
+
+    #[test]
+    fn test_check_geofence_accuracy_tolerance_capped() {
+        let sites = [site("HQ", 48.8566, 2.3522, 100)];
+
+        // About 133 meters away
+        let fuzzy = check_geofence(&sites, Some(&position(48.8578, 2.3522, Some(40.0))));
+        assert!(fuzzy.within);
+
+        // About 167 meters away: a poor fix only widens the site by the cap
+        let poor = check_geofence(&sites, Some(&position(48.8581, 2.3522, Some(80.0))));
+        assert!(!poor.within);
+
+        let small = [site("Kiosk", 48.8566, 2.3522, 20)];
+        // About 44 meters away, beyond twice the radius of a small site
+        let far = check_geofence(&small, Some(&position(48.8570, 2.3522, Some(5000.0))));
+        assert!(!far.within);
+    }
+
+    #[test]
+    fn test_check_geofence_large_site_beyond_nearest_center() {
+        // A small site 200 meters south, a large one whose center is 400 meters north
+        let small = site("Gate", 48.8548, 2.3522, 50);
+        let campus = site("Campus", 48.8602, 2.3522, 500);
+        let check = check_geofence(
+            &[small, campus.clone()],
+            Some(&position(48.8566, 2.3522, None)),
+        );
+
+        assert!(check.within);
+        assert_eq!(check.work_site_id, Some(campus.id));
+        assert!(check.distance_meters.unwrap() > 390.0);
+    }
+
+    #[test]
+    fn test_parse_position() {
+        assert!(parse_position(None, None, None).unwrap().is_none());
+        assert_eq!(
+            parse_position(Some(48.85), Some(2.35), Some(12.0)).unwrap(),
+            Some(position(48.85, 2.35, Some(12.0)))
+        );
+        assert!(parse_position(Some(48.85), None, None).is_err());
+        assert!(parse_position(Some(91.0), Some(2.35), None).is_err());
+        assert!(parse_position(Some(48.85), Some(2.35), Some(-1.0)).is_err());
+    }
+
+    #[test]
+    fn test_check_geofence_without_position_or_sites() {
+        let sites = [site("HQ", 48.8566, 2.3522, 100)];
+        assert!(!check_geofence(&sites, None).within);
+        assert!(!check_geofence(&[], Some(&position(48.8566, 2.3522, None))).within);
+    }
+}
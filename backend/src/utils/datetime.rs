@@ -102,6 +102,59 @@ pub fn end_of_day_tz(date: NaiveDate) -> DateTime<Utc> {
     Utc.from_utc_datetime(&end_of_day_naive(date))
 }
 
+/// Check whether a time of day falls within an optional `[earliest, latest]` window.
+///
+/// When both bounds are set and `earliest > latest`, the window spans midnight
+/// (e.g. 22:00 to 06:00 allows 23:30 and 05:00 but not 12:00).
+///
+/// # Example
+/// ```
+/// use chrono::NaiveTime;
+/// use timemanager_backend::utils::datetime::is_within_time_window;
+///
+/// let t = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+/// assert!(is_within_time_window(t(23), Some(t(22)), Some(t(6))));
+/// assert!(!is_within_time_window(t(12), Some(t(22)), Some(t(6))));
+/// ```
+pub fn is_within_time_window(
+    current: NaiveTime,
+    earliest: Option<NaiveTime>,
+    latest: Option<NaiveTime>,
+) -> bool {
+    match (earliest, latest) {
+        (Some(e), Some(l)) if e > l => current >= e || current <= l,
+        _ => {
+            let after_earliest = earliest.is_none_or(|e| current >= e);
+            let before_latest = latest.is_none_or(|l| current <= l);
+            after_earliest && before_latest
+        }
+    }
+}
+
+/// Length in minutes of a `start..end` time range, where an `end` before `start`
+/// means the range ends on the next day (e.g. 22:00 to 06:00 lasts 480 minutes).
+#[inline]
+pub fn time_span_minutes(start: NaiveTime, end: NaiveTime) -> i64 {
+    let minutes = (end - start).num_minutes();
+    if minutes < 0 {
+        minutes + 24 * 60
+    } else {
+        minutes
+    }
+}
+
+/// Anchor a `start..end` time range on a date, rolling `end` over to the next day
+/// when the range spans midnight.
+pub fn time_range_on(
+    date: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+) -> (NaiveDateTime, NaiveDateTime) {
+    let range_start = date.and_time(start);
+    let range_end = range_start + chrono::Duration::minutes(time_span_minutes(start, end));
+    (range_start, range_end)
+}
+
 /// Get the first day of a year (January 1st).
 ///
 /// Returns an error if the year is out of the valid range for NaiveDate.
@@ -228,6 +281,70 @@ mod tests {
         assert_eq!(end_of_day(date), end_of_day_tz(date));
     }
 
+    #[test]
+    fn test_is_within_time_window() {
+        let t = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        // Same-day window
+        assert!(is_within_time_window(
+            t(9, 0),
+            Some(t(8, 0)),
+            Some(t(10, 0))
+        ));
+        assert!(!is_within_time_window(
+            t(7, 59),
+            Some(t(8, 0)),
+            Some(t(10, 0))
+        ));
+        assert!(is_within_time_window(t(7, 0), None, Some(t(10, 0))));
+        assert!(is_within_time_window(t(23, 0), Some(t(8, 0)), None));
+
+        // Window spanning midnight
+        assert!(is_within_time_window(
+            t(22, 0),
+            Some(t(22, 0)),
+            Some(t(6, 0))
+        ));
+        assert!(is_within_time_window(
+            t(3, 0),
+            Some(t(22, 0)),
+            Some(t(6, 0))
+        ));
+        assert!(!is_within_time_window(
+            t(6, 1),
+            Some(t(22, 0)),
+            Some(t(6, 0))
+        ));
+        assert!(!is_within_time_window(
+            t(12, 0),
+            Some(t(22, 0)),
+            Some(t(6, 0))
+        ));
+    }
+
+    #[test]
+    fn test_time_span_minutes() {
+        let t = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        assert_eq!(time_span_minutes(t(9), t(17)), 480);
+        assert_eq!(time_span_minutes(t(22), t(6)), 480);
+        assert_eq!(time_span_minutes(t(9), t(9)), 0);
+    }
+
+    #[test]
+    fn test_time_range_on_overnight() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let (start, end) = time_range_on(
+            date,
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        );
+
+        assert_eq!(start.date(), date);
+        assert_eq!(end.date(), NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        assert_eq!(end.time(), NaiveTime::from_hms_opt(6, 0, 0).unwrap());
+    }
+
     #[test]
     fn test_leap_year_handling() {
         // Feb 29 on a leap year
@@ -0,0 +1,48 @@
+//! Geographic helpers for geofenced clock in/out.
+
+/// Mean Earth radius in meters
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Great-circle distance in meters between two coordinates (haversine formula).
+///
+/// # Example
+/// ```
+/// use timemanager_backend::utils::geo::distance_meters;
+///
+/// assert_eq!(distance_meters(48.8566, 2.3522, 48.8566, 2.3522), 0.0);
+/// ```
+pub fn distance_meters(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let delta_phi = (lat2 - lat1).to_radians();
+    let delta_lambda = (lon2 - lon1).to_radians();
+
+    let a = (delta_phi / 2.0).sin().powi(2)
+        + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_paris_to_london() {
+        // Paris to London city centers, roughly 344 km
+        let d = distance_meters(48.8566, 2.3522, 51.5074, -0.1278);
+        assert!((d - 343_500.0).abs() < 1_000.0, "got {}", d);
+    }
+
+    #[test]
+    fn test_distance_short_range() {
+        // 0.001 degree of latitude is about 111 meters
+        let d = distance_meters(48.8566, 2.3522, 48.8576, 2.3522);
+        assert!((d - 111.2).abs() < 1.0, "got {}", d);
+    }
+
+    #[test]
+    fn test_distance_across_antimeridian() {
+        let d = distance_meters(0.0, 179.9995, 0.0, -179.9995);
+        assert!((d - 111.2).abs() < 1.0, "got {}", d);
+    }
+}
@@ -2,9 +2,12 @@
 // This module contains helper functions and utilities
 
 pub mod datetime;
+pub mod geo;
 pub mod json;
 pub mod jwt;
+pub mod network;
 pub mod password;
+pub mod timezone;
 
 // Re-export commonly used types
 pub use jwt::JwtService;
@@ -12,9 +15,13 @@ pub use password::PasswordService;
 
 // Re-export datetime helpers for convenience
 pub use datetime::{
-    end_of_day, end_of_day_naive, end_of_day_time, end_of_day_tz, end_of_year, midnight,
-    start_of_day, start_of_day_naive, start_of_day_tz, start_of_year,
+    end_of_day, end_of_day_naive, end_of_day_time, end_of_day_tz, end_of_year,
+    is_within_time_window, midnight, start_of_day, start_of_day_naive, start_of_day_tz,
+    start_of_year, time_range_on, time_span_minutes,
 };
 
+// Re-export timezone helpers for convenience
+pub use timezone::{local_day_bounds, parse_timezone, to_local, to_utc};
+
 // Re-export JSON helpers for convenience
 pub use json::{to_json_string, to_json_string_pretty, to_json_value};
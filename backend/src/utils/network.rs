@@ -0,0 +1,125 @@
+use std::net::IpAddr;
+
+use ipnet::IpNet;
+
+/// Parse a CIDR range, accepting a bare address as a single-host range
+pub fn parse_network(value: &str) -> Option<IpNet> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .map(|net| net.trunc())
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .ok()
+}
+
+/// Whether an address belongs to one of the networks
+pub fn is_in_networks(ip: IpAddr, networks: &[IpNet]) -> bool {
+    let ip = ip.to_canonical();
+    networks.iter().any(|net| net.contains(&ip))
+}
+
+/// Resolve the client address of a request
+///
+/// `X-Forwarded-For` is only honored when the socket peer is a trusted proxy.
+/// The chain is then walked from the nearest hop, skipping trusted proxies, so
+/// that entries prepended by the client cannot spoof the address.
+pub fn resolve_client_ip(
+    peer: Option<IpAddr>,
+    forwarded_for: Option<&str>,
+    trusted_proxies: &[IpNet],
+) -> Option<IpAddr> {
+    let peer_ip = peer?.to_canonical();
+    if !is_in_networks(peer_ip, trusted_proxies) {
+        return Some(peer_ip);
+    }
+
+    let Some(chain) = forwarded_for else {
+        return Some(peer_ip);
+    };
+    let mut client = peer_ip;
+    for hop in chain.rsplit(',') {
+        match hop.trim().parse::<IpAddr>() {
+            Ok(ip) => {
+                client = ip.to_canonical();
+                if !is_in_networks(client, trusted_proxies) {
+                    break;
+                }
+            }
+            // A malformed hop cannot be trusted further
+            Err(_) => break,
+        }
+    }
+    Some(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_network() {
+        assert_eq!(
+            parse_network("10.1.2.3/8").map(|n| n.to_string()),
+            Some("10.0.0.0/8".to_string())
+        );
+        assert_eq!(
+            parse_network(" 192.0.2.7 ").map(|n| n.to_string()),
+            Some("192.0.2.7/32".to_string())
+        );
+        assert_eq!(
+            parse_network("2001:db8::/32").map(|n| n.to_string()),
+            Some("2001:db8::/32".to_string())
+        );
+        assert!(parse_network("10.0.0.0/33").is_none());
+        assert!(parse_network("office").is_none());
+    }
+
+    #[test]
+    fn test_is_in_networks() {
+        let networks = vec![parse_network("203.0.113.0/24").unwrap()];
+
+        assert!(is_in_networks(ip("203.0.113.42"), &networks));
+        // IPv4-mapped IPv6 addresses match their IPv4 range
+        assert!(is_in_networks(ip("::ffff:203.0.113.42"), &networks));
+        assert!(!is_in_networks(ip("198.51.100.1"), &networks));
+    }
+
+    #[test]
+    fn test_resolve_client_ip_ignores_header_from_untrusted_peer() {
+        let trusted = vec![parse_network("10.0.0.0/8").unwrap()];
+
+        assert_eq!(
+            resolve_client_ip(Some(ip("198.51.100.1")), Some("203.0.113.5"), &trusted),
+            Some(ip("198.51.100.1"))
+        );
+        assert_eq!(
+            resolve_client_ip(Some(ip("10.0.0.2")), Some("203.0.113.5"), &[]),
+            Some(ip("10.0.0.2"))
+        );
+        assert_eq!(resolve_client_ip(None, Some("203.0.113.5"), &trusted), None);
+    }
+
+    #[test]
+    fn test_resolve_client_ip_walks_trusted_chain() {
+        let trusted = vec![parse_network("10.0.0.0/8").unwrap()];
+
+        // Spoofed first entry, real client, then an internal proxy
+        assert_eq!(
+            resolve_client_ip(
+                Some(ip("10.0.0.2")),
+                Some("1.2.3.4, 203.0.113.5, 10.0.0.9"),
+                &trusted
+            ),
+            Some(ip("203.0.113.5"))
+        );
+        // No header behind a trusted proxy
+        assert_eq!(
+            resolve_client_ip(Some(ip("10.0.0.2")), None, &trusted),
+            Some(ip("10.0.0.2"))
+        );
+    }
+}
@@ -113,6 +113,33 @@ impl PasswordService {
         Ok(())
     }
 
+    /// Hash a kiosk PIN using Argon2
+    ///
+    /// PINs are short, so they are only used on kiosk devices where failed
+    /// attempts are rate limited.
+    pub fn hash_pin(&self, pin: &str) -> Result<String, AppError> {
+        self.validate_pin(pin)?;
+
+        let salt = SaltString::generate(&mut OsRng);
+        let pin_hash = self
+            .argon2
+            .hash_password(pin.as_bytes(), &salt)
+            .map_err(|_e| AppError::InternalError)?;
+
+        Ok(pin_hash.to_string())
+    }
+
+    /// Validate a kiosk PIN format (4 to 8 digits)
+    pub fn validate_pin(&self, pin: &str) -> Result<(), AppError> {
+        if pin.len() < 4 || pin.len() > 8 || !pin.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AppError::ValidationError(
+                "PIN must be between 4 and 8 digits".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Check if password has been compromised.
     /// Note: For actual HIBP validation, use HibpService.validate_not_compromised()
     /// which is already integrated in auth handlers (accept_invite, change_password, reset_password).
@@ -296,6 +323,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hash_and_verify_pin() {
+        let service = create_test_service();
+
+        let hash = service.hash_pin("4821").unwrap();
+
+        assert!(hash.starts_with("$argon2"));
+        assert!(service.verify_password("4821", &hash).unwrap());
+        assert!(!service.verify_password("4822", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_pin() {
+        let service = create_test_service();
+
+        assert!(service.hash_pin("123").is_err());
+        assert!(service.hash_pin("123456789").is_err());
+        assert!(service.hash_pin("12a4").is_err());
+        assert!(service.validate_pin("00000000").is_ok());
+    }
+
     #[test]
     #[allow(deprecated)]
     fn test_is_password_compromised_deprecated() {
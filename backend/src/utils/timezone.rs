@@ -0,0 +1,146 @@
+//! Timezone helpers for resolving organization-local dates and times.
+//!
+//! Timestamps are stored in UTC, while schedules, restriction windows and
+//! break windows are expressed in the organization's local time. These helpers
+//! convert between the two through the organization's IANA timezone and take
+//! care of DST transitions.
+
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use super::datetime::midnight;
+
+/// Timezone used when an organization has none or an unknown one.
+pub const DEFAULT_TIMEZONE: Tz = chrono_tz::Europe::Paris;
+
+/// Check whether a string is a known IANA timezone name.
+///
+/// # Example
+/// ```
+/// use timemanager_backend::utils::timezone::is_valid_timezone;
+///
+/// assert!(is_valid_timezone("Europe/Paris"));
+/// assert!(!is_valid_timezone("Mars/Olympus"));
+/// ```
+pub fn is_valid_timezone(name: &str) -> bool {
+    name.parse::<Tz>().is_ok()
+}
+
+/// Parse an IANA timezone name, falling back to [`DEFAULT_TIMEZONE`].
+pub fn parse_timezone(name: &str) -> Tz {
+    name.parse::<Tz>().unwrap_or_else(|_| {
+        tracing::warn!(
+            timezone = %name,
+            "Unknown timezone, falling back to {}",
+            DEFAULT_TIMEZONE
+        );
+        DEFAULT_TIMEZONE
+    })
+}
+
+/// Convert a UTC instant to the local wall-clock time of a timezone.
+#[inline]
+pub fn to_local(dt: DateTime<Utc>, tz: Tz) -> NaiveDateTime {
+    dt.with_timezone(&tz).naive_local()
+}
+
+/// Convert a local wall-clock time of a timezone to a UTC instant.
+///
+/// - Ambiguous times (clocks turned back) resolve to the earliest instant.
+/// - Non-existent times (clocks turned forward) are shifted forward by the
+///   length of the gap, e.g. 02:30 becomes 03:30 on a one-hour DST switch.
+pub fn to_utc(local: NaiveDateTime, tz: Tz) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => dt.with_timezone(&Utc),
+        LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        LocalResult::None => {
+            // Use the offset in effect before the transition
+            let before = local - Duration::days(1);
+            let offset = tz.offset_from_utc_datetime(&before).fix();
+            (local - Duration::seconds(offset.local_minus_utc() as i64)).and_utc()
+        }
+    }
+}
+
+/// UTC bounds `[start, end)` of a local calendar day in a timezone.
+///
+/// The day may last 23 or 25 hours on DST transitions.
+pub fn local_day_bounds(date: NaiveDate, tz: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let next = date.succ_opt().unwrap_or(date);
+    (
+        to_utc(date.and_time(midnight()), tz),
+        to_utc(next.and_time(midnight()), tz),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn local(date: (i32, u32, u32), time: (u32, u32)) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+            .unwrap()
+            .and_time(NaiveTime::from_hms_opt(time.0, time.1, 0).unwrap())
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(
+            parse_timezone("America/New_York"),
+            chrono_tz::America::New_York
+        );
+        assert_eq!(parse_timezone("not/a_zone"), DEFAULT_TIMEZONE);
+    }
+
+    #[test]
+    fn test_to_local_winter_and_summer() {
+        let tz = chrono_tz::Europe::Paris;
+
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 7, 0, 0).unwrap();
+        assert_eq!(to_local(winter, tz), local((2024, 1, 15), (8, 0)));
+
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 6, 0, 0).unwrap();
+        assert_eq!(to_local(summer, tz), local((2024, 7, 15), (8, 0)));
+    }
+
+    #[test]
+    fn test_to_utc_round_trip() {
+        let tz = chrono_tz::Europe::Paris;
+        let dt = local((2024, 7, 15), (8, 0));
+
+        assert_eq!(to_local(to_utc(dt, tz), tz), dt);
+    }
+
+    #[test]
+    fn test_to_utc_spring_forward_gap() {
+        // 2024-03-31 02:00 -> 03:00 in Paris, 02:30 does not exist
+        let tz = chrono_tz::Europe::Paris;
+        let utc = to_utc(local((2024, 3, 31), (2, 30)), tz);
+
+        assert_eq!(utc, Utc.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap());
+        assert_eq!(to_local(utc, tz), local((2024, 3, 31), (3, 30)));
+    }
+
+    #[test]
+    fn test_to_utc_fall_back_ambiguous() {
+        // 2024-10-27 03:00 -> 02:00 in Paris, 02:30 happens twice
+        let tz = chrono_tz::Europe::Paris;
+        let utc = to_utc(local((2024, 10, 27), (2, 30)), tz);
+
+        assert_eq!(utc, Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_local_day_bounds_dst_days() {
+        let tz = chrono_tz::Europe::Paris;
+
+        let (start, end) = local_day_bounds(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(), tz);
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 3, 30, 23, 0, 0).unwrap());
+        assert_eq!((end - start).num_hours(), 23);
+
+        let (start, end) = local_day_bounds(NaiveDate::from_ymd_opt(2024, 10, 27).unwrap(), tz);
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 10, 26, 22, 0, 0).unwrap());
+        assert_eq!((end - start).num_hours(), 25);
+    }
+}
@@ -132,6 +132,391 @@ mod repository_integration_tests {
         // assert!(result.is_ok());
     }
 
+    /// Insert an organization and an employee, returns their ids
+    async fn seed_org_and_user(pool: &DbPool) -> (Uuid, Uuid) {
+        use diesel::sql_types::{Text, Uuid as SqlUuid};
+
+        let mut conn = pool.get().await.expect("Failed to get connection");
+        let org_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
+        let organization =
+            diesel::sql_query("INSERT INTO organizations (id, name, slug) VALUES ($1, 'Test', $2)")
+                .bind::<SqlUuid, _>(org_id)
+                .bind::<Text, _>(org_id.to_string());
+        diesel_async::RunQueryDsl::execute(organization, &mut conn)
+            .await
+            .expect("Failed to seed organization");
+
+        let user = diesel::sql_query(
+            "INSERT INTO users (id, organization_id, email, password_hash, first_name, last_name) \
+             VALUES ($1, $2, $3, 'x', 'Test', 'User')",
+        )
+        .bind::<SqlUuid, _>(user_id)
+        .bind::<SqlUuid, _>(org_id)
+        .bind::<Text, _>(format!("{}@test.example.com", user_id));
+        diesel_async::RunQueryDsl::execute(user, &mut conn)
+            .await
+            .expect("Failed to seed user");
+
+        (org_id, user_id)
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_backdated_sync_event_in_closed_period_is_rejected() {
+        use chrono::{Duration, Utc};
+        use timemanager_backend::domain::enums::ClockSyncStatus;
+        use timemanager_backend::models::{
+            ClockSyncAction, ClockSyncBatchRequest, ClockSyncEventRequest, NewPayPeriod,
+        };
+        use timemanager_backend::services::ClockSyncService;
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+
+        // Close the period holding the offline event
+        let recorded_at = Utc::now() - Duration::days(3);
+        let day = recorded_at.date_naive();
+        PayPeriodRepository::new(pool.clone())
+            .close(NewPayPeriod {
+                organization_id: org_id,
+                start_date: day - Duration::days(1),
+                end_date: day + Duration::days(1),
+                closed_by: None,
+            })
+            .await
+            .expect("Failed to close period");
+
+        let response = ClockSyncService::new(pool.clone())
+            .sync_batch(
+                org_id,
+                user_id,
+                ClockSyncBatchRequest {
+                    sent_at: Utc::now(),
+                    events: vec![ClockSyncEventRequest {
+                        idempotency_key: "offline-1".to_string(),
+                        device_id: "phone-1".to_string(),
+                        action: ClockSyncAction::ClockIn,
+                        client_timestamp: recorded_at,
+                        notes: None,
+                        latitude: None,
+                        longitude: None,
+                        accuracy: None,
+                    }],
+                },
+            )
+            .await
+            .expect("Sync failed");
+
+        assert_eq!(response.rejected, 1);
+        let result = &response.results[0];
+        assert_eq!(result.status, ClockSyncStatus::Rejected);
+        assert!(result.clock_entry_id.is_none());
+        assert!(result
+            .message
+            .as_deref()
+            .is_some_and(|m| m.contains("pay period")));
+
+        let open = ClockRepository::new(pool.clone())
+            .find_open_entry(org_id, user_id)
+            .await
+            .expect("Query failed");
+        assert!(open.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_period_with_open_clock_entry_cannot_be_closed() {
+        use chrono::{Duration, Utc};
+        use diesel::sql_types::{Timestamptz, Uuid as SqlUuid};
+        use timemanager_backend::error::AppError;
+        use timemanager_backend::models::NewPayPeriod;
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+
+        // Forgotten entry started inside the period
+        let clock_in = Utc::now() - Duration::days(3);
+        let mut conn = pool.get().await.expect("Failed to get connection");
+        let entry = diesel::sql_query(
+            "INSERT INTO clock_entries (organization_id, user_id, clock_in) VALUES ($1, $2, $3)",
+        )
+        .bind::<SqlUuid, _>(org_id)
+        .bind::<SqlUuid, _>(user_id)
+        .bind::<Timestamptz, _>(clock_in);
+        diesel_async::RunQueryDsl::execute(entry, &mut conn)
+            .await
+            .expect("Failed to seed clock entry");
+
+        let day = clock_in.date_naive();
+        let result = PayPeriodRepository::new(pool.clone())
+            .close(NewPayPeriod {
+                organization_id: org_id,
+                start_date: day - Duration::days(1),
+                end_date: day + Duration::days(1),
+                closed_by: None,
+            })
+            .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_absence_type_unit_is_locked_once_balances_exist() {
+        use diesel::sql_types::Uuid as SqlUuid;
+        use timemanager_backend::domain::enums::AbsenceUnit;
+        use timemanager_backend::error::AppError;
+        use timemanager_backend::services::{
+            AbsenceTypeService, CreateAbsenceTypeRequest, UpdateAbsenceTypeRequest,
+        };
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+        let service = AbsenceTypeService::new(pool.clone());
+
+        let absence_type = service
+            .create(
+                org_id,
+                CreateAbsenceTypeRequest {
+                    name: "Leave".to_string(),
+                    code: "LEAVE".to_string(),
+                    color: None,
+                    requires_approval: None,
+                    affects_balance: Some(true),
+                    is_paid: None,
+                    unit: None,
+                },
+            )
+            .await
+            .expect("Failed to create absence type");
+        let to_hours = || UpdateAbsenceTypeRequest {
+            name: None,
+            code: None,
+            color: None,
+            requires_approval: None,
+            affects_balance: None,
+            is_paid: None,
+            unit: Some(AbsenceUnit::Hours),
+        };
+
+        // Unused types can switch unit freely
+        let switched = service
+            .update(org_id, absence_type.id, to_hours())
+            .await
+            .expect("Failed to switch unit");
+        assert_eq!(switched.unit, AbsenceUnit::Hours);
+
+        let mut conn = pool.get().await.expect("Failed to get connection");
+        let balance = diesel::sql_query(
+            "INSERT INTO leave_balances (organization_id, user_id, absence_type_id, year, initial_balance) \
+             VALUES ($1, $2, $3, 2026, 25)",
+        )
+        .bind::<SqlUuid, _>(org_id)
+        .bind::<SqlUuid, _>(user_id)
+        .bind::<SqlUuid, _>(absence_type.id);
+        diesel_async::RunQueryDsl::execute(balance, &mut conn)
+            .await
+            .expect("Failed to seed leave balance");
+
+        let result = service
+            .update(
+                org_id,
+                absence_type.id,
+                UpdateAbsenceTypeRequest {
+                    unit: Some(AbsenceUnit::Days),
+                    ..to_hours()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        // Keeping the unit is still allowed
+        assert!(service
+            .update(org_id, absence_type.id, to_hours())
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_hourly_absence_without_schedule_is_rejected() {
+        use chrono::{Duration, NaiveTime, Utc};
+        use timemanager_backend::domain::enums::AbsenceUnit;
+        use timemanager_backend::error::AppError;
+        use timemanager_backend::services::{
+            AbsenceService, AbsenceTypeService, CreateAbsenceRequest, CreateAbsenceTypeRequest,
+        };
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+
+        let appointment = AbsenceTypeService::new(pool.clone())
+            .create(
+                org_id,
+                CreateAbsenceTypeRequest {
+                    name: "Appointment".to_string(),
+                    code: "APPT".to_string(),
+                    color: None,
+                    requires_approval: None,
+                    affects_balance: Some(false),
+                    is_paid: None,
+                    unit: Some(AbsenceUnit::Hours),
+                },
+            )
+            .await
+            .expect("Failed to create absence type");
+
+        // Neither the user, a team nor the organization has a schedule
+        let date = Utc::now().date_naive() + Duration::days(7);
+        let result = AbsenceService::new(pool.clone())
+            .create_request(
+                org_id,
+                user_id,
+                CreateAbsenceRequest {
+                    type_id: appointment.id,
+                    start_date: date,
+                    end_date: date,
+                    start_half: None,
+                    end_half: None,
+                    start_time: NaiveTime::from_hms_opt(9, 0, 0),
+                    end_time: NaiveTime::from_hms_opt(11, 0, 0),
+                    reason: None,
+                },
+            )
+            .await;
+
+        match result {
+            Err(AppError::ValidationError(message)) => assert!(message.contains("schedule")),
+            other => panic!("Expected a validation error, got {:?}", other.map(|a| a.id)),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_concurrent_sync_of_same_event_applies_it_once() {
+        use chrono::{Duration, Utc};
+        use timemanager_backend::domain::enums::ClockSyncStatus;
+        use timemanager_backend::error::AppError;
+        use timemanager_backend::models::{
+            ClockSyncAction, ClockSyncBatchRequest, ClockSyncEventRequest,
+        };
+        use timemanager_backend::services::ClockSyncService;
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+
+        let recorded_at = Utc::now() - Duration::minutes(30);
+        let batch = || ClockSyncBatchRequest {
+            sent_at: Utc::now(),
+            events: vec![ClockSyncEventRequest {
+                idempotency_key: "offline-1".to_string(),
+                device_id: "phone-1".to_string(),
+                action: ClockSyncAction::ClockIn,
+                client_timestamp: recorded_at,
+                notes: None,
+                latitude: None,
+                longitude: None,
+                accuracy: None,
+            }],
+        };
+
+        let first = ClockSyncService::new(pool.clone());
+        let second = ClockSyncService::new(pool.clone());
+        let (a, b) = tokio::join!(
+            first.sync_batch(org_id, user_id, batch()),
+            second.sync_batch(org_id, user_id, batch()),
+        );
+        // One upload replays the event, the other sees it as a duplicate, or is told to
+        // retry while the event is being replayed
+        let mut results = Vec::new();
+        for response in [a, b] {
+            match response {
+                Ok(response) => results.extend(response.results),
+                Err(e) => assert!(matches!(e, AppError::Conflict(_)), "{}", e),
+            }
+        }
+        let replayed: Vec<_> = results.iter().filter(|r| !r.duplicate).collect();
+        assert_eq!(replayed.len(), 1);
+        assert_eq!(replayed[0].status, ClockSyncStatus::Applied);
+
+        // A later retry returns the stored outcome
+        let retry = ClockSyncService::new(pool.clone())
+            .sync_batch(org_id, user_id, batch())
+            .await
+            .expect("Sync failed");
+        assert!(retry.results[0].duplicate);
+        assert_eq!(retry.results[0].status, ClockSyncStatus::Applied);
+        assert_eq!(retry.results[0].clock_entry_id, replayed[0].clock_entry_id);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires database setup"]
+    async fn test_abandoned_sync_reservation_is_taken_over() {
+        use chrono::{Duration, Utc};
+        use diesel::sql_types::{Text, Timestamptz, Uuid as SqlUuid};
+        use timemanager_backend::domain::enums::ClockSyncStatus;
+        use timemanager_backend::error::AppError;
+        use timemanager_backend::models::{
+            ClockSyncAction, ClockSyncBatchRequest, ClockSyncEventRequest,
+        };
+        use timemanager_backend::services::ClockSyncService;
+
+        let pool = create_test_pool();
+        let (org_id, user_id) = seed_org_and_user(&pool).await;
+
+        let recorded_at = Utc::now() - Duration::minutes(30);
+        let batch = |key: &str| ClockSyncBatchRequest {
+            sent_at: Utc::now(),
+            events: vec![ClockSyncEventRequest {
+                idempotency_key: key.to_string(),
+                device_id: "phone-1".to_string(),
+                action: ClockSyncAction::ClockIn,
+                client_timestamp: recorded_at,
+                notes: None,
+                latitude: None,
+                longitude: None,
+                accuracy: None,
+            }],
+        };
+
+        // Reservations left behind by uploads that never finished
+        let mut conn = pool.get().await.expect("Failed to get connection");
+        for (key, reserved_at) in [
+            ("stale", Utc::now() - Duration::hours(1)),
+            ("recent", Utc::now()),
+        ] {
+            let reservation = diesel::sql_query(
+                "INSERT INTO clock_sync_events \
+                 (organization_id, user_id, device_id, idempotency_key, action, client_timestamp, status, reserved_at) \
+                 VALUES ($1, $2, 'phone-1', $3, 'clock_in', $4, 'pending', $5)",
+            )
+            .bind::<SqlUuid, _>(org_id)
+            .bind::<SqlUuid, _>(user_id)
+            .bind::<Text, _>(key)
+            .bind::<Timestamptz, _>(recorded_at)
+            .bind::<Timestamptz, _>(reserved_at);
+            diesel_async::RunQueryDsl::execute(reservation, &mut conn)
+                .await
+                .expect("Failed to seed reservation");
+        }
+
+        let service = ClockSyncService::new(pool.clone());
+
+        // A reservation that may still be replayed asks for a retry
+        let result = service.sync_batch(org_id, user_id, batch("recent")).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        // An abandoned one is replayed by the next upload
+        let response = service
+            .sync_batch(org_id, user_id, batch("stale"))
+            .await
+            .expect("Sync failed");
+        assert!(!response.results[0].duplicate);
+        assert_eq!(response.results[0].status, ClockSyncStatus::Applied);
+        assert!(response.results[0].clock_entry_id.is_some());
+    }
+
     #[test]
     fn test_repository_types_compile() {
         // This test verifies that repository types compile correctly
@@ -29,6 +29,14 @@ describe('Auth Integration Tests', () => {
       user_name: 'Test User',
       total_hours_worked: 0,
       theoretical_hours: 40,
+      theoretical_breakdown: {
+        planned_hours: 40,
+        closed_day_hours: 0,
+        absence_hours: 0,
+        expected_hours: 40,
+        closed_days: 0,
+        absence_days: 0,
+      },
       hours_variance: 0,
       punctuality_rate: 100,
       days_worked: 0,
@@ -7,9 +7,11 @@
 import { apiRequest } from './client';
 import { ABSENCE_TYPE_ENDPOINTS } from '../config/constants';
 import type {
+  AbsenceApprovalStep,
   AbsenceType,
   CreateAbsenceTypeRequest,
   UpdateAbsenceTypeRequest,
+  UpdateApprovalStepsRequest,
 } from '../types/absence';
 
 /**
@@ -97,6 +99,37 @@ export const absenceTypesApi = {
       url: ABSENCE_TYPE_ENDPOINTS.DELETE(id),
     });
   },
+
+  /**
+   * Get the approval chain of an absence type (Manager+)
+   *
+   * @param id - Absence type ID
+   * @returns Approval steps in order
+   */
+  getApprovalSteps: async (id: string): Promise<AbsenceApprovalStep[]> => {
+    return apiRequest<AbsenceApprovalStep[]>({
+      method: 'GET',
+      url: ABSENCE_TYPE_ENDPOINTS.APPROVAL_STEPS(id),
+    });
+  },
+
+  /**
+   * Replace the approval chain of an absence type (Admin+)
+   *
+   * @param id - Absence type ID
+   * @param data - Steps in order, empty for a single manager approval
+   * @returns Saved approval steps
+   */
+  updateApprovalSteps: async (
+    id: string,
+    data: UpdateApprovalStepsRequest
+  ): Promise<AbsenceApprovalStep[]> => {
+    return apiRequest<AbsenceApprovalStep[]>({
+      method: 'PUT',
+      url: ABSENCE_TYPE_ENDPOINTS.APPROVAL_STEPS(id),
+      data,
+    });
+  },
 };
 
 /**
@@ -108,4 +141,6 @@ export const {
   create: createAbsenceType,
   update: updateAbsenceType,
   delete: deleteAbsenceType,
+  getApprovalSteps: getAbsenceApprovalSteps,
+  updateApprovalSteps: updateAbsenceApprovalSteps,
 } = absenceTypesApi;
@@ -8,8 +8,11 @@ import { apiRequest } from './client';
 import { ABSENCE_ENDPOINTS } from '../config/constants';
 import type {
   Absence,
+  AbsencePreview,
+  AbsenceSettings,
   PaginatedAbsences,
   CreateAbsenceRequest,
+  ApproveAbsenceRequest,
   RejectAbsenceRequest,
   AbsenceFilter,
   PendingAbsenceFilter,
@@ -79,6 +82,51 @@ export const absencesApi = {
     });
   },
 
+  /**
+   * Preview the days or hours an absence request would take
+   *
+   * @param data - Absence request data
+   * @returns Charged dates, days or hours, and the balance left afterwards
+   */
+  preview: async (data: CreateAbsenceRequest): Promise<AbsencePreview> => {
+    const params = new URLSearchParams();
+    Object.entries(data).forEach(([key, value]) => {
+      if (value !== undefined && value !== null && value !== '') {
+        params.append(key, String(value));
+      }
+    });
+    return apiRequest<AbsencePreview>({
+      method: 'GET',
+      url: `${ABSENCE_ENDPOINTS.PREVIEW}?${params.toString()}`,
+    });
+  },
+
+  /**
+   * Get the absence settings of the organization (Manager+)
+   *
+   * @returns Absence settings
+   */
+  getSettings: async (): Promise<AbsenceSettings> => {
+    return apiRequest<AbsenceSettings>({
+      method: 'GET',
+      url: ABSENCE_ENDPOINTS.SETTINGS,
+    });
+  },
+
+  /**
+   * Update the absence settings of the organization (Admin+)
+   *
+   * @param data - New settings
+   * @returns Updated absence settings
+   */
+  updateSettings: async (data: AbsenceSettings): Promise<AbsenceSettings> => {
+    return apiRequest<AbsenceSettings>({
+      method: 'PUT',
+      url: ABSENCE_ENDPOINTS.SETTINGS,
+      data,
+    });
+  },
+
   /**
    * List pending absences for approval (Manager+)
    *
@@ -107,15 +155,17 @@ export const absencesApi = {
   },
 
   /**
-   * Approve an absence request (Manager+)
+   * Approve the next step of an absence request (Manager+)
    *
    * @param id - Absence ID
-   * @returns Approved absence
+   * @param data - Optional approval comment
+   * @returns Absence, still pending while steps remain
    */
-  approve: async (id: string): Promise<Absence> => {
+  approve: async (id: string, data?: ApproveAbsenceRequest): Promise<Absence> => {
     return apiRequest<Absence>({
       method: 'POST',
       url: ABSENCE_ENDPOINTS.APPROVE(id),
+      data: data || {},
     });
   },
 
@@ -155,6 +205,9 @@ export const {
   list: listAbsences,
   get: getAbsence,
   create: createAbsence,
+  preview: previewAbsence,
+  getSettings: getAbsenceSettings,
+  updateSettings: updateAbsenceSettings,
   listPending: listPendingAbsences,
   approve: approveAbsence,
   reject: rejectAbsence,
@@ -140,8 +140,9 @@ export const ScheduleForm: FC<ScheduleFormProps> = ({
         newErrors.days = 'All active days must have start and end times';
         break;
       }
-      if (day.start_time >= day.end_time) {
-        newErrors.days = 'End time must be after start time';
+      // An end time before the start time means an overnight shift
+      if (day.start_time === day.end_time) {
+        newErrors.days = 'Start and end times must differ';
         break;
       }
     }
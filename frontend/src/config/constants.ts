@@ -114,6 +114,7 @@ export const ABSENCE_TYPE_ENDPOINTS = {
   GET: (id: string) => `/absence-types/${id}`,
   UPDATE: (id: string) => `/absence-types/${id}`,
   DELETE: (id: string) => `/absence-types/${id}`,
+  APPROVAL_STEPS: (id: string) => `/absence-types/${id}/approval-steps`,
 } as const;
 
 /**
@@ -123,6 +124,8 @@ export const ABSENCE_ENDPOINTS = {
   LIST: '/absences',
   CREATE: '/absences',
   PENDING: '/absences/pending',
+  PREVIEW: '/absences/preview',
+  SETTINGS: '/absences/settings',
   GET: (id: string) => `/absences/${id}`,
   APPROVE: (id: string) => `/absences/${id}/approve`,
   REJECT: (id: string) => `/absences/${id}/reject`,
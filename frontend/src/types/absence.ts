@@ -4,6 +4,8 @@
  * TypeScript type definitions for absence/leave management.
  */
 
+import { UserRole } from './auth';
+
 /**
  * Absence status enum
  */
@@ -14,6 +16,23 @@ export enum AbsenceStatus {
   Cancelled = 'cancelled',
 }
 
+/**
+ * Part of a date taken by an absence
+ */
+export enum AbsenceHalfDay {
+  Full = 'full',
+  Morning = 'morning',
+  Afternoon = 'afternoon',
+}
+
+/**
+ * Unit of an absence type, for requests and balances
+ */
+export enum AbsenceUnit {
+  Days = 'days',
+  Hours = 'hours',
+}
+
 /**
  * Absence type configuration
  */
@@ -25,6 +44,7 @@ export interface AbsenceType {
   requires_approval: boolean;
   affects_balance: boolean;
   is_paid: boolean;
+  unit: AbsenceUnit;
   created_at: string;
   updated_at: string;
 }
@@ -47,6 +67,12 @@ export interface Absence {
   type_color: string;
   start_date: string;
   end_date: string;
+  start_half: AbsenceHalfDay;
+  end_half: AbsenceHalfDay;
+  /** Set for hourly absence types (HH:MM:SS) */
+  start_time: string | null;
+  end_time: string | null;
+  hours: number | null;
   days_count: number;
   status: AbsenceStatus;
   reason: string | null;
@@ -54,9 +80,45 @@ export interface Absence {
   approved_by: string | null;
   approver_name: string | null;
   approved_at: string | null;
+  /** Roles approving the absence, one per step */
+  required_approvals: UserRole[];
+  /** Steps approved so far */
+  approvals: AbsenceApproval[];
   created_at: string;
 }
 
+/**
+ * Approved step of an absence
+ */
+export interface AbsenceApproval {
+  step_order: number;
+  approver_id: string | null;
+  approver_name: string | null;
+  comment: string | null;
+  approved_at: string;
+}
+
+/**
+ * Step of the approval chain of an absence type
+ */
+export interface AbsenceApprovalStep {
+  step_order: number;
+  /** Lowest role allowed to approve the step (Manager or Admin) */
+  approver_role: UserRole;
+  /** Only required for absences of more than this many days */
+  min_days: number | null;
+}
+
+/**
+ * Replace approval chain request, steps in order
+ */
+export interface UpdateApprovalStepsRequest {
+  steps: Array<{
+    approver_role: UserRole;
+    min_days?: number | null;
+  }>;
+}
+
 /**
  * Leave balance for a specific absence type
  */
@@ -67,6 +129,8 @@ export interface LeaveBalance {
   type_name: string;
   type_code: string;
   type_color: string;
+  /** Unit the amounts are held in */
+  unit: AbsenceUnit;
   year: number;
   initial_balance: number;
   used: number;
@@ -106,6 +170,7 @@ export interface CreateAbsenceTypeRequest {
   requires_approval?: boolean;
   affects_balance?: boolean;
   is_paid?: boolean;
+  unit?: AbsenceUnit;
 }
 
 /**
@@ -118,6 +183,7 @@ export interface UpdateAbsenceTypeRequest {
   requires_approval?: boolean;
   affects_balance?: boolean;
   is_paid?: boolean;
+  unit?: AbsenceUnit;
 }
 
 /**
@@ -127,9 +193,50 @@ export interface CreateAbsenceRequest {
   type_id: string;
   start_date: string;
   end_date: string;
+  /** Part of the start date taken (full day when omitted) */
+  start_half?: AbsenceHalfDay;
+  /** Part of the end date taken (full day when omitted) */
+  end_half?: AbsenceHalfDay;
+  /** Time range on a single date, required for hourly absence types */
+  start_time?: string;
+  end_time?: string;
   reason?: string;
 }
 
+/**
+ * Cost of an absence request, computed before it is submitted
+ */
+export interface AbsencePreview {
+  type_id: string;
+  unit: AbsenceUnit;
+  start_date: string;
+  end_date: string;
+  /** Dates charged, at least partly */
+  working_dates: string[];
+  days_count: number;
+  /** Set for hourly absence types */
+  hours: number | null;
+  /** Remaining balance before the request, null when the type has no balance */
+  remaining_balance: number | null;
+  /** Remaining balance once the request is approved */
+  remaining_after: number | null;
+}
+
+/**
+ * Absence settings of an organization
+ */
+export interface AbsenceSettings {
+  /** Working week of users without a work schedule (0 = Monday, 6 = Sunday) */
+  working_days: number[];
+}
+
+/**
+ * Approve absence request
+ */
+export interface ApproveAbsenceRequest {
+  comment?: string;
+}
+
 /**
  * Reject absence request
  */
@@ -71,6 +71,8 @@ export interface ClockRestriction {
   clock_out_earliest: string | null;
   clock_out_latest: string | null;
   enforce_schedule: boolean;
+  schedule_early_minutes: number;
+  schedule_late_minutes: number;
   require_manager_approval: boolean;
   is_active: boolean;
   max_daily_clock_events: number | null;
@@ -95,6 +97,8 @@ export interface ClockRestrictionResponse {
   clock_out_earliest: string | null;
   clock_out_latest: string | null;
   enforce_schedule: boolean;
+  schedule_early_minutes: number;
+  schedule_late_minutes: number;
   require_manager_approval: boolean;
   is_active: boolean;
   max_daily_clock_events: number | null;
@@ -123,6 +127,8 @@ export interface CreateClockRestrictionRequest {
   clock_out_earliest?: string | null;
   clock_out_latest?: string | null;
   enforce_schedule?: boolean;
+  schedule_early_minutes?: number;
+  schedule_late_minutes?: number;
   require_manager_approval?: boolean;
   max_daily_clock_events?: number | null;
 }
@@ -137,6 +143,8 @@ export interface UpdateClockRestrictionRequest {
   clock_out_earliest?: string | null;
   clock_out_latest?: string | null;
   enforce_schedule?: boolean;
+  schedule_early_minutes?: number;
+  schedule_late_minutes?: number;
   require_manager_approval?: boolean;
   is_active?: boolean;
   max_daily_clock_events?: number | null;
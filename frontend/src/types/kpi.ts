@@ -4,6 +4,18 @@
  * TypeScript type definitions for KPI stats and dashboards.
  */
 
+/**
+ * Breakdown of expected hours
+ */
+export interface ExpectedHours {
+  planned_hours: number;
+  closed_day_hours: number;
+  absence_hours: number;
+  expected_hours: number;
+  closed_days: number;
+  absence_days: number;
+}
+
 /**
  * User KPIs
  */
@@ -12,6 +24,7 @@ export interface UserKPIs {
   user_name: string;
   total_hours_worked: number;
   theoretical_hours: number;
+  theoretical_breakdown: ExpectedHours;
   hours_variance: number;
   punctuality_rate: number;
   days_worked: number;
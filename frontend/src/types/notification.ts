@@ -14,6 +14,7 @@ export enum NotificationType {
   ClockCorrection = 'clock_correction',
   ClockApproved = 'clock_approved',
   ClockRejected = 'clock_rejected',
+  ClockAutoClosed = 'clock_auto_closed',
 }
 
 /**
@@ -72,6 +73,7 @@ export const NOTIFICATION_TYPE_LABELS: Record<NotificationType, string> = {
   [NotificationType.ClockCorrection]: 'Correction de pointage',
   [NotificationType.ClockApproved]: 'Pointage approuvé',
   [NotificationType.ClockRejected]: 'Pointage refusé',
+  [NotificationType.ClockAutoClosed]: 'Pointage clôturé automatiquement',
 };
 
 /**
@@ -84,6 +86,7 @@ export const NOTIFICATION_TYPE_ICONS: Record<NotificationType, string> = {
   [NotificationType.ClockCorrection]: 'edit',
   [NotificationType.ClockApproved]: 'check-circle',
   [NotificationType.ClockRejected]: 'x-circle',
+  [NotificationType.ClockAutoClosed]: 'alert-triangle',
 };
 
 /**
@@ -96,4 +99,5 @@ export const NOTIFICATION_TYPE_COLORS: Record<NotificationType, string> = {
   [NotificationType.ClockCorrection]: 'text-blue-600',
   [NotificationType.ClockApproved]: 'text-green-600',
   [NotificationType.ClockRejected]: 'text-red-600',
+  [NotificationType.ClockAutoClosed]: 'text-orange-600',
 };